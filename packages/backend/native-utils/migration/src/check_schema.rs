@@ -0,0 +1,810 @@
+//! Compares the SeaORM entities of native-utils with the schema of the
+//! database, to find changes made by TypeORM migrations that are not reflected
+//! in the entities yet.
+//!
+//! The entities were generated without indexes other than primary keys and
+//! unique constraints, so other indexes are only compared with columns marked
+//! as `indexed`.
+
+use native_utils::model::entity::{prelude::*, sea_orm_active_enums::*};
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{
+        ActiveEnum, ColumnTrait, DbBackend, DbConn, EntityTrait, Iterable, PrimaryKeyToColumn,
+        Statement,
+    },
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::error::Error;
+
+/// Tables in the database that have no entities on purpose.
+const IGNORED_TABLES: &[&str] = &["seaql_migrations"];
+/// Prefixes of chart tables, which are managed by TypeORM without entities.
+const IGNORED_TABLE_PREFIXES: &[&str] = &["__chart__", "__chart_day__"];
+
+fn is_ignored(table: &str) -> bool {
+    IGNORED_TABLES.contains(&table)
+        || IGNORED_TABLE_PREFIXES
+            .iter()
+            .any(|prefix| table.starts_with(prefix))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schema {
+    pub tables: BTreeMap<String, Table>,
+    /// Values of enum types, sorted.
+    pub enums: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Table {
+    pub columns: BTreeMap<String, Column>,
+    pub primary_key: Vec<String>,
+    /// Columns with a single-column unique constraint other than the primary
+    /// key.
+    pub unique: BTreeSet<String>,
+    /// Columns with a single-column unique index that is not a constraint.
+    pub unique_index: BTreeSet<String>,
+    /// Columns with a single-column index.
+    pub indexed: BTreeSet<String>,
+}
+
+impl Table {
+    /// A single-column primary key is unique anyway, with or without another
+    /// unique index.
+    fn remove_primary_key_from_unique(&mut self) {
+        if let [pk] = self.primary_key.as_slice() {
+            self.unique.remove(pk);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Column {
+    /// `udt_name` of the type in PostgreSQL, e.g. `varchar` or `_int4`.
+    pub udt: String,
+    pub nullable: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Diff {
+    MissingTable(String),
+    ExtraTable(String),
+    MissingColumn {
+        table: String,
+        column: String,
+    },
+    ExtraColumn {
+        table: String,
+        column: String,
+    },
+    Type {
+        table: String,
+        column: String,
+        entity: String,
+        db: String,
+    },
+    Nullable {
+        table: String,
+        column: String,
+        entity: bool,
+    },
+    PrimaryKey {
+        table: String,
+        entity: Vec<String>,
+        db: Vec<String>,
+    },
+    MissingUnique {
+        table: String,
+        column: String,
+    },
+    ExtraUnique {
+        table: String,
+        column: String,
+    },
+    MissingIndex {
+        table: String,
+        column: String,
+    },
+    MissingEnum(String),
+    EnumValues {
+        name: String,
+        entity: Vec<String>,
+        db: Vec<String>,
+    },
+}
+
+/// Lines starting with `-` are only in the entities, `+` only in the
+/// database, and `~` differ between them.
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn null(nullable: bool) -> &'static str {
+            match nullable {
+                true => "NULL",
+                false => "NOT NULL",
+            }
+        }
+
+        match self {
+            Diff::MissingTable(table) => write!(f, "- table {}", table),
+            Diff::ExtraTable(table) => write!(f, "+ table {}", table),
+            Diff::MissingColumn { table, column } => write!(f, "- column {}.{}", table, column),
+            Diff::ExtraColumn { table, column } => write!(f, "+ column {}.{}", table, column),
+            Diff::Type {
+                table,
+                column,
+                entity,
+                db,
+            } => write!(f, "~ column {}.{}: {} -> {}", table, column, entity, db),
+            Diff::Nullable {
+                table,
+                column,
+                entity,
+            } => write!(
+                f,
+                "~ column {}.{}: {} -> {}",
+                table,
+                column,
+                null(*entity),
+                null(!entity)
+            ),
+            Diff::PrimaryKey { table, entity, db } => write!(
+                f,
+                "~ primary key of {}: ({}) -> ({})",
+                table,
+                entity.join(", "),
+                db.join(", ")
+            ),
+            Diff::MissingUnique { table, column } => write!(f, "- unique {}.{}", table, column),
+            Diff::ExtraUnique { table, column } => write!(f, "+ unique {}.{}", table, column),
+            Diff::MissingIndex { table, column } => write!(f, "- index {}.{}", table, column),
+            Diff::MissingEnum(name) => write!(f, "- enum {}", name),
+            Diff::EnumValues { name, entity, db } => write!(
+                f,
+                "~ enum {}: ({}) -> ({})",
+                name,
+                entity.join(", "),
+                db.join(", ")
+            ),
+        }
+    }
+}
+
+/// Returns `udt_name` of the PostgreSQL type that SeaORM maps `ty` to.
+fn udt_name(ty: &ColumnType) -> String {
+    match ty {
+        ColumnType::Char(_) => "bpchar".to_string(),
+        ColumnType::String(_) => "varchar".to_string(),
+        ColumnType::Text => "text".to_string(),
+        ColumnType::TinyInteger | ColumnType::SmallInteger => "int2".to_string(),
+        ColumnType::Integer => "int4".to_string(),
+        ColumnType::BigInteger => "int8".to_string(),
+        ColumnType::Float => "float4".to_string(),
+        ColumnType::Double => "float8".to_string(),
+        ColumnType::Decimal(_) | ColumnType::Money(_) => "numeric".to_string(),
+        ColumnType::DateTime | ColumnType::Timestamp => "timestamp".to_string(),
+        ColumnType::TimestampWithTimeZone => "timestamptz".to_string(),
+        ColumnType::Time => "time".to_string(),
+        ColumnType::Date => "date".to_string(),
+        ColumnType::Binary(_) | ColumnType::VarBinary(_) => "bytea".to_string(),
+        ColumnType::Boolean => "bool".to_string(),
+        ColumnType::Json => "json".to_string(),
+        ColumnType::JsonBinary => "jsonb".to_string(),
+        ColumnType::Uuid => "uuid".to_string(),
+        ColumnType::Custom(name) | ColumnType::Enum { name, .. } => name.to_string(),
+        ColumnType::Array(ty) => format!("_{}", udt_name(ty)),
+        ty => format!("{:?}", ty),
+    }
+}
+
+/// Returns whether a column of `entity` type can be of `db` type. Rust
+/// `String`s without `column_type` are mapped to `varchar`, because the
+/// entities do not tell `char` from `varchar`.
+fn same_type(entity: &str, db: &str) -> bool {
+    entity == db || (entity == "varchar" && db == "bpchar")
+}
+
+fn entity_table<E: EntityTrait + Default>(schema: &mut Schema) {
+    let mut table = Table::default();
+    for col in E::Column::iter() {
+        let def = col.def();
+        let name = col.to_string();
+        // `ColumnDef` has no getters of these in SeaORM 0.11.
+        if def.clone().unique() == def {
+            table.unique.insert(name.clone());
+        }
+        if def.clone().indexed() == def {
+            table.indexed.insert(name.clone());
+        }
+        table.columns.insert(
+            name,
+            Column {
+                udt: udt_name(def.get_column_type()),
+                nullable: def.is_null(),
+            },
+        );
+    }
+    table.primary_key = E::PrimaryKey::iter()
+        .map(|pk| pk.into_column().to_string())
+        .collect();
+    table.remove_primary_key_from_unique();
+    schema
+        .tables
+        .insert(E::default().table_name().to_string(), table);
+}
+
+fn entity_enum<E: ActiveEnum<Value = String>>(schema: &mut Schema) {
+    let mut values = E::values();
+    values.sort();
+    schema.enums.insert(E::name().to_string(), values);
+}
+
+/// Returns the schema that the entities expect.
+pub fn entity_schema() -> Schema {
+    let mut schema = Schema::default();
+
+    macro_rules! tables {
+        ($($entity:ident),* $(,)?) => {
+            $(entity_table::<$entity>(&mut schema);)*
+        };
+    }
+    macro_rules! enums {
+        ($($enum:ident),* $(,)?) => {
+            $(entity_enum::<$enum>(&mut schema);)*
+        };
+    }
+
+    tables!(
+        AbuseUserReport,
+        AccessToken,
+        ActiveUserSketch,
+        Ad,
+        Announcement,
+        AnnouncementRead,
+        Antenna,
+        App,
+        AttestationChallenge,
+        AuthSession,
+        Blocking,
+        Channel,
+        ChannelFollowing,
+        ChannelNotePining,
+        ChartSketch,
+        Clip,
+        ClipNote,
+        DriveBlob,
+        DriveFile,
+        DriveFolder,
+        Emoji,
+        FollowRequest,
+        Following,
+        GalleryLike,
+        GalleryPost,
+        Hashtag,
+        Instance,
+        InstanceDelivery,
+        MessagingMessage,
+        Meta,
+        Migrations,
+        ModerationLog,
+        MutedNote,
+        Muting,
+        Note,
+        NoteEdit,
+        NoteFavorite,
+        NoteReaction,
+        NoteThreadMuting,
+        NoteUnread,
+        NoteWatching,
+        Notification,
+        Page,
+        PageLike,
+        PasswordResetRequest,
+        Poll,
+        PollVote,
+        PromoNote,
+        PromoRead,
+        RegistrationTicket,
+        RegistryItem,
+        Relay,
+        RenoteMuting,
+        Signin,
+        SwSubscription,
+        UsedUsername,
+        User,
+        UserGroup,
+        UserGroupInvitation,
+        UserGroupInvite,
+        UserGroupJoining,
+        UserIp,
+        UserKeypair,
+        UserList,
+        UserListJoining,
+        UserNotePining,
+        UserPending,
+        UserProfile,
+        UserPublickey,
+        UserSecurityKey,
+        Webhook,
+    );
+    enums!(
+        AntennaSrcEnum,
+        MetaSensitivemediadetectionEnum,
+        MetaSensitivemediadetectionsensitivityEnum,
+        MutedNoteReasonEnum,
+        NoteVisibilityEnum,
+        NotificationTypeEnum,
+        PageVisibilityEnum,
+        PollNotevisibilityEnum,
+        RelayStatusEnum,
+        UserProfileFfvisibilityEnum,
+    );
+
+    schema
+}
+
+const COLUMNS_QUERY: &str = r#"
+SELECT c.table_name, c.column_name, c.udt_name, c.is_nullable = 'YES'
+FROM information_schema.columns c
+JOIN information_schema.tables t
+  ON t.table_schema = c.table_schema AND t.table_name = c.table_name
+WHERE c.table_schema = current_schema() AND t.table_type = 'BASE TABLE'
+"#;
+
+const INDEXES_QUERY: &str = r#"
+SELECT t.relname::text, ix.indisprimary, ix.indisunique,
+  EXISTS (SELECT 1 FROM pg_constraint c WHERE c.conindid = ix.indexrelid AND c.contype = 'u'),
+  string_agg(a.attname::text, ',' ORDER BY k.ord)
+FROM pg_index ix
+JOIN pg_class t ON t.oid = ix.indrelid
+JOIN pg_namespace n ON n.oid = t.relnamespace
+CROSS JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, ord)
+JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+WHERE n.nspname = current_schema()
+GROUP BY ix.indexrelid, t.relname, ix.indisprimary, ix.indisunique
+"#;
+
+const ENUMS_QUERY: &str = r#"
+SELECT t.typname::text, e.enumlabel::text
+FROM pg_type t
+JOIN pg_enum e ON e.enumtypid = t.oid
+JOIN pg_namespace n ON n.oid = t.typnamespace
+WHERE n.nspname = current_schema()
+"#;
+
+/// Reads the schema of the current schema of the database.
+#[tracing::instrument(skip_all)]
+pub async fn db_schema(db: &DbConn) -> Result<Schema, DbErr> {
+    let mut schema = Schema::default();
+    let query = |sql: &str| Statement::from_string(DbBackend::Postgres, sql.to_string());
+
+    for row in db.query_all(query(COLUMNS_QUERY)).await? {
+        let (table, column, udt, nullable): (String, String, String, bool) =
+            row.try_get_many_by_index()?;
+        if is_ignored(&table) {
+            continue;
+        }
+        schema
+            .tables
+            .entry(table)
+            .or_default()
+            .columns
+            .insert(column, Column { udt, nullable });
+    }
+
+    for row in db.query_all(query(INDEXES_QUERY)).await? {
+        let (table_name, primary, unique, constraint, columns): (String, bool, bool, bool, String) =
+            row.try_get_many_by_index()?;
+        let Some(table) = schema.tables.get_mut(&table_name) else {
+            continue;
+        };
+        let columns: Vec<String> = columns.split(',').map(str::to_string).collect();
+        if primary {
+            table.primary_key = columns;
+        } else if let [column] = columns.as_slice() {
+            match (unique, constraint) {
+                (true, true) => table.unique.insert(column.clone()),
+                (true, false) => table.unique_index.insert(column.clone()),
+                _ => false,
+            };
+            table.indexed.insert(column.clone());
+        }
+    }
+
+    for row in db.query_all(query(ENUMS_QUERY)).await? {
+        let (name, value): (String, String) = row.try_get_many_by_index()?;
+        schema.enums.entry(name).or_default().push(value);
+    }
+    for table in schema.tables.values_mut() {
+        table.remove_primary_key_from_unique();
+    }
+    for values in schema.enums.values_mut() {
+        values.sort();
+    }
+
+    Ok(schema)
+}
+
+/// Returns the differences between the schema of the entities and that of the
+/// database. Enums only in the database are not reported, since columns of
+/// them are reported as type differences.
+pub fn compare(entity: &Schema, db: &Schema) -> Vec<Diff> {
+    let mut diffs = Vec::new();
+
+    for (name, table) in &entity.tables {
+        let Some(db_table) = db.tables.get(name) else {
+            diffs.push(Diff::MissingTable(name.clone()));
+            continue;
+        };
+        compare_table(name, table, db_table, &mut diffs);
+    }
+    for name in db.tables.keys() {
+        if !entity.tables.contains_key(name) {
+            diffs.push(Diff::ExtraTable(name.clone()));
+        }
+    }
+
+    for (name, values) in &entity.enums {
+        match db.enums.get(name) {
+            None => diffs.push(Diff::MissingEnum(name.clone())),
+            Some(db_values) if db_values != values => diffs.push(Diff::EnumValues {
+                name: name.clone(),
+                entity: values.clone(),
+                db: db_values.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    diffs
+}
+
+fn compare_table(name: &str, table: &Table, db_table: &Table, diffs: &mut Vec<Diff>) {
+    let table_name = || name.to_string();
+
+    for (column_name, column) in &table.columns {
+        let Some(db_column) = db_table.columns.get(column_name) else {
+            diffs.push(Diff::MissingColumn {
+                table: table_name(),
+                column: column_name.clone(),
+            });
+            continue;
+        };
+        if !same_type(&column.udt, &db_column.udt) {
+            diffs.push(Diff::Type {
+                table: table_name(),
+                column: column_name.clone(),
+                entity: column.udt.clone(),
+                db: db_column.udt.clone(),
+            });
+        }
+        if column.nullable != db_column.nullable {
+            diffs.push(Diff::Nullable {
+                table: table_name(),
+                column: column_name.clone(),
+                entity: column.nullable,
+            });
+        }
+    }
+    for column_name in db_table.columns.keys() {
+        if !table.columns.contains_key(column_name) {
+            diffs.push(Diff::ExtraColumn {
+                table: table_name(),
+                column: column_name.clone(),
+            });
+        }
+    }
+
+    if table.primary_key != db_table.primary_key {
+        diffs.push(Diff::PrimaryKey {
+            table: table_name(),
+            entity: table.primary_key.clone(),
+            db: db_table.primary_key.clone(),
+        });
+    }
+    for column in table.unique.iter().filter(|column| {
+        !db_table.unique.contains(*column) && !db_table.unique_index.contains(*column)
+    }) {
+        diffs.push(Diff::MissingUnique {
+            table: table_name(),
+            column: column.clone(),
+        });
+    }
+    for column in db_table.unique.difference(&table.unique) {
+        diffs.push(Diff::ExtraUnique {
+            table: table_name(),
+            column: column.clone(),
+        });
+    }
+    for column in table.indexed.difference(&db_table.indexed) {
+        diffs.push(Diff::MissingIndex {
+            table: table_name(),
+            column: column.clone(),
+        });
+    }
+}
+
+/// Prints the differences between the entities and the schema of `db`.
+pub async fn check(db: &DbConn) -> Result<(), Error> {
+    if db.get_database_backend() != DbBackend::Postgres {
+        return Err(Error::InvalidArgument(
+            "check-schema only supports PostgreSQL".to_string(),
+        ));
+    }
+    let diffs = compare(&entity_schema(), &db_schema(db).await?);
+    for diff in &diffs {
+        println!("{}", diff);
+    }
+    match diffs.len() {
+        0 => Ok(()),
+        n => Err(Error::SchemaMismatch(n)),
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use sea_orm_migration::prelude::ColumnType;
+    use sea_orm_migration::sea_orm::sea_query::SeaRc;
+
+    use super::{compare, entity_schema, is_ignored, udt_name, Column, Diff, Schema, Table};
+
+    fn column(udt: &str, nullable: bool) -> Column {
+        Column {
+            udt: udt.to_string(),
+            nullable,
+        }
+    }
+
+    fn table() -> Table {
+        Table {
+            columns: [
+                ("id".to_string(), column("varchar", false)),
+                ("name".to_string(), column("varchar", true)),
+            ]
+            .into(),
+            primary_key: vec!["id".to_string()],
+            unique: ["name".to_string()].into(),
+            unique_index: Default::default(),
+            indexed: ["name".to_string()].into(),
+        }
+    }
+
+    #[test]
+    fn udt_names() {
+        assert_eq!(udt_name(&ColumnType::TimestampWithTimeZone), "timestamptz");
+        assert_eq!(
+            udt_name(&ColumnType::Array(SeaRc::new(ColumnType::String(None)))),
+            "_varchar"
+        );
+    }
+
+    #[test]
+    fn ignored_tables() {
+        assert!(is_ignored("seaql_migrations"));
+        assert!(is_ignored("__chart_day__notes"));
+        assert!(!is_ignored("note"));
+    }
+
+    #[test]
+    fn entities() {
+        let schema = entity_schema();
+        let note = &schema.tables["note"];
+        assert_eq!(note.primary_key, vec!["id".to_string()]);
+        assert_eq!(note.columns["text"], column("text", true));
+        assert_eq!(
+            note.columns["visibility"],
+            column("note_visibility_enum", false)
+        );
+        assert!(schema.tables["poll"].unique.is_empty());
+        assert!(schema.tables["user"].unique.contains("token"));
+        assert_eq!(
+            schema.enums["relay_status_enum"],
+            vec!["accepted", "rejected", "requesting"]
+        );
+    }
+
+    #[test]
+    fn same_schema() {
+        let schema = Schema {
+            tables: [("a".to_string(), table())].into(),
+            enums: [("e".to_string(), vec!["x".to_string()])].into(),
+        };
+        assert_eq!(compare(&schema, &schema), vec![]);
+    }
+
+    #[test]
+    fn drift() {
+        let entity = Schema {
+            tables: [
+                ("a".to_string(), table()),
+                ("b".to_string(), table()),
+                ("d".to_string(), table()),
+            ]
+            .into(),
+            enums: [
+                ("e".to_string(), vec!["x".to_string()]),
+                ("f".to_string(), vec!["y".to_string()]),
+            ]
+            .into(),
+        };
+
+        let mut a = table();
+        a.columns.insert("name".to_string(), column("text", false));
+        a.columns.insert("extra".to_string(), column("int4", false));
+        a.unique.clear();
+        a.indexed.clear();
+        let mut b = table();
+        b.columns.insert("id".to_string(), column("bpchar", false));
+        b.unique.clear();
+        b.unique_index.insert("name".to_string());
+        let mut c = table();
+        c.primary_key = vec![];
+        let db = Schema {
+            tables: [
+                ("a".to_string(), a),
+                ("b".to_string(), b),
+                ("c".to_string(), c),
+            ]
+            .into(),
+            enums: [("e".to_string(), vec!["x".to_string(), "z".to_string()])].into(),
+        };
+
+        let diffs = compare(&entity, &db);
+        assert_eq!(
+            diffs.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "~ column a.name: varchar -> text",
+                "~ column a.name: NULL -> NOT NULL",
+                "+ column a.extra",
+                "- unique a.name",
+                "- index a.name",
+                "- table d",
+                "+ table c",
+                "~ enum e: (x) -> (x, z)",
+                "- enum f",
+            ]
+        );
+        assert_eq!(
+            diffs[2],
+            Diff::ExtraColumn {
+                table: "a".to_string(),
+                column: "extra".to_string()
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod int_test {
+    use migration::{InitialSchema, Migrator};
+    use sea_orm_migration::{
+        prelude::*,
+        sea_orm::{ConnectionTrait, Database, DbBackend, DbConn, Statement},
+    };
+    use std::collections::BTreeMap;
+
+    use super::{entity_schema, is_ignored, Schema};
+
+    /// Columns of each table with whether they are nullable, and the primary
+    /// key. Types are not compared, since SQLite has no column types.
+    type Layout = BTreeMap<String, (BTreeMap<String, bool>, Vec<String>)>;
+
+    fn entity_layout(schema: &Schema) -> Layout {
+        schema
+            .tables
+            .iter()
+            .map(|(name, table)| {
+                let columns = table
+                    .columns
+                    .iter()
+                    .map(|(column, def)| (column.clone(), def.nullable))
+                    .collect();
+                (name.clone(), (columns, table.primary_key.clone()))
+            })
+            .collect()
+    }
+
+    async fn query(db: &DbConn, sql: &str) -> Vec<sea_orm_migration::sea_orm::QueryResult> {
+        db.query_all(Statement::from_string(DbBackend::Sqlite, sql.to_string()))
+            .await
+            .unwrap()
+    }
+
+    async fn table_names(db: &DbConn) -> Vec<String> {
+        query(
+            db,
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+        )
+        .await
+        .iter()
+        .map(|row| row.try_get_by_index(0).unwrap())
+        .collect()
+    }
+
+    async fn sqlite_layout(db: &DbConn) -> Layout {
+        let mut layout = Layout::new();
+        for table in table_names(db).await {
+            if is_ignored(&table) {
+                continue;
+            }
+            let mut columns = BTreeMap::new();
+            let mut primary_key = Vec::new();
+            let sql = format!(
+                "SELECT name, \"notnull\", pk FROM pragma_table_info('{}') ORDER BY pk",
+                table
+            );
+            for row in query(db, &sql).await {
+                let (name, not_null, pk): (String, bool, i32) =
+                    row.try_get_many_by_index().unwrap();
+                if pk > 0 {
+                    primary_key.push(name.clone());
+                }
+                columns.insert(name, !not_null);
+            }
+            layout.insert(table, (columns, primary_key));
+        }
+        layout
+    }
+
+    async fn count_migrations(db: &DbConn) -> usize {
+        query(db, "SELECT name FROM migrations").await.len()
+    }
+
+    #[tokio::test]
+    async fn migrated_schema_matches_entities() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+
+        let mut expected = entity_layout(&entity_schema());
+        let mut actual = sqlite_layout(&db).await;
+        // the released migrations dropping these tables do not run on SQLite
+        for table in ["antenna_note", "reversi_game", "reversi_matching"] {
+            assert!(actual.remove(table).is_some(), "{}", table);
+        }
+        // the primary key is reported in column order by SQLite
+        for (_, primary_key) in expected.values_mut().chain(actual.values_mut()) {
+            primary_key.sort();
+        }
+        let diffs: Vec<String> = expected
+            .keys()
+            .chain(actual.keys())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .filter(|table| expected.get(*table) != actual.get(*table))
+            .map(|table| {
+                format!(
+                    "{}: {:?} -> {:?}",
+                    table,
+                    expected.get(table),
+                    actual.get(table)
+                )
+            })
+            .collect();
+        assert_eq!(diffs, Vec::<String>::new());
+    }
+
+    #[tokio::test]
+    async fn initial_schema_up_and_down() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let manager = SchemaManager::new(&db);
+
+        for _ in 0..2 {
+            InitialSchema.up(&manager).await.unwrap();
+            assert!(manager.has_table("note").await.unwrap());
+            assert!(count_migrations(&db).await > 0);
+
+            InitialSchema.down(&manager).await.unwrap();
+            assert_eq!(table_names(&db).await, Vec::<String>::new());
+        }
+
+        // only checks that TypeORM has been run on existing databases
+        InitialSchema.up(&manager).await.unwrap();
+        let tables = table_names(&db).await.len();
+        let migrations = count_migrations(&db).await;
+        InitialSchema.up(&manager).await.unwrap();
+        assert_eq!(table_names(&db).await.len(), tables);
+        assert_eq!(count_migrations(&db).await, migrations);
+    }
+}
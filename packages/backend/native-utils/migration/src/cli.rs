@@ -0,0 +1,74 @@
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Runs the database migrations of Firefish")]
+pub struct Cli {
+    /// Path to the config file [default: ../../.config/default.yml]
+    #[arg(short, long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Print the SQL of the migrations instead of running them
+    #[cfg(feature = "dry-run")]
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Database URL (postgres:// or sqlite://), overriding the config file
+    #[arg(short = 'u', long, global = true, env = "DATABASE_URL")]
+    pub database_url: Option<String>,
+
+    /// Database schema, ignored for SQLite
+    #[arg(
+        short = 's',
+        long,
+        global = true,
+        env = "DATABASE_SCHEMA",
+        default_value = "public"
+    )]
+    pub database_schema: String,
+
+    /// Show debug messages
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Apply pending migrations (default)
+    Up {
+        /// Number of pending migrations to apply
+        #[arg(short, long)]
+        num: Option<u32>,
+    },
+    /// Rollback applied migrations
+    Down {
+        /// Number of applied migrations to rollback
+        #[arg(short, long, default_value_t = 1)]
+        num: u32,
+    },
+    /// Check the status of all migrations
+    Status,
+    /// Drop all tables, then reapply all migrations
+    Fresh,
+    /// Rollback all applied migrations, then reapply all migrations
+    Refresh,
+    /// Rollback all applied migrations
+    Reset,
+    /// Compare the entities of native-utils with the database schema
+    #[cfg(feature = "check-schema")]
+    CheckSchema,
+    /// Convert array columns to JSON, which is also done after applying migrations
+    #[cfg(feature = "convert")]
+    Convert {
+        /// Convert JSON columns back to arrays, for builds without the `noarray` feature
+        #[arg(long)]
+        reverse: bool,
+
+        /// Number of rows updated at once
+        #[arg(long, default_value_t = crate::vec_to_json::DEFAULT_BATCH_SIZE)]
+        batch_size: u64,
+    },
+}
@@ -0,0 +1,146 @@
+//! Prints the SQL of migrations without running them.
+//!
+//! Migrations are run against a mock connection of the same backend, and the
+//! statements they send are collected from the `debug-print` logs of SeaORM,
+//! as the transaction log of the mock connection does not expose them. Only
+//! built with the `dry-run` feature, which enables both features of SeaORM.
+
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{DatabaseConnection, DbBackend, DbConn, MockDatabase, MockExecResult},
+};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use tracing::{
+    field::Field, instrument::WithSubscriber, level_filters::LevelFilter, Event, Subscriber,
+};
+use tracing_subscriber::{
+    field::Visit,
+    filter::Targets,
+    layer::{Context, SubscriberExt},
+    Layer,
+};
+
+use migration::{has_typeorm_migrations, InitialSchema};
+
+use crate::error::Error;
+
+/// Number of results the mock connection returns, which bounds the number of
+/// statements a migration can run.
+const MOCK_RESULTS: usize = 4096;
+
+const MOCK_TARGET: &str = "sea_orm::driver::mock";
+
+/// Collects the messages of events.
+#[derive(Clone, Default)]
+struct Collector(Arc<Mutex<Vec<String>>>);
+
+impl Visit for Collector {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.lock().unwrap().push(format!("{:?}", value));
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for Collector {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        event.record(&mut self.clone());
+    }
+}
+
+fn mock_connection(backend: DbBackend) -> DatabaseConnection {
+    MockDatabase::new(backend)
+        .append_query_results((0..MOCK_RESULTS).map(|_| Vec::<BTreeMap<&str, Value>>::new()))
+        .append_exec_results((0..MOCK_RESULTS).map(|_| MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 0,
+        }))
+        .into_connection()
+}
+
+/// Returns the statements `migration` runs, rendered for `backend`.
+async fn statements(
+    migration: &dyn MigrationTrait,
+    backend: DbBackend,
+    down: bool,
+) -> Result<Vec<String>, DbErr> {
+    let collector = Collector::default();
+    let subscriber = tracing_subscriber::registry().with(
+        collector
+            .clone()
+            .with_filter(Targets::new().with_target(MOCK_TARGET, LevelFilter::DEBUG)),
+    );
+
+    let conn = mock_connection(backend);
+    let manager = SchemaManager::new(&conn);
+    let result = async {
+        match down {
+            false => migration.up(&manager).await,
+            true => migration.down(&manager).await,
+        }
+    }
+    .with_subscriber(subscriber)
+    .await;
+    result?;
+
+    let stmts = collector.0.lock().unwrap().clone();
+    Ok(stmts)
+}
+
+/// Prints the SQL of the pending migrations `up` would apply, or of the
+/// applied migrations `down` would rollback.
+pub async fn dry_run<M: MigratorTrait>(
+    db: &DbConn,
+    down: bool,
+    num: Option<u32>,
+) -> Result<(), Error> {
+    // avoid creating the table of applied migrations
+    let applied: Vec<String> = match SchemaManager::new(db).has_table("seaql_migrations").await? {
+        false => Vec::new(),
+        true => M::get_migration_models(db)
+            .await?
+            .into_iter()
+            .map(|m| m.version)
+            .collect(),
+    };
+    let mut names: Vec<String> = match down {
+        false => M::migrations()
+            .iter()
+            .map(|m| m.name().to_string())
+            .filter(|name| !applied.contains(name))
+            .collect(),
+        true => applied.into_iter().rev().collect(),
+    };
+    names.truncate(num.map_or(usize::MAX, |n| n as usize));
+
+    if names.is_empty() {
+        println!("-- No migrations to run");
+        return Ok(());
+    }
+
+    let backend = db.get_database_backend();
+    let migrations = M::migrations();
+    for name in &names {
+        let Some(migration) = migrations.iter().find(|m| m.name() == name) else {
+            continue;
+        };
+        println!(
+            "-- {} ({})",
+            migration.name(),
+            if down { "down" } else { "up" }
+        );
+        // the mock connection has no tables of TypeORM
+        if !down && name == InitialSchema.name() && has_typeorm_migrations(db).await? {
+            println!("-- Nothing to run, the schema of TypeORM exists");
+            println!();
+            continue;
+        }
+        for stmt in statements(migration.as_ref(), backend, down).await? {
+            println!("{};", stmt);
+        }
+        println!();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use sea_orm_migration::prelude::DbErr;
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Failed to read config file {}: {source}", path.display())]
+    ConfigRead {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse config file {}: {source}", path.display())]
+    ConfigParse {
+        path: PathBuf,
+        source: serde_yaml::Error,
+    },
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+    #[cfg(any(feature = "dry-run", feature = "check-schema"))]
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[cfg(feature = "check-schema")]
+    #[error("{0} differences between the entities and the database")]
+    SchemaMismatch(usize),
+    #[error("Database error: {0}")]
+    Db(#[from] DbErr),
+}
@@ -1,8 +1,14 @@
 pub use sea_orm_migration::prelude::*;
 
+mod m0000_initial_schema;
 mod m20230531_180824_drop_reversi;
 mod m20230627_185451_index_note_url;
 mod m20230709_000510_move_antenna_to_cache;
+mod m20261019_093000_drive_blob;
+mod m20261019_120000_chart_sketch;
+mod m20261019_150000_stats_rollup;
+
+pub use m0000_initial_schema::{has_typeorm_migrations, Migration as InitialSchema};
 
 pub struct Migrator;
 
@@ -10,9 +16,13 @@ pub struct Migrator;
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         vec![
+            Box::new(m0000_initial_schema::Migration),
             Box::new(m20230531_180824_drop_reversi::Migration),
             Box::new(m20230627_185451_index_note_url::Migration),
             Box::new(m20230709_000510_move_antenna_to_cache::Migration),
+            Box::new(m20261019_093000_drive_blob::Migration),
+            Box::new(m20261019_120000_chart_sketch::Migration),
+            Box::new(m20261019_150000_stats_rollup::Migration),
         ]
     }
 }
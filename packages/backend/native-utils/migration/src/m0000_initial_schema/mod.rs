@@ -0,0 +1,277 @@
+//! Baseline of the schema created by the TypeORM migrations in
+//! `packages/backend/migration`, so that new instances do not need to run
+//! them anymore.
+//!
+//! Existing instances have the `migrations` table of TypeORM, where this
+//! migration does nothing but is recorded as applied. New instances get the
+//! `migrations` table with all TypeORM migrations in it, so that the TypeORM
+//! runner does not apply them again. Rolling back drops all tables.
+
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    sea_orm::{ConnectionTrait, DbBackend},
+};
+
+mod schema;
+
+/// Enum type of PostgreSQL, which is `text` in SQLite.
+pub struct Enum {
+    pub name: &'static str,
+    pub values: &'static [&'static str],
+}
+
+impl Enum {
+    fn column_type(&self) -> ColumnType {
+        ColumnType::Enum {
+            name: Alias::new(self.name).into_iden(),
+            variants: self
+                .values
+                .iter()
+                .map(|v| Alias::new(v).into_iden())
+                .collect(),
+        }
+    }
+}
+
+trait ColumnDefExt {
+    fn enum_type(&mut self, e: &Enum) -> &mut Self;
+    /// Array of PostgreSQL, which is JSON in SQLite.
+    fn array_of(&mut self, elem: ColumnType, backend: DbBackend) -> &mut Self;
+}
+
+impl ColumnDefExt for ColumnDef {
+    fn enum_type(&mut self, e: &Enum) -> &mut Self {
+        self.enumeration(Alias::new(e.name), e.values.iter().map(|v| Alias::new(v)))
+    }
+
+    fn array_of(&mut self, elem: ColumnType, backend: DbBackend) -> &mut Self {
+        match backend {
+            DbBackend::Sqlite => self.json_binary(),
+            _ => self.array(elem),
+        }
+    }
+}
+
+/// Returns the default value of array columns.
+fn array_default(values: &[&str], backend: DbBackend) -> String {
+    match backend {
+        DbBackend::Sqlite => serde_json::to_string(values).unwrap(),
+        _ => format!("{{{}}}", values.join(",")),
+    }
+}
+
+/// Returns whether the database has the `migrations` table of TypeORM, i.e.
+/// this migration does nothing on it.
+pub async fn has_typeorm_migrations<C: ConnectionTrait>(db: &C) -> Result<bool, DbErr> {
+    let backend = db.get_database_backend();
+    let stmt = match backend {
+        DbBackend::Postgres => Query::select()
+            .expr(Expr::asterisk().count())
+            .from((Alias::new("information_schema"), Alias::new("tables")))
+            .and_where(Expr::col(Alias::new("table_schema")).eq(Expr::cust("current_schema()")))
+            .and_where(Expr::col(Alias::new("table_name")).eq("migrations"))
+            .to_owned(),
+        DbBackend::Sqlite => Query::select()
+            .expr(Expr::asterisk().count())
+            .from(Alias::new("sqlite_master"))
+            .and_where(Expr::col(Alias::new("type")).eq("table"))
+            .and_where(Expr::col(Alias::new("name")).eq("migrations"))
+            .to_owned(),
+        DbBackend::MySql => return Ok(false),
+    };
+    match db.query_one(backend.build(&stmt)).await? {
+        Some(row) => Ok(row.try_get_by_index::<i64>(0)? > 0),
+        None => Ok(false),
+    }
+}
+
+/// Checks that the last TypeORM migration has been applied, since the
+/// following migrations depend on it.
+async fn check_typeorm_migrations<C: ConnectionTrait>(db: &C) -> Result<(), DbErr> {
+    let (_, last) = schema::TYPEORM_MIGRATIONS.last().unwrap();
+    let stmt = Query::select()
+        .expr(Expr::asterisk().count())
+        .from(Migrations::Table)
+        .and_where(Expr::col(Migrations::Name).eq(*last))
+        .to_owned();
+    let count = match db.query_one(db.get_database_backend().build(&stmt)).await? {
+        Some(row) => row.try_get_by_index::<i64>(0)?,
+        None => 0,
+    };
+    match count {
+        0 => Err(DbErr::Migration(format!(
+            "TypeORM migration {} has not been applied. Run the TypeORM migrations first.",
+            last
+        ))),
+        _ => Ok(()),
+    }
+}
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m0000_initial_schema"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        if has_typeorm_migrations(db).await? {
+            return check_typeorm_migrations(db).await;
+        }
+
+        if backend == DbBackend::Postgres {
+            for e in schema::ENUMS {
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(Alias::new(e.name))
+                            .values(e.values.iter().map(|v| Alias::new(v)))
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        // SQLite cannot add foreign keys to existing tables, while PostgreSQL
+        // cannot create tables referencing ones that do not exist yet.
+        let mut foreign_keys = Vec::new();
+        for (mut table, fks) in schema::tables(backend) {
+            match backend {
+                DbBackend::Sqlite => {
+                    for mut fk in fks {
+                        table.foreign_key(&mut fk);
+                    }
+                }
+                _ => foreign_keys.extend(fks),
+            }
+            manager.create_table(table).await?;
+        }
+        for fk in foreign_keys {
+            manager.create_foreign_key(fk).await?;
+        }
+
+        for index in schema::indexes() {
+            manager.create_index(index).await?;
+        }
+        if backend == DbBackend::Postgres {
+            for index in schema::gin_indexes() {
+                manager.create_index(index).await?;
+            }
+        }
+        for sql in schema::PARTIAL_INDEXES {
+            db.execute_unprepared(sql).await?;
+        }
+        if backend == DbBackend::Postgres {
+            for sql in schema::POSTGRES_STATEMENTS {
+                db.execute_unprepared(sql).await?;
+            }
+        }
+
+        let mut insert = Query::insert()
+            .into_table(Migrations::Table)
+            .columns([Migrations::Timestamp, Migrations::Name])
+            .to_owned();
+        for (timestamp, name) in schema::TYPEORM_MIGRATIONS {
+            insert.values_panic([(*timestamp).into(), (*name).into()]);
+        }
+        db.execute(backend.build(&insert)).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        // cascades between the tables being dropped fail on SQLite
+        if backend == DbBackend::Sqlite {
+            db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        }
+
+        for (table, _) in schema::tables(backend).into_iter().rev() {
+            let Some(name) = table.get_table_name() else {
+                continue;
+            };
+            let mut stmt = Table::drop().table(name.clone()).if_exists().to_owned();
+            if backend == DbBackend::Postgres {
+                stmt.cascade();
+            }
+            manager.drop_table(stmt).await?;
+        }
+
+        if backend == DbBackend::Sqlite {
+            db.execute_unprepared("PRAGMA foreign_keys = ON").await?;
+        }
+
+        if backend == DbBackend::Postgres {
+            db.execute_unprepared("DROP FUNCTION IF EXISTS note_replies")
+                .await?;
+            for e in schema::ENUMS {
+                manager
+                    .drop_type(Type::drop().name(Alias::new(e.name)).if_exists().to_owned())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Migrations {
+    Table,
+    Timestamp,
+    Name,
+}
+
+#[cfg(test)]
+mod unit_test {
+    use sea_orm_migration::{prelude::*, sea_orm::DbBackend};
+
+    use super::{array_default, schema, ColumnDefExt};
+
+    #[test]
+    fn array_default_by_backend() {
+        assert_eq!(array_default(&[], DbBackend::Postgres), "{}");
+        assert_eq!(array_default(&["a", "b"], DbBackend::Postgres), "{a,b}");
+        assert_eq!(array_default(&[], DbBackend::Sqlite), "[]");
+        assert_eq!(
+            array_default(&["a", "b"], DbBackend::Sqlite),
+            r#"["a","b"]"#
+        );
+    }
+
+    #[test]
+    fn array_column_by_backend() {
+        let table = |backend: DbBackend| {
+            Table::create()
+                .table(Alias::new("t"))
+                .col(
+                    ColumnDef::new(Alias::new("c"))
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null(),
+                )
+                .to_owned()
+        };
+        assert_eq!(
+            table(DbBackend::Postgres).to_string(PostgresQueryBuilder),
+            r#"CREATE TABLE "t" ( "c" varchar(128)[] NOT NULL )"#
+        );
+        assert_eq!(
+            table(DbBackend::Sqlite).to_string(SqliteQueryBuilder),
+            r#"CREATE TABLE "t" ( "c" text NOT NULL )"#
+        );
+    }
+
+    #[test]
+    fn last_typeorm_migration() {
+        let (timestamp, name) = schema::TYPEORM_MIGRATIONS.last().unwrap();
+        assert!(name.ends_with(&timestamp.to_string()));
+    }
+}
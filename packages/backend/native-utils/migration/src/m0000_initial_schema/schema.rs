@@ -0,0 +1,10004 @@
+//! Schema created by the TypeORM migrations up to
+//! `FirefishRepo1689957674000`, generated from the catalog of PostgreSQL.
+
+use sea_orm_migration::{prelude::*, sea_orm::DbBackend};
+
+use super::{array_default, ColumnDefExt, Enum};
+
+pub const ANTENNA_SRC_ENUM: Enum = Enum {
+    name: "antenna_src_enum",
+    values: &["home", "all", "users", "list", "group", "instances"],
+};
+pub const LOG_LEVEL_ENUM: Enum = Enum {
+    name: "log_level_enum",
+    values: &["error", "warning", "info", "success", "debug"],
+};
+pub const META_SENSITIVEMEDIADETECTION_ENUM: Enum = Enum {
+    name: "meta_sensitivemediadetection_enum",
+    values: &["none", "all", "local", "remote"],
+};
+pub const META_SENSITIVEMEDIADETECTIONSENSITIVITY_ENUM: Enum = Enum {
+    name: "meta_sensitivemediadetectionsensitivity_enum",
+    values: &["medium", "low", "high", "veryLow", "veryHigh"],
+};
+pub const MUTED_NOTE_REASON_ENUM: Enum = Enum {
+    name: "muted_note_reason_enum",
+    values: &["word", "manual", "spam", "other"],
+};
+pub const NOTE_VISIBILITY_ENUM: Enum = Enum {
+    name: "note_visibility_enum",
+    values: &["public", "home", "followers", "specified", "hidden"],
+};
+pub const NOTIFICATION_TYPE_ENUM: Enum = Enum {
+    name: "notification_type_enum",
+    values: &[
+        "follow",
+        "mention",
+        "reply",
+        "renote",
+        "quote",
+        "reaction",
+        "pollVote",
+        "pollEnded",
+        "receiveFollowRequest",
+        "followRequestAccepted",
+        "groupInvited",
+        "app",
+    ],
+};
+pub const PAGE_VISIBILITY_ENUM: Enum = Enum {
+    name: "page_visibility_enum",
+    values: &["public", "followers", "specified"],
+};
+pub const POLL_NOTEVISIBILITY_ENUM: Enum = Enum {
+    name: "poll_notevisibility_enum",
+    values: &["public", "home", "followers", "specified"],
+};
+pub const RELAY_STATUS_ENUM: Enum = Enum {
+    name: "relay_status_enum",
+    values: &["requesting", "accepted", "rejected"],
+};
+pub const USER_PROFILE_FFVISIBILITY_ENUM: Enum = Enum {
+    name: "user_profile_ffvisibility_enum",
+    values: &["public", "followers", "private"],
+};
+pub const USER_PROFILE_MUTINGNOTIFICATIONTYPES_ENUM: Enum = Enum {
+    name: "user_profile_mutingnotificationtypes_enum",
+    values: &[
+        "follow",
+        "mention",
+        "reply",
+        "renote",
+        "quote",
+        "reaction",
+        "pollVote",
+        "pollEnded",
+        "receiveFollowRequest",
+        "followRequestAccepted",
+        "groupInvited",
+        "app",
+    ],
+};
+
+pub const ENUMS: &[Enum] = &[
+    ANTENNA_SRC_ENUM,
+    LOG_LEVEL_ENUM,
+    META_SENSITIVEMEDIADETECTION_ENUM,
+    META_SENSITIVEMEDIADETECTIONSENSITIVITY_ENUM,
+    MUTED_NOTE_REASON_ENUM,
+    NOTE_VISIBILITY_ENUM,
+    NOTIFICATION_TYPE_ENUM,
+    PAGE_VISIBILITY_ENUM,
+    POLL_NOTEVISIBILITY_ENUM,
+    RELAY_STATUS_ENUM,
+    USER_PROFILE_FFVISIBILITY_ENUM,
+    USER_PROFILE_MUTINGNOTIFICATIONTYPES_ENUM,
+];
+
+/// Returns the tables with their foreign keys.
+pub fn tables(backend: DbBackend) -> Vec<(TableCreateStatement, Vec<ForeignKeyCreateStatement>)> {
+    vec![
+        (
+            Table::create()
+                .table(ChartActiveUsers::Table)
+                .col(
+                    ColumnDef::new(ChartActiveUsers::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartActiveUsers::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRegisteredWithinWeek)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::RegisteredWithinWeek)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRegisteredWithinMonth)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::RegisteredWithinMonth)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRegisteredWithinYear)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::RegisteredWithinYear)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRegisteredOutsideWeek)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::RegisteredOutsideWeek)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRegisteredOutsideMonth)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::RegisteredOutsideMonth)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRegisteredOutsideYear)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::RegisteredOutsideYear)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::ReadWrite)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempRead)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::Read)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::UniqueTempWrite)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartActiveUsers::Write)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_0ad37b7ef50f4ddc84363d7ccca")
+                        .unique()
+                        .col(ChartActiveUsers::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartApRequest::Table)
+                .col(
+                    ColumnDef::new(ChartApRequest::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartApRequest::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartApRequest::DeliverFailed)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartApRequest::DeliverSucceeded)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartApRequest::InboxReceived)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_e56f4beac5746d44bc3e19c80d0")
+                        .unique()
+                        .col(ChartApRequest::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDrive::Table)
+                .col(
+                    ColumnDef::new(ChartDrive::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDrive::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDrive::LocalIncCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::LocalIncSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::LocalDecCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::LocalDecSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::RemoteIncCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::RemoteIncSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::RemoteDecCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDrive::RemoteDecSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_13565815f618a1ff53886c5b28a")
+                        .unique()
+                        .col(ChartDrive::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartFederation::Table)
+                .col(
+                    ColumnDef::new(ChartFederation::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartFederation::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartFederation::UniqueTempDeliveredInstances)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::DeliveredInstances)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::UniqueTempInboxInstances)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::InboxInstances)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::UniqueTempStalled)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::Stalled)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::Sub)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::Pub)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::Pubsub)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::SubActive)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartFederation::PubActive)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_36cb699c49580d4e6c2e6159f97")
+                        .unique()
+                        .col(ChartFederation::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartHashtag::Table)
+                .col(
+                    ColumnDef::new(ChartHashtag::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartHashtag::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartHashtag::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartHashtag::LocalUsers)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartHashtag::RemoteUsers)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartHashtag::UniqueTempLocalUsers)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartHashtag::UniqueTempRemoteUsers)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_25a97c02003338124b2b75fdbc8")
+                        .unique()
+                        .col(ChartHashtag::Date)
+                        .col(ChartHashtag::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartInstance::Table)
+                .col(
+                    ColumnDef::new(ChartInstance::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartInstance::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartInstance::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::RequestsFailed)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::RequestsSucceeded)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::RequestsReceived)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesInc)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesDec)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesDiffsNormal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesDiffsReply)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesDiffsRenote)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::UsersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::UsersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::UsersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::FollowingTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::FollowingInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::FollowingDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::FollowersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::FollowersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::FollowersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::DriveTotalFiles)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::DriveIncFiles)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::DriveIncUsage)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::DriveDecFiles)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::DriveDecUsage)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartInstance::NotesDiffsWithFile)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_39ee857ab2f23493037c6b66311")
+                        .unique()
+                        .col(ChartInstance::Date)
+                        .col(ChartInstance::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartNetwork::Table)
+                .col(
+                    ColumnDef::new(ChartNetwork::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartNetwork::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartNetwork::IncomingRequests)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNetwork::OutgoingRequests)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNetwork::TotalTime)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNetwork::IncomingBytes)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNetwork::OutgoingBytes)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_a1efd3e0048a5f2793a47360dc6")
+                        .unique()
+                        .col(ChartNetwork::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartNotes::Table)
+                .col(
+                    ColumnDef::new(ChartNotes::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartNotes::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartNotes::LocalTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::LocalInc)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::LocalDec)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::LocalDiffsNormal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::LocalDiffsReply)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::LocalDiffsRenote)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteInc)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteDec)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteDiffsNormal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteDiffsReply)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteDiffsRenote)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::LocalDiffsWithFile)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartNotes::RemoteDiffsWithFile)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_42eb716a37d381cdf566192b2be")
+                        .unique()
+                        .col(ChartNotes::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartPerUserDrive::Table)
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartPerUserDrive::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::TotalCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::TotalSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::IncCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::IncSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::DecCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserDrive::DecSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_30bf67687f483ace115c5ca6429")
+                        .unique()
+                        .col(ChartPerUserDrive::Date)
+                        .col(ChartPerUserDrive::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartPerUserFollowing::Table)
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::LocalFollowingsTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::LocalFollowingsInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::LocalFollowingsDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::LocalFollowersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::LocalFollowersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::LocalFollowersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::RemoteFollowingsTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::RemoteFollowingsInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::RemoteFollowingsDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::RemoteFollowersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::RemoteFollowersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserFollowing::RemoteFollowersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_b77d4dd9562c3a899d9a286fcd7")
+                        .unique()
+                        .col(ChartPerUserFollowing::Date)
+                        .col(ChartPerUserFollowing::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartPerUserNotes::Table)
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartPerUserNotes::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::Total)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::Inc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::Dec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::DiffsNormal)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::DiffsReply)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::DiffsRenote)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserNotes::DiffsWithFile)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_5048e9daccbbbc6d567bb142d34")
+                        .unique()
+                        .col(ChartPerUserNotes::Date)
+                        .col(ChartPerUserNotes::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartPerUserReaction::Table)
+                .col(
+                    ColumnDef::new(ChartPerUserReaction::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserReaction::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserReaction::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserReaction::LocalCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartPerUserReaction::RemoteCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_229a41ad465f9205f1f57032910")
+                        .unique()
+                        .col(ChartPerUserReaction::Date)
+                        .col(ChartPerUserReaction::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartTest::Table)
+                .col(
+                    ColumnDef::new(ChartTest::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartTest::Date).integer().not_null())
+                .col(ColumnDef::new(ChartTest::Group).string_len(128))
+                .col(ColumnDef::new(ChartTest::FooTotal).big_integer().not_null())
+                .col(ColumnDef::new(ChartTest::FooInc).big_integer().not_null())
+                .col(ColumnDef::new(ChartTest::FooDec).big_integer().not_null())
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartTestGrouped::Table)
+                .col(
+                    ColumnDef::new(ChartTestGrouped::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartTestGrouped::Date).integer().not_null())
+                .col(ColumnDef::new(ChartTestGrouped::Group).string_len(128))
+                .col(
+                    ColumnDef::new(ChartTestGrouped::FooTotal)
+                        .big_integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartTestGrouped::FooInc)
+                        .big_integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartTestGrouped::FooDec)
+                        .big_integer()
+                        .not_null(),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartTestUnique::Table)
+                .col(
+                    ColumnDef::new(ChartTestUnique::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartTestUnique::Date).integer().not_null())
+                .col(ColumnDef::new(ChartTestUnique::Group).string_len(128))
+                .col(
+                    ColumnDef::new(ChartTestUnique::Foo)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartUsers::Table)
+                .col(
+                    ColumnDef::new(ChartUsers::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartUsers::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartUsers::LocalTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartUsers::LocalInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartUsers::LocalDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartUsers::RemoteTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartUsers::RemoteInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartUsers::RemoteDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_845254b3eaf708ae8a6cac30265")
+                        .unique()
+                        .col(ChartUsers::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayActiveUsers::Table)
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRegisteredWithinWeek)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::RegisteredWithinWeek)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRegisteredWithinMonth)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::RegisteredWithinMonth)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRegisteredWithinYear)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::RegisteredWithinYear)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRegisteredOutsideWeek)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::RegisteredOutsideWeek)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRegisteredOutsideMonth)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::RegisteredOutsideMonth)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRegisteredOutsideYear)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::RegisteredOutsideYear)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::ReadWrite)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempRead)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::Read)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::UniqueTempWrite)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayActiveUsers::Write)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_d5954f3df5e5e3bdfc3c03f3906")
+                        .unique()
+                        .col(ChartDayActiveUsers::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayApRequest::Table)
+                .col(
+                    ColumnDef::new(ChartDayApRequest::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayApRequest::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayApRequest::DeliverFailed)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayApRequest::DeliverSucceeded)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayApRequest::InboxReceived)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_a848f66d6cec11980a5dd595822")
+                        .unique()
+                        .col(ChartDayApRequest::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayDrive::Table)
+                .col(
+                    ColumnDef::new(ChartDayDrive::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayDrive::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayDrive::LocalIncCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::LocalIncSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::LocalDecCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::LocalDecSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::RemoteIncCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::RemoteIncSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::RemoteDecCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayDrive::RemoteDecSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_0b60ebb3aa0065f10b0616c1171")
+                        .unique()
+                        .col(ChartDayDrive::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayFederation::Table)
+                .col(
+                    ColumnDef::new(ChartDayFederation::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::UniqueTempDeliveredInstances)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::DeliveredInstances)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::UniqueTempInboxInstances)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::InboxInstances)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::UniqueTempStalled)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::Stalled)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::Sub)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::Pub)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::Pubsub)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::SubActive)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayFederation::PubActive)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_617a8fe225a6e701d89e02d2c74")
+                        .unique()
+                        .col(ChartDayFederation::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayHashtag::Table)
+                .col(
+                    ColumnDef::new(ChartDayHashtag::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayHashtag::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayHashtag::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayHashtag::LocalUsers)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayHashtag::RemoteUsers)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayHashtag::UniqueTempLocalUsers)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(ChartDayHashtag::UniqueTempRemoteUsers)
+                        .array_of(ColumnType::String(None), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_8f589cf056ff51f09d6096f6450")
+                        .unique()
+                        .col(ChartDayHashtag::Date)
+                        .col(ChartDayHashtag::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayInstance::Table)
+                .col(
+                    ColumnDef::new(ChartDayInstance::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayInstance::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayInstance::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::RequestsFailed)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::RequestsSucceeded)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::RequestsReceived)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesInc)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesDec)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesDiffsNormal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesDiffsReply)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesDiffsRenote)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::UsersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::UsersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::UsersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::FollowingTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::FollowingInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::FollowingDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::FollowersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::FollowersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::FollowersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::DriveTotalFiles)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::DriveIncFiles)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::DriveIncUsage)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::DriveDecFiles)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::DriveDecUsage)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayInstance::NotesDiffsWithFile)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_fea7c0278325a1a2492f2d6acbf")
+                        .unique()
+                        .col(ChartDayInstance::Date)
+                        .col(ChartDayInstance::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayNetwork::Table)
+                .col(
+                    ColumnDef::new(ChartDayNetwork::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayNetwork::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayNetwork::IncomingRequests)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNetwork::OutgoingRequests)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNetwork::TotalTime)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNetwork::IncomingBytes)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNetwork::OutgoingBytes)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_8bfa548c2b31f9e07db113773ee")
+                        .unique()
+                        .col(ChartDayNetwork::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayNotes::Table)
+                .col(
+                    ColumnDef::new(ChartDayNotes::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayNotes::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalInc)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalDec)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalDiffsNormal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalDiffsReply)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalDiffsRenote)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteInc)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteDec)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteDiffsNormal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteDiffsReply)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteDiffsRenote)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::LocalDiffsWithFile)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayNotes::RemoteDiffsWithFile)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_1a527b423ad0858a1af5a056d43")
+                        .unique()
+                        .col(ChartDayNotes::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayPerUserDrive::Table)
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::TotalCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::TotalSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::IncCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::IncSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::DecCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserDrive::DecSize)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_62aa5047b5aec92524f24c701d7")
+                        .unique()
+                        .col(ChartDayPerUserDrive::Date)
+                        .col(ChartDayPerUserDrive::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayPerUserFollowing::Table)
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::LocalFollowingsTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::LocalFollowingsInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::LocalFollowingsDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::LocalFollowersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::LocalFollowersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::LocalFollowersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::RemoteFollowingsTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::RemoteFollowingsInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::RemoteFollowingsDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::RemoteFollowersTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::RemoteFollowersInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserFollowing::RemoteFollowersDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_e4849a3231f38281280ea4c0eee")
+                        .unique()
+                        .col(ChartDayPerUserFollowing::Date)
+                        .col(ChartDayPerUserFollowing::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayPerUserNotes::Table)
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::Total)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::Inc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::Dec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::DiffsNormal)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::DiffsReply)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::DiffsRenote)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserNotes::DiffsWithFile)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_c5545d4b31cdc684034e33b81c3")
+                        .unique()
+                        .col(ChartDayPerUserNotes::Date)
+                        .col(ChartDayPerUserNotes::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayPerUserReaction::Table)
+                .col(
+                    ColumnDef::new(ChartDayPerUserReaction::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserReaction::Date)
+                        .integer()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserReaction::Group)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserReaction::LocalCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayPerUserReaction::RemoteCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_d54b653660d808b118e36c184c0")
+                        .unique()
+                        .col(ChartDayPerUserReaction::Date)
+                        .col(ChartDayPerUserReaction::Group),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ChartDayUsers::Table)
+                .col(
+                    ColumnDef::new(ChartDayUsers::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(ChartDayUsers::Date).integer().not_null())
+                .col(
+                    ColumnDef::new(ChartDayUsers::LocalTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayUsers::LocalInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayUsers::LocalDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayUsers::RemoteTotal)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayUsers::RemoteInc)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(ChartDayUsers::RemoteDec)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_cad6e07c20037f31cdba8a350c3")
+                        .unique()
+                        .col(ChartDayUsers::Date),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(AbuseUserReport::Table)
+                .col(
+                    ColumnDef::new(AbuseUserReport::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AbuseUserReport::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AbuseUserReport::TargetUserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AbuseUserReport::ReporterId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(AbuseUserReport::AssigneeId).string_len(32))
+                .col(
+                    ColumnDef::new(AbuseUserReport::Resolved)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(AbuseUserReport::Comment)
+                        .string_len(2048)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(AbuseUserReport::TargetUserHost).string_len(128))
+                .col(ColumnDef::new(AbuseUserReport::ReporterHost).string_len(128))
+                .col(
+                    ColumnDef::new(AbuseUserReport::Forwarded)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_87873f5f5cc5c321a1306b2d18c")
+                        .col(AbuseUserReport::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_04cc96756f89d0b7f9473e8cdf3")
+                    .from(AbuseUserReport::Table, AbuseUserReport::ReporterId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_08b883dd5fdd6f9c4c1572b36de")
+                    .from(AbuseUserReport::Table, AbuseUserReport::AssigneeId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("fk_7f4e851a35d81b64dda28eee0")
+                    .from(AbuseUserReport::Table, AbuseUserReport::TargetUserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(AccessToken::Table)
+                .col(ColumnDef::new(AccessToken::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(AccessToken::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AccessToken::Token)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(AccessToken::Hash).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(AccessToken::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(AccessToken::AppId).string_len(32))
+                .col(ColumnDef::new(AccessToken::LastUsedAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(AccessToken::Session).string_len(128))
+                .col(ColumnDef::new(AccessToken::Name).string_len(128))
+                .col(ColumnDef::new(AccessToken::Description).string_len(512))
+                .col(ColumnDef::new(AccessToken::IconUrl).string_len(512))
+                .col(
+                    ColumnDef::new(AccessToken::Permission)
+                        .array_of(ColumnType::String(Some(64)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(AccessToken::Fetched)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_f20f028607b2603deabd8182d12")
+                        .col(AccessToken::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_9949557d0e1b2c19e5344c171e9")
+                    .from(AccessToken::Table, AccessToken::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_a3ff16c90cc87a82a0b5959e560")
+                    .from(AccessToken::Table, AccessToken::AppId)
+                    .to(App::Table, App::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Ad::Table)
+                .col(ColumnDef::new(Ad::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Ad::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Ad::ExpiresAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Ad::Place).string_len(32).not_null())
+                .col(ColumnDef::new(Ad::Priority).string_len(32).not_null())
+                .col(ColumnDef::new(Ad::Url).string_len(1024).not_null())
+                .col(ColumnDef::new(Ad::ImageUrl).string_len(1024).not_null())
+                .col(ColumnDef::new(Ad::Memo).string_len(8192).not_null())
+                .col(ColumnDef::new(Ad::Ratio).integer().not_null().default(1))
+                .primary_key(
+                    Index::create()
+                        .name("PK_0193d5ef09746e88e9ea92c634d")
+                        .col(Ad::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(Announcement::Table)
+                .col(ColumnDef::new(Announcement::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Announcement::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Announcement::Text)
+                        .string_len(8192)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Announcement::Title)
+                        .string_len(256)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Announcement::ImageUrl).string_len(1024))
+                .col(ColumnDef::new(Announcement::UpdatedAt).timestamp_with_time_zone())
+                .col(
+                    ColumnDef::new(Announcement::ShowPopup)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Announcement::IsGoodNews)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_e0ef0550174fd1099a308fd18a0")
+                        .col(Announcement::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(AnnouncementRead::Table)
+                .col(
+                    ColumnDef::new(AnnouncementRead::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AnnouncementRead::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AnnouncementRead::AnnouncementId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AnnouncementRead::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_4b90ad1f42681d97b2683890c5e")
+                        .col(AnnouncementRead::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_603a7b1e7aa0533c6c88e9bfafe")
+                    .from(AnnouncementRead::Table, AnnouncementRead::AnnouncementId)
+                    .to(Announcement::Table, Announcement::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_8288151386172b8109f7239ab28")
+                    .from(AnnouncementRead::Table, AnnouncementRead::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Antenna::Table)
+                .col(ColumnDef::new(Antenna::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Antenna::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Antenna::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(Antenna::Name).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(Antenna::Src)
+                        .enum_type(&ANTENNA_SRC_ENUM)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Antenna::UserListId).string_len(32))
+                .col(
+                    ColumnDef::new(Antenna::Keywords)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(ColumnDef::new(Antenna::WithFile).boolean().not_null())
+                .col(ColumnDef::new(Antenna::Expression).string_len(2048))
+                .col(ColumnDef::new(Antenna::Notify).boolean().not_null())
+                .col(
+                    ColumnDef::new(Antenna::CaseSensitive)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Antenna::WithReplies)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Antenna::UserGroupJoiningId).string_len(32))
+                .col(
+                    ColumnDef::new(Antenna::Users)
+                        .array_of(ColumnType::String(Some(1024)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Antenna::ExcludeKeywords)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(Antenna::Instances)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_c170b99775e1dccca947c9f2d5f")
+                        .col(Antenna::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_6446c571a0e8d0f05f01c789096")
+                    .from(Antenna::Table, Antenna::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_709d7d32053d0dd7620f678eeb9")
+                    .from(Antenna::Table, Antenna::UserListId)
+                    .to(UserList::Table, UserList::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_ccbf5a8c0be4511133dcc50ddeb")
+                    .from(Antenna::Table, Antenna::UserGroupJoiningId)
+                    .to(UserGroupJoining::Table, UserGroupJoining::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(AntennaNote::Table)
+                .col(ColumnDef::new(AntennaNote::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(AntennaNote::NoteId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AntennaNote::AntennaId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AntennaNote::Read)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_fb28d94d0989a3872df19fd6ef8")
+                        .col(AntennaNote::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_0d775946662d2575dfd2068a5f5")
+                    .from(AntennaNote::Table, AntennaNote::AntennaId)
+                    .to(Antenna::Table, Antenna::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_bd0397be22147e17210940e125b")
+                    .from(AntennaNote::Table, AntennaNote::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(App::Table)
+                .col(ColumnDef::new(App::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(App::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(App::UserId).string_len(32))
+                .col(ColumnDef::new(App::Secret).string_len(64).not_null())
+                .col(ColumnDef::new(App::Name).string_len(128).not_null())
+                .col(ColumnDef::new(App::Description).string_len(512).not_null())
+                .col(
+                    ColumnDef::new(App::Permission)
+                        .array_of(ColumnType::String(Some(64)), backend)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(App::CallbackUrl).string_len(512))
+                .primary_key(
+                    Index::create()
+                        .name("PK_9478629fc093d229df09e560aea")
+                        .col(App::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_3f5b0899ef90527a3462d7c2cb3")
+                .from(App::Table, App::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::SetNull)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(AttestationChallenge::Table)
+                .col(
+                    ColumnDef::new(AttestationChallenge::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AttestationChallenge::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AttestationChallenge::Challenge)
+                        .string_len(64)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AttestationChallenge::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AttestationChallenge::RegistrationChallenge)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_d0ba6786e093f1bcb497572a6b5")
+                        .col(AttestationChallenge::Id)
+                        .col(AttestationChallenge::UserId),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_f1a461a618fa1755692d0e0d592")
+                .from(AttestationChallenge::Table, AttestationChallenge::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(AuthSession::Table)
+                .col(ColumnDef::new(AuthSession::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(AuthSession::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(AuthSession::Token)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(AuthSession::UserId).string_len(32))
+                .col(ColumnDef::new(AuthSession::AppId).string_len(32).not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_19354ed146424a728c1112a8cbf")
+                        .col(AuthSession::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_c072b729d71697f959bde66ade0")
+                    .from(AuthSession::Table, AuthSession::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_dbe037d4bddd17b03a1dc778dee")
+                    .from(AuthSession::Table, AuthSession::AppId)
+                    .to(App::Table, App::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Blocking::Table)
+                .col(ColumnDef::new(Blocking::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Blocking::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Blocking::BlockeeId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Blocking::BlockerId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_e5d9a541cc1965ee7e048ea09dd")
+                        .col(Blocking::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_0627125f1a8a42c9a1929edb552")
+                    .from(Blocking::Table, Blocking::BlockerId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_2cd4a2743a99671308f5417759e")
+                    .from(Blocking::Table, Blocking::BlockeeId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Channel::Table)
+                .col(ColumnDef::new(Channel::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Channel::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Channel::LastNotedAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(Channel::UserId).string_len(32))
+                .col(ColumnDef::new(Channel::Name).string_len(128).not_null())
+                .col(ColumnDef::new(Channel::Description).string_len(2048))
+                .col(ColumnDef::new(Channel::BannerId).string_len(32))
+                .col(
+                    ColumnDef::new(Channel::NotesCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Channel::UsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_590f33ee6ee7d76437acf362e39")
+                        .col(Channel::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_823bae55bd81b3be6e05cff4383")
+                    .from(Channel::Table, Channel::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_999da2bcc7efadbfe0e92d3bc19")
+                    .from(Channel::Table, Channel::BannerId)
+                    .to(DriveFile::Table, DriveFile::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(ChannelFollowing::Table)
+                .col(
+                    ColumnDef::new(ChannelFollowing::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChannelFollowing::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChannelFollowing::FolloweeId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChannelFollowing::FollowerId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_8b104be7f7415113f2a02cd5bdd")
+                        .col(ChannelFollowing::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_0e43068c3f92cab197c3d3cd86e")
+                    .from(ChannelFollowing::Table, ChannelFollowing::FolloweeId)
+                    .to(Channel::Table, Channel::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_6d8084ec9496e7334a4602707e1")
+                    .from(ChannelFollowing::Table, ChannelFollowing::FollowerId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(ChannelNotePining::Table)
+                .col(
+                    ColumnDef::new(ChannelNotePining::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChannelNotePining::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChannelNotePining::ChannelId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ChannelNotePining::NoteId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_44f7474496bcf2e4b741681146d")
+                        .col(ChannelNotePining::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_10b19ef67d297ea9de325cd4502")
+                    .from(ChannelNotePining::Table, ChannelNotePining::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_8125f950afd3093acb10d2db8a8")
+                    .from(ChannelNotePining::Table, ChannelNotePining::ChannelId)
+                    .to(Channel::Table, Channel::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Clip::Table)
+                .col(ColumnDef::new(Clip::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Clip::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Clip::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(Clip::Name).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(Clip::IsPublic)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Clip::Description).string_len(2048))
+                .primary_key(
+                    Index::create()
+                        .name("PK_f0685dac8d4dd056d7255670b75")
+                        .col(Clip::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_2b5ec6c574d6802c94c80313fb2")
+                .from(Clip::Table, Clip::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(ClipNote::Table)
+                .col(ColumnDef::new(ClipNote::Id).string_len(32).not_null())
+                .col(ColumnDef::new(ClipNote::NoteId).string_len(32).not_null())
+                .col(ColumnDef::new(ClipNote::ClipId).string_len(32).not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_e94cda2f40a99b57e032a1a738b")
+                        .col(ClipNote::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_a012eaf5c87c65da1deb5fdbfa3")
+                    .from(ClipNote::Table, ClipNote::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_ebe99317bbbe9968a0c6f579adf")
+                    .from(ClipNote::Table, ClipNote::ClipId)
+                    .to(Clip::Table, Clip::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(DriveFile::Table)
+                .col(ColumnDef::new(DriveFile::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(DriveFile::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(DriveFile::UserId).string_len(32))
+                .col(ColumnDef::new(DriveFile::UserHost).string_len(128))
+                .col(ColumnDef::new(DriveFile::Md5).string_len(32).not_null())
+                .col(ColumnDef::new(DriveFile::Name).string_len(256).not_null())
+                .col(ColumnDef::new(DriveFile::Type).string_len(128).not_null())
+                .col(ColumnDef::new(DriveFile::Size).integer().not_null())
+                .col(ColumnDef::new(DriveFile::Comment).string_len(8192))
+                .col(
+                    ColumnDef::new(DriveFile::Properties)
+                        .json_binary()
+                        .not_null()
+                        .default("{}"),
+                )
+                .col(
+                    ColumnDef::new(DriveFile::StoredInternal)
+                        .boolean()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(DriveFile::Url).string_len(512).not_null())
+                .col(ColumnDef::new(DriveFile::ThumbnailUrl).string_len(512))
+                .col(ColumnDef::new(DriveFile::WebpublicUrl).string_len(512))
+                .col(ColumnDef::new(DriveFile::AccessKey).string_len(256))
+                .col(ColumnDef::new(DriveFile::ThumbnailAccessKey).string_len(256))
+                .col(ColumnDef::new(DriveFile::WebpublicAccessKey).string_len(256))
+                .col(ColumnDef::new(DriveFile::Uri).string_len(512))
+                .col(ColumnDef::new(DriveFile::Src).string_len(512))
+                .col(ColumnDef::new(DriveFile::FolderId).string_len(32))
+                .col(
+                    ColumnDef::new(DriveFile::IsSensitive)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(DriveFile::IsLink)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(DriveFile::Blurhash).string_len(128))
+                .col(ColumnDef::new(DriveFile::WebpublicType).string_len(128))
+                .col(
+                    ColumnDef::new(DriveFile::RequestHeaders)
+                        .json_binary()
+                        .default("{}"),
+                )
+                .col(ColumnDef::new(DriveFile::RequestIp).string_len(128))
+                .col(
+                    ColumnDef::new(DriveFile::MaybeSensitive)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(DriveFile::MaybePorn)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_43ddaaaf18c9e68029b7cbb032e")
+                        .col(DriveFile::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_860fa6f6c7df5bb887249fba22e")
+                    .from(DriveFile::Table, DriveFile::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_bb90d1956dafc4068c28aa7560a")
+                    .from(DriveFile::Table, DriveFile::FolderId)
+                    .to(DriveFolder::Table, DriveFolder::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(DriveFolder::Table)
+                .col(ColumnDef::new(DriveFolder::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(DriveFolder::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(DriveFolder::Name).string_len(128).not_null())
+                .col(ColumnDef::new(DriveFolder::UserId).string_len(32))
+                .col(ColumnDef::new(DriveFolder::ParentId).string_len(32))
+                .primary_key(
+                    Index::create()
+                        .name("PK_7a0c089191f5ebdc214e0af808a")
+                        .col(DriveFolder::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_00ceffb0cdc238b3233294f08f2")
+                    .from(DriveFolder::Table, DriveFolder::ParentId)
+                    .to(DriveFolder::Table, DriveFolder::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_f4fc06e49c0171c85f1c48060d2")
+                    .from(DriveFolder::Table, DriveFolder::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Emoji::Table)
+                .col(ColumnDef::new(Emoji::Id).string_len(32).not_null())
+                .col(ColumnDef::new(Emoji::UpdatedAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(Emoji::Name).string_len(128).not_null())
+                .col(ColumnDef::new(Emoji::Host).string_len(128))
+                .col(
+                    ColumnDef::new(Emoji::OriginalUrl)
+                        .string_len(512)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Emoji::Uri).string_len(512))
+                .col(ColumnDef::new(Emoji::Type).string_len(64))
+                .col(
+                    ColumnDef::new(Emoji::Aliases)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(Emoji::Category).string_len(128))
+                .col(
+                    ColumnDef::new(Emoji::PublicUrl)
+                        .string_len(512)
+                        .not_null()
+                        .default(""),
+                )
+                .col(ColumnDef::new(Emoji::License).string_len(1024))
+                .col(ColumnDef::new(Emoji::Width).integer())
+                .col(ColumnDef::new(Emoji::Height).integer())
+                .primary_key(
+                    Index::create()
+                        .name("PK_df74ce05e24999ee01ea0bc50a3")
+                        .col(Emoji::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(FollowRequest::Table)
+                .col(ColumnDef::new(FollowRequest::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(FollowRequest::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(FollowRequest::FolloweeId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(FollowRequest::FollowerId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(FollowRequest::RequestId).string_len(128))
+                .col(ColumnDef::new(FollowRequest::FollowerHost).string_len(128))
+                .col(ColumnDef::new(FollowRequest::FollowerInbox).string_len(512))
+                .col(ColumnDef::new(FollowRequest::FollowerSharedInbox).string_len(512))
+                .col(ColumnDef::new(FollowRequest::FolloweeHost).string_len(128))
+                .col(ColumnDef::new(FollowRequest::FolloweeInbox).string_len(512))
+                .col(ColumnDef::new(FollowRequest::FolloweeSharedInbox).string_len(512))
+                .primary_key(
+                    Index::create()
+                        .name("PK_53a9aa3725f7a3deb150b39dbfc")
+                        .col(FollowRequest::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_12c01c0d1a79f77d9f6c15fadd2")
+                    .from(FollowRequest::Table, FollowRequest::FolloweeId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_a7fd92dd6dc519e6fb435dd108f")
+                    .from(FollowRequest::Table, FollowRequest::FollowerId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Following::Table)
+                .col(ColumnDef::new(Following::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Following::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Following::FolloweeId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Following::FollowerId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Following::FollowerHost).string_len(128))
+                .col(ColumnDef::new(Following::FollowerInbox).string_len(512))
+                .col(ColumnDef::new(Following::FollowerSharedInbox).string_len(512))
+                .col(ColumnDef::new(Following::FolloweeHost).string_len(128))
+                .col(ColumnDef::new(Following::FolloweeInbox).string_len(512))
+                .col(ColumnDef::new(Following::FolloweeSharedInbox).string_len(512))
+                .primary_key(
+                    Index::create()
+                        .name("PK_c76c6e044bdf76ecf8bfb82a645")
+                        .col(Following::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_24e0042143a18157b234df186c3")
+                    .from(Following::Table, Following::FolloweeId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_6516c5a6f3c015b4eed39978be5")
+                    .from(Following::Table, Following::FollowerId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(GalleryLike::Table)
+                .col(ColumnDef::new(GalleryLike::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(GalleryLike::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(GalleryLike::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(GalleryLike::PostId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_853ab02be39b8de45cd720cc15f")
+                        .col(GalleryLike::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_8fd5215095473061855ceb948cf")
+                    .from(GalleryLike::Table, GalleryLike::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_b1cb568bfe569e47b7051699fc8")
+                    .from(GalleryLike::Table, GalleryLike::PostId)
+                    .to(GalleryPost::Table, GalleryPost::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(GalleryPost::Table)
+                .col(ColumnDef::new(GalleryPost::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(GalleryPost::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(GalleryPost::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(GalleryPost::Title)
+                        .string_len(256)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(GalleryPost::Description).string_len(2048))
+                .col(
+                    ColumnDef::new(GalleryPost::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(GalleryPost::FileIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(GalleryPost::IsSensitive)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(GalleryPost::LikedCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(GalleryPost::Tags)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_8e90d7b6015f2c4518881b14753")
+                        .col(GalleryPost::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_985b836dddd8615e432d7043ddb")
+                .from(GalleryPost::Table, GalleryPost::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(Hashtag::Table)
+                .col(ColumnDef::new(Hashtag::Id).string_len(32).not_null())
+                .col(ColumnDef::new(Hashtag::Name).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(Hashtag::MentionedUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::MentionedUsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::MentionedLocalUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::MentionedLocalUsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::MentionedRemoteUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::MentionedRemoteUsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::AttachedUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::AttachedUsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::AttachedLocalUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::AttachedLocalUsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::AttachedRemoteUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Hashtag::AttachedRemoteUsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_cb36eb8af8412bfa978f1165d78")
+                        .col(Hashtag::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(Instance::Table)
+                .col(ColumnDef::new(Instance::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Instance::CaughtAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Instance::Host).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(Instance::UsersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Instance::NotesCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Instance::FollowingCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Instance::FollowersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(ColumnDef::new(Instance::LatestRequestSentAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(Instance::LatestStatus).integer())
+                .col(ColumnDef::new(Instance::LatestRequestReceivedAt).timestamp_with_time_zone())
+                .col(
+                    ColumnDef::new(Instance::LastCommunicatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Instance::IsNotResponding)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Instance::SoftwareName).string_len(64))
+                .col(ColumnDef::new(Instance::SoftwareVersion).string_len(64))
+                .col(ColumnDef::new(Instance::OpenRegistrations).boolean())
+                .col(ColumnDef::new(Instance::Name).string_len(256))
+                .col(ColumnDef::new(Instance::Description).string_len(4096))
+                .col(ColumnDef::new(Instance::MaintainerName).string_len(128))
+                .col(ColumnDef::new(Instance::MaintainerEmail).string_len(256))
+                .col(ColumnDef::new(Instance::InfoUpdatedAt).timestamp_with_time_zone())
+                .col(
+                    ColumnDef::new(Instance::IsSuspended)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Instance::IconUrl).string_len(256))
+                .col(ColumnDef::new(Instance::ThemeColor).string_len(64))
+                .col(ColumnDef::new(Instance::FaviconUrl).string_len(256))
+                .primary_key(
+                    Index::create()
+                        .name("PK_eaf60e4a0c399c9935413e06474")
+                        .col(Instance::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(MessagingMessage::Table)
+                .col(
+                    ColumnDef::new(MessagingMessage::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(MessagingMessage::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(MessagingMessage::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(MessagingMessage::RecipientId).string_len(32))
+                .col(ColumnDef::new(MessagingMessage::Text).string_len(4096))
+                .col(
+                    ColumnDef::new(MessagingMessage::IsRead)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(MessagingMessage::FileId).string_len(32))
+                .col(ColumnDef::new(MessagingMessage::GroupId).string_len(32))
+                .col(
+                    ColumnDef::new(MessagingMessage::Reads)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(MessagingMessage::Uri).string_len(512))
+                .primary_key(
+                    Index::create()
+                        .name("PK_db398fd79dc95d0eb8c30456eaa")
+                        .col(MessagingMessage::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_2c4be03b446884f9e9c502135be")
+                    .from(MessagingMessage::Table, MessagingMessage::GroupId)
+                    .to(UserGroup::Table, UserGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_535def119223ac05ad3fa9ef64b")
+                    .from(MessagingMessage::Table, MessagingMessage::FileId)
+                    .to(DriveFile::Table, DriveFile::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_5377c307783fce2b6d352e1203b")
+                    .from(MessagingMessage::Table, MessagingMessage::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_cac14a4e3944454a5ce7daa5142")
+                    .from(MessagingMessage::Table, MessagingMessage::RecipientId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Meta::Table)
+                .col(ColumnDef::new(Meta::Id).string_len(32).not_null())
+                .col(ColumnDef::new(Meta::Name).string_len(128))
+                .col(ColumnDef::new(Meta::Description).string_len(1024))
+                .col(ColumnDef::new(Meta::MaintainerName).string_len(128))
+                .col(ColumnDef::new(Meta::MaintainerEmail).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::DisableRegistration)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::DisableLocalTimeline)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::DisableGlobalTimeline)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::UseStarForReactionFallback)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::Langs)
+                        .array_of(ColumnType::String(Some(64)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::HiddenTags)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::BlockedHosts)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::MascotImageUrl)
+                        .string_len(512)
+                        .default("/static-assets/badges/info.png"),
+                )
+                .col(ColumnDef::new(Meta::BannerUrl).string_len(512))
+                .col(
+                    ColumnDef::new(Meta::ErrorImageUrl)
+                        .string_len(512)
+                        .default("/static-assets/badges/error.png"),
+                )
+                .col(ColumnDef::new(Meta::IconUrl).string_len(512))
+                .col(
+                    ColumnDef::new(Meta::CacheRemoteFiles)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableRecaptcha)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::RecaptchaSiteKey).string_len(64))
+                .col(ColumnDef::new(Meta::RecaptchaSecretKey).string_len(64))
+                .col(
+                    ColumnDef::new(Meta::LocalDriveCapacityMb)
+                        .integer()
+                        .not_null()
+                        .default(1024),
+                )
+                .col(
+                    ColumnDef::new(Meta::RemoteDriveCapacityMb)
+                        .integer()
+                        .not_null()
+                        .default(32),
+                )
+                .col(ColumnDef::new(Meta::SummalyProxy).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::EnableEmail)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::Email).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::SmtpSecure)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::SmtpHost).string_len(128))
+                .col(ColumnDef::new(Meta::SmtpPort).integer())
+                .col(ColumnDef::new(Meta::SmtpUser).string_len(1024))
+                .col(ColumnDef::new(Meta::SmtpPass).string_len(1024))
+                .col(
+                    ColumnDef::new(Meta::EnableServiceWorker)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::SwPublicKey).string_len(128))
+                .col(ColumnDef::new(Meta::SwPrivateKey).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::EnableTwitterIntegration)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::TwitterConsumerKey).string_len(128))
+                .col(ColumnDef::new(Meta::TwitterConsumerSecret).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::EnableGithubIntegration)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::GithubClientId).string_len(128))
+                .col(ColumnDef::new(Meta::GithubClientSecret).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::EnableDiscordIntegration)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::DiscordClientId).string_len(128))
+                .col(ColumnDef::new(Meta::DiscordClientSecret).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::PinnedUsers)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(Meta::ToSUrl).string_len(512))
+                .col(
+                    ColumnDef::new(Meta::RepositoryUrl)
+                        .string_len(512)
+                        .not_null()
+                        .default("https://codeberg.org/firefish/firefish"),
+                )
+                .col(
+                    ColumnDef::new(Meta::FeedbackUrl)
+                        .string_len(512)
+                        .default("https://codeberg.org/firefish/firefish/issues"),
+                )
+                .col(
+                    ColumnDef::new(Meta::UseObjectStorage)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::ObjectStorageBucket).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStoragePrefix).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStorageBaseUrl).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStorageEndpoint).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStorageRegion).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStorageAccessKey).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStorageSecretKey).string_len(512))
+                .col(ColumnDef::new(Meta::ObjectStoragePort).integer())
+                .col(
+                    ColumnDef::new(Meta::ObjectStorageUseSSL)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(ColumnDef::new(Meta::ProxyAccountId).string_len(32))
+                .col(
+                    ColumnDef::new(Meta::ObjectStorageUseProxy)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableHcaptcha)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::HcaptchaSiteKey).string_len(64))
+                .col(ColumnDef::new(Meta::HcaptchaSecretKey).string_len(64))
+                .col(
+                    ColumnDef::new(Meta::ObjectStorageSetPublicRead)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::PinnedPages)
+                        .array_of(ColumnType::String(Some(512)), backend)
+                        .not_null()
+                        .default(array_default(
+                            &[
+                                "/featured",
+                                "/channels",
+                                "/explore",
+                                "/pages",
+                                "/about-misskey",
+                            ],
+                            backend,
+                        )),
+                )
+                .col(ColumnDef::new(Meta::BackgroundImageUrl).string_len(512))
+                .col(ColumnDef::new(Meta::LogoImageUrl).string_len(512))
+                .col(ColumnDef::new(Meta::PinnedClipId).string_len(32))
+                .col(
+                    ColumnDef::new(Meta::ObjectStorageS3ForcePathStyle)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(
+                    ColumnDef::new(Meta::AllowedHosts)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(Meta::SecureMode).boolean().default(false))
+                .col(ColumnDef::new(Meta::PrivateMode).boolean().default(false))
+                .col(ColumnDef::new(Meta::DeeplAuthKey).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::DeeplIsPro)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::EmailRequiredForSignup)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Meta::ThemeColor).string_len(512))
+                .col(ColumnDef::new(Meta::DefaultLightTheme).string_len(8192))
+                .col(ColumnDef::new(Meta::DefaultDarkTheme).string_len(8192))
+                .col(
+                    ColumnDef::new(Meta::SensitiveMediaDetection)
+                        .enum_type(&META_SENSITIVEMEDIADETECTION_ENUM)
+                        .not_null()
+                        .default("none"),
+                )
+                .col(
+                    ColumnDef::new(Meta::SensitiveMediaDetectionSensitivity)
+                        .enum_type(&META_SENSITIVEMEDIADETECTIONSENSITIVITY_ENUM)
+                        .not_null()
+                        .default("medium"),
+                )
+                .col(
+                    ColumnDef::new(Meta::SetSensitiveFlagAutomatically)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableIpLogging)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableSensitiveMediaDetectionForVideos)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableActiveEmailValidation)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(
+                    ColumnDef::new(Meta::CustomMOTD)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::CustomSplashIcons)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::DisableRecommendedTimeline)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(
+                    ColumnDef::new(Meta::RecommendedInstances)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableGuestTimeline)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::DefaultReaction)
+                        .string_len(256)
+                        .not_null()
+                        .default("⭐"),
+                )
+                .col(ColumnDef::new(Meta::LibreTranslateApiUrl).string_len(512))
+                .col(ColumnDef::new(Meta::LibreTranslateApiKey).string_len(128))
+                .col(
+                    ColumnDef::new(Meta::SilencedHosts)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Meta::ExperimentalFeatures)
+                        .json_binary()
+                        .not_null()
+                        .default("{}"),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableServerMachineStats)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Meta::EnableIdenticonGeneration)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(ColumnDef::new(Meta::DonationLink).string_len(256))
+                .primary_key(
+                    Index::create()
+                        .name("PK_c4c17a6c2bd7651338b60fc590b")
+                        .col(Meta::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_ab1bc0c1e209daa77b8e8d212ad")
+                .from(Meta::Table, Meta::ProxyAccountId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::SetNull)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(Migrations::Table)
+                .col(
+                    ColumnDef::new(Migrations::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(Migrations::Timestamp)
+                        .big_integer()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Migrations::Name).string().not_null())
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ModerationLog::Table)
+                .col(ColumnDef::new(ModerationLog::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(ModerationLog::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ModerationLog::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ModerationLog::Type)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(ModerationLog::Info).json_binary().not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_d0adca6ecfd068db83e4526cc26")
+                        .col(ModerationLog::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_a08ad074601d204e0f69da9a954")
+                .from(ModerationLog::Table, ModerationLog::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(MutedNote::Table)
+                .col(ColumnDef::new(MutedNote::Id).string_len(32).not_null())
+                .col(ColumnDef::new(MutedNote::NoteId).string_len(32).not_null())
+                .col(ColumnDef::new(MutedNote::UserId).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(MutedNote::Reason)
+                        .enum_type(&MUTED_NOTE_REASON_ENUM)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_897e2eff1c0b9b64e55ca1418a4")
+                        .col(MutedNote::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_70ab9786313d78e4201d81cdb89")
+                    .from(MutedNote::Table, MutedNote::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_d8e07aa18c2d64e86201601aec1")
+                    .from(MutedNote::Table, MutedNote::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Muting::Table)
+                .col(ColumnDef::new(Muting::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Muting::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Muting::MuteeId).string_len(32).not_null())
+                .col(ColumnDef::new(Muting::MuterId).string_len(32).not_null())
+                .col(ColumnDef::new(Muting::ExpiresAt).timestamp_with_time_zone())
+                .primary_key(
+                    Index::create()
+                        .name("PK_2e92d06c8b5c602eeb27ca9ba48")
+                        .col(Muting::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_93060675b4a79a577f31d260c67")
+                    .from(Muting::Table, Muting::MuterId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_ec96b4fed9dae517e0dbbe0675c")
+                    .from(Muting::Table, Muting::MuteeId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Note::Table)
+                .col(ColumnDef::new(Note::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Note::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Note::ReplyId).string_len(32))
+                .col(ColumnDef::new(Note::RenoteId).string_len(32))
+                .col(ColumnDef::new(Note::Text).text())
+                .col(ColumnDef::new(Note::Name).string_len(256))
+                .col(ColumnDef::new(Note::Cw).string_len(512))
+                .col(ColumnDef::new(Note::UserId).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Note::LocalOnly)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Note::RenoteCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Note::RepliesCount)
+                        .small_integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Note::Reactions)
+                        .json_binary()
+                        .not_null()
+                        .default("{}"),
+                )
+                .col(
+                    ColumnDef::new(Note::Visibility)
+                        .enum_type(&NOTE_VISIBILITY_ENUM)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Note::Uri).string_len(512))
+                .col(ColumnDef::new(Note::Score).integer().not_null().default(0))
+                .col(
+                    ColumnDef::new(Note::FileIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Note::AttachedFileTypes)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Note::VisibleUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Note::Mentions)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Note::MentionedRemoteUsers)
+                        .text()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(Note::Emojis)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Note::Tags)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Note::HasPoll)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Note::UserHost).string_len(128))
+                .col(ColumnDef::new(Note::ReplyUserId).string_len(32))
+                .col(ColumnDef::new(Note::ReplyUserHost).string_len(128))
+                .col(ColumnDef::new(Note::RenoteUserId).string_len(32))
+                .col(ColumnDef::new(Note::RenoteUserHost).string_len(128))
+                .col(ColumnDef::new(Note::Url).string_len(512))
+                .col(ColumnDef::new(Note::ChannelId).string_len(32))
+                .col(ColumnDef::new(Note::ThreadId).string_len(256))
+                .col(ColumnDef::new(Note::UpdatedAt).timestamp_with_time_zone())
+                .primary_key(
+                    Index::create()
+                        .name("PK_96d0c172a4fba276b1bbed43058")
+                        .col(Note::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_17cb3553c700a4985dff5a30ff5")
+                    .from(Note::Table, Note::ReplyId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_52ccc804d7c69037d558bac4c96")
+                    .from(Note::Table, Note::RenoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_5b87d9d19127bd5d92026017a7b")
+                    .from(Note::Table, Note::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_f22169eb10657bded6d875ac8f9")
+                    .from(Note::Table, Note::ChannelId)
+                    .to(Channel::Table, Channel::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(NoteEdit::Table)
+                .col(ColumnDef::new(NoteEdit::Id).string_len(32).not_null())
+                .col(ColumnDef::new(NoteEdit::NoteId).string_len(32).not_null())
+                .col(ColumnDef::new(NoteEdit::Text).text())
+                .col(ColumnDef::new(NoteEdit::Cw).string_len(512))
+                .col(
+                    ColumnDef::new(NoteEdit::FileIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(NoteEdit::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_736fc6e0d4e222ecc6f82058e08")
+                        .col(NoteEdit::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_702ad5ae993a672e4fbffbcd38c")
+                .from(NoteEdit::Table, NoteEdit::NoteId)
+                .to(Note::Table, Note::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(NoteFavorite::Table)
+                .col(ColumnDef::new(NoteFavorite::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(NoteFavorite::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteFavorite::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteFavorite::NoteId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_af0da35a60b9fa4463a62082b36")
+                        .col(NoteFavorite::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_0e00498f180193423c992bc4370")
+                    .from(NoteFavorite::Table, NoteFavorite::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_47f4b1892f5d6ba8efb3057d81a")
+                    .from(NoteFavorite::Table, NoteFavorite::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(NoteReaction::Table)
+                .col(ColumnDef::new(NoteReaction::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(NoteReaction::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteReaction::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteReaction::NoteId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteReaction::Reaction)
+                        .string_len(260)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_767ec729b108799b587a3fcc9cf")
+                        .col(NoteReaction::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_13761f64257f40c5636d0ff95ee")
+                    .from(NoteReaction::Table, NoteReaction::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_45145e4953780f3cd5656f0ea6a")
+                    .from(NoteReaction::Table, NoteReaction::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(NoteThreadMuting::Table)
+                .col(
+                    ColumnDef::new(NoteThreadMuting::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteThreadMuting::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteThreadMuting::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteThreadMuting::ThreadId)
+                        .string_len(256)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_ec5936d94d1a0369646d12a3a47")
+                        .col(NoteThreadMuting::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_29c11c7deb06615076f8c95b80a")
+                .from(NoteThreadMuting::Table, NoteThreadMuting::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(NoteUnread::Table)
+                .col(ColumnDef::new(NoteUnread::Id).string_len(32).not_null())
+                .col(ColumnDef::new(NoteUnread::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(NoteUnread::NoteId).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(NoteUnread::NoteUserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(NoteUnread::IsSpecified).boolean().not_null())
+                .col(ColumnDef::new(NoteUnread::IsMentioned).boolean().not_null())
+                .col(ColumnDef::new(NoteUnread::NoteChannelId).string_len(32))
+                .primary_key(
+                    Index::create()
+                        .name("PK_1904eda61a784f57e6e51fa9c1f")
+                        .col(NoteUnread::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_56b0166d34ddae49d8ef7610bb9")
+                    .from(NoteUnread::Table, NoteUnread::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_e637cba4dc4410218c4251260e4")
+                    .from(NoteUnread::Table, NoteUnread::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(NoteWatching::Table)
+                .col(ColumnDef::new(NoteWatching::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(NoteWatching::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteWatching::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteWatching::NoteId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(NoteWatching::NoteUserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_49286fdb23725945a74aa27d757")
+                        .col(NoteWatching::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_03e7028ab8388a3f5e3ce2a8619")
+                    .from(NoteWatching::Table, NoteWatching::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_b0134ec406e8d09a540f8182888")
+                    .from(NoteWatching::Table, NoteWatching::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Notification::Table)
+                .col(ColumnDef::new(Notification::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Notification::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Notification::NotifieeId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Notification::NotifierId).string_len(32))
+                .col(
+                    ColumnDef::new(Notification::IsRead)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(Notification::NoteId).string_len(32))
+                .col(ColumnDef::new(Notification::Reaction).string_len(128))
+                .col(ColumnDef::new(Notification::Choice).integer())
+                .col(ColumnDef::new(Notification::FollowRequestId).string_len(32))
+                .col(
+                    ColumnDef::new(Notification::Type)
+                        .enum_type(&NOTIFICATION_TYPE_ENUM)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Notification::UserGroupInvitationId).string_len(32))
+                .col(ColumnDef::new(Notification::CustomBody).string_len(2048))
+                .col(ColumnDef::new(Notification::CustomHeader).string_len(256))
+                .col(ColumnDef::new(Notification::CustomIcon).string_len(1024))
+                .col(ColumnDef::new(Notification::AppAccessTokenId).string_len(32))
+                .primary_key(
+                    Index::create()
+                        .name("PK_705b6c7cdf9b2c2ff7ac7872cb7")
+                        .col(Notification::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_3b4e96eec8d36a8bbb9d02aa710")
+                    .from(Notification::Table, Notification::NotifierId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_3c601b70a1066d2c8b517094cb9")
+                    .from(Notification::Table, Notification::NotifieeId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_769cb6b73a1efe22ddf733ac453")
+                    .from(Notification::Table, Notification::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_8fe87814e978053a53b1beb7e98")
+                    .from(Notification::Table, Notification::UserGroupInvitationId)
+                    .to(UserGroupInvitation::Table, UserGroupInvitation::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_bd7fab507621e635b32cd31892c")
+                    .from(Notification::Table, Notification::FollowRequestId)
+                    .to(FollowRequest::Table, FollowRequest::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_e22bf6bda77b6adc1fd9e75c8c9")
+                    .from(Notification::Table, Notification::AppAccessTokenId)
+                    .to(AccessToken::Table, AccessToken::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Page::Table)
+                .col(ColumnDef::new(Page::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Page::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Page::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Page::Title).string_len(256).not_null())
+                .col(ColumnDef::new(Page::Name).string_len(256).not_null())
+                .col(ColumnDef::new(Page::Summary).string_len(256))
+                .col(ColumnDef::new(Page::AlignCenter).boolean().not_null())
+                .col(ColumnDef::new(Page::Font).string_len(32).not_null())
+                .col(ColumnDef::new(Page::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(Page::EyeCatchingImageId).string_len(32))
+                .col(
+                    ColumnDef::new(Page::Content)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(Page::Variables)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(Page::Visibility)
+                        .enum_type(&PAGE_VISIBILITY_ENUM)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Page::VisibleUserIds)
+                        .array_of(ColumnType::String(Some(32)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Page::LikedCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(Page::HideTitleWhenPinned)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(Page::Script)
+                        .string_len(16384)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(Page::IsPublic)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_742f4117e065c5b6ad21b37ba1f")
+                        .col(Page::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_a9ca79ad939bf06066b81c9d3aa")
+                    .from(Page::Table, Page::EyeCatchingImageId)
+                    .to(DriveFile::Table, DriveFile::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_ae1d917992dd0c9d9bbdad06c4a")
+                    .from(Page::Table, Page::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(PageLike::Table)
+                .col(ColumnDef::new(PageLike::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(PageLike::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(PageLike::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(PageLike::PageId).string_len(32).not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_813f034843af992d3ae0f43c64c")
+                        .col(PageLike::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_0e61efab7f88dbb79c9166dbb48")
+                    .from(PageLike::Table, PageLike::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_cf8782626dced3176038176a847")
+                    .from(PageLike::Table, PageLike::PageId)
+                    .to(Page::Table, Page::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(PasswordResetRequest::Table)
+                .col(
+                    ColumnDef::new(PasswordResetRequest::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(PasswordResetRequest::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(PasswordResetRequest::Token)
+                        .string_len(256)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(PasswordResetRequest::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_fcf4b02eae1403a2edaf87fd074")
+                        .col(PasswordResetRequest::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_4bb7fd4a34492ae0e6cc8d30ac8")
+                .from(PasswordResetRequest::Table, PasswordResetRequest::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(Poll::Table)
+                .col(ColumnDef::new(Poll::NoteId).string_len(32).not_null())
+                .col(ColumnDef::new(Poll::ExpiresAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(Poll::Multiple).boolean().not_null())
+                .col(
+                    ColumnDef::new(Poll::Choices)
+                        .array_of(ColumnType::String(Some(256)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(Poll::Votes)
+                        .array_of(ColumnType::Integer, backend)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(Poll::NoteVisibility)
+                        .enum_type(&POLL_NOTEVISIBILITY_ENUM)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Poll::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(Poll::UserHost).string_len(128))
+                .primary_key(
+                    Index::create()
+                        .name("PK_da851e06d0dfe2ef397d8b1bf1b")
+                        .col(Poll::NoteId),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_da851e06d0dfe2ef397d8b1bf1b")
+                .from(Poll::Table, Poll::NoteId)
+                .to(Note::Table, Note::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(PollVote::Table)
+                .col(ColumnDef::new(PollVote::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(PollVote::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(PollVote::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(PollVote::NoteId).string_len(32).not_null())
+                .col(ColumnDef::new(PollVote::Choice).integer().not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_fd002d371201c472490ba89c6a0")
+                        .col(PollVote::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_66d2bd2ee31d14bcc23069a89f8")
+                    .from(PollVote::Table, PollVote::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_aecfbd5ef60374918e63ee95fa7")
+                    .from(PollVote::Table, PollVote::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(PromoNote::Table)
+                .col(ColumnDef::new(PromoNote::NoteId).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(PromoNote::ExpiresAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(PromoNote::UserId).string_len(32).not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_e263909ca4fe5d57f8d4230dd5c")
+                        .col(PromoNote::NoteId),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_e263909ca4fe5d57f8d4230dd5c")
+                .from(PromoNote::Table, PromoNote::NoteId)
+                .to(Note::Table, Note::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(PromoRead::Table)
+                .col(ColumnDef::new(PromoRead::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(PromoRead::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(PromoRead::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(PromoRead::NoteId).string_len(32).not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_61917c1541002422b703318b7c9")
+                        .col(PromoRead::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_9657d55550c3d37bfafaf7d4b05")
+                    .from(PromoRead::Table, PromoRead::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_a46a1a603ecee695d7db26da5f4")
+                    .from(PromoRead::Table, PromoRead::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(RegistrationTicket::Table)
+                .col(
+                    ColumnDef::new(RegistrationTicket::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RegistrationTicket::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RegistrationTicket::Code)
+                        .string_len(64)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_f11696b6fafcf3662d4292734f8")
+                        .col(RegistrationTicket::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(RegistryItem::Table)
+                .col(ColumnDef::new(RegistryItem::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(RegistryItem::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RegistryItem::UpdatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RegistryItem::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RegistryItem::Key)
+                        .string_len(1024)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RegistryItem::Scope)
+                        .array_of(ColumnType::String(Some(1024)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(RegistryItem::Domain).string_len(512))
+                .col(
+                    ColumnDef::new(RegistryItem::Value)
+                        .json_binary()
+                        .default("{}"),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_64b3f7e6008b4d89b826cd3af95")
+                        .col(RegistryItem::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_fb9d21ba0abb83223263df6bcb3")
+                .from(RegistryItem::Table, RegistryItem::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(Relay::Table)
+                .col(ColumnDef::new(Relay::Id).string_len(32).not_null())
+                .col(ColumnDef::new(Relay::Inbox).string_len(512).not_null())
+                .col(
+                    ColumnDef::new(Relay::Status)
+                        .enum_type(&RELAY_STATUS_ENUM)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_78ebc9cfddf4292633b7ba57aee")
+                        .col(Relay::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(RenoteMuting::Table)
+                .col(ColumnDef::new(RenoteMuting::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(RenoteMuting::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RenoteMuting::MuteeId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(RenoteMuting::MuterId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_renoteMuting_id")
+                        .col(RenoteMuting::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(ReversiGame::Table)
+                .col(ColumnDef::new(ReversiGame::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(ReversiGame::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(ReversiGame::StartedAt).timestamp_with_time_zone())
+                .col(
+                    ColumnDef::new(ReversiGame::User1Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::User2Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::User1Accepted)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::User2Accepted)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(ReversiGame::Black).integer())
+                .col(
+                    ColumnDef::new(ReversiGame::IsStarted)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::IsEnded)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(ReversiGame::WinnerId).string_len(32))
+                .col(ColumnDef::new(ReversiGame::Surrendered).string_len(32))
+                .col(
+                    ColumnDef::new(ReversiGame::Logs)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::Map)
+                        .array_of(ColumnType::String(Some(64)), backend)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(ReversiGame::Bw).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(ReversiGame::IsLlotheo)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::CanPutEverywhere)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(ReversiGame::LoopedBoard)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(ReversiGame::Form1).json_binary())
+                .col(ColumnDef::new(ReversiGame::Form2).json_binary())
+                .col(ColumnDef::new(ReversiGame::Crc32).string_len(32))
+                .primary_key(
+                    Index::create()
+                        .name("PK_76b30eeba71b1193ad7c5311c3f")
+                        .col(ReversiGame::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_6649a4e8c5d5cf32fb03b5da9f6")
+                    .from(ReversiGame::Table, ReversiGame::User2Id)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_f7467510c60a45ce5aca6292743")
+                    .from(ReversiGame::Table, ReversiGame::User1Id)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(ReversiMatching::Table)
+                .col(
+                    ColumnDef::new(ReversiMatching::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ReversiMatching::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ReversiMatching::ParentId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(ReversiMatching::ChildId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_880bd0afbab232f21c8b9d146cf")
+                        .col(ReversiMatching::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_3b25402709dd9882048c2bbade0")
+                    .from(ReversiMatching::Table, ReversiMatching::ParentId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_e247b23a3c9b45f89ec1299d066")
+                    .from(ReversiMatching::Table, ReversiMatching::ChildId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(Signin::Table)
+                .col(ColumnDef::new(Signin::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Signin::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Signin::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(Signin::Ip).string_len(128).not_null())
+                .col(ColumnDef::new(Signin::Headers).json_binary().not_null())
+                .col(ColumnDef::new(Signin::Success).boolean().not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_9e96ddc025712616fc492b3b588")
+                        .col(Signin::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_2c308dbdc50d94dc625670055f7")
+                .from(Signin::Table, Signin::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(SwSubscription::Table)
+                .col(ColumnDef::new(SwSubscription::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(SwSubscription::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(SwSubscription::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(SwSubscription::Endpoint)
+                        .string_len(512)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(SwSubscription::Auth)
+                        .string_len(256)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(SwSubscription::Publickey)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(SwSubscription::SendReadMessage)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_e8f763631530051b95eb6279b91")
+                        .col(SwSubscription::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_97754ca6f2baff9b4abb7f853dd")
+                .from(SwSubscription::Table, SwSubscription::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(UsedUsername::Table)
+                .col(
+                    ColumnDef::new(UsedUsername::Username)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UsedUsername::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_78fd79d2d24c6ac2f4cc9a31a5d")
+                        .col(UsedUsername::Username),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(User::Table)
+                .col(ColumnDef::new(User::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(User::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(User::UpdatedAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(User::LastFetchedAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(User::Username).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(User::UsernameLower)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(User::Name).string_len(128))
+                .col(
+                    ColumnDef::new(User::FollowersCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(User::FollowingCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(
+                    ColumnDef::new(User::NotesCount)
+                        .integer()
+                        .not_null()
+                        .default(0),
+                )
+                .col(ColumnDef::new(User::AvatarId).string_len(32))
+                .col(ColumnDef::new(User::BannerId).string_len(32))
+                .col(
+                    ColumnDef::new(User::Tags)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(User::IsSuspended)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsSilenced)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsLocked)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsBot)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsCat)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsAdmin)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsModerator)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::Emojis)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(User::Host).string_len(128))
+                .col(ColumnDef::new(User::Inbox).string_len(512))
+                .col(ColumnDef::new(User::SharedInbox).string_len(512))
+                .col(ColumnDef::new(User::Featured).string_len(512))
+                .col(ColumnDef::new(User::Uri).string_len(512))
+                .col(ColumnDef::new(User::Token).char_len(16))
+                .col(
+                    ColumnDef::new(User::IsExplorable)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(ColumnDef::new(User::FollowersUri).string_len(512))
+                .col(ColumnDef::new(User::LastActiveDate).timestamp_with_time_zone())
+                .col(
+                    ColumnDef::new(User::HideOnlineStatus)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(User::IsDeleted)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(User::DriveCapacityOverrideMb).integer())
+                .col(ColumnDef::new(User::MovedToUri).string_len(512))
+                .col(ColumnDef::new(User::AlsoKnownAs).text())
+                .col(
+                    ColumnDef::new(User::SpeakAsCat)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_cace4a159ff9f2512dd42373760")
+                        .col(User::Id),
+                )
+                .index(
+                    Index::create()
+                        .name("REL_58f5c71eaab331645112cf8cfa")
+                        .unique()
+                        .col(User::AvatarId),
+                )
+                .index(
+                    Index::create()
+                        .name("REL_afc64b53f8db3707ceb34eb28e")
+                        .unique()
+                        .col(User::BannerId),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_a854e557b1b14814750c7c7b0c9")
+                        .unique()
+                        .col(User::Token),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_58f5c71eaab331645112cf8cfa5")
+                    .from(User::Table, User::AvatarId)
+                    .to(DriveFile::Table, DriveFile::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_afc64b53f8db3707ceb34eb28e2")
+                    .from(User::Table, User::BannerId)
+                    .to(DriveFile::Table, DriveFile::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserGroup::Table)
+                .col(ColumnDef::new(UserGroup::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(UserGroup::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(UserGroup::Name).string_len(256).not_null())
+                .col(ColumnDef::new(UserGroup::UserId).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(UserGroup::IsPrivate)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_3c29fba6fe013ec8724378ce7c9")
+                        .col(UserGroup::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_3d6b372788ab01be58853003c93")
+                .from(UserGroup::Table, UserGroup::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(UserGroupInvitation::Table)
+                .col(
+                    ColumnDef::new(UserGroupInvitation::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupInvitation::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupInvitation::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupInvitation::UserGroupId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_160c63ec02bf23f6a5c5e8140d6")
+                        .col(UserGroupInvitation::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_5cc8c468090e129857e9fecce5a")
+                    .from(UserGroupInvitation::Table, UserGroupInvitation::UserGroupId)
+                    .to(UserGroup::Table, UserGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_bfbc6305547539369fe73eb144a")
+                    .from(UserGroupInvitation::Table, UserGroupInvitation::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserGroupInvite::Table)
+                .col(
+                    ColumnDef::new(UserGroupInvite::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupInvite::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupInvite::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupInvite::UserGroupId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_3893884af0d3a5f4d01e7921a97")
+                        .col(UserGroupInvite::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_1039988afa3bf991185b277fe03")
+                    .from(UserGroupInvite::Table, UserGroupInvite::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_e10924607d058004304611a436a")
+                    .from(UserGroupInvite::Table, UserGroupInvite::UserGroupId)
+                    .to(UserGroup::Table, UserGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserGroupJoining::Table)
+                .col(
+                    ColumnDef::new(UserGroupJoining::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupJoining::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupJoining::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserGroupJoining::UserGroupId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_15f2425885253c5507e1599cfe7")
+                        .col(UserGroupJoining::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_67dc758bc0566985d1b3d399865")
+                    .from(UserGroupJoining::Table, UserGroupJoining::UserGroupId)
+                    .to(UserGroup::Table, UserGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_f3a1b4bd0c7cabba958a0c0b231")
+                    .from(UserGroupJoining::Table, UserGroupJoining::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserIp::Table)
+                .col(
+                    ColumnDef::new(UserIp::Id)
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(UserIp::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(UserIp::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(UserIp::Ip).string_len(128).not_null())
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(UserKeypair::Table)
+                .col(
+                    ColumnDef::new(UserKeypair::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserKeypair::PublicKey)
+                        .string_len(4096)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserKeypair::PrivateKey)
+                        .string_len(4096)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_f4853eb41ab722fe05f81cedeb6")
+                        .col(UserKeypair::UserId),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_f4853eb41ab722fe05f81cedeb6")
+                .from(UserKeypair::Table, UserKeypair::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(UserList::Table)
+                .col(ColumnDef::new(UserList::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(UserList::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(UserList::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(UserList::Name).string_len(128).not_null())
+                .primary_key(
+                    Index::create()
+                        .name("PK_87bab75775fd9b1ff822b656402")
+                        .col(UserList::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_b7fcefbdd1c18dce86687531f99")
+                .from(UserList::Table, UserList::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(UserListJoining::Table)
+                .col(
+                    ColumnDef::new(UserListJoining::Id)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserListJoining::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserListJoining::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserListJoining::UserListId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_11abb3768da1c5f8de101c9df45")
+                        .col(UserListJoining::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_605472305f26818cc93d1baaa74")
+                    .from(UserListJoining::Table, UserListJoining::UserListId)
+                    .to(UserList::Table, UserList::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_d844bfc6f3f523a05189076efaa")
+                    .from(UserListJoining::Table, UserListJoining::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserNotePining::Table)
+                .col(ColumnDef::new(UserNotePining::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(UserNotePining::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserNotePining::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserNotePining::NoteId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_a6a2dad4ae000abce2ea9d9b103")
+                        .col(UserNotePining::Id),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_68881008f7c3588ad7ecae471cf")
+                    .from(UserNotePining::Table, UserNotePining::NoteId)
+                    .to(Note::Table, Note::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_bfbc6f79ba4007b4ce5097f08d6")
+                    .from(UserNotePining::Table, UserNotePining::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserPending::Table)
+                .col(ColumnDef::new(UserPending::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(UserPending::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(UserPending::Code).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(UserPending::Username)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserPending::Email)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserPending::Password)
+                        .string_len(128)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_d4c84e013c98ec02d19b8fbbafa")
+                        .col(UserPending::Id),
+                )
+                .to_owned(),
+            vec![],
+        ),
+        (
+            Table::create()
+                .table(UserProfile::Table)
+                .col(
+                    ColumnDef::new(UserProfile::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(ColumnDef::new(UserProfile::Location).string_len(128))
+                .col(ColumnDef::new(UserProfile::Birthday).char_len(10))
+                .col(ColumnDef::new(UserProfile::Description).string_len(2048))
+                .col(
+                    ColumnDef::new(UserProfile::Fields)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(ColumnDef::new(UserProfile::Url).string_len(512))
+                .col(ColumnDef::new(UserProfile::Email).string_len(128))
+                .col(ColumnDef::new(UserProfile::EmailVerifyCode).string_len(128))
+                .col(
+                    ColumnDef::new(UserProfile::EmailVerified)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(UserProfile::TwoFactorTempSecret).string_len(128))
+                .col(ColumnDef::new(UserProfile::TwoFactorSecret).string_len(128))
+                .col(
+                    ColumnDef::new(UserProfile::TwoFactorEnabled)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(UserProfile::Password).string_len(128))
+                .col(
+                    ColumnDef::new(UserProfile::ClientData)
+                        .json_binary()
+                        .not_null()
+                        .default("{}"),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::AutoAcceptFollowed)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::AlwaysMarkNsfw)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::CarefulBot)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(UserProfile::UserHost).string_len(128))
+                .col(
+                    ColumnDef::new(UserProfile::SecurityKeysAvailable)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::UsePasswordLessLogin)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(ColumnDef::new(UserProfile::PinnedPageId).string_len(32))
+                .col(
+                    ColumnDef::new(UserProfile::Room)
+                        .json_binary()
+                        .not_null()
+                        .default("{}"),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::Integrations)
+                        .json_binary()
+                        .not_null()
+                        .default("{}"),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::InjectFeaturedNote)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::EnableWordMute)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::MutedWords)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::MutingNotificationTypes)
+                        .array_of(
+                            USER_PROFILE_MUTINGNOTIFICATIONTYPES_ENUM.column_type(),
+                            backend,
+                        )
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::NoCrawle)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::ReceiveAnnouncementEmail)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::EmailNotificationTypes)
+                        .json_binary()
+                        .not_null()
+                        .default("[\"follow\", \"receiveFollowRequest\", \"groupInvited\"]"),
+                )
+                .col(ColumnDef::new(UserProfile::Lang).string_len(32))
+                .col(
+                    ColumnDef::new(UserProfile::MutedInstances)
+                        .json_binary()
+                        .not_null()
+                        .default("[]"),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::PublicReactions)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::FfVisibility)
+                        .enum_type(&USER_PROFILE_FFVISIBILITY_ENUM)
+                        .not_null()
+                        .default("public"),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::AutoSensitive)
+                        .boolean()
+                        .not_null()
+                        .default(false),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::ModerationNote)
+                        .string_len(8192)
+                        .not_null()
+                        .default(""),
+                )
+                .col(
+                    ColumnDef::new(UserProfile::PreventAiLearning)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_51cb79b5555effaf7d69ba1cff9")
+                        .col(UserProfile::UserId),
+                )
+                .index(
+                    Index::create()
+                        .name("UQ_6dc44f1ceb65b1e72bacef2ca27")
+                        .unique()
+                        .col(UserProfile::PinnedPageId),
+                )
+                .to_owned(),
+            vec![
+                ForeignKey::create()
+                    .name("FK_51cb79b5555effaf7d69ba1cff9")
+                    .from(UserProfile::Table, UserProfile::UserId)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+                ForeignKey::create()
+                    .name("FK_6dc44f1ceb65b1e72bacef2ca27")
+                    .from(UserProfile::Table, UserProfile::PinnedPageId)
+                    .to(Page::Table, Page::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            ],
+        ),
+        (
+            Table::create()
+                .table(UserPublickey::Table)
+                .col(
+                    ColumnDef::new(UserPublickey::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserPublickey::KeyId)
+                        .string_len(256)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserPublickey::KeyPem)
+                        .string_len(4096)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_10c146e4b39b443ede016f6736d")
+                        .col(UserPublickey::UserId),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_10c146e4b39b443ede016f6736d")
+                .from(UserPublickey::Table, UserPublickey::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(UserSecurityKey::Table)
+                .col(ColumnDef::new(UserSecurityKey::Id).string().not_null())
+                .col(
+                    ColumnDef::new(UserSecurityKey::UserId)
+                        .string_len(32)
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserSecurityKey::PublicKey)
+                        .string()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserSecurityKey::LastUsed)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(
+                    ColumnDef::new(UserSecurityKey::Name)
+                        .string_len(30)
+                        .not_null(),
+                )
+                .primary_key(
+                    Index::create()
+                        .name("PK_3e508571121ab39c5f85d10c166")
+                        .col(UserSecurityKey::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_ff9ca3b5f3ee3d0681367a9b447")
+                .from(UserSecurityKey::Table, UserSecurityKey::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+        (
+            Table::create()
+                .table(Webhook::Table)
+                .col(ColumnDef::new(Webhook::Id).string_len(32).not_null())
+                .col(
+                    ColumnDef::new(Webhook::CreatedAt)
+                        .timestamp_with_time_zone()
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Webhook::UserId).string_len(32).not_null())
+                .col(ColumnDef::new(Webhook::Name).string_len(128).not_null())
+                .col(
+                    ColumnDef::new(Webhook::On)
+                        .array_of(ColumnType::String(Some(128)), backend)
+                        .not_null()
+                        .default(array_default(&[], backend)),
+                )
+                .col(ColumnDef::new(Webhook::Url).string_len(1024).not_null())
+                .col(ColumnDef::new(Webhook::Secret).string_len(1024).not_null())
+                .col(
+                    ColumnDef::new(Webhook::Active)
+                        .boolean()
+                        .not_null()
+                        .default(true),
+                )
+                .col(ColumnDef::new(Webhook::LatestSentAt).timestamp_with_time_zone())
+                .col(ColumnDef::new(Webhook::LatestStatus).integer())
+                .primary_key(
+                    Index::create()
+                        .name("PK_e6765510c2d078db49632b59020")
+                        .col(Webhook::Id),
+                )
+                .to_owned(),
+            vec![ForeignKey::create()
+                .name("FK_f272c8c8805969e6a6449c77b3c")
+                .from(Webhook::Table, Webhook::UserId)
+                .to(User::Table, User::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::NoAction)
+                .to_owned()],
+        ),
+    ]
+}
+
+pub fn indexes() -> Vec<IndexCreateStatement> {
+    vec![
+        Index::create()
+            .name("IDX_0ad37b7ef50f4ddc84363d7ccc")
+            .table(ChartActiveUsers::Table)
+            .col(ChartActiveUsers::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_e56f4beac5746d44bc3e19c80d")
+            .table(ChartApRequest::Table)
+            .col(ChartApRequest::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_13565815f618a1ff53886c5b28")
+            .table(ChartDrive::Table)
+            .col(ChartDrive::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_36cb699c49580d4e6c2e6159f9")
+            .table(ChartFederation::Table)
+            .col(ChartFederation::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_25a97c02003338124b2b75fdbc")
+            .table(ChartHashtag::Table)
+            .col(ChartHashtag::Date)
+            .col(ChartHashtag::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_39ee857ab2f23493037c6b6631")
+            .table(ChartInstance::Table)
+            .col(ChartInstance::Date)
+            .col(ChartInstance::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_a1efd3e0048a5f2793a47360dc")
+            .table(ChartNetwork::Table)
+            .col(ChartNetwork::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_42eb716a37d381cdf566192b2b")
+            .table(ChartNotes::Table)
+            .col(ChartNotes::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_30bf67687f483ace115c5ca642")
+            .table(ChartPerUserDrive::Table)
+            .col(ChartPerUserDrive::Date)
+            .col(ChartPerUserDrive::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_b77d4dd9562c3a899d9a286fcd")
+            .table(ChartPerUserFollowing::Table)
+            .col(ChartPerUserFollowing::Date)
+            .col(ChartPerUserFollowing::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_5048e9daccbbbc6d567bb142d3")
+            .table(ChartPerUserNotes::Table)
+            .col(ChartPerUserNotes::Date)
+            .col(ChartPerUserNotes::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_229a41ad465f9205f1f5703291")
+            .table(ChartPerUserReaction::Table)
+            .col(ChartPerUserReaction::Date)
+            .col(ChartPerUserReaction::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_a319e5dbf47e8a17497623beae")
+            .table(ChartTest::Table)
+            .col(ChartTest::Date)
+            .col(ChartTest::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_b14489029e4b3aaf4bba5fb524")
+            .table(ChartTestGrouped::Table)
+            .col(ChartTestGrouped::Date)
+            .col(ChartTestGrouped::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_a0cd75442dd10d0643a17c4a49")
+            .table(ChartTestUnique::Table)
+            .col(ChartTestUnique::Date)
+            .col(ChartTestUnique::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_845254b3eaf708ae8a6cac3026")
+            .table(ChartUsers::Table)
+            .col(ChartUsers::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_d5954f3df5e5e3bdfc3c03f390")
+            .table(ChartDayActiveUsers::Table)
+            .col(ChartDayActiveUsers::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_a848f66d6cec11980a5dd59582")
+            .table(ChartDayApRequest::Table)
+            .col(ChartDayApRequest::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_0b60ebb3aa0065f10b0616c117")
+            .table(ChartDayDrive::Table)
+            .col(ChartDayDrive::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_617a8fe225a6e701d89e02d2c7")
+            .table(ChartDayFederation::Table)
+            .col(ChartDayFederation::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_8f589cf056ff51f09d6096f645")
+            .table(ChartDayHashtag::Table)
+            .col(ChartDayHashtag::Date)
+            .col(ChartDayHashtag::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_fea7c0278325a1a2492f2d6acb")
+            .table(ChartDayInstance::Table)
+            .col(ChartDayInstance::Date)
+            .col(ChartDayInstance::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_8bfa548c2b31f9e07db113773e")
+            .table(ChartDayNetwork::Table)
+            .col(ChartDayNetwork::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_1a527b423ad0858a1af5a056d4")
+            .table(ChartDayNotes::Table)
+            .col(ChartDayNotes::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_62aa5047b5aec92524f24c701d")
+            .table(ChartDayPerUserDrive::Table)
+            .col(ChartDayPerUserDrive::Date)
+            .col(ChartDayPerUserDrive::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_e4849a3231f38281280ea4c0ee")
+            .table(ChartDayPerUserFollowing::Table)
+            .col(ChartDayPerUserFollowing::Date)
+            .col(ChartDayPerUserFollowing::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_c5545d4b31cdc684034e33b81c")
+            .table(ChartDayPerUserNotes::Table)
+            .col(ChartDayPerUserNotes::Date)
+            .col(ChartDayPerUserNotes::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_d54b653660d808b118e36c184c")
+            .table(ChartDayPerUserReaction::Table)
+            .col(ChartDayPerUserReaction::Date)
+            .col(ChartDayPerUserReaction::Group)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_cad6e07c20037f31cdba8a350c")
+            .table(ChartDayUsers::Table)
+            .col(ChartDayUsers::Date)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_04cc96756f89d0b7f9473e8cdf")
+            .table(AbuseUserReport::Table)
+            .col(AbuseUserReport::ReporterId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2b15aaf4a0dc5be3499af7ab6a")
+            .table(AbuseUserReport::Table)
+            .col(AbuseUserReport::Resolved)
+            .to_owned(),
+        Index::create()
+            .name("IDX_4ebbf7f93cdc10e8d1ef2fc6cd")
+            .table(AbuseUserReport::Table)
+            .col(AbuseUserReport::TargetUserHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_db2098070b2b5a523c58181f74")
+            .table(AbuseUserReport::Table)
+            .col(AbuseUserReport::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f8d8b93740ad12c4ce8213a199")
+            .table(AbuseUserReport::Table)
+            .col(AbuseUserReport::ReporterHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_64c327441248bae40f7d92f34f")
+            .table(AccessToken::Table)
+            .col(AccessToken::Hash)
+            .to_owned(),
+        Index::create()
+            .name("IDX_70ba8f6af34bc924fc9e12adb8")
+            .table(AccessToken::Table)
+            .col(AccessToken::Token)
+            .to_owned(),
+        Index::create()
+            .name("IDX_9949557d0e1b2c19e5344c171e")
+            .table(AccessToken::Table)
+            .col(AccessToken::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_bf3a053c07d9fb5d87317c56ee")
+            .table(AccessToken::Table)
+            .col(AccessToken::Session)
+            .to_owned(),
+        Index::create()
+            .name("IDX_1129c2ef687fc272df040bafaa")
+            .table(Ad::Table)
+            .col(Ad::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2da24ce20ad209f1d9dc032457")
+            .table(Ad::Table)
+            .col(Ad::ExpiresAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_118ec703e596086fc4515acb39")
+            .table(Announcement::Table)
+            .col(Announcement::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_603a7b1e7aa0533c6c88e9bfaf")
+            .table(AnnouncementRead::Table)
+            .col(AnnouncementRead::AnnouncementId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8288151386172b8109f7239ab2")
+            .table(AnnouncementRead::Table)
+            .col(AnnouncementRead::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_924fa71815cfa3941d003702a0")
+            .table(AnnouncementRead::Table)
+            .col(AnnouncementRead::UserId)
+            .col(AnnouncementRead::AnnouncementId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_6446c571a0e8d0f05f01c78909")
+            .table(Antenna::Table)
+            .col(Antenna::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0d775946662d2575dfd2068a5f")
+            .table(AntennaNote::Table)
+            .col(AntennaNote::AntennaId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_335a0bf3f904406f9ef3dd51c2")
+            .table(AntennaNote::Table)
+            .col(AntennaNote::NoteId)
+            .col(AntennaNote::AntennaId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_9937ea48d7ae97ffb4f3f063a4")
+            .table(AntennaNote::Table)
+            .col(AntennaNote::Read)
+            .to_owned(),
+        Index::create()
+            .name("IDX_bd0397be22147e17210940e125")
+            .table(AntennaNote::Table)
+            .col(AntennaNote::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_048a757923ed8b157e9895da53")
+            .table(App::Table)
+            .col(App::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3f5b0899ef90527a3462d7c2cb")
+            .table(App::Table)
+            .col(App::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f49922d511d666848f250663c4")
+            .table(App::Table)
+            .col(App::Secret)
+            .to_owned(),
+        Index::create()
+            .name("IDX_47efb914aed1f72dd39a306c7b")
+            .table(AttestationChallenge::Table)
+            .col(AttestationChallenge::Challenge)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f1a461a618fa1755692d0e0d59")
+            .table(AttestationChallenge::Table)
+            .col(AttestationChallenge::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_62cb09e1129f6ec024ef66e183")
+            .table(AuthSession::Table)
+            .col(AuthSession::Token)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0627125f1a8a42c9a1929edb55")
+            .table(Blocking::Table)
+            .col(Blocking::BlockerId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2cd4a2743a99671308f5417759")
+            .table(Blocking::Table)
+            .col(Blocking::BlockeeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_98a1bc5cb30dfd159de056549f")
+            .table(Blocking::Table)
+            .col(Blocking::BlockerId)
+            .col(Blocking::BlockeeId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_b9a354f7941c1e779f3b33aea6")
+            .table(Blocking::Table)
+            .col(Blocking::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_094b86cd36bb805d1aa1e8cc9a")
+            .table(Channel::Table)
+            .col(Channel::UsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0f58c11241e649d2a638a8de94")
+            .table(Channel::Table)
+            .col(Channel::NotesCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_29ef80c6f13bcea998447fce43")
+            .table(Channel::Table)
+            .col(Channel::LastNotedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_71cb7b435b7c0d4843317e7e16")
+            .table(Channel::Table)
+            .col(Channel::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_823bae55bd81b3be6e05cff438")
+            .table(Channel::Table)
+            .col(Channel::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0e43068c3f92cab197c3d3cd86")
+            .table(ChannelFollowing::Table)
+            .col(ChannelFollowing::FolloweeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_11e71f2511589dcc8a4d3214f9")
+            .table(ChannelFollowing::Table)
+            .col(ChannelFollowing::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2e230dd45a10e671d781d99f3e")
+            .table(ChannelFollowing::Table)
+            .col(ChannelFollowing::FollowerId)
+            .col(ChannelFollowing::FolloweeId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_6d8084ec9496e7334a4602707e")
+            .table(ChannelFollowing::Table)
+            .col(ChannelFollowing::FollowerId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8125f950afd3093acb10d2db8a")
+            .table(ChannelNotePining::Table)
+            .col(ChannelNotePining::ChannelId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f36fed37d6d4cdcc68c803cd9c")
+            .table(ChannelNotePining::Table)
+            .col(ChannelNotePining::ChannelId)
+            .col(ChannelNotePining::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_2b5ec6c574d6802c94c80313fb")
+            .table(Clip::Table)
+            .col(Clip::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_6fc0ec357d55a18646262fdfff")
+            .table(ClipNote::Table)
+            .col(ClipNote::NoteId)
+            .col(ClipNote::ClipId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_a012eaf5c87c65da1deb5fdbfa")
+            .table(ClipNote::Table)
+            .col(ClipNote::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_ebe99317bbbe9968a0c6f579ad")
+            .table(ClipNote::Table)
+            .col(ClipNote::ClipId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_315c779174fe8247ab324f036e")
+            .table(DriveFile::Table)
+            .col(DriveFile::IsLink)
+            .to_owned(),
+        Index::create()
+            .name("IDX_37bb9a1b4585f8a3beb24c62d6")
+            .table(DriveFile::Table)
+            .col(DriveFile::Md5)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3b33dff77bb64b23c88151d23e")
+            .table(DriveFile::Table)
+            .col(DriveFile::MaybeSensitive)
+            .to_owned(),
+        Index::create()
+            .name("IDX_55720b33a61a7c806a8215b825")
+            .table(DriveFile::Table)
+            .col(DriveFile::UserId)
+            .col(DriveFile::FolderId)
+            .col(DriveFile::Id)
+            .to_owned(),
+        Index::create()
+            .name("IDX_860fa6f6c7df5bb887249fba22")
+            .table(DriveFile::Table)
+            .col(DriveFile::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8bdcd3dd2bddb78014999a16ce")
+            .table(DriveFile::Table)
+            .col(DriveFile::MaybePorn)
+            .to_owned(),
+        Index::create()
+            .name("IDX_92779627994ac79277f070c91e")
+            .table(DriveFile::Table)
+            .col(DriveFile::UserHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a40b8df8c989d7db937ea27cf6")
+            .table(DriveFile::Table)
+            .col(DriveFile::Type)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a7eba67f8b3fa27271e85d2e26")
+            .table(DriveFile::Table)
+            .col(DriveFile::IsSensitive)
+            .to_owned(),
+        Index::create()
+            .name("IDX_bb90d1956dafc4068c28aa7560")
+            .table(DriveFile::Table)
+            .col(DriveFile::FolderId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_c55b2b7c284d9fef98026fc88e")
+            .table(DriveFile::Table)
+            .col(DriveFile::WebpublicAccessKey)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_c8dfad3b72196dd1d6b5db168a")
+            .table(DriveFile::Table)
+            .col(DriveFile::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d85a184c2540d2deba33daf642")
+            .table(DriveFile::Table)
+            .col(DriveFile::AccessKey)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_e5848eac4940934e23dbc17581")
+            .table(DriveFile::Table)
+            .col(DriveFile::Uri)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e74022ce9a074b3866f70e0d27")
+            .table(DriveFile::Table)
+            .col(DriveFile::ThumbnailAccessKey)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_00ceffb0cdc238b3233294f08f")
+            .table(DriveFolder::Table)
+            .col(DriveFolder::ParentId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_02878d441ceae15ce060b73daf")
+            .table(DriveFolder::Table)
+            .col(DriveFolder::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f4fc06e49c0171c85f1c48060d")
+            .table(DriveFolder::Table)
+            .col(DriveFolder::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_4f4d35e1256c84ae3d1f0eab10")
+            .table(Emoji::Table)
+            .col(Emoji::Name)
+            .col(Emoji::Host)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_5900e907bb46516ddf2871327c")
+            .table(Emoji::Table)
+            .col(Emoji::Host)
+            .to_owned(),
+        Index::create()
+            .name("IDX_b37dafc86e9af007e3295c2781")
+            .table(Emoji::Table)
+            .col(Emoji::Name)
+            .to_owned(),
+        Index::create()
+            .name("IDX_12c01c0d1a79f77d9f6c15fadd")
+            .table(FollowRequest::Table)
+            .col(FollowRequest::FolloweeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a7fd92dd6dc519e6fb435dd108")
+            .table(FollowRequest::Table)
+            .col(FollowRequest::FollowerId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d54a512b822fac7ed52800f6b4")
+            .table(FollowRequest::Table)
+            .col(FollowRequest::FollowerId)
+            .col(FollowRequest::FolloweeId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_24e0042143a18157b234df186c")
+            .table(Following::Table)
+            .col(Following::FolloweeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_307be5f1d1252e0388662acb96")
+            .table(Following::Table)
+            .col(Following::FollowerId)
+            .col(Following::FolloweeId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_4ccd2239268ebbd1b35e318754")
+            .table(Following::Table)
+            .col(Following::FollowerHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_582f8fab771a9040a12961f3e7")
+            .table(Following::Table)
+            .col(Following::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_6516c5a6f3c015b4eed39978be")
+            .table(Following::Table)
+            .col(Following::FollowerId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_fcdafee716dfe9c3b5fde90f30")
+            .table(Following::Table)
+            .col(Following::FolloweeHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8fd5215095473061855ceb948c")
+            .table(GalleryLike::Table)
+            .col(GalleryLike::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_df1b5f4099e99fb0bc5eae53b6")
+            .table(GalleryLike::Table)
+            .col(GalleryLike::UserId)
+            .col(GalleryLike::PostId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_05cca34b985d1b8edc1d1e28df")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::Tags)
+            .to_owned(),
+        Index::create()
+            .name("IDX_1a165c68a49d08f11caffbd206")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::LikedCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3ca50563facd913c425e7a89ee")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::FileIds)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8f1a239bd077c8864a20c62c2c")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_985b836dddd8615e432d7043dd")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f2d744d9a14d0dfb8b96cb7fc5")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::IsSensitive)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f631d37835adb04792e361807c")
+            .table(GalleryPost::Table)
+            .col(GalleryPost::UpdatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0b03cbcd7e6a7ce068efa8ecc2")
+            .table(Hashtag::Table)
+            .col(Hashtag::AttachedRemoteUsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0c44bf4f680964145f2a68a341")
+            .table(Hashtag::Table)
+            .col(Hashtag::AttachedLocalUsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0e206cec573f1edff4a3062923")
+            .table(Hashtag::Table)
+            .col(Hashtag::MentionedLocalUsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2710a55f826ee236ea1a62698f")
+            .table(Hashtag::Table)
+            .col(Hashtag::MentionedUsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_347fec870eafea7b26c8a73bac")
+            .table(Hashtag::Table)
+            .col(Hashtag::Name)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_4c02d38a976c3ae132228c6fce")
+            .table(Hashtag::Table)
+            .col(Hashtag::MentionedRemoteUsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d57f9030cd3af7f63ffb1c267c")
+            .table(Hashtag::Table)
+            .col(Hashtag::AttachedUsersCount)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2cd3b2a6b4cf0b910b260afe08")
+            .table(Instance::Table)
+            .col(Instance::CaughtAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_34500da2e38ac393f7bb6b299c")
+            .table(Instance::Table)
+            .col(Instance::IsSuspended)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8d5afc98982185799b160e10eb")
+            .table(Instance::Table)
+            .col(Instance::Host)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_2c4be03b446884f9e9c502135b")
+            .table(MessagingMessage::Table)
+            .col(MessagingMessage::GroupId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_5377c307783fce2b6d352e1203")
+            .table(MessagingMessage::Table)
+            .col(MessagingMessage::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_cac14a4e3944454a5ce7daa514")
+            .table(MessagingMessage::Table)
+            .col(MessagingMessage::RecipientId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e21cd3646e52ef9c94aaf17c2e")
+            .table(MessagingMessage::Table)
+            .col(MessagingMessage::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a08ad074601d204e0f69da9a95")
+            .table(ModerationLog::Table)
+            .col(ModerationLog::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_636e977ff90b23676fb5624b25")
+            .table(MutedNote::Table)
+            .col(MutedNote::Reason)
+            .to_owned(),
+        Index::create()
+            .name("IDX_70ab9786313d78e4201d81cdb8")
+            .table(MutedNote::Table)
+            .col(MutedNote::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a8c6bfd637d3f1d67a27c48e27")
+            .table(MutedNote::Table)
+            .col(MutedNote::NoteId)
+            .col(MutedNote::UserId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_d8e07aa18c2d64e86201601aec")
+            .table(MutedNote::Table)
+            .col(MutedNote::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_1eb9d9824a630321a29fd3b290")
+            .table(Muting::Table)
+            .col(Muting::MuterId)
+            .col(Muting::MuteeId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_93060675b4a79a577f31d260c6")
+            .table(Muting::Table)
+            .col(Muting::MuterId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_c1fd1c3dfb0627aa36c253fd14")
+            .table(Muting::Table)
+            .col(Muting::ExpiresAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_ec96b4fed9dae517e0dbbe0675")
+            .table(Muting::Table)
+            .col(Muting::MuteeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f86d57fbca33c7a4e6897490cc")
+            .table(Muting::Table)
+            .col(Muting::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_renote_muting_createdAt")
+            .table(Muting::Table)
+            .col(Muting::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_renote_muting_muteeId")
+            .table(Muting::Table)
+            .col(Muting::MuteeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_renote_muting_muterId")
+            .table(Muting::Table)
+            .col(Muting::MuterId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_153536c67d05e9adb24e99fc2b")
+            .table(Note::Table)
+            .col(Note::Uri)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_17cb3553c700a4985dff5a30ff")
+            .table(Note::Table)
+            .col(Note::ReplyId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_25dfc71b0369b003a4cd434d0b")
+            .table(Note::Table)
+            .col(Note::AttachedFileTypes)
+            .to_owned(),
+        Index::create()
+            .name("IDX_51c063b6a133a9cb87145450f5")
+            .table(Note::Table)
+            .col(Note::FileIds)
+            .to_owned(),
+        Index::create()
+            .name("IDX_52ccc804d7c69037d558bac4c9")
+            .table(Note::Table)
+            .col(Note::RenoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_54ebcb6d27222913b908d56fd8")
+            .table(Note::Table)
+            .col(Note::Mentions)
+            .to_owned(),
+        Index::create()
+            .name("IDX_5b87d9d19127bd5d92026017a7")
+            .table(Note::Table)
+            .col(Note::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_7125a826ab192eb27e11d358a5")
+            .table(Note::Table)
+            .col(Note::UserHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_796a8c03959361f97dc2be1d5c")
+            .table(Note::Table)
+            .col(Note::VisibleUserIds)
+            .to_owned(),
+        Index::create()
+            .name("IDX_88937d94d7443d9a99a76fa5c0")
+            .table(Note::Table)
+            .col(Note::Tags)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d4ebdef929896d6dc4a3c5bb48")
+            .table(Note::Table)
+            .col(Note::ThreadId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e7c0567f5261063592f022e9b5")
+            .table(Note::Table)
+            .col(Note::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f22169eb10657bded6d875ac8f")
+            .table(Note::Table)
+            .col(Note::ChannelId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_702ad5ae993a672e4fbffbcd38")
+            .table(NoteEdit::Table)
+            .col(NoteEdit::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0f4fb9ad355f3effff221ef245")
+            .table(NoteFavorite::Table)
+            .col(NoteFavorite::UserId)
+            .col(NoteFavorite::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_47f4b1892f5d6ba8efb3057d81")
+            .table(NoteFavorite::Table)
+            .col(NoteFavorite::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_01f4581f114e0ebd2bbb876f0b")
+            .table(NoteReaction::Table)
+            .col(NoteReaction::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_13761f64257f40c5636d0ff95e")
+            .table(NoteReaction::Table)
+            .col(NoteReaction::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_45145e4953780f3cd5656f0ea6")
+            .table(NoteReaction::Table)
+            .col(NoteReaction::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_ad0c221b25672daf2df320a817")
+            .table(NoteReaction::Table)
+            .col(NoteReaction::UserId)
+            .col(NoteReaction::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_29c11c7deb06615076f8c95b80")
+            .table(NoteThreadMuting::Table)
+            .col(NoteThreadMuting::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_ae7aab18a2641d3e5f25e0c4ea")
+            .table(NoteThreadMuting::Table)
+            .col(NoteThreadMuting::UserId)
+            .col(NoteThreadMuting::ThreadId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_c426394644267453e76f036926")
+            .table(NoteThreadMuting::Table)
+            .col(NoteThreadMuting::ThreadId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_25b1dd384bec391b07b74b861c")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::IsMentioned)
+            .to_owned(),
+        Index::create()
+            .name("IDX_29e8c1d579af54d4232939f994")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::NoteUserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_56b0166d34ddae49d8ef7610bb")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_6a57f051d82c6d4036c141e107")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::NoteChannelId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_89a29c9237b8c3b6b3cbb4cb30")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::IsSpecified)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d908433a4953cc13216cd9c274")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::UserId)
+            .col(NoteUnread::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_e637cba4dc4410218c4251260e")
+            .table(NoteUnread::Table)
+            .col(NoteUnread::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_03e7028ab8388a3f5e3ce2a861")
+            .table(NoteWatching::Table)
+            .col(NoteWatching::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_318cdf42a9cfc11f479bd802bb")
+            .table(NoteWatching::Table)
+            .col(NoteWatching::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_44499765eec6b5489d72c4253b")
+            .table(NoteWatching::Table)
+            .col(NoteWatching::NoteUserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a42c93c69989ce1d09959df4cf")
+            .table(NoteWatching::Table)
+            .col(NoteWatching::UserId)
+            .col(NoteWatching::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_b0134ec406e8d09a540f818288")
+            .table(NoteWatching::Table)
+            .col(NoteWatching::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_080ab397c379af09b9d2169e5b")
+            .table(Notification::Table)
+            .col(Notification::IsRead)
+            .to_owned(),
+        Index::create()
+            .name("IDX_33f33cc8ef29d805a97ff4628b")
+            .table(Notification::Table)
+            .col(Notification::Type)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3b4e96eec8d36a8bbb9d02aa71")
+            .table(Notification::Table)
+            .col(Notification::NotifierId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3c601b70a1066d2c8b517094cb")
+            .table(Notification::Table)
+            .col(Notification::NotifieeId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_b11a5e627c41d4dc3170f1d370")
+            .table(Notification::Table)
+            .col(Notification::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e22bf6bda77b6adc1fd9e75c8c")
+            .table(Notification::Table)
+            .col(Notification::AppAccessTokenId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2133ef8317e4bdb839c0dcbf13")
+            .table(Page::Table)
+            .col(Page::UserId)
+            .col(Page::Name)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_90148bbc2bf0854428786bfc15")
+            .table(Page::Table)
+            .col(Page::VisibleUserIds)
+            .to_owned(),
+        Index::create()
+            .name("IDX_ae1d917992dd0c9d9bbdad06c4")
+            .table(Page::Table)
+            .col(Page::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_af639b066dfbca78b01a920f8a")
+            .table(Page::Table)
+            .col(Page::UpdatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_b82c19c08afb292de4600d99e4")
+            .table(Page::Table)
+            .col(Page::Name)
+            .to_owned(),
+        Index::create()
+            .name("IDX_fbb4297c927a9b85e9cefa2eb1")
+            .table(Page::Table)
+            .col(Page::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0e61efab7f88dbb79c9166dbb4")
+            .table(PageLike::Table)
+            .col(PageLike::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_4ce6fb9c70529b4c8ac46c9bfa")
+            .table(PageLike::Table)
+            .col(PageLike::UserId)
+            .col(PageLike::PageId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_0b575fa9a4cfe638a925949285")
+            .table(PasswordResetRequest::Table)
+            .col(PasswordResetRequest::Token)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_4bb7fd4a34492ae0e6cc8d30ac")
+            .table(PasswordResetRequest::Table)
+            .col(PasswordResetRequest::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0610ebcfcfb4a18441a9bcdab2")
+            .table(Poll::Table)
+            .col(Poll::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_7fa20a12319c7f6dc3aed98c0a")
+            .table(Poll::Table)
+            .col(Poll::UserHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0fb627e1c2f753262a74f0562d")
+            .table(PollVote::Table)
+            .col(PollVote::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_50bd7164c5b78f1f4a42c4d21f")
+            .table(PollVote::Table)
+            .col(PollVote::UserId)
+            .col(PollVote::NoteId)
+            .col(PollVote::Choice)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_66d2bd2ee31d14bcc23069a89f")
+            .table(PollVote::Table)
+            .col(PollVote::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_aecfbd5ef60374918e63ee95fa")
+            .table(PollVote::Table)
+            .col(PollVote::NoteId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_83f0862e9bae44af52ced7099e")
+            .table(PromoNote::Table)
+            .col(PromoNote::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2882b8a1a07c7d281a98b6db16")
+            .table(PromoRead::Table)
+            .col(PromoRead::UserId)
+            .col(PromoRead::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_9657d55550c3d37bfafaf7d4b0")
+            .table(PromoRead::Table)
+            .col(PromoRead::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0ff69e8dfa9fe31bb4a4660f59")
+            .table(RegistrationTicket::Table)
+            .col(RegistrationTicket::Code)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_0a72bdfcdb97c0eca11fe7ecad")
+            .table(RegistryItem::Table)
+            .col(RegistryItem::Domain)
+            .to_owned(),
+        Index::create()
+            .name("IDX_22baca135bb8a3ea1a83d13df3")
+            .table(RegistryItem::Table)
+            .col(RegistryItem::Scope)
+            .to_owned(),
+        Index::create()
+            .name("IDX_fb9d21ba0abb83223263df6bcb")
+            .table(RegistryItem::Table)
+            .col(RegistryItem::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_0d9a1738f2cf7f3b1c3334dfab")
+            .table(Relay::Table)
+            .col(Relay::Inbox)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_b46ec40746efceac604142be1c")
+            .table(ReversiGame::Table)
+            .col(ReversiGame::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3b25402709dd9882048c2bbade")
+            .table(ReversiMatching::Table)
+            .col(ReversiMatching::ParentId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_b604d92d6c7aec38627f6eaf16")
+            .table(ReversiMatching::Table)
+            .col(ReversiMatching::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e247b23a3c9b45f89ec1299d06")
+            .table(ReversiMatching::Table)
+            .col(ReversiMatching::ChildId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_2c308dbdc50d94dc625670055f")
+            .table(Signin::Table)
+            .col(Signin::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_97754ca6f2baff9b4abb7f853d")
+            .table(SwSubscription::Table)
+            .col(SwSubscription::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3252a5df8d5bbd16b281f7799e")
+            .table(User::Table)
+            .col(User::Host)
+            .to_owned(),
+        Index::create()
+            .name("IDX_5deb01ae162d1d70b80d064c27")
+            .table(User::Table)
+            .col(User::UsernameLower)
+            .col(User::Host)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_80ca6e6ef65fb9ef34ea8c90f4")
+            .table(User::Table)
+            .col(User::UpdatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a27b942a0d6dcff90e3ee9b5e8")
+            .table(User::Table)
+            .col(User::UsernameLower)
+            .to_owned(),
+        Index::create()
+            .name("IDX_a854e557b1b14814750c7c7b0c")
+            .table(User::Table)
+            .col(User::Token)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_be623adaa4c566baf5d29ce0c8")
+            .table(User::Table)
+            .col(User::Uri)
+            .to_owned(),
+        Index::create()
+            .name("IDX_c8cc87bd0f2f4487d17c651fbf")
+            .table(User::Table)
+            .col(User::LastActiveDate)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d5a1b83c7cab66f167e6888188")
+            .table(User::Table)
+            .col(User::IsExplorable)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e11e649824a45d8ed01d597fd9")
+            .table(User::Table)
+            .col(User::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_fa99d777623947a5b05f394cae")
+            .table(User::Table)
+            .col(User::Tags)
+            .to_owned(),
+        Index::create()
+            .name("IDX_20e30aa35180e317e133d75316")
+            .table(UserGroup::Table)
+            .col(UserGroup::CreatedAt)
+            .to_owned(),
+        Index::create()
+            .name("IDX_3d6b372788ab01be58853003c9")
+            .table(UserGroup::Table)
+            .col(UserGroup::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_5cc8c468090e129857e9fecce5")
+            .table(UserGroupInvitation::Table)
+            .col(UserGroupInvitation::UserGroupId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_bfbc6305547539369fe73eb144")
+            .table(UserGroupInvitation::Table)
+            .col(UserGroupInvitation::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_e9793f65f504e5a31fbaedbf2f")
+            .table(UserGroupInvitation::Table)
+            .col(UserGroupInvitation::UserId)
+            .col(UserGroupInvitation::UserGroupId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_1039988afa3bf991185b277fe0")
+            .table(UserGroupInvite::Table)
+            .col(UserGroupInvite::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_78787741f9010886796f2320a4")
+            .table(UserGroupInvite::Table)
+            .col(UserGroupInvite::UserId)
+            .col(UserGroupInvite::UserGroupId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_e10924607d058004304611a436")
+            .table(UserGroupInvite::Table)
+            .col(UserGroupInvite::UserGroupId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_67dc758bc0566985d1b3d39986")
+            .table(UserGroupJoining::Table)
+            .col(UserGroupJoining::UserGroupId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_d9ecaed8c6dc43f3592c229282")
+            .table(UserGroupJoining::Table)
+            .col(UserGroupJoining::UserId)
+            .col(UserGroupJoining::UserGroupId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_f3a1b4bd0c7cabba958a0c0b23")
+            .table(UserGroupJoining::Table)
+            .col(UserGroupJoining::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_361b500e06721013c124b7b6c5")
+            .table(UserIp::Table)
+            .col(UserIp::UserId)
+            .col(UserIp::Ip)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_7f7f1c66f48e9a8e18a33bc515")
+            .table(UserIp::Table)
+            .col(UserIp::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_b7fcefbdd1c18dce86687531f9")
+            .table(UserList::Table)
+            .col(UserList::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_605472305f26818cc93d1baaa7")
+            .table(UserListJoining::Table)
+            .col(UserListJoining::UserListId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_90f7da835e4c10aca6853621e1")
+            .table(UserListJoining::Table)
+            .col(UserListJoining::UserId)
+            .col(UserListJoining::UserListId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_d844bfc6f3f523a05189076efa")
+            .table(UserListJoining::Table)
+            .col(UserListJoining::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_410cd649884b501c02d6e72738")
+            .table(UserNotePining::Table)
+            .col(UserNotePining::UserId)
+            .col(UserNotePining::NoteId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_bfbc6f79ba4007b4ce5097f08d")
+            .table(UserNotePining::Table)
+            .col(UserNotePining::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_4e5c4c99175638ec0761714ab0")
+            .table(UserPending::Table)
+            .col(UserPending::Code)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_3befe6f999c86aff06eb0257b4")
+            .table(UserProfile::Table)
+            .col(UserProfile::EnableWordMute)
+            .to_owned(),
+        Index::create()
+            .name("IDX_dce530b98e454793dac5ec2f5a")
+            .table(UserProfile::Table)
+            .col(UserProfile::UserHost)
+            .to_owned(),
+        Index::create()
+            .name("IDX_171e64971c780ebd23fae140bb")
+            .table(UserPublickey::Table)
+            .col(UserPublickey::KeyId)
+            .unique()
+            .to_owned(),
+        Index::create()
+            .name("IDX_0d7718e562dcedd0aa5cf2c9f7")
+            .table(UserSecurityKey::Table)
+            .col(UserSecurityKey::PublicKey)
+            .to_owned(),
+        Index::create()
+            .name("IDX_ff9ca3b5f3ee3d0681367a9b44")
+            .table(UserSecurityKey::Table)
+            .col(UserSecurityKey::UserId)
+            .to_owned(),
+        Index::create()
+            .name("IDX_5a056076f76b2efe08216ba655")
+            .table(Webhook::Table)
+            .col(Webhook::Active)
+            .to_owned(),
+        Index::create()
+            .name("IDX_8063a0586ed1dfbe86e982d961")
+            .table(Webhook::Table)
+            .col(Webhook::On)
+            .to_owned(),
+        Index::create()
+            .name("IDX_f272c8c8805969e6a6449c77b3")
+            .table(Webhook::Table)
+            .col(Webhook::UserId)
+            .to_owned(),
+    ]
+}
+
+/// GIN indexes of array columns, which are JSON in SQLite.
+pub fn gin_indexes() -> Vec<IndexCreateStatement> {
+    vec![
+        Index::create()
+            .name("IDX_NOTE_MENTIONS")
+            .table(Note::Table)
+            .col(Note::Mentions)
+            .full_text()
+            .to_owned(),
+        Index::create()
+            .name("IDX_NOTE_TAGS")
+            .table(Note::Table)
+            .col(Note::Tags)
+            .full_text()
+            .to_owned(),
+        Index::create()
+            .name("IDX_NOTE_VISIBLE_USER_IDS")
+            .table(Note::Table)
+            .col(Note::VisibleUserIds)
+            .full_text()
+            .to_owned(),
+    ]
+}
+
+/// Partial indexes, which SeaQuery cannot build.
+pub const PARTIAL_INDEXES: &[&str] = &[
+    r#"CREATE UNIQUE INDEX "IDX_dab383a36f3c9db4a0c9b02cf3" ON "__chart__test" ("date") WHERE ("group" IS NULL)"#,
+    r#"CREATE UNIQUE INDEX "IDX_da522b4008a9f5d7743b87ad55" ON "__chart__test_grouped" ("date") WHERE ("group" IS NULL)"#,
+    r#"CREATE UNIQUE INDEX "IDX_16effb2e888f6763673b579f80" ON "__chart__test_unique" ("date") WHERE ("group" IS NULL)"#,
+];
+
+/// Statements of PostgreSQL for what SeaQuery cannot build.
+pub const POSTGRES_STATEMENTS: &[&str] = &[
+    // PostgreSQL skips unique constraints duplicating the primary key in CREATE TABLE
+    r#"ALTER TABLE "poll" ADD CONSTRAINT "UQ_da851e06d0dfe2ef397d8b1bf1b" UNIQUE ("noteId")"#,
+    r#"ALTER TABLE "promo_note" ADD CONSTRAINT "UQ_e263909ca4fe5d57f8d4230dd5c" UNIQUE ("noteId")"#,
+    r#"ALTER TABLE "user_keypair" ADD CONSTRAINT "UQ_f4853eb41ab722fe05f81cedeb6" UNIQUE ("userId")"#,
+    r#"ALTER TABLE "user_profile" ADD CONSTRAINT "UQ_51cb79b5555effaf7d69ba1cff9" UNIQUE ("userId")"#,
+    r#"ALTER TABLE "user_publickey" ADD CONSTRAINT "UQ_10c146e4b39b443ede016f6736d" UNIQUE ("userId")"#,
+    // names of the primary keys of serial columns
+    r#"ALTER TABLE "__chart__active_users" RENAME CONSTRAINT "__chart__active_users_pkey" TO "PK_317237a9f733b970604a11e314f""#,
+    r#"ALTER TABLE "__chart__ap_request" RENAME CONSTRAINT "__chart__ap_request_pkey" TO "PK_56a25cd447c7ee08876b3baf8d8""#,
+    r#"ALTER TABLE "__chart__drive" RENAME CONSTRAINT "__chart__drive_pkey" TO "PK_f96bc548a765cd4b3b354221ce7""#,
+    r#"ALTER TABLE "__chart__federation" RENAME CONSTRAINT "__chart__federation_pkey" TO "PK_b39dcd31a0fe1a7757e348e85fd""#,
+    r#"ALTER TABLE "__chart__hashtag" RENAME CONSTRAINT "__chart__hashtag_pkey" TO "PK_c32f1ea2b44a5d2f7881e37f8f9""#,
+    r#"ALTER TABLE "__chart__instance" RENAME CONSTRAINT "__chart__instance_pkey" TO "PK_1267c67c7c2d47b4903975f2c00""#,
+    r#"ALTER TABLE "__chart__network" RENAME CONSTRAINT "__chart__network_pkey" TO "PK_bc4290c2e27fad14ef0c1ca93f3""#,
+    r#"ALTER TABLE "__chart__notes" RENAME CONSTRAINT "__chart__notes_pkey" TO "PK_0aec823fa85c7f901bdb3863b14""#,
+    r#"ALTER TABLE "__chart__per_user_drive" RENAME CONSTRAINT "__chart__per_user_drive_pkey" TO "PK_d0ef23d24d666e1a44a0cd3d208""#,
+    r#"ALTER TABLE "__chart__per_user_following" RENAME CONSTRAINT "__chart__per_user_following_pkey" TO "PK_85bb1b540363a29c2fec83bd907""#,
+    r#"ALTER TABLE "__chart__per_user_notes" RENAME CONSTRAINT "__chart__per_user_notes_pkey" TO "PK_334acf6e915af2f29edc11b8e50""#,
+    r#"ALTER TABLE "__chart__per_user_reaction" RENAME CONSTRAINT "__chart__per_user_reaction_pkey" TO "PK_984f54dae441e65b633e8d27a7f""#,
+    r#"ALTER TABLE "__chart__test" RENAME CONSTRAINT "__chart__test_pkey" TO "PK_b4bc31dffbd1b785276a3ecfc1e""#,
+    r#"ALTER TABLE "__chart__test_grouped" RENAME CONSTRAINT "__chart__test_grouped_pkey" TO "PK_f4a2b175d308695af30d4293272""#,
+    r#"ALTER TABLE "__chart__test_unique" RENAME CONSTRAINT "__chart__test_unique_pkey" TO "PK_409bac9c97cc612d8500012319d""#,
+    r#"ALTER TABLE "__chart__users" RENAME CONSTRAINT "__chart__users_pkey" TO "PK_4dfcf2c78d03524b9eb2c99d328""#,
+    r#"ALTER TABLE "__chart_day__active_users" RENAME CONSTRAINT "__chart_day__active_users_pkey" TO "PK_b1790489b14f005ae8f404f5795""#,
+    r#"ALTER TABLE "__chart_day__ap_request" RENAME CONSTRAINT "__chart_day__ap_request_pkey" TO "PK_9318b49daee320194e23f712e69""#,
+    r#"ALTER TABLE "__chart_day__drive" RENAME CONSTRAINT "__chart_day__drive_pkey" TO "PK_e7ec0de057c77c40fc8d8b62151""#,
+    r#"ALTER TABLE "__chart_day__federation" RENAME CONSTRAINT "__chart_day__federation_pkey" TO "PK_7ca721c769f31698e0e1331e8e6""#,
+    r#"ALTER TABLE "__chart_day__hashtag" RENAME CONSTRAINT "__chart_day__hashtag_pkey" TO "PK_13d5a3b089344e5557f8e0980b4""#,
+    r#"ALTER TABLE "__chart_day__instance" RENAME CONSTRAINT "__chart_day__instance_pkey" TO "PK_479a8ff9d959274981087043023""#,
+    r#"ALTER TABLE "__chart_day__network" RENAME CONSTRAINT "__chart_day__network_pkey" TO "PK_cac499d6f471042dfed1e7e0132""#,
+    r#"ALTER TABLE "__chart_day__notes" RENAME CONSTRAINT "__chart_day__notes_pkey" TO "PK_1fa4139e1f338272b758d05e090""#,
+    r#"ALTER TABLE "__chart_day__per_user_drive" RENAME CONSTRAINT "__chart_day__per_user_drive_pkey" TO "PK_1ae135254c137011645da7f4045""#,
+    r#"ALTER TABLE "__chart_day__per_user_following" RENAME CONSTRAINT "__chart_day__per_user_following_pkey" TO "PK_68ce6b67da57166da66fc8fb27e""#,
+    r#"ALTER TABLE "__chart_day__per_user_notes" RENAME CONSTRAINT "__chart_day__per_user_notes_pkey" TO "PK_58bab6b6d3ad9310cbc7460fd28""#,
+    r#"ALTER TABLE "__chart_day__per_user_reaction" RENAME CONSTRAINT "__chart_day__per_user_reaction_pkey" TO "PK_8af24e2d51ff781a354fe595eda""#,
+    r#"ALTER TABLE "__chart_day__users" RENAME CONSTRAINT "__chart_day__users_pkey" TO "PK_d7f7185abb9851f70c4726c54bd""#,
+    r#"ALTER TABLE "migrations" RENAME CONSTRAINT "migrations_pkey" TO "PK_8c82d7f526340ab734260ea46be""#,
+    r#"ALTER TABLE "user_ip" RENAME CONSTRAINT "user_ip_pkey" TO "PK_2c44ddfbf7c0464d028dcef325e""#,
+    r#"COMMENT ON COLUMN "abuse_user_report"."createdAt" IS 'The created date of the AbuseUserReport.'"#,
+    r#"COMMENT ON COLUMN "abuse_user_report"."targetUserHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "abuse_user_report"."reporterHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "access_token"."createdAt" IS 'The created date of the AccessToken.'"#,
+    r#"COMMENT ON COLUMN "ad"."createdAt" IS 'The created date of the Ad.'"#,
+    r#"COMMENT ON COLUMN "ad"."expiresAt" IS 'The expired date of the Ad.'"#,
+    r#"COMMENT ON COLUMN "announcement"."createdAt" IS 'The created date of the Announcement.'"#,
+    r#"COMMENT ON COLUMN "announcement"."updatedAt" IS 'The updated date of the Announcement.'"#,
+    r#"COMMENT ON COLUMN "announcement_read"."createdAt" IS 'The created date of the AnnouncementRead.'"#,
+    r#"COMMENT ON COLUMN "antenna"."createdAt" IS 'The created date of the Antenna.'"#,
+    r#"COMMENT ON COLUMN "antenna"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "antenna"."name" IS 'The name of the Antenna.'"#,
+    r#"COMMENT ON COLUMN "antenna_note"."noteId" IS 'The note ID.'"#,
+    r#"COMMENT ON COLUMN "antenna_note"."antennaId" IS 'The antenna ID.'"#,
+    r#"COMMENT ON COLUMN "app"."createdAt" IS 'The created date of the App.'"#,
+    r#"COMMENT ON COLUMN "app"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "app"."secret" IS 'The secret key of the App.'"#,
+    r#"COMMENT ON COLUMN "app"."name" IS 'The name of the App.'"#,
+    r#"COMMENT ON COLUMN "app"."description" IS 'The description of the App.'"#,
+    r#"COMMENT ON COLUMN "app"."permission" IS 'The permission of the App.'"#,
+    r#"COMMENT ON COLUMN "app"."callbackUrl" IS 'The callbackUrl of the App.'"#,
+    r#"COMMENT ON COLUMN "attestation_challenge"."challenge" IS 'Hex-encoded sha256 hash of the challenge.'"#,
+    r#"COMMENT ON COLUMN "attestation_challenge"."createdAt" IS 'The date challenge was created for expiry purposes.'"#,
+    r#"COMMENT ON COLUMN "attestation_challenge"."registrationChallenge" IS 'Indicates that the challenge is only for registration purposes if true to prevent the challenge for being used as authentication.'"#,
+    r#"COMMENT ON COLUMN "auth_session"."createdAt" IS 'The created date of the AuthSession.'"#,
+    r#"COMMENT ON COLUMN "blocking"."createdAt" IS 'The created date of the Blocking.'"#,
+    r#"COMMENT ON COLUMN "blocking"."blockeeId" IS 'The blockee user ID.'"#,
+    r#"COMMENT ON COLUMN "blocking"."blockerId" IS 'The blocker user ID.'"#,
+    r#"COMMENT ON COLUMN "channel"."createdAt" IS 'The created date of the Channel.'"#,
+    r#"COMMENT ON COLUMN "channel"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "channel"."name" IS 'The name of the Channel.'"#,
+    r#"COMMENT ON COLUMN "channel"."description" IS 'The description of the Channel.'"#,
+    r#"COMMENT ON COLUMN "channel"."bannerId" IS 'The ID of banner Channel.'"#,
+    r#"COMMENT ON COLUMN "channel"."notesCount" IS 'The count of notes.'"#,
+    r#"COMMENT ON COLUMN "channel"."usersCount" IS 'The count of users.'"#,
+    r#"COMMENT ON COLUMN "channel_following"."createdAt" IS 'The created date of the ChannelFollowing.'"#,
+    r#"COMMENT ON COLUMN "channel_following"."followeeId" IS 'The followee channel ID.'"#,
+    r#"COMMENT ON COLUMN "channel_following"."followerId" IS 'The follower user ID.'"#,
+    r#"COMMENT ON COLUMN "channel_note_pining"."createdAt" IS 'The created date of the ChannelNotePining.'"#,
+    r#"COMMENT ON COLUMN "clip"."createdAt" IS 'The created date of the Clip.'"#,
+    r#"COMMENT ON COLUMN "clip"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "clip"."name" IS 'The name of the Clip.'"#,
+    r#"COMMENT ON COLUMN "clip"."description" IS 'The description of the Clip.'"#,
+    r#"COMMENT ON COLUMN "clip_note"."noteId" IS 'The note ID.'"#,
+    r#"COMMENT ON COLUMN "clip_note"."clipId" IS 'The clip ID.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."createdAt" IS 'The created date of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."userHost" IS 'The host of owner. It will be null if the user in local.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."md5" IS 'The MD5 hash of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."name" IS 'The file name of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."type" IS 'The content type (MIME) of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."size" IS 'The file size (bytes) of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."comment" IS 'The comment of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."properties" IS 'The any properties of the DriveFile. For example, it includes image width/height.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."url" IS 'The URL of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."thumbnailUrl" IS 'The URL of the thumbnail of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."webpublicUrl" IS 'The URL of the webpublic of the DriveFile.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."uri" IS 'The URI of the DriveFile. it will be null when the DriveFile is local.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."folderId" IS 'The parent folder ID. If null, it means the DriveFile is located in root.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."isSensitive" IS 'Whether the DriveFile is NSFW.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."isLink" IS 'Whether the DriveFile is direct link to remote server.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."blurhash" IS 'The BlurHash string.'"#,
+    r#"COMMENT ON COLUMN "drive_file"."maybeSensitive" IS 'Whether the DriveFile is NSFW. (predict)'"#,
+    r#"COMMENT ON COLUMN "drive_folder"."createdAt" IS 'The created date of the DriveFolder.'"#,
+    r#"COMMENT ON COLUMN "drive_folder"."name" IS 'The name of the DriveFolder.'"#,
+    r#"COMMENT ON COLUMN "drive_folder"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "drive_folder"."parentId" IS 'The parent folder ID. If null, it means the DriveFolder is located in root.'"#,
+    r#"COMMENT ON COLUMN "emoji"."width" IS 'Image width'"#,
+    r#"COMMENT ON COLUMN "emoji"."height" IS 'Image height'"#,
+    r#"COMMENT ON COLUMN "follow_request"."createdAt" IS 'The created date of the FollowRequest.'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followeeId" IS 'The followee user ID.'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followerId" IS 'The follower user ID.'"#,
+    r#"COMMENT ON COLUMN "follow_request"."requestId" IS 'id of Follow Activity.'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followerHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followerInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followerSharedInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followeeHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followeeInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "follow_request"."followeeSharedInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "following"."createdAt" IS 'The created date of the Following.'"#,
+    r#"COMMENT ON COLUMN "following"."followeeId" IS 'The followee user ID.'"#,
+    r#"COMMENT ON COLUMN "following"."followerId" IS 'The follower user ID.'"#,
+    r#"COMMENT ON COLUMN "following"."followerHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "following"."followerInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "following"."followerSharedInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "following"."followeeHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "following"."followeeInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "following"."followeeSharedInbox" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "gallery_post"."createdAt" IS 'The created date of the GalleryPost.'"#,
+    r#"COMMENT ON COLUMN "gallery_post"."updatedAt" IS 'The updated date of the GalleryPost.'"#,
+    r#"COMMENT ON COLUMN "gallery_post"."userId" IS 'The ID of author.'"#,
+    r#"COMMENT ON COLUMN "gallery_post"."isSensitive" IS 'Whether the post is sensitive.'"#,
+    r#"COMMENT ON COLUMN "instance"."caughtAt" IS 'The caught date of the Instance.'"#,
+    r#"COMMENT ON COLUMN "instance"."host" IS 'The host of the Instance.'"#,
+    r#"COMMENT ON COLUMN "instance"."usersCount" IS 'The count of the users of the Instance.'"#,
+    r#"COMMENT ON COLUMN "instance"."notesCount" IS 'The count of the notes of the Instance.'"#,
+    r#"COMMENT ON COLUMN "instance"."softwareName" IS 'The software of the Instance.'"#,
+    r#"COMMENT ON COLUMN "messaging_message"."createdAt" IS 'The created date of the MessagingMessage.'"#,
+    r#"COMMENT ON COLUMN "messaging_message"."userId" IS 'The sender user ID.'"#,
+    r#"COMMENT ON COLUMN "messaging_message"."recipientId" IS 'The recipient user ID.'"#,
+    r#"COMMENT ON COLUMN "messaging_message"."groupId" IS 'The recipient group ID.'"#,
+    r#"COMMENT ON COLUMN "meta"."localDriveCapacityMb" IS 'Drive capacity of a local user (MB)'"#,
+    r#"COMMENT ON COLUMN "meta"."remoteDriveCapacityMb" IS 'Drive capacity of a remote user (MB)'"#,
+    r#"COMMENT ON COLUMN "meta"."defaultReaction" IS 'The fallback reaction for emoji reacts'"#,
+    r#"COMMENT ON COLUMN "moderation_log"."createdAt" IS 'The created date of the ModerationLog.'"#,
+    r#"COMMENT ON COLUMN "muted_note"."noteId" IS 'The note ID.'"#,
+    r#"COMMENT ON COLUMN "muted_note"."userId" IS 'The user ID.'"#,
+    r#"COMMENT ON COLUMN "muted_note"."reason" IS 'The reason of the MutedNote.'"#,
+    r#"COMMENT ON COLUMN "muting"."createdAt" IS 'The created date of the Muting.'"#,
+    r#"COMMENT ON COLUMN "muting"."muteeId" IS 'The mutee user ID.'"#,
+    r#"COMMENT ON COLUMN "muting"."muterId" IS 'The muter user ID.'"#,
+    r#"COMMENT ON COLUMN "note"."createdAt" IS 'The created date of the Note.'"#,
+    r#"COMMENT ON COLUMN "note"."replyId" IS 'The ID of reply target.'"#,
+    r#"COMMENT ON COLUMN "note"."renoteId" IS 'The ID of renote target.'"#,
+    r#"COMMENT ON COLUMN "note"."userId" IS 'The ID of author.'"#,
+    r#"COMMENT ON COLUMN "note"."uri" IS 'The URI of a note. it will be null when the note is local.'"#,
+    r#"COMMENT ON COLUMN "note"."userHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note"."replyUserId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note"."replyUserHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note"."renoteUserId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note"."renoteUserHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note"."url" IS 'The human readable url of a note. it will be null when the note is local.'"#,
+    r#"COMMENT ON COLUMN "note"."channelId" IS 'The ID of source channel.'"#,
+    r#"COMMENT ON COLUMN "note"."updatedAt" IS 'The updated date of the Note.'"#,
+    r#"COMMENT ON COLUMN "note_edit"."noteId" IS 'The ID of note.'"#,
+    r#"COMMENT ON COLUMN "note_edit"."updatedAt" IS 'The updated date of the Note.'"#,
+    r#"COMMENT ON COLUMN "note_favorite"."createdAt" IS 'The created date of the NoteFavorite.'"#,
+    r#"COMMENT ON COLUMN "note_reaction"."createdAt" IS 'The created date of the NoteReaction.'"#,
+    r#"COMMENT ON COLUMN "note_unread"."noteUserId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note_unread"."noteChannelId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "note_watching"."createdAt" IS 'The created date of the NoteWatching.'"#,
+    r#"COMMENT ON COLUMN "note_watching"."userId" IS 'The watcher ID.'"#,
+    r#"COMMENT ON COLUMN "note_watching"."noteId" IS 'The target Note ID.'"#,
+    r#"COMMENT ON COLUMN "note_watching"."noteUserId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "notification"."createdAt" IS 'The created date of the Notification.'"#,
+    r#"COMMENT ON COLUMN "notification"."notifieeId" IS 'The ID of recipient user of the Notification.'"#,
+    r#"COMMENT ON COLUMN "notification"."notifierId" IS 'The ID of sender user of the Notification.'"#,
+    r#"COMMENT ON COLUMN "notification"."isRead" IS 'Whether the Notification is read.'"#,
+    r#"COMMENT ON COLUMN "notification"."type" IS 'The type of the Notification.'"#,
+    r#"COMMENT ON COLUMN "page"."createdAt" IS 'The created date of the Page.'"#,
+    r#"COMMENT ON COLUMN "page"."updatedAt" IS 'The updated date of the Page.'"#,
+    r#"COMMENT ON COLUMN "page"."userId" IS 'The ID of author.'"#,
+    r#"COMMENT ON COLUMN "poll"."noteVisibility" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "poll"."userId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "poll"."userHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "poll_vote"."createdAt" IS 'The created date of the PollVote.'"#,
+    r#"COMMENT ON COLUMN "promo_note"."userId" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "promo_read"."createdAt" IS 'The created date of the PromoRead.'"#,
+    r#"COMMENT ON COLUMN "registry_item"."createdAt" IS 'The created date of the RegistryItem.'"#,
+    r#"COMMENT ON COLUMN "registry_item"."updatedAt" IS 'The updated date of the RegistryItem.'"#,
+    r#"COMMENT ON COLUMN "registry_item"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "registry_item"."key" IS 'The key of the RegistryItem.'"#,
+    r#"COMMENT ON COLUMN "registry_item"."value" IS 'The value of the RegistryItem.'"#,
+    r#"COMMENT ON COLUMN "reversi_game"."createdAt" IS 'The created date of the ReversiGame.'"#,
+    r#"COMMENT ON COLUMN "reversi_game"."startedAt" IS 'The started date of the ReversiGame.'"#,
+    r#"COMMENT ON COLUMN "reversi_matching"."createdAt" IS 'The created date of the ReversiMatching.'"#,
+    r#"COMMENT ON COLUMN "signin"."createdAt" IS 'The created date of the Signin.'"#,
+    r#"COMMENT ON COLUMN "user"."createdAt" IS 'The created date of the User.'"#,
+    r#"COMMENT ON COLUMN "user"."updatedAt" IS 'The updated date of the User.'"#,
+    r#"COMMENT ON COLUMN "user"."username" IS 'The username of the User.'"#,
+    r#"COMMENT ON COLUMN "user"."usernameLower" IS 'The username (lowercased) of the User.'"#,
+    r#"COMMENT ON COLUMN "user"."name" IS 'The name of the User.'"#,
+    r#"COMMENT ON COLUMN "user"."followersCount" IS 'The count of followers.'"#,
+    r#"COMMENT ON COLUMN "user"."followingCount" IS 'The count of following.'"#,
+    r#"COMMENT ON COLUMN "user"."notesCount" IS 'The count of notes.'"#,
+    r#"COMMENT ON COLUMN "user"."avatarId" IS 'The ID of avatar DriveFile.'"#,
+    r#"COMMENT ON COLUMN "user"."bannerId" IS 'The ID of banner DriveFile.'"#,
+    r#"COMMENT ON COLUMN "user"."isSuspended" IS 'Whether the User is suspended.'"#,
+    r#"COMMENT ON COLUMN "user"."isSilenced" IS 'Whether the User is silenced.'"#,
+    r#"COMMENT ON COLUMN "user"."isLocked" IS 'Whether the User is locked.'"#,
+    r#"COMMENT ON COLUMN "user"."isBot" IS 'Whether the User is a bot.'"#,
+    r#"COMMENT ON COLUMN "user"."isCat" IS 'Whether the User is a cat.'"#,
+    r#"COMMENT ON COLUMN "user"."isAdmin" IS 'Whether the User is the admin.'"#,
+    r#"COMMENT ON COLUMN "user"."isModerator" IS 'Whether the User is a moderator.'"#,
+    r#"COMMENT ON COLUMN "user"."host" IS 'The host of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."inbox" IS 'The inbox URL of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."sharedInbox" IS 'The sharedInbox URL of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."featured" IS 'The featured URL of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."uri" IS 'The URI of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."token" IS 'The native access token of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."isExplorable" IS 'Whether the User is explorable.'"#,
+    r#"COMMENT ON COLUMN "user"."followersUri" IS 'The URI of the user Follower Collection. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user"."isDeleted" IS 'Whether the User is deleted.'"#,
+    r#"COMMENT ON COLUMN "user"."driveCapacityOverrideMb" IS 'Overrides user drive capacity limit'"#,
+    r#"COMMENT ON COLUMN "user"."movedToUri" IS 'The URI of the new account of the User'"#,
+    r#"COMMENT ON COLUMN "user"."alsoKnownAs" IS 'URIs the user is known as too'"#,
+    r#"COMMENT ON COLUMN "user"."speakAsCat" IS 'Whether to speak as a cat if isCat.'"#,
+    r#"COMMENT ON COLUMN "user_group"."createdAt" IS 'The created date of the UserGroup.'"#,
+    r#"COMMENT ON COLUMN "user_group"."userId" IS 'The ID of owner.'"#,
+    r#"COMMENT ON COLUMN "user_group_invitation"."createdAt" IS 'The created date of the UserGroupInvitation.'"#,
+    r#"COMMENT ON COLUMN "user_group_invitation"."userId" IS 'The user ID.'"#,
+    r#"COMMENT ON COLUMN "user_group_invitation"."userGroupId" IS 'The group ID.'"#,
+    r#"COMMENT ON COLUMN "user_group_joining"."createdAt" IS 'The created date of the UserGroupJoining.'"#,
+    r#"COMMENT ON COLUMN "user_group_joining"."userId" IS 'The user ID.'"#,
+    r#"COMMENT ON COLUMN "user_group_joining"."userGroupId" IS 'The group ID.'"#,
+    r#"COMMENT ON COLUMN "user_list"."createdAt" IS 'The created date of the UserList.'"#,
+    r#"COMMENT ON COLUMN "user_list"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "user_list"."name" IS 'The name of the UserList.'"#,
+    r#"COMMENT ON COLUMN "user_list_joining"."createdAt" IS 'The created date of the UserListJoining.'"#,
+    r#"COMMENT ON COLUMN "user_list_joining"."userId" IS 'The user ID.'"#,
+    r#"COMMENT ON COLUMN "user_list_joining"."userListId" IS 'The list ID.'"#,
+    r#"COMMENT ON COLUMN "user_note_pining"."createdAt" IS 'The created date of the UserNotePinings.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."location" IS 'The location of the User.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."birthday" IS 'The birthday (YYYY-MM-DD) of the User.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."description" IS 'The description (bio) of the User.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."url" IS 'Remote URL of the user.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."email" IS 'The email address of the User.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."password" IS 'The password hash of the User. It will be null if the origin of the user is local.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."clientData" IS 'The client-specific data of the User.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."userHost" IS '[Denormalized]'"#,
+    r#"COMMENT ON COLUMN "user_profile"."room" IS 'The room data of the User.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."noCrawle" IS 'Whether reject index by crawler.'"#,
+    r#"COMMENT ON COLUMN "user_profile"."mutedInstances" IS 'List of instances muted by the user.'"#,
+    r#"COMMENT ON COLUMN "user_security_key"."id" IS 'Variable-length id given to navigator.credentials.get()'"#,
+    r#"COMMENT ON COLUMN "user_security_key"."publicKey" IS 'Variable-length public key used to verify attestations (hex-encoded).'"#,
+    r#"COMMENT ON COLUMN "user_security_key"."lastUsed" IS 'The date of the last time the UserSecurityKey was successfully validated.'"#,
+    r#"COMMENT ON COLUMN "user_security_key"."name" IS 'User-defined name for this key'"#,
+    r#"COMMENT ON COLUMN "webhook"."createdAt" IS 'The created date of the Antenna.'"#,
+    r#"COMMENT ON COLUMN "webhook"."userId" IS 'The owner ID.'"#,
+    r#"COMMENT ON COLUMN "webhook"."name" IS 'The name of the Antenna.'"#,
+    r#"CREATE OR REPLACE FUNCTION public.note_replies(start_id character varying, max_depth integer, max_breadth integer)
+ RETURNS TABLE(id character varying)
+ LANGUAGE sql
+AS $function$
+				SELECT DISTINCT id FROM (
+					WITH RECURSIVE tree (id, ancestors, depth) AS (
+						SELECT start_id, '{}'::VARCHAR[], 0
+					UNION
+						SELECT
+							note.id,
+							CASE
+								WHEN note."replyId" = tree.id THEN tree.ancestors || note."replyId"
+								ELSE tree.ancestors || note."renoteId"
+							END,
+							depth + 1
+						FROM note, tree
+						WHERE (
+							note."replyId" = tree.id
+							OR
+							(
+								-- get renotes but not pure renotes
+								note."renoteId" = tree.id
+								AND
+								(
+									note.text IS NOT NULL
+									OR
+									CARDINALITY(note."fileIds") != 0
+									OR
+									note."hasPoll" = TRUE
+								)
+							)
+						) AND depth < max_depth
+					)
+					SELECT
+						id,
+						-- apply the limit per node
+						row_number() OVER (PARTITION BY ancestors[array_upper(ancestors, 1)]) AS nth_child
+					FROM tree
+					WHERE depth > 0
+				) AS recursive WHERE nth_child < max_breadth
+			$function$"#,
+];
+
+/// Migrations recorded in the `migrations` table of TypeORM.
+pub const TYPEORM_MIGRATIONS: &[(i64, &str)] = &[
+    (1000000000000, "Init1000000000000"),
+    (1556348509290, "Pages1556348509290"),
+    (1556746559567, "UserProfile1556746559567"),
+    (1557476068003, "PinnedUsers1557476068003"),
+    (1557761316509, "AddSomeUrls1557761316509"),
+    (1557932705754, "ObjectStorageSetting1557932705754"),
+    (1558072954435, "PageLike1558072954435"),
+    (1558103093633, "UserGroup1558103093633"),
+    (1558257926829, "UserGroupInvite1558257926829"),
+    (1558266512381, "UserListJoining1558266512381"),
+    (1561706992953, "webauthn1561706992953"),
+    (1561873850023, "ChartIndexes1561873850023"),
+    (1562422242907, "PasswordLessLogin1562422242907"),
+    (1562444565093, "PinnedPage1562444565093"),
+    (1562448332510, "PageTitleHideOption1562448332510"),
+    (1562869971568, "ModerationLog1562869971568"),
+    (1563757595828, "UsedUsername1563757595828"),
+    (1565634203341, "room1565634203341"),
+    (1571220798684, "CustomEmojiCategory1571220798684"),
+    (1572760203493, "nodeinfo1572760203493"),
+    (1576269851876, "TalkFederationId1576269851876"),
+    (1576869585998, "ProxyRemoteFiles1576869585998"),
+    (1579267006611, "v121579267006611"),
+    (1579270193251, "v1221579270193251"),
+    (1579282808087, "v1231579282808087"),
+    (1579544426412, "v1241579544426412"),
+    (1579977526288, "v1251579977526288"),
+    (1579993013959, "v1261579993013959"),
+    (1580069531114, "v1271580069531114"),
+    (1580148575182, "v1281580148575182"),
+    (1580154400017, "v1291580154400017"),
+    (1580276619901, "v12101580276619901"),
+    (1580331224276, "v12111580331224276"),
+    (1580508795118, "v12121580508795118"),
+    (1580543501339, "v12131580543501339"),
+    (1580864313253, "v12141580864313253"),
+    (1581526429287, "userGroupInvitation1581526429287"),
+    (1581695816408, "userGroupAntenna1581695816408"),
+    (1581708415836, "driveUserFolderIdIndex1581708415836"),
+    (1581979837262, "promo1581979837262"),
+    (1582019042083, "featuredInjecttion1582019042083"),
+    (1582210532752, "antennaExclude1582210532752"),
+    (1582875306439, "noteReactionLength1582875306439"),
+    (1585361548360, "miauth1585361548360"),
+    (1585385921215, "customNotification1585385921215"),
+    (1585772678853, "apUrl1585772678853"),
+    (1586624197029, "AddObjectStorageUseProxy1586624197029"),
+    (1586641139527, "remoteReaction1586641139527"),
+    (1586708940386, "pageAiScript1586708940386"),
+    (1588044505511, "hCaptcha1588044505511"),
+    (1589023282116, "pubRelay1589023282116"),
+    (1595075960584, "blurhash1595075960584"),
+    (1595077605646, "blurhashForAvatarBanner1595077605646"),
+    (1595676934834, "instanceIconUrl1595676934834"),
+    (1595771249699, "wordMute1595771249699"),
+    (1595782306083, "wordMute21595782306083"),
+    (1596548170836, "channel1596548170836"),
+    (1596786425167, "channel21596786425167"),
+    (1597230137744, "objectStorageSetPublicRead1597230137744"),
+    (1597236229720, "IncludingNotificationTypes1597236229720"),
+    (1597385880794, "addSensitiveIndex1597385880794"),
+    (1597459042300, "channelUnread1597459042300"),
+    (1597893996136, "ChannelNoteIdDescIndex1597893996136"),
+    (1600353287890, "mutingNotificationTypes1600353287890"),
+    (1603094348345, "refineAbuseUserReport1603094348345"),
+    (1603095701770, "refineAbuseUserReport21603095701770"),
+    (1603776877564, "instanceThemeColor1603776877564"),
+    (1603781553011, "instanceFavicon1603781553011"),
+    (1604821689616, "deleteAutoWatch1604821689616"),
+    (1605408848373, "clipDescription1605408848373"),
+    (1605408971051, "comments1605408971051"),
+    (1605585339718, "instancePinnedPages1605585339718"),
+    (1605965516823, "instanceImages1605965516823"),
+    (1606191203881, "noCrawle1606191203881"),
+    (1607151207216, "instancePinnedClip1607151207216"),
+    (1607353487793, "isExplorable1607353487793"),
+    (1610277136869, "registry1610277136869"),
+    (1610277585759, "registry21610277585759"),
+    (1610283021566, "registry31610283021566"),
+    (1611354329133, "followersUri1611354329133"),
+    (1611397665007, "gallery1611397665007"),
+    (1611547387175, "objectStorageS3ForcePathStyle1611547387175"),
+    (1612619156584, "announcementEmail1612619156584"),
+    (1613155914446, "emailNotificationTypes1613155914446"),
+    (1613181457597, "userLang1613181457597"),
+    (1613503367223, "useBigintForDriveUsage1613503367223"),
+    (1615965918224, "chartV21615965918224"),
+    (1615966519402, "chartV221615966519402"),
+    (1618637372000, "userLastActiveDate1618637372000"),
+    (1618639857000, "userHideOnlineStatus1618639857000"),
+    (1619942102890, "passwordReset1619942102890"),
+    (1620019354680, "ad1620019354680"),
+    (1620364649428, "ad21620364649428"),
+    (1621479946000, "addNoteIndexes1621479946000"),
+    (1622679304522, "userProfileDescriptionLength1622679304522"),
+    (1622681548499, "logMessageLength1622681548499"),
+    (1626509500668, "fixRemoteFileProxy1626509500668"),
+    (1626733991004, "allowlistSecureMode1626733991004"),
+    (1629004542760, "chartReindex1629004542760"),
+    (1629024377804, "deeplIntegration1629024377804"),
+    (1629288472000, "fixChannelUserId1629288472000"),
+    (1629512953000, "isUserDeleted1629512953000"),
+    (1629778475000, "deeplIntegration21629778475000"),
+    (1629833361000, "addShowTLReplies1629833361000"),
+    (1629968054000, "userInstanceBlocks1629968054000"),
+    (1633068642000, "emailRequiredForSignup1633068642000"),
+    (1633071909016, "userPending1633071909016"),
+    (1634486652000, "userPublicReactions1634486652000"),
+    (1634902659689, "deleteLog1634902659689"),
+    (1635500777168, "noteThreadMute1635500777168"),
+    (1636197624383, "ffVisibility1636197624383"),
+    (1636697408073, "removeViaMobile1636697408073"),
+    (1637320813000, "forwardedReport1637320813000"),
+    (1639325650583, "chartV31639325650583"),
+    (1642611822809, "emojiUrl1642611822809"),
+    (1642613870898, "driveFileWebpublicType1642613870898"),
+    (1643963705770, "chartV41643963705770"),
+    (1643966656277, "chartV51643966656277"),
+    (1643967331284, "chartV61643967331284"),
+    (1644010796173, "convertHardMutes1644010796173"),
+    (1644058404077, "chartV71644058404077"),
+    (1644059847460, "chartV81644059847460"),
+    (1644060125705, "chartV91644060125705"),
+    (1644073149413, "chartV101644073149413"),
+    (1644095659741, "chartV111644095659741"),
+    (1644328606241, "chartV121644328606241"),
+    (1644331238153, "chartV131644331238153"),
+    (1644344266289, "chartV141644344266289"),
+    (1644395759931, "instanceThemeColor1644395759931"),
+    (1644481657998, "chartV151644481657998"),
+    (1644551208096, "followingIndexes1644551208096"),
+    (1645340161439, "removeMaxNoteTextLength1645340161439"),
+    (1645599900873, "federationChartPubsub1645599900873"),
+    (1646143552768, "instanceDefaultTheme1646143552768"),
+    (1646387162108, "muteExpiresAt1646387162108"),
+    (1646549089451, "pollEndedNotification1646549089451"),
+    (1646633030285, "chartFederationActive1646633030285"),
+    (1646655454495, "removeInstanceDriveColumns1646655454495"),
+    (1646732390560, "chartFederationActiveSubPub1646732390560"),
+    (1648548247382, "webhook1648548247382"),
+    (1648816172177, "webhook21648816172177"),
+    (1651224615271, "foreignKeyReports1651224615271"),
+    (1652859567549, "uniformThemecolor1652859567549"),
+    (1655368940105, "nsfwDetection1655368940105"),
+    (1655371960534, "nsfwDetection21655371960534"),
+    (1655388169582, "nsfwDetection31655388169582"),
+    (1655393015659, "nsfwDetection41655393015659"),
+    (1655813815729, "driveCapacityOverrideMb1655813815729"),
+    (1655918165614, "userIp1655918165614"),
+    (1656122560740, "fileIp1656122560740"),
+    (1656251734807, "nsfwDetection51656251734807"),
+    (1656328812281, "ip21656328812281"),
+    (1656408772602, "nsfwDetection61656408772602"),
+    (1656772790599, "userModerationNote1656772790599"),
+    (1657346559800, "activeEmailValidation1657346559800"),
+    (1658203170545, "calckey1658203170545"),
+    (1658656633972, "noteRepliesFunction1658656633972"),
+    (1658939464003, "CustomMOTD1658939464003"),
+    (1658941974648, "CustomSplashIcons1658941974648"),
+    (1658981842728, "FixFirefish1658981842728"),
+    (1659042130648, "RecommendedTimeline1659042130648"),
+    (1660068273737, "GuestTimeline1660068273737"),
+    (1665091090561, "addRenoteMuting1665091090561"),
+    (1668828368510, "Page1668828368510"),
+    (1668831378728, "FixFirefishAgain1668831378728"),
+    (
+        1669138716634,
+        "whetherPushNotifyToSendReadMessage1669138716634",
+    ),
+    (1669288094000, "addMovedToAndKnownAs1669288094000"),
+    (
+        1671199573000,
+        "addFkAbuseUserReportTargetUserIdToUserId1671199573000",
+    ),
+    (1671388343000, "FirefishRepoMove1671388343000"),
+    (1672882664294, "DefaultReaction1672882664294"),
+    (1673336077243, "PollChoiceLength1673336077243"),
+    (1676093997212, "AntennaInstances1676093997212"),
+    (1677935903517, "DriveComment1677935903517"),
+    (1678426061773, "tweakVarcharLength1678426061773"),
+    (1678945242650, "addPropsForCustomEmoji1678945242650"),
+    (1679269929000, "FixRepo1679269929000"),
+    (1680375641101, "CleanCharts1680375641101"),
+    (1680426269172, "SpeakAsCat1680426269172"),
+    (1682753227899, "NoteEdit1682753227899"),
+    (1682777547198, "LibreTranslate1682777547198"),
+    (1682891890317, "InstanceSilence1682891890317"),
+    (1682891891317, "AddHiddenPosts1682891891317"),
+    (1683682889948, "PreventAiLearning1683682889948"),
+    (1683980686995, "ExperimentalFeatures1683980686995"),
+    (1684206886988, "RemoveShowTimelineReplies1684206886988"),
+    (1684494870830, "EmojiSize1684494870830"),
+    (1688280713783, "AddMetaOptions1688280713783"),
+    (1688845537045, "AnnouncementPopup1688845537045"),
+    (1689136347561, "DonationLink1689136347561"),
+    (1689739513827, "FirefishRepo1689739513827"),
+    (1689957674000, "FirefishRepo1689957674000"),
+];
+
+#[derive(Iden)]
+enum ChartActiveUsers {
+    #[iden = "__chart__active_users"]
+    Table,
+    Id,
+    Date,
+    #[iden = "unique_temp___registeredWithinWeek"]
+    UniqueTempRegisteredWithinWeek,
+    #[iden = "___registeredWithinWeek"]
+    RegisteredWithinWeek,
+    #[iden = "unique_temp___registeredWithinMonth"]
+    UniqueTempRegisteredWithinMonth,
+    #[iden = "___registeredWithinMonth"]
+    RegisteredWithinMonth,
+    #[iden = "unique_temp___registeredWithinYear"]
+    UniqueTempRegisteredWithinYear,
+    #[iden = "___registeredWithinYear"]
+    RegisteredWithinYear,
+    #[iden = "unique_temp___registeredOutsideWeek"]
+    UniqueTempRegisteredOutsideWeek,
+    #[iden = "___registeredOutsideWeek"]
+    RegisteredOutsideWeek,
+    #[iden = "unique_temp___registeredOutsideMonth"]
+    UniqueTempRegisteredOutsideMonth,
+    #[iden = "___registeredOutsideMonth"]
+    RegisteredOutsideMonth,
+    #[iden = "unique_temp___registeredOutsideYear"]
+    UniqueTempRegisteredOutsideYear,
+    #[iden = "___registeredOutsideYear"]
+    RegisteredOutsideYear,
+    #[iden = "___readWrite"]
+    ReadWrite,
+    #[iden = "unique_temp___read"]
+    UniqueTempRead,
+    #[iden = "___read"]
+    Read,
+    #[iden = "unique_temp___write"]
+    UniqueTempWrite,
+    #[iden = "___write"]
+    Write,
+}
+#[derive(Iden)]
+enum ChartApRequest {
+    #[iden = "__chart__ap_request"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___deliverFailed"]
+    DeliverFailed,
+    #[iden = "___deliverSucceeded"]
+    DeliverSucceeded,
+    #[iden = "___inboxReceived"]
+    InboxReceived,
+}
+#[derive(Iden)]
+enum ChartDrive {
+    #[iden = "__chart__drive"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___local_incCount"]
+    LocalIncCount,
+    #[iden = "___local_incSize"]
+    LocalIncSize,
+    #[iden = "___local_decCount"]
+    LocalDecCount,
+    #[iden = "___local_decSize"]
+    LocalDecSize,
+    #[iden = "___remote_incCount"]
+    RemoteIncCount,
+    #[iden = "___remote_incSize"]
+    RemoteIncSize,
+    #[iden = "___remote_decCount"]
+    RemoteDecCount,
+    #[iden = "___remote_decSize"]
+    RemoteDecSize,
+}
+#[derive(Iden)]
+enum ChartFederation {
+    #[iden = "__chart__federation"]
+    Table,
+    Id,
+    Date,
+    #[iden = "unique_temp___deliveredInstances"]
+    UniqueTempDeliveredInstances,
+    #[iden = "___deliveredInstances"]
+    DeliveredInstances,
+    #[iden = "unique_temp___inboxInstances"]
+    UniqueTempInboxInstances,
+    #[iden = "___inboxInstances"]
+    InboxInstances,
+    #[iden = "unique_temp___stalled"]
+    UniqueTempStalled,
+    #[iden = "___stalled"]
+    Stalled,
+    #[iden = "___sub"]
+    Sub,
+    #[iden = "___pub"]
+    Pub,
+    #[iden = "___pubsub"]
+    Pubsub,
+    #[iden = "___subActive"]
+    SubActive,
+    #[iden = "___pubActive"]
+    PubActive,
+}
+#[derive(Iden)]
+enum ChartHashtag {
+    #[iden = "__chart__hashtag"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___local_users"]
+    LocalUsers,
+    #[iden = "___remote_users"]
+    RemoteUsers,
+    #[iden = "unique_temp___local_users"]
+    UniqueTempLocalUsers,
+    #[iden = "unique_temp___remote_users"]
+    UniqueTempRemoteUsers,
+}
+#[derive(Iden)]
+enum ChartInstance {
+    #[iden = "__chart__instance"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___requests_failed"]
+    RequestsFailed,
+    #[iden = "___requests_succeeded"]
+    RequestsSucceeded,
+    #[iden = "___requests_received"]
+    RequestsReceived,
+    #[iden = "___notes_total"]
+    NotesTotal,
+    #[iden = "___notes_inc"]
+    NotesInc,
+    #[iden = "___notes_dec"]
+    NotesDec,
+    #[iden = "___notes_diffs_normal"]
+    NotesDiffsNormal,
+    #[iden = "___notes_diffs_reply"]
+    NotesDiffsReply,
+    #[iden = "___notes_diffs_renote"]
+    NotesDiffsRenote,
+    #[iden = "___users_total"]
+    UsersTotal,
+    #[iden = "___users_inc"]
+    UsersInc,
+    #[iden = "___users_dec"]
+    UsersDec,
+    #[iden = "___following_total"]
+    FollowingTotal,
+    #[iden = "___following_inc"]
+    FollowingInc,
+    #[iden = "___following_dec"]
+    FollowingDec,
+    #[iden = "___followers_total"]
+    FollowersTotal,
+    #[iden = "___followers_inc"]
+    FollowersInc,
+    #[iden = "___followers_dec"]
+    FollowersDec,
+    #[iden = "___drive_totalFiles"]
+    DriveTotalFiles,
+    #[iden = "___drive_incFiles"]
+    DriveIncFiles,
+    #[iden = "___drive_incUsage"]
+    DriveIncUsage,
+    #[iden = "___drive_decFiles"]
+    DriveDecFiles,
+    #[iden = "___drive_decUsage"]
+    DriveDecUsage,
+    #[iden = "___notes_diffs_withFile"]
+    NotesDiffsWithFile,
+}
+#[derive(Iden)]
+enum ChartNetwork {
+    #[iden = "__chart__network"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___incomingRequests"]
+    IncomingRequests,
+    #[iden = "___outgoingRequests"]
+    OutgoingRequests,
+    #[iden = "___totalTime"]
+    TotalTime,
+    #[iden = "___incomingBytes"]
+    IncomingBytes,
+    #[iden = "___outgoingBytes"]
+    OutgoingBytes,
+}
+#[derive(Iden)]
+enum ChartNotes {
+    #[iden = "__chart__notes"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___local_total"]
+    LocalTotal,
+    #[iden = "___local_inc"]
+    LocalInc,
+    #[iden = "___local_dec"]
+    LocalDec,
+    #[iden = "___local_diffs_normal"]
+    LocalDiffsNormal,
+    #[iden = "___local_diffs_reply"]
+    LocalDiffsReply,
+    #[iden = "___local_diffs_renote"]
+    LocalDiffsRenote,
+    #[iden = "___remote_total"]
+    RemoteTotal,
+    #[iden = "___remote_inc"]
+    RemoteInc,
+    #[iden = "___remote_dec"]
+    RemoteDec,
+    #[iden = "___remote_diffs_normal"]
+    RemoteDiffsNormal,
+    #[iden = "___remote_diffs_reply"]
+    RemoteDiffsReply,
+    #[iden = "___remote_diffs_renote"]
+    RemoteDiffsRenote,
+    #[iden = "___local_diffs_withFile"]
+    LocalDiffsWithFile,
+    #[iden = "___remote_diffs_withFile"]
+    RemoteDiffsWithFile,
+}
+#[derive(Iden)]
+enum ChartPerUserDrive {
+    #[iden = "__chart__per_user_drive"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___totalCount"]
+    TotalCount,
+    #[iden = "___totalSize"]
+    TotalSize,
+    #[iden = "___incCount"]
+    IncCount,
+    #[iden = "___incSize"]
+    IncSize,
+    #[iden = "___decCount"]
+    DecCount,
+    #[iden = "___decSize"]
+    DecSize,
+}
+#[derive(Iden)]
+enum ChartPerUserFollowing {
+    #[iden = "__chart__per_user_following"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___local_followings_total"]
+    LocalFollowingsTotal,
+    #[iden = "___local_followings_inc"]
+    LocalFollowingsInc,
+    #[iden = "___local_followings_dec"]
+    LocalFollowingsDec,
+    #[iden = "___local_followers_total"]
+    LocalFollowersTotal,
+    #[iden = "___local_followers_inc"]
+    LocalFollowersInc,
+    #[iden = "___local_followers_dec"]
+    LocalFollowersDec,
+    #[iden = "___remote_followings_total"]
+    RemoteFollowingsTotal,
+    #[iden = "___remote_followings_inc"]
+    RemoteFollowingsInc,
+    #[iden = "___remote_followings_dec"]
+    RemoteFollowingsDec,
+    #[iden = "___remote_followers_total"]
+    RemoteFollowersTotal,
+    #[iden = "___remote_followers_inc"]
+    RemoteFollowersInc,
+    #[iden = "___remote_followers_dec"]
+    RemoteFollowersDec,
+}
+#[derive(Iden)]
+enum ChartPerUserNotes {
+    #[iden = "__chart__per_user_notes"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___total"]
+    Total,
+    #[iden = "___inc"]
+    Inc,
+    #[iden = "___dec"]
+    Dec,
+    #[iden = "___diffs_normal"]
+    DiffsNormal,
+    #[iden = "___diffs_reply"]
+    DiffsReply,
+    #[iden = "___diffs_renote"]
+    DiffsRenote,
+    #[iden = "___diffs_withFile"]
+    DiffsWithFile,
+}
+#[derive(Iden)]
+enum ChartPerUserReaction {
+    #[iden = "__chart__per_user_reaction"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___local_count"]
+    LocalCount,
+    #[iden = "___remote_count"]
+    RemoteCount,
+}
+#[derive(Iden)]
+enum ChartTest {
+    #[iden = "__chart__test"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___foo_total"]
+    FooTotal,
+    #[iden = "___foo_inc"]
+    FooInc,
+    #[iden = "___foo_dec"]
+    FooDec,
+}
+#[derive(Iden)]
+enum ChartTestGrouped {
+    #[iden = "__chart__test_grouped"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___foo_total"]
+    FooTotal,
+    #[iden = "___foo_inc"]
+    FooInc,
+    #[iden = "___foo_dec"]
+    FooDec,
+}
+#[derive(Iden)]
+enum ChartTestUnique {
+    #[iden = "__chart__test_unique"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___foo"]
+    Foo,
+}
+#[derive(Iden)]
+enum ChartUsers {
+    #[iden = "__chart__users"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___local_total"]
+    LocalTotal,
+    #[iden = "___local_inc"]
+    LocalInc,
+    #[iden = "___local_dec"]
+    LocalDec,
+    #[iden = "___remote_total"]
+    RemoteTotal,
+    #[iden = "___remote_inc"]
+    RemoteInc,
+    #[iden = "___remote_dec"]
+    RemoteDec,
+}
+#[derive(Iden)]
+enum ChartDayActiveUsers {
+    #[iden = "__chart_day__active_users"]
+    Table,
+    Id,
+    Date,
+    #[iden = "unique_temp___registeredWithinWeek"]
+    UniqueTempRegisteredWithinWeek,
+    #[iden = "___registeredWithinWeek"]
+    RegisteredWithinWeek,
+    #[iden = "unique_temp___registeredWithinMonth"]
+    UniqueTempRegisteredWithinMonth,
+    #[iden = "___registeredWithinMonth"]
+    RegisteredWithinMonth,
+    #[iden = "unique_temp___registeredWithinYear"]
+    UniqueTempRegisteredWithinYear,
+    #[iden = "___registeredWithinYear"]
+    RegisteredWithinYear,
+    #[iden = "unique_temp___registeredOutsideWeek"]
+    UniqueTempRegisteredOutsideWeek,
+    #[iden = "___registeredOutsideWeek"]
+    RegisteredOutsideWeek,
+    #[iden = "unique_temp___registeredOutsideMonth"]
+    UniqueTempRegisteredOutsideMonth,
+    #[iden = "___registeredOutsideMonth"]
+    RegisteredOutsideMonth,
+    #[iden = "unique_temp___registeredOutsideYear"]
+    UniqueTempRegisteredOutsideYear,
+    #[iden = "___registeredOutsideYear"]
+    RegisteredOutsideYear,
+    #[iden = "___readWrite"]
+    ReadWrite,
+    #[iden = "unique_temp___read"]
+    UniqueTempRead,
+    #[iden = "___read"]
+    Read,
+    #[iden = "unique_temp___write"]
+    UniqueTempWrite,
+    #[iden = "___write"]
+    Write,
+}
+#[derive(Iden)]
+enum ChartDayApRequest {
+    #[iden = "__chart_day__ap_request"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___deliverFailed"]
+    DeliverFailed,
+    #[iden = "___deliverSucceeded"]
+    DeliverSucceeded,
+    #[iden = "___inboxReceived"]
+    InboxReceived,
+}
+#[derive(Iden)]
+enum ChartDayDrive {
+    #[iden = "__chart_day__drive"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___local_incCount"]
+    LocalIncCount,
+    #[iden = "___local_incSize"]
+    LocalIncSize,
+    #[iden = "___local_decCount"]
+    LocalDecCount,
+    #[iden = "___local_decSize"]
+    LocalDecSize,
+    #[iden = "___remote_incCount"]
+    RemoteIncCount,
+    #[iden = "___remote_incSize"]
+    RemoteIncSize,
+    #[iden = "___remote_decCount"]
+    RemoteDecCount,
+    #[iden = "___remote_decSize"]
+    RemoteDecSize,
+}
+#[derive(Iden)]
+enum ChartDayFederation {
+    #[iden = "__chart_day__federation"]
+    Table,
+    Id,
+    Date,
+    #[iden = "unique_temp___deliveredInstances"]
+    UniqueTempDeliveredInstances,
+    #[iden = "___deliveredInstances"]
+    DeliveredInstances,
+    #[iden = "unique_temp___inboxInstances"]
+    UniqueTempInboxInstances,
+    #[iden = "___inboxInstances"]
+    InboxInstances,
+    #[iden = "unique_temp___stalled"]
+    UniqueTempStalled,
+    #[iden = "___stalled"]
+    Stalled,
+    #[iden = "___sub"]
+    Sub,
+    #[iden = "___pub"]
+    Pub,
+    #[iden = "___pubsub"]
+    Pubsub,
+    #[iden = "___subActive"]
+    SubActive,
+    #[iden = "___pubActive"]
+    PubActive,
+}
+#[derive(Iden)]
+enum ChartDayHashtag {
+    #[iden = "__chart_day__hashtag"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___local_users"]
+    LocalUsers,
+    #[iden = "___remote_users"]
+    RemoteUsers,
+    #[iden = "unique_temp___local_users"]
+    UniqueTempLocalUsers,
+    #[iden = "unique_temp___remote_users"]
+    UniqueTempRemoteUsers,
+}
+#[derive(Iden)]
+enum ChartDayInstance {
+    #[iden = "__chart_day__instance"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___requests_failed"]
+    RequestsFailed,
+    #[iden = "___requests_succeeded"]
+    RequestsSucceeded,
+    #[iden = "___requests_received"]
+    RequestsReceived,
+    #[iden = "___notes_total"]
+    NotesTotal,
+    #[iden = "___notes_inc"]
+    NotesInc,
+    #[iden = "___notes_dec"]
+    NotesDec,
+    #[iden = "___notes_diffs_normal"]
+    NotesDiffsNormal,
+    #[iden = "___notes_diffs_reply"]
+    NotesDiffsReply,
+    #[iden = "___notes_diffs_renote"]
+    NotesDiffsRenote,
+    #[iden = "___users_total"]
+    UsersTotal,
+    #[iden = "___users_inc"]
+    UsersInc,
+    #[iden = "___users_dec"]
+    UsersDec,
+    #[iden = "___following_total"]
+    FollowingTotal,
+    #[iden = "___following_inc"]
+    FollowingInc,
+    #[iden = "___following_dec"]
+    FollowingDec,
+    #[iden = "___followers_total"]
+    FollowersTotal,
+    #[iden = "___followers_inc"]
+    FollowersInc,
+    #[iden = "___followers_dec"]
+    FollowersDec,
+    #[iden = "___drive_totalFiles"]
+    DriveTotalFiles,
+    #[iden = "___drive_incFiles"]
+    DriveIncFiles,
+    #[iden = "___drive_incUsage"]
+    DriveIncUsage,
+    #[iden = "___drive_decFiles"]
+    DriveDecFiles,
+    #[iden = "___drive_decUsage"]
+    DriveDecUsage,
+    #[iden = "___notes_diffs_withFile"]
+    NotesDiffsWithFile,
+}
+#[derive(Iden)]
+enum ChartDayNetwork {
+    #[iden = "__chart_day__network"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___incomingRequests"]
+    IncomingRequests,
+    #[iden = "___outgoingRequests"]
+    OutgoingRequests,
+    #[iden = "___totalTime"]
+    TotalTime,
+    #[iden = "___incomingBytes"]
+    IncomingBytes,
+    #[iden = "___outgoingBytes"]
+    OutgoingBytes,
+}
+#[derive(Iden)]
+enum ChartDayNotes {
+    #[iden = "__chart_day__notes"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___local_total"]
+    LocalTotal,
+    #[iden = "___local_inc"]
+    LocalInc,
+    #[iden = "___local_dec"]
+    LocalDec,
+    #[iden = "___local_diffs_normal"]
+    LocalDiffsNormal,
+    #[iden = "___local_diffs_reply"]
+    LocalDiffsReply,
+    #[iden = "___local_diffs_renote"]
+    LocalDiffsRenote,
+    #[iden = "___remote_total"]
+    RemoteTotal,
+    #[iden = "___remote_inc"]
+    RemoteInc,
+    #[iden = "___remote_dec"]
+    RemoteDec,
+    #[iden = "___remote_diffs_normal"]
+    RemoteDiffsNormal,
+    #[iden = "___remote_diffs_reply"]
+    RemoteDiffsReply,
+    #[iden = "___remote_diffs_renote"]
+    RemoteDiffsRenote,
+    #[iden = "___local_diffs_withFile"]
+    LocalDiffsWithFile,
+    #[iden = "___remote_diffs_withFile"]
+    RemoteDiffsWithFile,
+}
+#[derive(Iden)]
+enum ChartDayPerUserDrive {
+    #[iden = "__chart_day__per_user_drive"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___totalCount"]
+    TotalCount,
+    #[iden = "___totalSize"]
+    TotalSize,
+    #[iden = "___incCount"]
+    IncCount,
+    #[iden = "___incSize"]
+    IncSize,
+    #[iden = "___decCount"]
+    DecCount,
+    #[iden = "___decSize"]
+    DecSize,
+}
+#[derive(Iden)]
+enum ChartDayPerUserFollowing {
+    #[iden = "__chart_day__per_user_following"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___local_followings_total"]
+    LocalFollowingsTotal,
+    #[iden = "___local_followings_inc"]
+    LocalFollowingsInc,
+    #[iden = "___local_followings_dec"]
+    LocalFollowingsDec,
+    #[iden = "___local_followers_total"]
+    LocalFollowersTotal,
+    #[iden = "___local_followers_inc"]
+    LocalFollowersInc,
+    #[iden = "___local_followers_dec"]
+    LocalFollowersDec,
+    #[iden = "___remote_followings_total"]
+    RemoteFollowingsTotal,
+    #[iden = "___remote_followings_inc"]
+    RemoteFollowingsInc,
+    #[iden = "___remote_followings_dec"]
+    RemoteFollowingsDec,
+    #[iden = "___remote_followers_total"]
+    RemoteFollowersTotal,
+    #[iden = "___remote_followers_inc"]
+    RemoteFollowersInc,
+    #[iden = "___remote_followers_dec"]
+    RemoteFollowersDec,
+}
+#[derive(Iden)]
+enum ChartDayPerUserNotes {
+    #[iden = "__chart_day__per_user_notes"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___total"]
+    Total,
+    #[iden = "___inc"]
+    Inc,
+    #[iden = "___dec"]
+    Dec,
+    #[iden = "___diffs_normal"]
+    DiffsNormal,
+    #[iden = "___diffs_reply"]
+    DiffsReply,
+    #[iden = "___diffs_renote"]
+    DiffsRenote,
+    #[iden = "___diffs_withFile"]
+    DiffsWithFile,
+}
+#[derive(Iden)]
+enum ChartDayPerUserReaction {
+    #[iden = "__chart_day__per_user_reaction"]
+    Table,
+    Id,
+    Date,
+    Group,
+    #[iden = "___local_count"]
+    LocalCount,
+    #[iden = "___remote_count"]
+    RemoteCount,
+}
+#[derive(Iden)]
+enum ChartDayUsers {
+    #[iden = "__chart_day__users"]
+    Table,
+    Id,
+    Date,
+    #[iden = "___local_total"]
+    LocalTotal,
+    #[iden = "___local_inc"]
+    LocalInc,
+    #[iden = "___local_dec"]
+    LocalDec,
+    #[iden = "___remote_total"]
+    RemoteTotal,
+    #[iden = "___remote_inc"]
+    RemoteInc,
+    #[iden = "___remote_dec"]
+    RemoteDec,
+}
+#[derive(Iden)]
+enum AbuseUserReport {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "targetUserId"]
+    TargetUserId,
+    #[iden = "reporterId"]
+    ReporterId,
+    #[iden = "assigneeId"]
+    AssigneeId,
+    Resolved,
+    Comment,
+    #[iden = "targetUserHost"]
+    TargetUserHost,
+    #[iden = "reporterHost"]
+    ReporterHost,
+    Forwarded,
+}
+#[derive(Iden)]
+enum AccessToken {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Token,
+    Hash,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "appId"]
+    AppId,
+    #[iden = "lastUsedAt"]
+    LastUsedAt,
+    Session,
+    Name,
+    Description,
+    #[iden = "iconUrl"]
+    IconUrl,
+    Permission,
+    Fetched,
+}
+#[derive(Iden)]
+enum Ad {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "expiresAt"]
+    ExpiresAt,
+    Place,
+    Priority,
+    Url,
+    #[iden = "imageUrl"]
+    ImageUrl,
+    Memo,
+    Ratio,
+}
+#[derive(Iden)]
+enum Announcement {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Text,
+    Title,
+    #[iden = "imageUrl"]
+    ImageUrl,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+    #[iden = "showPopup"]
+    ShowPopup,
+    #[iden = "isGoodNews"]
+    IsGoodNews,
+}
+#[derive(Iden)]
+enum AnnouncementRead {
+    Table,
+    Id,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "announcementId"]
+    AnnouncementId,
+    #[iden = "createdAt"]
+    CreatedAt,
+}
+#[derive(Iden)]
+enum Antenna {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Name,
+    Src,
+    #[iden = "userListId"]
+    UserListId,
+    Keywords,
+    #[iden = "withFile"]
+    WithFile,
+    Expression,
+    Notify,
+    #[iden = "caseSensitive"]
+    CaseSensitive,
+    #[iden = "withReplies"]
+    WithReplies,
+    #[iden = "userGroupJoiningId"]
+    UserGroupJoiningId,
+    Users,
+    #[iden = "excludeKeywords"]
+    ExcludeKeywords,
+    Instances,
+}
+#[derive(Iden)]
+enum AntennaNote {
+    Table,
+    Id,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "antennaId"]
+    AntennaId,
+    Read,
+}
+#[derive(Iden)]
+enum App {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Secret,
+    Name,
+    Description,
+    Permission,
+    #[iden = "callbackUrl"]
+    CallbackUrl,
+}
+#[derive(Iden)]
+enum AttestationChallenge {
+    Table,
+    Id,
+    #[iden = "userId"]
+    UserId,
+    Challenge,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "registrationChallenge"]
+    RegistrationChallenge,
+}
+#[derive(Iden)]
+enum AuthSession {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Token,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "appId"]
+    AppId,
+}
+#[derive(Iden)]
+enum Blocking {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "blockeeId"]
+    BlockeeId,
+    #[iden = "blockerId"]
+    BlockerId,
+}
+#[derive(Iden)]
+enum Channel {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "lastNotedAt"]
+    LastNotedAt,
+    #[iden = "userId"]
+    UserId,
+    Name,
+    Description,
+    #[iden = "bannerId"]
+    BannerId,
+    #[iden = "notesCount"]
+    NotesCount,
+    #[iden = "usersCount"]
+    UsersCount,
+}
+#[derive(Iden)]
+enum ChannelFollowing {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "followeeId"]
+    FolloweeId,
+    #[iden = "followerId"]
+    FollowerId,
+}
+#[derive(Iden)]
+enum ChannelNotePining {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "channelId"]
+    ChannelId,
+    #[iden = "noteId"]
+    NoteId,
+}
+#[derive(Iden)]
+enum Clip {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Name,
+    #[iden = "isPublic"]
+    IsPublic,
+    Description,
+}
+#[derive(Iden)]
+enum ClipNote {
+    Table,
+    Id,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "clipId"]
+    ClipId,
+}
+#[derive(Iden)]
+enum DriveFile {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "userHost"]
+    UserHost,
+    Md5,
+    Name,
+    Type,
+    Size,
+    Comment,
+    Properties,
+    #[iden = "storedInternal"]
+    StoredInternal,
+    Url,
+    #[iden = "thumbnailUrl"]
+    ThumbnailUrl,
+    #[iden = "webpublicUrl"]
+    WebpublicUrl,
+    #[iden = "accessKey"]
+    AccessKey,
+    #[iden = "thumbnailAccessKey"]
+    ThumbnailAccessKey,
+    #[iden = "webpublicAccessKey"]
+    WebpublicAccessKey,
+    Uri,
+    Src,
+    #[iden = "folderId"]
+    FolderId,
+    #[iden = "isSensitive"]
+    IsSensitive,
+    #[iden = "isLink"]
+    IsLink,
+    Blurhash,
+    #[iden = "webpublicType"]
+    WebpublicType,
+    #[iden = "requestHeaders"]
+    RequestHeaders,
+    #[iden = "requestIp"]
+    RequestIp,
+    #[iden = "maybeSensitive"]
+    MaybeSensitive,
+    #[iden = "maybePorn"]
+    MaybePorn,
+}
+#[derive(Iden)]
+enum DriveFolder {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Name,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "parentId"]
+    ParentId,
+}
+#[derive(Iden)]
+enum Emoji {
+    Table,
+    Id,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+    Name,
+    Host,
+    #[iden = "originalUrl"]
+    OriginalUrl,
+    Uri,
+    Type,
+    Aliases,
+    Category,
+    #[iden = "publicUrl"]
+    PublicUrl,
+    License,
+    Width,
+    Height,
+}
+#[derive(Iden)]
+enum FollowRequest {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "followeeId"]
+    FolloweeId,
+    #[iden = "followerId"]
+    FollowerId,
+    #[iden = "requestId"]
+    RequestId,
+    #[iden = "followerHost"]
+    FollowerHost,
+    #[iden = "followerInbox"]
+    FollowerInbox,
+    #[iden = "followerSharedInbox"]
+    FollowerSharedInbox,
+    #[iden = "followeeHost"]
+    FolloweeHost,
+    #[iden = "followeeInbox"]
+    FolloweeInbox,
+    #[iden = "followeeSharedInbox"]
+    FolloweeSharedInbox,
+}
+#[derive(Iden)]
+enum Following {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "followeeId"]
+    FolloweeId,
+    #[iden = "followerId"]
+    FollowerId,
+    #[iden = "followerHost"]
+    FollowerHost,
+    #[iden = "followerInbox"]
+    FollowerInbox,
+    #[iden = "followerSharedInbox"]
+    FollowerSharedInbox,
+    #[iden = "followeeHost"]
+    FolloweeHost,
+    #[iden = "followeeInbox"]
+    FolloweeInbox,
+    #[iden = "followeeSharedInbox"]
+    FolloweeSharedInbox,
+}
+#[derive(Iden)]
+enum GalleryLike {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "postId"]
+    PostId,
+}
+#[derive(Iden)]
+enum GalleryPost {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+    Title,
+    Description,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "fileIds"]
+    FileIds,
+    #[iden = "isSensitive"]
+    IsSensitive,
+    #[iden = "likedCount"]
+    LikedCount,
+    Tags,
+}
+#[derive(Iden)]
+enum Hashtag {
+    Table,
+    Id,
+    Name,
+    #[iden = "mentionedUserIds"]
+    MentionedUserIds,
+    #[iden = "mentionedUsersCount"]
+    MentionedUsersCount,
+    #[iden = "mentionedLocalUserIds"]
+    MentionedLocalUserIds,
+    #[iden = "mentionedLocalUsersCount"]
+    MentionedLocalUsersCount,
+    #[iden = "mentionedRemoteUserIds"]
+    MentionedRemoteUserIds,
+    #[iden = "mentionedRemoteUsersCount"]
+    MentionedRemoteUsersCount,
+    #[iden = "attachedUserIds"]
+    AttachedUserIds,
+    #[iden = "attachedUsersCount"]
+    AttachedUsersCount,
+    #[iden = "attachedLocalUserIds"]
+    AttachedLocalUserIds,
+    #[iden = "attachedLocalUsersCount"]
+    AttachedLocalUsersCount,
+    #[iden = "attachedRemoteUserIds"]
+    AttachedRemoteUserIds,
+    #[iden = "attachedRemoteUsersCount"]
+    AttachedRemoteUsersCount,
+}
+#[derive(Iden)]
+enum Instance {
+    Table,
+    Id,
+    #[iden = "caughtAt"]
+    CaughtAt,
+    Host,
+    #[iden = "usersCount"]
+    UsersCount,
+    #[iden = "notesCount"]
+    NotesCount,
+    #[iden = "followingCount"]
+    FollowingCount,
+    #[iden = "followersCount"]
+    FollowersCount,
+    #[iden = "latestRequestSentAt"]
+    LatestRequestSentAt,
+    #[iden = "latestStatus"]
+    LatestStatus,
+    #[iden = "latestRequestReceivedAt"]
+    LatestRequestReceivedAt,
+    #[iden = "lastCommunicatedAt"]
+    LastCommunicatedAt,
+    #[iden = "isNotResponding"]
+    IsNotResponding,
+    #[iden = "softwareName"]
+    SoftwareName,
+    #[iden = "softwareVersion"]
+    SoftwareVersion,
+    #[iden = "openRegistrations"]
+    OpenRegistrations,
+    Name,
+    Description,
+    #[iden = "maintainerName"]
+    MaintainerName,
+    #[iden = "maintainerEmail"]
+    MaintainerEmail,
+    #[iden = "infoUpdatedAt"]
+    InfoUpdatedAt,
+    #[iden = "isSuspended"]
+    IsSuspended,
+    #[iden = "iconUrl"]
+    IconUrl,
+    #[iden = "themeColor"]
+    ThemeColor,
+    #[iden = "faviconUrl"]
+    FaviconUrl,
+}
+#[derive(Iden)]
+enum MessagingMessage {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "recipientId"]
+    RecipientId,
+    Text,
+    #[iden = "isRead"]
+    IsRead,
+    #[iden = "fileId"]
+    FileId,
+    #[iden = "groupId"]
+    GroupId,
+    Reads,
+    Uri,
+}
+#[derive(Iden)]
+enum Meta {
+    Table,
+    Id,
+    Name,
+    Description,
+    #[iden = "maintainerName"]
+    MaintainerName,
+    #[iden = "maintainerEmail"]
+    MaintainerEmail,
+    #[iden = "disableRegistration"]
+    DisableRegistration,
+    #[iden = "disableLocalTimeline"]
+    DisableLocalTimeline,
+    #[iden = "disableGlobalTimeline"]
+    DisableGlobalTimeline,
+    #[iden = "useStarForReactionFallback"]
+    UseStarForReactionFallback,
+    Langs,
+    #[iden = "hiddenTags"]
+    HiddenTags,
+    #[iden = "blockedHosts"]
+    BlockedHosts,
+    #[iden = "mascotImageUrl"]
+    MascotImageUrl,
+    #[iden = "bannerUrl"]
+    BannerUrl,
+    #[iden = "errorImageUrl"]
+    ErrorImageUrl,
+    #[iden = "iconUrl"]
+    IconUrl,
+    #[iden = "cacheRemoteFiles"]
+    CacheRemoteFiles,
+    #[iden = "enableRecaptcha"]
+    EnableRecaptcha,
+    #[iden = "recaptchaSiteKey"]
+    RecaptchaSiteKey,
+    #[iden = "recaptchaSecretKey"]
+    RecaptchaSecretKey,
+    #[iden = "localDriveCapacityMb"]
+    LocalDriveCapacityMb,
+    #[iden = "remoteDriveCapacityMb"]
+    RemoteDriveCapacityMb,
+    #[iden = "summalyProxy"]
+    SummalyProxy,
+    #[iden = "enableEmail"]
+    EnableEmail,
+    Email,
+    #[iden = "smtpSecure"]
+    SmtpSecure,
+    #[iden = "smtpHost"]
+    SmtpHost,
+    #[iden = "smtpPort"]
+    SmtpPort,
+    #[iden = "smtpUser"]
+    SmtpUser,
+    #[iden = "smtpPass"]
+    SmtpPass,
+    #[iden = "enableServiceWorker"]
+    EnableServiceWorker,
+    #[iden = "swPublicKey"]
+    SwPublicKey,
+    #[iden = "swPrivateKey"]
+    SwPrivateKey,
+    #[iden = "enableTwitterIntegration"]
+    EnableTwitterIntegration,
+    #[iden = "twitterConsumerKey"]
+    TwitterConsumerKey,
+    #[iden = "twitterConsumerSecret"]
+    TwitterConsumerSecret,
+    #[iden = "enableGithubIntegration"]
+    EnableGithubIntegration,
+    #[iden = "githubClientId"]
+    GithubClientId,
+    #[iden = "githubClientSecret"]
+    GithubClientSecret,
+    #[iden = "enableDiscordIntegration"]
+    EnableDiscordIntegration,
+    #[iden = "discordClientId"]
+    DiscordClientId,
+    #[iden = "discordClientSecret"]
+    DiscordClientSecret,
+    #[iden = "pinnedUsers"]
+    PinnedUsers,
+    #[iden = "ToSUrl"]
+    ToSUrl,
+    #[iden = "repositoryUrl"]
+    RepositoryUrl,
+    #[iden = "feedbackUrl"]
+    FeedbackUrl,
+    #[iden = "useObjectStorage"]
+    UseObjectStorage,
+    #[iden = "objectStorageBucket"]
+    ObjectStorageBucket,
+    #[iden = "objectStoragePrefix"]
+    ObjectStoragePrefix,
+    #[iden = "objectStorageBaseUrl"]
+    ObjectStorageBaseUrl,
+    #[iden = "objectStorageEndpoint"]
+    ObjectStorageEndpoint,
+    #[iden = "objectStorageRegion"]
+    ObjectStorageRegion,
+    #[iden = "objectStorageAccessKey"]
+    ObjectStorageAccessKey,
+    #[iden = "objectStorageSecretKey"]
+    ObjectStorageSecretKey,
+    #[iden = "objectStoragePort"]
+    ObjectStoragePort,
+    #[iden = "objectStorageUseSSL"]
+    ObjectStorageUseSSL,
+    #[iden = "proxyAccountId"]
+    ProxyAccountId,
+    #[iden = "objectStorageUseProxy"]
+    ObjectStorageUseProxy,
+    #[iden = "enableHcaptcha"]
+    EnableHcaptcha,
+    #[iden = "hcaptchaSiteKey"]
+    HcaptchaSiteKey,
+    #[iden = "hcaptchaSecretKey"]
+    HcaptchaSecretKey,
+    #[iden = "objectStorageSetPublicRead"]
+    ObjectStorageSetPublicRead,
+    #[iden = "pinnedPages"]
+    PinnedPages,
+    #[iden = "backgroundImageUrl"]
+    BackgroundImageUrl,
+    #[iden = "logoImageUrl"]
+    LogoImageUrl,
+    #[iden = "pinnedClipId"]
+    PinnedClipId,
+    #[iden = "objectStorageS3ForcePathStyle"]
+    ObjectStorageS3ForcePathStyle,
+    #[iden = "allowedHosts"]
+    AllowedHosts,
+    #[iden = "secureMode"]
+    SecureMode,
+    #[iden = "privateMode"]
+    PrivateMode,
+    #[iden = "deeplAuthKey"]
+    DeeplAuthKey,
+    #[iden = "deeplIsPro"]
+    DeeplIsPro,
+    #[iden = "emailRequiredForSignup"]
+    EmailRequiredForSignup,
+    #[iden = "themeColor"]
+    ThemeColor,
+    #[iden = "defaultLightTheme"]
+    DefaultLightTheme,
+    #[iden = "defaultDarkTheme"]
+    DefaultDarkTheme,
+    #[iden = "sensitiveMediaDetection"]
+    SensitiveMediaDetection,
+    #[iden = "sensitiveMediaDetectionSensitivity"]
+    SensitiveMediaDetectionSensitivity,
+    #[iden = "setSensitiveFlagAutomatically"]
+    SetSensitiveFlagAutomatically,
+    #[iden = "enableIpLogging"]
+    EnableIpLogging,
+    #[iden = "enableSensitiveMediaDetectionForVideos"]
+    EnableSensitiveMediaDetectionForVideos,
+    #[iden = "enableActiveEmailValidation"]
+    EnableActiveEmailValidation,
+    #[iden = "customMOTD"]
+    CustomMOTD,
+    #[iden = "customSplashIcons"]
+    CustomSplashIcons,
+    #[iden = "disableRecommendedTimeline"]
+    DisableRecommendedTimeline,
+    #[iden = "recommendedInstances"]
+    RecommendedInstances,
+    #[iden = "enableGuestTimeline"]
+    EnableGuestTimeline,
+    #[iden = "defaultReaction"]
+    DefaultReaction,
+    #[iden = "libreTranslateApiUrl"]
+    LibreTranslateApiUrl,
+    #[iden = "libreTranslateApiKey"]
+    LibreTranslateApiKey,
+    #[iden = "silencedHosts"]
+    SilencedHosts,
+    #[iden = "experimentalFeatures"]
+    ExperimentalFeatures,
+    #[iden = "enableServerMachineStats"]
+    EnableServerMachineStats,
+    #[iden = "enableIdenticonGeneration"]
+    EnableIdenticonGeneration,
+    #[iden = "donationLink"]
+    DonationLink,
+}
+#[derive(Iden)]
+enum Migrations {
+    Table,
+    Id,
+    Timestamp,
+    Name,
+}
+#[derive(Iden)]
+enum ModerationLog {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Type,
+    Info,
+}
+#[derive(Iden)]
+enum MutedNote {
+    Table,
+    Id,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "userId"]
+    UserId,
+    Reason,
+}
+#[derive(Iden)]
+enum Muting {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "muteeId"]
+    MuteeId,
+    #[iden = "muterId"]
+    MuterId,
+    #[iden = "expiresAt"]
+    ExpiresAt,
+}
+#[derive(Iden)]
+enum Note {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "replyId"]
+    ReplyId,
+    #[iden = "renoteId"]
+    RenoteId,
+    Text,
+    Name,
+    Cw,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "localOnly"]
+    LocalOnly,
+    #[iden = "renoteCount"]
+    RenoteCount,
+    #[iden = "repliesCount"]
+    RepliesCount,
+    Reactions,
+    Visibility,
+    Uri,
+    Score,
+    #[iden = "fileIds"]
+    FileIds,
+    #[iden = "attachedFileTypes"]
+    AttachedFileTypes,
+    #[iden = "visibleUserIds"]
+    VisibleUserIds,
+    Mentions,
+    #[iden = "mentionedRemoteUsers"]
+    MentionedRemoteUsers,
+    Emojis,
+    Tags,
+    #[iden = "hasPoll"]
+    HasPoll,
+    #[iden = "userHost"]
+    UserHost,
+    #[iden = "replyUserId"]
+    ReplyUserId,
+    #[iden = "replyUserHost"]
+    ReplyUserHost,
+    #[iden = "renoteUserId"]
+    RenoteUserId,
+    #[iden = "renoteUserHost"]
+    RenoteUserHost,
+    Url,
+    #[iden = "channelId"]
+    ChannelId,
+    #[iden = "threadId"]
+    ThreadId,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+}
+#[derive(Iden)]
+enum NoteEdit {
+    Table,
+    Id,
+    #[iden = "noteId"]
+    NoteId,
+    Text,
+    Cw,
+    #[iden = "fileIds"]
+    FileIds,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+}
+#[derive(Iden)]
+enum NoteFavorite {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+}
+#[derive(Iden)]
+enum NoteReaction {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+    Reaction,
+}
+#[derive(Iden)]
+enum NoteThreadMuting {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "threadId"]
+    ThreadId,
+}
+#[derive(Iden)]
+enum NoteUnread {
+    Table,
+    Id,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "noteUserId"]
+    NoteUserId,
+    #[iden = "isSpecified"]
+    IsSpecified,
+    #[iden = "isMentioned"]
+    IsMentioned,
+    #[iden = "noteChannelId"]
+    NoteChannelId,
+}
+#[derive(Iden)]
+enum NoteWatching {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "noteUserId"]
+    NoteUserId,
+}
+#[derive(Iden)]
+enum Notification {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "notifieeId"]
+    NotifieeId,
+    #[iden = "notifierId"]
+    NotifierId,
+    #[iden = "isRead"]
+    IsRead,
+    #[iden = "noteId"]
+    NoteId,
+    Reaction,
+    Choice,
+    #[iden = "followRequestId"]
+    FollowRequestId,
+    Type,
+    #[iden = "userGroupInvitationId"]
+    UserGroupInvitationId,
+    #[iden = "customBody"]
+    CustomBody,
+    #[iden = "customHeader"]
+    CustomHeader,
+    #[iden = "customIcon"]
+    CustomIcon,
+    #[iden = "appAccessTokenId"]
+    AppAccessTokenId,
+}
+#[derive(Iden)]
+enum Page {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+    Title,
+    Name,
+    Summary,
+    #[iden = "alignCenter"]
+    AlignCenter,
+    Font,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "eyeCatchingImageId"]
+    EyeCatchingImageId,
+    Content,
+    Variables,
+    Visibility,
+    #[iden = "visibleUserIds"]
+    VisibleUserIds,
+    #[iden = "likedCount"]
+    LikedCount,
+    #[iden = "hideTitleWhenPinned"]
+    HideTitleWhenPinned,
+    Script,
+    #[iden = "isPublic"]
+    IsPublic,
+}
+#[derive(Iden)]
+enum PageLike {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "pageId"]
+    PageId,
+}
+#[derive(Iden)]
+enum PasswordResetRequest {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Token,
+    #[iden = "userId"]
+    UserId,
+}
+#[derive(Iden)]
+enum Poll {
+    Table,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "expiresAt"]
+    ExpiresAt,
+    Multiple,
+    Choices,
+    Votes,
+    #[iden = "noteVisibility"]
+    NoteVisibility,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "userHost"]
+    UserHost,
+}
+#[derive(Iden)]
+enum PollVote {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+    Choice,
+}
+#[derive(Iden)]
+enum PromoNote {
+    Table,
+    #[iden = "noteId"]
+    NoteId,
+    #[iden = "expiresAt"]
+    ExpiresAt,
+    #[iden = "userId"]
+    UserId,
+}
+#[derive(Iden)]
+enum PromoRead {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+}
+#[derive(Iden)]
+enum RegistrationTicket {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Code,
+}
+#[derive(Iden)]
+enum RegistryItem {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+    #[iden = "userId"]
+    UserId,
+    Key,
+    Scope,
+    Domain,
+    Value,
+}
+#[derive(Iden)]
+enum Relay {
+    Table,
+    Id,
+    Inbox,
+    Status,
+}
+#[derive(Iden)]
+enum RenoteMuting {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "muteeId"]
+    MuteeId,
+    #[iden = "muterId"]
+    MuterId,
+}
+#[derive(Iden)]
+enum ReversiGame {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "startedAt"]
+    StartedAt,
+    #[iden = "user1Id"]
+    User1Id,
+    #[iden = "user2Id"]
+    User2Id,
+    #[iden = "user1Accepted"]
+    User1Accepted,
+    #[iden = "user2Accepted"]
+    User2Accepted,
+    Black,
+    #[iden = "isStarted"]
+    IsStarted,
+    #[iden = "isEnded"]
+    IsEnded,
+    #[iden = "winnerId"]
+    WinnerId,
+    Surrendered,
+    Logs,
+    Map,
+    Bw,
+    #[iden = "isLlotheo"]
+    IsLlotheo,
+    #[iden = "canPutEverywhere"]
+    CanPutEverywhere,
+    #[iden = "loopedBoard"]
+    LoopedBoard,
+    Form1,
+    Form2,
+    Crc32,
+}
+#[derive(Iden)]
+enum ReversiMatching {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "parentId"]
+    ParentId,
+    #[iden = "childId"]
+    ChildId,
+}
+#[derive(Iden)]
+enum Signin {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Ip,
+    Headers,
+    Success,
+}
+#[derive(Iden)]
+enum SwSubscription {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Endpoint,
+    Auth,
+    Publickey,
+    #[iden = "sendReadMessage"]
+    SendReadMessage,
+}
+#[derive(Iden)]
+enum UsedUsername {
+    Table,
+    Username,
+    #[iden = "createdAt"]
+    CreatedAt,
+}
+#[derive(Iden)]
+enum User {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "updatedAt"]
+    UpdatedAt,
+    #[iden = "lastFetchedAt"]
+    LastFetchedAt,
+    Username,
+    #[iden = "usernameLower"]
+    UsernameLower,
+    Name,
+    #[iden = "followersCount"]
+    FollowersCount,
+    #[iden = "followingCount"]
+    FollowingCount,
+    #[iden = "notesCount"]
+    NotesCount,
+    #[iden = "avatarId"]
+    AvatarId,
+    #[iden = "bannerId"]
+    BannerId,
+    Tags,
+    #[iden = "isSuspended"]
+    IsSuspended,
+    #[iden = "isSilenced"]
+    IsSilenced,
+    #[iden = "isLocked"]
+    IsLocked,
+    #[iden = "isBot"]
+    IsBot,
+    #[iden = "isCat"]
+    IsCat,
+    #[iden = "isAdmin"]
+    IsAdmin,
+    #[iden = "isModerator"]
+    IsModerator,
+    Emojis,
+    Host,
+    Inbox,
+    #[iden = "sharedInbox"]
+    SharedInbox,
+    Featured,
+    Uri,
+    Token,
+    #[iden = "isExplorable"]
+    IsExplorable,
+    #[iden = "followersUri"]
+    FollowersUri,
+    #[iden = "lastActiveDate"]
+    LastActiveDate,
+    #[iden = "hideOnlineStatus"]
+    HideOnlineStatus,
+    #[iden = "isDeleted"]
+    IsDeleted,
+    #[iden = "driveCapacityOverrideMb"]
+    DriveCapacityOverrideMb,
+    #[iden = "movedToUri"]
+    MovedToUri,
+    #[iden = "alsoKnownAs"]
+    AlsoKnownAs,
+    #[iden = "speakAsCat"]
+    SpeakAsCat,
+}
+#[derive(Iden)]
+enum UserGroup {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Name,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "isPrivate"]
+    IsPrivate,
+}
+#[derive(Iden)]
+enum UserGroupInvitation {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "userGroupId"]
+    UserGroupId,
+}
+#[derive(Iden)]
+enum UserGroupInvite {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "userGroupId"]
+    UserGroupId,
+}
+#[derive(Iden)]
+enum UserGroupJoining {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "userGroupId"]
+    UserGroupId,
+}
+#[derive(Iden)]
+enum UserIp {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Ip,
+}
+#[derive(Iden)]
+enum UserKeypair {
+    Table,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "publicKey"]
+    PublicKey,
+    #[iden = "privateKey"]
+    PrivateKey,
+}
+#[derive(Iden)]
+enum UserList {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Name,
+}
+#[derive(Iden)]
+enum UserListJoining {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "userListId"]
+    UserListId,
+}
+#[derive(Iden)]
+enum UserNotePining {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "noteId"]
+    NoteId,
+}
+#[derive(Iden)]
+enum UserPending {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    Code,
+    Username,
+    Email,
+    Password,
+}
+#[derive(Iden)]
+enum UserProfile {
+    Table,
+    #[iden = "userId"]
+    UserId,
+    Location,
+    Birthday,
+    Description,
+    Fields,
+    Url,
+    Email,
+    #[iden = "emailVerifyCode"]
+    EmailVerifyCode,
+    #[iden = "emailVerified"]
+    EmailVerified,
+    #[iden = "twoFactorTempSecret"]
+    TwoFactorTempSecret,
+    #[iden = "twoFactorSecret"]
+    TwoFactorSecret,
+    #[iden = "twoFactorEnabled"]
+    TwoFactorEnabled,
+    Password,
+    #[iden = "clientData"]
+    ClientData,
+    #[iden = "autoAcceptFollowed"]
+    AutoAcceptFollowed,
+    #[iden = "alwaysMarkNsfw"]
+    AlwaysMarkNsfw,
+    #[iden = "carefulBot"]
+    CarefulBot,
+    #[iden = "userHost"]
+    UserHost,
+    #[iden = "securityKeysAvailable"]
+    SecurityKeysAvailable,
+    #[iden = "usePasswordLessLogin"]
+    UsePasswordLessLogin,
+    #[iden = "pinnedPageId"]
+    PinnedPageId,
+    Room,
+    Integrations,
+    #[iden = "injectFeaturedNote"]
+    InjectFeaturedNote,
+    #[iden = "enableWordMute"]
+    EnableWordMute,
+    #[iden = "mutedWords"]
+    MutedWords,
+    #[iden = "mutingNotificationTypes"]
+    MutingNotificationTypes,
+    #[iden = "noCrawle"]
+    NoCrawle,
+    #[iden = "receiveAnnouncementEmail"]
+    ReceiveAnnouncementEmail,
+    #[iden = "emailNotificationTypes"]
+    EmailNotificationTypes,
+    Lang,
+    #[iden = "mutedInstances"]
+    MutedInstances,
+    #[iden = "publicReactions"]
+    PublicReactions,
+    #[iden = "ffVisibility"]
+    FfVisibility,
+    #[iden = "autoSensitive"]
+    AutoSensitive,
+    #[iden = "moderationNote"]
+    ModerationNote,
+    #[iden = "preventAiLearning"]
+    PreventAiLearning,
+}
+#[derive(Iden)]
+enum UserPublickey {
+    Table,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "keyId"]
+    KeyId,
+    #[iden = "keyPem"]
+    KeyPem,
+}
+#[derive(Iden)]
+enum UserSecurityKey {
+    Table,
+    Id,
+    #[iden = "userId"]
+    UserId,
+    #[iden = "publicKey"]
+    PublicKey,
+    #[iden = "lastUsed"]
+    LastUsed,
+    Name,
+}
+#[derive(Iden)]
+enum Webhook {
+    Table,
+    Id,
+    #[iden = "createdAt"]
+    CreatedAt,
+    #[iden = "userId"]
+    UserId,
+    Name,
+    On,
+    Url,
+    Secret,
+    Active,
+    #[iden = "latestSentAt"]
+    LatestSentAt,
+    #[iden = "latestStatus"]
+    LatestStatus,
+}
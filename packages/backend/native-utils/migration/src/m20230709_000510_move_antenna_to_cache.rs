@@ -1,21 +1,290 @@
-use redis::streams::StreamMaxlen;
-use sea_orm::Statement;
+//! Moves `antenna_note` into the Redis streams of antenna timelines.
+//!
+//! The copy is resumable: the id of the last copied row is recorded in
+//! `antenna_note_migration_checkpoint` after each batch, and a rerun continues
+//! from there. A batch may be copied twice if the migration stops between the
+//! Redis write and the checkpoint update. The source table is dropped only
+//! after the stream lengths are verified.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use redis::{aio::ConnectionManager, streams::StreamMaxlen};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbBackend};
 use sea_orm_migration::prelude::*;
 use std::env;
 
+/// Maximum length of antenna timeline streams.
+const STREAM_MAXLEN: usize = 200;
+/// Row id of the single checkpoint.
+const CHECKPOINT_ID: i32 = 1;
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
+fn redis_err(e: redis::RedisError) -> DbErr {
+    DbErr::Custom(format!("Redis error: {}", e))
+}
+
+fn env_var(name: &str) -> Result<String, DbErr> {
+    env::var(name).map_err(|_| DbErr::Custom(format!("{} is not set", name)))
+}
+
+/// Lazily connects to Redis, so that nothing is connected when there is no
+/// data to copy (or in a dry run).
+struct Cache {
+    conn: Option<ConnectionManager>,
+    prefix: String,
+}
+
+impl Cache {
+    fn new() -> Result<Self, DbErr> {
+        Ok(Self {
+            conn: None,
+            prefix: env_var("CACHE_PREFIX")?,
+        })
+    }
+
+    async fn conn(&mut self) -> Result<&mut ConnectionManager, DbErr> {
+        if self.conn.is_none() {
+            let client = redis::Client::open(env_var("CACHE_URL")?).map_err(redis_err)?;
+            self.conn = Some(ConnectionManager::new(client).await.map_err(redis_err)?);
+        }
+        Ok(self.conn.as_mut().unwrap())
+    }
+
+    fn key(&self, antenna_id: &str) -> String {
+        format!("{}:antennaTimeline:{}", self.prefix, antenna_id)
+    }
+}
+
+async fn query_count(manager: &SchemaManager<'_>, stmt: SelectStatement) -> Result<i64, DbErr> {
+    let bk = manager.get_database_backend();
+    match manager.get_connection().query_one(bk.build(&stmt)).await? {
+        Some(row) => row.try_get_by_index::<i64>(0),
+        None => Ok(0),
+    }
+}
+
+fn count_after(last_id: &Option<String>) -> SelectStatement {
+    let mut stmt = Query::select()
+        .expr(Expr::col((AntennaNote::Table, AntennaNote::Id)).count())
+        .from(AntennaNote::Table)
+        .to_owned();
+    if let Some(last_id) = last_id {
+        stmt.and_where(Expr::col((AntennaNote::Table, AntennaNote::Id)).gt(last_id.to_owned()));
+    }
+    stmt
+}
+
+/// Connects to the database outside of the migration transaction, so that
+/// the checkpoint survives a failed run.
+async fn connect_checkpoint_db() -> Result<DatabaseConnection, DbErr> {
+    let url = env_var("DATABASE_URL")?;
+    let schema = env::var("DATABASE_SCHEMA").unwrap_or("public".to_string());
+    let mut options = ConnectOptions::new(url);
+    options.max_connections(1).set_schema_search_path(schema);
+    let db = Database::connect(options).await?;
+
+    let stmt = Table::create()
+        .table(Checkpoint::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Checkpoint::Id)
+                .integer()
+                .not_null()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Checkpoint::LastId).string_len(32).not_null())
+        .to_owned();
+    db.execute(db.get_database_backend().build(&stmt)).await?;
+    Ok(db)
+}
+
+async fn read_checkpoint(db: &DatabaseConnection) -> Result<Option<String>, DbErr> {
+    let stmt = Query::select()
+        .column(Checkpoint::LastId)
+        .from(Checkpoint::Table)
+        .and_where(Expr::col(Checkpoint::Id).eq(CHECKPOINT_ID))
+        .to_owned();
+    match db.query_one(db.get_database_backend().build(&stmt)).await? {
+        Some(row) => Ok(Some(row.try_get_by_index::<String>(0)?)),
+        None => Ok(None),
+    }
+}
+
+async fn write_checkpoint(db: &DatabaseConnection, last_id: &str) -> Result<(), DbErr> {
+    let stmt = Query::insert()
+        .into_table(Checkpoint::Table)
+        .columns([Checkpoint::Id, Checkpoint::LastId])
+        .values_panic([CHECKPOINT_ID.into(), last_id.into()])
+        .on_conflict(
+            OnConflict::column(Checkpoint::Id)
+                .update_column(Checkpoint::LastId)
+                .to_owned(),
+        )
+        .to_owned();
+    db.execute(db.get_database_backend().build(&stmt)).await?;
+    Ok(())
+}
+
+async fn drop_checkpoint(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let stmt = Table::drop()
+        .table(Checkpoint::Table)
+        .if_exists()
+        .to_owned();
+    db.execute(db.get_database_backend().build(&stmt)).await?;
+    Ok(())
+}
+
+/// Returns the id after which rows are copied when only the last
+/// `copy_limit` rows are wanted.
+async fn start_after(
+    manager: &SchemaManager<'_>,
+    total: i64,
+    copy_limit: i64,
+) -> Result<Option<String>, DbErr> {
+    if copy_limit <= 0 || copy_limit >= total {
+        return Ok(None);
+    }
+    let bk = manager.get_database_backend();
+    let stmt = Query::select()
+        .column((AntennaNote::Table, AntennaNote::Id))
+        .from(AntennaNote::Table)
+        .order_by((AntennaNote::Table, AntennaNote::Id), Order::Asc)
+        .limit(1)
+        .offset((total - copy_limit - 1) as u64)
+        .to_owned();
+    match manager.get_connection().query_one(bk.build(&stmt)).await? {
+        Some(row) => Ok(Some(row.try_get_by_index::<String>(0)?)),
+        None => Ok(None),
+    }
+}
+
+/// Copies the rows after `last_id` in batches of `read_limit`, recording the
+/// checkpoint after each batch.
+#[tracing::instrument(skip(manager, checkpoint_db, cache, progress))]
+async fn copy(
+    manager: &SchemaManager<'_>,
+    checkpoint_db: &DatabaseConnection,
+    cache: &mut Cache,
+    mut last_id: Option<String>,
+    read_limit: u64,
+    progress: &ProgressBar,
+) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    let bk = manager.get_database_backend();
+
+    loop {
+        let mut stmt = Query::select()
+            .column((AntennaNote::Table, AntennaNote::Id))
+            .column(AntennaNote::AntennaId)
+            .column(AntennaNote::NoteId)
+            .from(AntennaNote::Table)
+            .order_by((AntennaNote::Table, AntennaNote::Id), Order::Asc)
+            .limit(read_limit)
+            .to_owned();
+        if let Some(last_id) = &last_id {
+            stmt.and_where(Expr::col((AntennaNote::Table, AntennaNote::Id)).gt(last_id.to_owned()));
+        }
+
+        let rows: Vec<(String, String, String)> = db
+            .query_all(bk.build(&stmt))
+            .await?
+            .iter()
+            .map(|q| q.try_get_many_by_index())
+            .collect::<Result<_, _>>()?;
+        let Some((batch_last_id, _, _)) = rows.last() else {
+            break;
+        };
+
+        let mut pipe = redis::pipe();
+        for (_, antenna_id, note_id) in &rows {
+            pipe.xadd_maxlen(
+                cache.key(antenna_id),
+                StreamMaxlen::Approx(STREAM_MAXLEN),
+                "*",
+                &[("note", note_id)],
+            )
+            .ignore();
+        }
+        pipe.query_async::<_, ()>(cache.conn().await?)
+            .await
+            .map_err(redis_err)?;
+
+        write_checkpoint(checkpoint_db, batch_last_id).await?;
+        tracing::debug!(rows = rows.len(), last_id = %batch_last_id, "Copied a batch");
+        progress.inc(rows.len() as u64);
+        last_id = Some(batch_last_id.to_owned());
+    }
+
+    Ok(())
+}
+
+/// Checks that the stream of every antenna has at least as many entries as
+/// were copied for it, up to the maximum length of streams.
+#[tracing::instrument(skip(manager, cache))]
+async fn verify(
+    manager: &SchemaManager<'_>,
+    cache: &mut Cache,
+    start: &Option<String>,
+) -> Result<(), DbErr> {
+    let bk = manager.get_database_backend();
+    let mut stmt = Query::select()
+        .column(AntennaNote::AntennaId)
+        .expr(Expr::col((AntennaNote::Table, AntennaNote::Id)).count())
+        .from(AntennaNote::Table)
+        .group_by_col(AntennaNote::AntennaId)
+        .to_owned();
+    if let Some(start) = start {
+        stmt.and_where(Expr::col((AntennaNote::Table, AntennaNote::Id)).gt(start.to_owned()));
+    }
+    let expected: Vec<(String, i64)> = manager
+        .get_connection()
+        .query_all(bk.build(&stmt))
+        .await?
+        .iter()
+        .map(|q| q.try_get_many_by_index())
+        .collect::<Result<_, _>>()?;
+
+    for chunk in expected.chunks(1000) {
+        let mut pipe = redis::pipe();
+        for (antenna_id, _) in chunk {
+            pipe.xlen(cache.key(antenna_id));
+        }
+        let lens: Vec<usize> = pipe
+            .query_async(cache.conn().await?)
+            .await
+            .map_err(redis_err)?;
+        for ((antenna_id, count), len) in chunk.iter().zip(lens) {
+            let count = (*count as usize).min(STREAM_MAXLEN);
+            if len < count {
+                return Err(DbErr::Custom(format!(
+                    "Stream of antenna {} has {} entries, but {} were copied. \
+                     Drop antenna_note_migration_checkpoint to copy again.",
+                    antenna_id, len, count
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let cache_url = env::var("CACHE_URL").unwrap();
+        if manager.get_database_backend() == DbBackend::Sqlite {
+            return Ok(());
+        }
+
         let skip_copy = env::var("ANTENNA_MIGRATION_SKIP").unwrap_or_default();
         let copy_limit = env::var("ANTENNA_MIGRATION_COPY_LIMIT").unwrap_or_default();
         let read_limit: u64 = env::var("ANTENNA_MIGRATION_READ_LIMIT")
             .unwrap_or("10000".to_string())
             .parse()
-            .unwrap();
+            .map_err(|_| {
+                DbErr::Custom("ANTENNA_MIGRATION_READ_LIMIT must be a number".to_string())
+            })?;
+        #[allow(clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)]
         let copy_limit: i64 = match copy_limit.parse() {
             Ok(limit) => limit,
             Err(_) => 0,
@@ -24,93 +293,44 @@ impl MigrationTrait for Migration {
         if skip_copy == "true" {
             println!("Skipped antenna migration");
         } else {
-            let prefix = env::var("CACHE_PREFIX").unwrap();
-
-            let db = manager.get_connection();
-            let bk = manager.get_database_backend();
-
-            let count_stmt =
-                Statement::from_string(bk, "SELECT COUNT(1) FROM antenna_note".to_owned());
-            let total_num = db
-                .query_one(count_stmt)
-                .await?
-                .unwrap()
-                .try_get_by_index::<i64>(0)?;
-            let copy_limit = if copy_limit > 0 {
-                copy_limit
-            } else {
-                total_num
-            };
-            println!(
-                "Copying {} out of {} entries in antenna_note.",
-                copy_limit, total_num
-            );
-
-            let stmt_base = Query::select()
-                .column((AntennaNote::Table, AntennaNote::Id))
-                .column(AntennaNote::AntennaId)
-                .column(AntennaNote::NoteId)
-                .from(AntennaNote::Table)
-                .order_by((AntennaNote::Table, AntennaNote::Id), Order::Asc)
-                .limit(read_limit)
-                .to_owned();
-
-            let mut stmt = stmt_base.clone();
-
-            let client = redis::Client::open(cache_url).unwrap();
-            let mut redis_conn = client.get_connection().unwrap();
-
-            let mut remaining = total_num;
-            let mut pagination: i64 = 0;
-
-            loop {
-                let res = db.query_all(bk.build(&stmt)).await?;
-                if res.len() == 0 {
-                    break;
-                }
-                let val: Vec<(String, String, String)> = res
-                    .iter()
-                    .filter_map(|q| q.try_get_many_by_index().ok())
-                    .collect();
-
-                remaining -= val.len() as i64;
-                if remaining <= copy_limit {
-                    let mut pipe = redis::pipe();
-                    for v in &val {
-                        pipe.xadd_maxlen(
-                            format!("{}:antennaTimeline:{}", prefix, v.1),
-                            StreamMaxlen::Approx(200),
-                            "*",
-                            &[("note", v.2.to_owned())],
-                        )
-                        .ignore();
-                    }
-                    pipe.query::<()>(&mut redis_conn).unwrap();
-                }
-
-                let copied = total_num - remaining;
-                let copied = std::cmp::min(copied, total_num);
-                pagination += 1;
-                if pagination % 10 == 0 {
-                    println!(
-                        "Migrating antenna [{:.2}%]",
-                        (copied as f64 / total_num as f64) * 100_f64,
-                    );
-                }
-
-                if let Some((last_id, _, _)) = val.last() {
-                    stmt = stmt_base
-                        .clone()
-                        .and_where(
-                            Expr::col((AntennaNote::Table, AntennaNote::Id)).gt(last_id.to_owned()),
-                        )
-                        .to_owned();
-                } else {
-                    break;
-                }
-            }
+            let total = query_count(manager, count_after(&None)).await?;
+            let start = start_after(manager, total, copy_limit).await?;
+            let to_copy = query_count(manager, count_after(&start)).await?;
 
-            println!("Migrating antenna [100.00%]");
+            if to_copy > 0 {
+                let checkpoint_db = connect_checkpoint_db().await?;
+                let checkpoint = read_checkpoint(&checkpoint_db).await?;
+                let remaining = match &checkpoint {
+                    Some(_) => query_count(manager, count_after(&checkpoint)).await?,
+                    None => to_copy,
+                };
+
+                let progress = ProgressBar::new(to_copy as u64)
+                    .with_style(
+                        ProgressStyle::with_template("{prefix} {msg} {wide_bar} {pos}/{len}")
+                            .unwrap()
+                            .progress_chars("##-"),
+                    )
+                    .with_prefix("[*]")
+                    .with_message("Copying antenna_note");
+                progress.set_position((to_copy - remaining) as u64);
+
+                let mut cache = Cache::new()?;
+                copy(
+                    manager,
+                    &checkpoint_db,
+                    &mut cache,
+                    checkpoint.or(start.clone()),
+                    read_limit,
+                    &progress,
+                )
+                .await?;
+                progress.finish_with_message("Done antenna_note");
+
+                verify(manager, &mut cache, &start).await?;
+                drop_checkpoint(&checkpoint_db).await?;
+                checkpoint_db.close().await?;
+            }
         }
 
         manager
@@ -235,6 +455,15 @@ enum AntennaNote {
     Read,
 }
 
+#[derive(Iden)]
+enum Checkpoint {
+    #[iden = "antenna_note_migration_checkpoint"]
+    Table,
+    Id,
+    #[iden = "lastId"]
+    LastId,
+}
+
 #[derive(Iden)]
 enum Antenna {
     Table,
@@ -0,0 +1,115 @@
+//! Lets drive files with the same content share their stored objects.
+//!
+//! The access keys of `drive_file` are no longer unique, as the rows sharing
+//! objects have the same keys, and `drive_blob` counts the references to the
+//! objects by `accessKey`. Reverting fails while any objects are shared.
+
+use sea_orm_migration::prelude::*;
+
+/// Unique indexes on the access keys, created by TypeORM.
+const ACCESS_KEY_INDEXES: [(&str, DriveFile); 3] = [
+    ("IDX_d85a184c2540d2deba33daf642", DriveFile::AccessKey),
+    (
+        "IDX_e74022ce9a074b3866f70e0d27",
+        DriveFile::ThumbnailAccessKey,
+    ),
+    (
+        "IDX_c55b2b7c284d9fef98026fc88e",
+        DriveFile::WebpublicAccessKey,
+    ),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_drive_file_md5_size")
+                    .table(DriveFile::Table)
+                    .col(DriveFile::Md5)
+                    .col(DriveFile::Size)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+        for (name, col) in ACCESS_KEY_INDEXES {
+            replace_index(manager, name, col, false).await?;
+        }
+        manager
+            .create_table(
+                Table::create()
+                    .table(DriveBlob::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DriveBlob::Key)
+                            .string_len(256)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(DriveBlob::RefCount).integer().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DriveBlob::Table).if_exists().to_owned())
+            .await?;
+        for (name, col) in ACCESS_KEY_INDEXES {
+            replace_index(manager, name, col, true).await?;
+        }
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("IDX_drive_file_md5_size")
+                    .table(DriveFile::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Recreates the index on the access key, keeping its name.
+async fn replace_index(
+    manager: &SchemaManager<'_>,
+    name: &str,
+    col: DriveFile,
+    unique: bool,
+) -> Result<(), DbErr> {
+    manager
+        .drop_index(Index::drop().name(name).table(DriveFile::Table).to_owned())
+        .await?;
+    let mut index = Index::create();
+    index.name(name).table(DriveFile::Table).col(col);
+    if unique {
+        index.unique();
+    }
+    manager.create_index(index.to_owned()).await
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden, Clone, Copy)]
+enum DriveFile {
+    Table,
+    Md5,
+    Size,
+    #[iden = "accessKey"]
+    AccessKey,
+    #[iden = "thumbnailAccessKey"]
+    ThumbnailAccessKey,
+    #[iden = "webpublicAccessKey"]
+    WebpublicAccessKey,
+}
+
+#[derive(Iden)]
+enum DriveBlob {
+    Table,
+    Key,
+    #[iden = "refCount"]
+    RefCount,
+}
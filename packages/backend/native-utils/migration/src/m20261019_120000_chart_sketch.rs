@@ -0,0 +1,66 @@
+//! Stores the HyperLogLog sketches of the unique columns of the charts, which
+//! replace the `unique_temp___` arrays for the charts saved natively.
+//!
+//! The sketches are kept by the table and the date of the log they belong to,
+//! and `group` is empty for the charts without groups.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChartSketch::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ChartSketch::Chart)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ChartSketch::Date).integer().not_null())
+                    .col(
+                        ColumnDef::new(ChartSketch::Group)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ChartSketch::Key).string_len(128).not_null())
+                    .col(ColumnDef::new(ChartSketch::Sketch).binary().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(ChartSketch::Chart)
+                            .col(ChartSketch::Date)
+                            .col(ChartSketch::Group)
+                            .col(ChartSketch::Key),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ChartSketch::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum ChartSketch {
+    Table,
+    Chart,
+    Date,
+    Group,
+    Key,
+    Sketch,
+}
@@ -0,0 +1,108 @@
+//! Stores the daily rollups of the statistics shown on the admin dashboard.
+//!
+//! `active_user_sketch` keeps a HyperLogLog sketch of the local users active
+//! on each day, and `instance_delivery` counts the deliveries to each
+//! instance by day.
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ActiveUserSketch::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ActiveUserSketch::Date)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ActiveUserSketch::Sketch).binary().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(InstanceDelivery::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(InstanceDelivery::Host)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(InstanceDelivery::Date).integer().not_null())
+                    .col(
+                        ColumnDef::new(InstanceDelivery::Succeeded)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(InstanceDelivery::Failed)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(InstanceDelivery::Host)
+                            .col(InstanceDelivery::Date),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_instance_delivery_date")
+                    .table(InstanceDelivery::Table)
+                    .col(InstanceDelivery::Date)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(InstanceDelivery::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ActiveUserSketch::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+enum ActiveUserSketch {
+    Table,
+    Date,
+    Sketch,
+}
+
+#[derive(Iden)]
+enum InstanceDelivery {
+    Table,
+    Host,
+    Date,
+    Succeeded,
+    Failed,
+}
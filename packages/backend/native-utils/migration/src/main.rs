@@ -1,107 +1,160 @@
-use serde::Deserialize;
+use clap::Parser;
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectOptions, Database},
+};
 use std::env;
-use std::fs;
-use urlencoding::encode;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tracing_subscriber::{prelude::*, EnvFilter};
 
-use sea_orm_migration::prelude::*;
+use migration::Migrator;
+
+#[cfg(feature = "check-schema")]
+mod check_schema;
+mod cli;
+mod config;
+#[cfg(feature = "dry-run")]
+mod dry_run;
+mod error;
+#[cfg(feature = "convert")]
+mod vec_to_json;
+
+use cli::{Cli, Command};
+use config::Config;
+use error::Error;
 
 const DB_URL_ENV: &str = "DATABASE_URL";
+const DB_SCHEMA_ENV: &str = "DATABASE_SCHEMA";
 const CACHE_URL_ENV: &str = "CACHE_URL";
 const CACHE_PREFIX_ENV: &str = "CACHE_PREFIX";
 
-#[cfg(feature = "convert")]
-mod vec_to_json;
+const DEFAULT_CONFIG_PATH: &str = "../../.config/default.yml";
 
 #[tokio::main]
-async fn main() {
-    let cwd = env::current_dir().unwrap();
-    let yml = fs::File::open(cwd.join("../../.config/default.yml"))
-        .expect("Failed to open '.config/default.yml'");
-    let config: Config = serde_yaml::from_reader(yml).expect("Failed to parse yaml");
-
-    if env::var_os(DB_URL_ENV).is_none() {
-        env::set_var(
-            DB_URL_ENV,
-            format!(
-                "postgres://{}:{}@{}:{}/{}",
-                config.db.user,
-                encode(&config.db.pass),
-                config.db.host,
-                config.db.port,
-                config.db.db,
-            ),
-        );
-    };
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
 
-    if env::var_os(CACHE_URL_ENV).is_none() {
-        let redis_conf = match config.cache_server {
-            None => config.redis,
-            Some(conf) => conf,
-        };
-        let redis_proto = match redis_conf.tls {
-            None => "redis",
-            Some(_) => "rediss",
-        };
-        let redis_uri_userpass = match redis_conf.user {
-            None => "".to_string(),
-            Some(user) => format!("{}:{}@", user, encode(&redis_conf.pass.unwrap_or_default())),
-        };
-        let redis_uri_hostport = format!("{}:{}", redis_conf.host, redis_conf.port);
-        let redis_uri = format!(
-            "{}://{}{}/{}",
-            redis_proto, redis_uri_userpass, redis_uri_hostport, redis_conf.db
-        );
-        env::set_var(CACHE_URL_ENV, redis_uri);
-        env::set_var(
-            CACHE_PREFIX_ENV,
-            if redis_conf.prefix.is_empty() {
-                config.url.host_str().unwrap()
-            } else {
-                &redis_conf.prefix
-            },
-        );
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
     }
-
-    cli::run_cli(migration::Migrator).await;
-
-    #[cfg(feature = "convert")]
-    vec_to_json::convert().await;
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Config {
-    pub url: url::Url,
-    pub db: DbConfig,
-    pub redis: RedisConfig,
-    pub cache_server: Option<RedisConfig>,
+fn init_tracing(verbose: bool) {
+    let filter = EnvFilter::new(match verbose {
+        true => "debug",
+        false => "sea_orm_migration=info",
+    });
+    let fmt_layer = tracing_subscriber::fmt::layer();
+    if verbose {
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt_layer)
+            .init();
+    } else {
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(
+                fmt_layer
+                    .with_target(false)
+                    .with_level(false)
+                    .without_time(),
+            )
+            .init();
+    }
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-pub struct DbConfig {
-    pub host: String,
-    pub port: u32,
-    pub db: String,
-    pub user: String,
-    pub pass: String,
+/// Loads the config file unless the database and cache URLs are all given
+/// through the command line or environment variables.
+fn load_config(cli: &Cli) -> Result<Option<Config>, Error> {
+    if let Some(path) = &cli.config {
+        return Config::load(path).map(Some);
+    }
+    if cli.database_url.is_some() && env::var_os(CACHE_URL_ENV).is_some() {
+        return Ok(None);
+    }
+    let path = env::current_dir()
+        .map(|cwd| cwd.join(DEFAULT_CONFIG_PATH))
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_CONFIG_PATH));
+    Config::load(&path).map(Some)
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-pub struct RedisConfig {
-    pub host: String,
-    pub port: u32,
-    pub user: Option<String>,
-    pub pass: Option<String>,
-    pub tls: Option<TlsConfig>,
-    #[serde(default)]
-    pub db: u32,
-    #[serde(default)]
-    pub prefix: String,
-}
+async fn run(cli: Cli) -> Result<(), Error> {
+    let config = load_config(&cli)?;
+
+    let db_url = match (&cli.database_url, &config) {
+        (Some(url), _) => url.clone(),
+        (None, Some(config)) => config.db.url(),
+        (None, None) => unreachable!("config is loaded without a database URL"),
+    };
+    env::set_var(DB_URL_ENV, &db_url);
+    env::set_var(DB_SCHEMA_ENV, &cli.database_schema);
+
+    if let (None, Some(config)) = (env::var_os(CACHE_URL_ENV), &config) {
+        env::set_var(CACHE_URL_ENV, config.cache_server().url());
+        env::set_var(CACHE_PREFIX_ENV, config.cache_prefix());
+    }
+
+    let mut options = ConnectOptions::new(db_url.clone());
+    if !db_url.starts_with("sqlite:") {
+        options.set_schema_search_path(cli.database_schema.clone());
+    }
+    let db = Database::connect(options).await?;
+
+    let command = cli.command.unwrap_or(Command::Up { num: None });
+    #[cfg(feature = "dry-run")]
+    if cli.dry_run {
+        return match command {
+            Command::Up { num } => dry_run::dry_run::<Migrator>(&db, false, num).await,
+            Command::Down { num } => dry_run::dry_run::<Migrator>(&db, true, Some(num)).await,
+            _ => Err(Error::InvalidArgument(
+                "--dry-run is only supported by up and down".to_string(),
+            )),
+        };
+    }
+
+    #[cfg(feature = "convert")]
+    let convert = matches!(
+        command,
+        Command::Up { .. } | Command::Fresh | Command::Refresh
+    );
+
+    match command {
+        Command::Up { num } => Migrator::up(&db, num).await?,
+        Command::Down { num } => Migrator::down(&db, Some(num)).await?,
+        Command::Status => Migrator::status(&db).await?,
+        Command::Fresh => Migrator::fresh(&db).await?,
+        Command::Refresh => Migrator::refresh(&db).await?,
+        Command::Reset => Migrator::reset(&db).await?,
+        #[cfg(feature = "check-schema")]
+        Command::CheckSchema => check_schema::check(&db).await?,
+        #[cfg(feature = "convert")]
+        Command::Convert {
+            reverse,
+            batch_size,
+        } => {
+            let direction = match reverse {
+                true => vec_to_json::Direction::ToArray,
+                false => vec_to_json::Direction::ToJson,
+            };
+            vec_to_json::convert(&db, direction, batch_size).await?;
+        }
+    }
+
+    #[cfg(feature = "convert")]
+    if convert {
+        vec_to_json::convert(
+            &db,
+            vec_to_json::Direction::ToJson,
+            vec_to_json::DEFAULT_BATCH_SIZE,
+        )
+        .await?;
+    }
 
-#[derive(Debug, PartialEq, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TlsConfig {
-    pub host: String,
-    pub reject_unauthorized: bool,
+    Ok(())
 }
@@ -1,361 +1,476 @@
+//! Converts the array columns of PostgreSQL to JSONB, which native-utils
+//! reads when it is built with the `noarray` feature, and back.
+//!
+//! Each column is first copied to a temporary column, either with a single
+//! `UPDATE` or in batches paged by the primary key. The table is then locked,
+//! rows written during the copy are copied again, and every row is compared
+//! with the original before the columns are swapped in the same transaction,
+//! so an interrupted conversion leaves the original column intact and can
+//! simply be rerun.
+
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use native_utils::model::entity::newtype::{I32Vec, StringVec};
 use sea_orm_migration::{
     prelude::*,
-    sea_orm::{Database, DbBackend, DbConn, Statement, TryGetable},
+    sea_orm::{DbBackend, DbConn, Statement, TransactionTrait},
 };
 use serde_json::json;
-use std::env;
-use std::time::Duration;
 
-pub async fn convert() {
-    let uri = env::var("DATABASE_URL").expect("Environment variable 'DATABASE_URL' not set");
+/// Number of rows updated at once by default.
+pub const DEFAULT_BATCH_SIZE: u64 = 10000;
 
-    let db = Database::connect(uri).await.expect("Unable to connect");
-    let mp = MultiProgress::new();
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Converts arrays to JSONB.
+    ToJson,
+    /// Converts JSONB back to arrays, for rolling back from a `noarray` build.
+    ToArray,
+}
+
+impl Direction {
+    /// Returns `information_schema.columns.data_type` of converted columns.
+    fn data_type(self) -> &'static str {
+        match self {
+            Direction::ToJson => "jsonb",
+            Direction::ToArray => "ARRAY",
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Direction::ToJson => "json",
+            Direction::ToArray => "array",
+        }
+    }
+}
+
+/// Element type of an array column.
+#[derive(Clone, Copy, Debug)]
+enum Elem {
+    Varchar(u32),
+    Integer,
+    Enum(&'static str),
+}
+
+impl Elem {
+    fn array_type(self) -> String {
+        match self {
+            Elem::Varchar(len) => format!("varchar({})[]", len),
+            Elem::Integer => "integer[]".to_string(),
+            Elem::Enum(name) => format!("\"{}\"[]", name),
+        }
+    }
+}
+
+/// Length of `id()` columns of TypeORM entities.
+const ID: Elem = Elem::Varchar(32);
 
-    let handlers = vec![
-        tokio::spawn(to_json::<AccessToken, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+struct Column {
+    table: DynIden,
+    id: DynIden,
+    col: DynIden,
+    elem: Elem,
+}
+
+impl Column {
+    fn new<T: Iden + 'static>(table: T, id: T, col: T, elem: Elem) -> Self {
+        Self {
+            table: table.into_iden(),
+            id: id.into_iden(),
+            col: col.into_iden(),
+            elem,
+        }
+    }
+
+    fn name(&self) -> String {
+        format!("{}.{}", self.table.to_string(), self.col.to_string())
+    }
+}
+
+/// Returns the columns to convert, grouped by table. Columns of the same
+/// table are converted one after another.
+fn tables() -> Vec<Vec<Column>> {
+    use Elem::*;
+
+    vec![
+        vec![Column::new(
             AccessToken::Table,
             AccessToken::Id,
             AccessToken::Permission,
-        )),
-        tokio::spawn(to_json::<Antenna, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            Varchar(64),
+        )],
+        vec![Column::new(
             Antenna::Table,
             Antenna::Id,
             Antenna::Users,
-        )),
-        tokio::spawn(to_json::<App, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            Varchar(1024),
+        )],
+        vec![Column::new(
             App::Table,
             App::Id,
             App::Permission,
-        )),
-        tokio::spawn(to_json::<Emoji, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            Varchar(64),
+        )],
+        vec![Column::new(
             Emoji::Table,
             Emoji::Id,
             Emoji::Aliases,
-        )),
-        tokio::spawn(to_json::<GalleryPost, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            GalleryPost::Table,
-            GalleryPost::Id,
-            GalleryPost::FileIds,
-        )),
-        tokio::spawn(to_json::<GalleryPost, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            GalleryPost::Table,
-            GalleryPost::Id,
-            GalleryPost::Tags,
-        )),
-        tokio::spawn(to_json::<Hashtag, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Hashtag::Table,
-            Hashtag::Id,
-            Hashtag::MentionedUserIds,
-        )),
-        tokio::spawn(to_json::<Hashtag, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Hashtag::Table,
-            Hashtag::Id,
-            Hashtag::MentionedLocalUserIds,
-        )),
-        tokio::spawn(to_json::<Hashtag, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Hashtag::Table,
-            Hashtag::Id,
-            Hashtag::MentionedRemoteUserIds,
-        )),
-        tokio::spawn(to_json::<Hashtag, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Hashtag::Table,
-            Hashtag::Id,
-            Hashtag::AttachedUserIds,
-        )),
-        tokio::spawn(to_json::<Hashtag, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Hashtag::Table,
-            Hashtag::Id,
-            Hashtag::AttachedLocalUserIds,
-        )),
-        tokio::spawn(to_json::<Hashtag, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Hashtag::Table,
-            Hashtag::Id,
-            Hashtag::AttachedRemoteUserIds,
-        )),
-        tokio::spawn(to_json::<MessagingMessage, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            Varchar(128),
+        )],
+        vec![
+            Column::new(
+                GalleryPost::Table,
+                GalleryPost::Id,
+                GalleryPost::FileIds,
+                ID,
+            ),
+            Column::new(
+                GalleryPost::Table,
+                GalleryPost::Id,
+                GalleryPost::Tags,
+                Varchar(128),
+            ),
+        ],
+        vec![
+            Column::new(Hashtag::Table, Hashtag::Id, Hashtag::MentionedUserIds, ID),
+            Column::new(
+                Hashtag::Table,
+                Hashtag::Id,
+                Hashtag::MentionedLocalUserIds,
+                ID,
+            ),
+            Column::new(
+                Hashtag::Table,
+                Hashtag::Id,
+                Hashtag::MentionedRemoteUserIds,
+                ID,
+            ),
+            Column::new(Hashtag::Table, Hashtag::Id, Hashtag::AttachedUserIds, ID),
+            Column::new(
+                Hashtag::Table,
+                Hashtag::Id,
+                Hashtag::AttachedLocalUserIds,
+                ID,
+            ),
+            Column::new(
+                Hashtag::Table,
+                Hashtag::Id,
+                Hashtag::AttachedRemoteUserIds,
+                ID,
+            ),
+        ],
+        vec![Column::new(
             MessagingMessage::Table,
             MessagingMessage::Id,
             MessagingMessage::Reads,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::Langs,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::BlockedHosts,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::HiddenTags,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::PinnedUsers,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::PinnedPages,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::RecommendedInstances,
-        )),
-        tokio::spawn(to_json::<Meta, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Meta::Table,
-            Meta::Id,
-            Meta::SilencedHosts,
-        )),
-        tokio::spawn(to_json::<Note, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Note::Table,
-            Note::Id,
-            Note::FileIds,
-        )),
-        tokio::spawn(to_json::<Note, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Note::Table,
-            Note::Id,
-            Note::AttachedFileTypes,
-        )),
-        tokio::spawn(to_json::<Note, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Note::Table,
-            Note::Id,
-            Note::VisibleUserIds,
-        )),
-        tokio::spawn(to_json::<Note, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Note::Table,
-            Note::Id,
-            Note::Mentions,
-        )),
-        tokio::spawn(to_json::<Note, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Note::Table,
-            Note::Id,
-            Note::Emojis,
-        )),
-        tokio::spawn(to_json::<Note, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Note::Table,
-            Note::Id,
-            Note::Tags,
-        )),
-        tokio::spawn(to_json::<NoteEdit, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            ID,
+        )],
+        vec![
+            Column::new(Meta::Table, Meta::Id, Meta::Langs, Varchar(64)),
+            Column::new(Meta::Table, Meta::Id, Meta::BlockedHosts, Varchar(256)),
+            Column::new(Meta::Table, Meta::Id, Meta::HiddenTags, Varchar(256)),
+            Column::new(Meta::Table, Meta::Id, Meta::PinnedUsers, Varchar(256)),
+            Column::new(Meta::Table, Meta::Id, Meta::PinnedPages, Varchar(512)),
+            Column::new(
+                Meta::Table,
+                Meta::Id,
+                Meta::RecommendedInstances,
+                Varchar(256),
+            ),
+            Column::new(Meta::Table, Meta::Id, Meta::SilencedHosts, Varchar(256)),
+        ],
+        vec![
+            Column::new(Note::Table, Note::Id, Note::FileIds, ID),
+            Column::new(Note::Table, Note::Id, Note::AttachedFileTypes, Varchar(256)),
+            Column::new(Note::Table, Note::Id, Note::VisibleUserIds, ID),
+            Column::new(Note::Table, Note::Id, Note::Mentions, ID),
+            Column::new(Note::Table, Note::Id, Note::Emojis, Varchar(128)),
+            Column::new(Note::Table, Note::Id, Note::Tags, Varchar(128)),
+        ],
+        vec![Column::new(
             NoteEdit::Table,
             NoteEdit::Id,
             NoteEdit::FileIds,
-        )),
-        tokio::spawn(to_json::<Page, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Page::Table,
-            Page::Id,
-            Page::VisibleUserIds,
-        )),
-        tokio::spawn(to_json::<RegistryItem, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            ID,
+        )],
+        vec![Column::new(Page::Table, Page::Id, Page::VisibleUserIds, ID)],
+        vec![Column::new(
             RegistryItem::Table,
             RegistryItem::Id,
             RegistryItem::Scope,
-        )),
-        tokio::spawn(to_json::<User, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            User::Table,
-            User::Id,
-            User::Tags,
-        )),
-        tokio::spawn(to_json::<User, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            User::Table,
-            User::Id,
-            User::Emojis,
-        )),
-        tokio::spawn(to_json::<Webhook, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            Varchar(1024),
+        )],
+        vec![
+            Column::new(User::Table, User::Id, User::Tags, Varchar(128)),
+            Column::new(User::Table, User::Id, User::Emojis, Varchar(128)),
+        ],
+        vec![Column::new(
             Webhook::Table,
             Webhook::Id,
             Webhook::On,
-        )),
-        tokio::spawn(to_json::<Poll, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
-            Poll::Table,
-            Poll::NoteId,
-            Poll::Choices,
-        )),
-        tokio::spawn(to_json::<Poll, Vec<i32>, I32Vec>(
-            db.clone(),
-            mp.clone(),
-            Poll::Table,
-            Poll::NoteId,
-            Poll::Votes,
-        )),
-        tokio::spawn(to_json::<UserProfile, Vec<String>, StringVec>(
-            db.clone(),
-            mp.clone(),
+            Varchar(128),
+        )],
+        vec![
+            Column::new(Poll::Table, Poll::NoteId, Poll::Choices, Varchar(256)),
+            Column::new(Poll::Table, Poll::NoteId, Poll::Votes, Integer),
+        ],
+        vec![Column::new(
             UserProfile::Table,
             UserProfile::UserId,
             UserProfile::MutingNotificationTypes,
-        )),
-    ];
+            Enum("user_profile_mutingnotificationtypes_enum"),
+        )],
+    ]
+}
+
+/// Converts all array columns in `direction`. Columns that are already
+/// converted are skipped, so this can be run any number of times.
+pub async fn convert(db: &DbConn, direction: Direction, batch_size: u64) -> Result<(), DbErr> {
+    if db.get_database_backend() != DbBackend::Postgres {
+        return Ok(());
+    }
+
+    let mp = MultiProgress::new();
+    let mp = &mp;
+    let tasks = tables().into_iter().map(|columns| async move {
+        for column in columns {
+            convert_column(db, mp, &column, direction, batch_size).await?;
+        }
+        Ok::<(), DbErr>(())
+    });
+    futures::future::try_join_all(tasks).await?;
 
-    futures::future::join_all(handlers).await;
+    Ok(())
+}
+
+/// Returns the `data_type` of the column in `information_schema`, or [None] if
+/// the column does not exist.
+async fn data_type(db: &DbConn, table: &str, col: &str) -> Result<Option<String>, DbErr> {
+    let stmt = Query::select()
+        .column(Alias::new("data_type"))
+        .from((Alias::new("information_schema"), Alias::new("columns")))
+        .and_where(Expr::col(Alias::new("table_schema")).eq(Expr::cust("current_schema()")))
+        .and_where(Expr::col(Alias::new("table_name")).eq(table))
+        .and_where(Expr::col(Alias::new("column_name")).eq(col))
+        .to_owned();
+    match db.query_one(DbBackend::Postgres.build(&stmt)).await? {
+        Some(row) => Ok(Some(row.try_get_by_index(0)?)),
+        None => Ok(None),
+    }
+}
+
+async fn count(db: &impl ConnectionTrait, stmt: SelectStatement) -> Result<u64, DbErr> {
+    match db.query_one(DbBackend::Postgres.build(&stmt)).await? {
+        Some(row) => Ok(row.try_get_by_index::<i64>(0)? as u64),
+        None => Ok(0),
+    }
 }
 
-fn select_query<T: Iden + 'static>(table: T, id: T, col: T) -> String {
-    Query::select()
-        .column(id)
-        .column(col)
-        .from(table)
-        .to_string(PostgresQueryBuilder)
+/// Returns the expression converting `col` in `direction`.
+fn convert_expr(column: &Column, direction: Direction) -> SimpleExpr {
+    let col = Expr::col(column.col.clone()).into();
+    match direction {
+        Direction::ToJson => Expr::cust_with_exprs("COALESCE(to_jsonb($1), '[]'::jsonb)", [col]),
+        Direction::ToArray => Expr::cust_with_exprs(
+            &format!(
+                "ARRAY(SELECT jsonb_array_elements_text($1))::{}",
+                column.elem.array_type()
+            ),
+            [col],
+        ),
+    }
 }
 
-async fn get_vec<T: TryGetable>(db: &DbConn, query: String) -> Result<Vec<(String, T)>, DbErr> {
-    let res: Vec<(String, T)> = db
-        .query_all(Statement::from_string(DbBackend::Postgres, query))
-        .await?
-        .iter()
-        .filter_map(|r| r.try_get_many_by_index().ok())
-        .collect();
-    Ok(res)
+/// Returns the expression that is true if `array` and `json` hold different
+/// elements. `NULL` counts as an empty array on both sides.
+fn mismatch_expr(array: DynIden, json: DynIden) -> SimpleExpr {
+    Expr::cust_with_exprs(
+        "COALESCE(to_jsonb($1), '[]'::jsonb) IS DISTINCT FROM COALESCE($2, '[]'::jsonb)",
+        [Expr::col(array).into(), Expr::col(json).into()],
+    )
 }
 
-async fn convert_col<T: Iden + Clone + 'static>(
+#[tracing::instrument(skip(db, mp, column), fields(column = %column.name()))]
+async fn convert_column(
     db: &DbConn,
-    table: T,
-    col: T,
+    mp: &MultiProgress,
+    column: &Column,
+    direction: Direction,
+    batch_size: u64,
 ) -> Result<(), DbErr> {
+    let table = column.table.to_string();
+    let col = column.col.to_string();
+    let tmp = format!("{}_{}", col, direction.suffix());
+
+    match data_type(db, &table, &col).await? {
+        Some(data_type) if data_type != direction.data_type() => {}
+        // Already converted, or the table does not exist in this version
+        _ => {
+            tracing::debug!("Skipped");
+            return Ok(());
+        }
+    }
+
+    // Left by an interrupted conversion
+    if data_type(db, &table, &tmp).await?.is_some() {
+        let stmt = Table::alter()
+            .table(column.table.clone())
+            .drop_column(Alias::new(&tmp))
+            .to_owned();
+        db.execute(DbBackend::Postgres.build(&stmt)).await?;
+    }
+
+    let mut tmp_def = ColumnDef::new(Alias::new(&tmp));
+    match direction {
+        Direction::ToJson => tmp_def.json_binary(),
+        Direction::ToArray => tmp_def.custom(Alias::new(&column.elem.array_type())),
+    };
     let stmt = Table::alter()
-        .table(table)
-        .drop_column(col.to_owned())
-        .add_column(
-            ColumnDef::new(col.to_owned())
-                .json_binary()
-                .not_null()
-                .default(json!([])),
-        )
-        .to_string(PostgresQueryBuilder);
-    db.query_one(Statement::from_string(DbBackend::Postgres, stmt))
-        .await?;
-    Ok(())
-}
+        .table(column.table.clone())
+        .add_column(&mut tmp_def)
+        .to_owned();
+    db.execute(DbBackend::Postgres.build(&stmt)).await?;
 
-async fn to_json<T, U, V>(
-    db: DbConn,
-    mp: MultiProgress,
-    table: T,
-    id: T,
-    col: T,
-) -> Result<(), DbErr>
-where
-    T: Iden + Clone + 'static,
-    U: TryGetable + IntoIterator + Clone,
-    V: From<U> + Into<SimpleExpr>,
-{
-    let query = select_query(table.clone(), id.clone(), col.clone());
-    let loading = ProgressBar::new_spinner()
-        .with_style(ProgressStyle::with_template("{prefix} {msg} {spinner}").unwrap())
-        .with_prefix("[-]")
-        .with_message(format!(
-            "Loading data from {}.{}",
-            table.to_string(),
-            col.to_string()
-        ));
-    let loading = mp.add(loading);
-    loading.enable_steady_tick(Duration::from_millis(100));
-    let res = get_vec::<U>(&db, query).await?;
-    let models: Vec<(String, V)> = res
-        .iter()
-        .filter(|(_, r)| r.clone().into_iter().count() > 0)
-        .map(|(id, r)| (id.clone(), <V>::from(r.clone())))
-        .collect();
-    loading.finish_and_clear();
-    convert_col(&db, table.clone(), col.clone()).await?;
-
-    let progress = ProgressBar::new(models.len() as u64)
+    let total = count(
+        db,
+        Query::select()
+            .expr(Expr::asterisk().count())
+            .from(column.table.clone())
+            .to_owned(),
+    )
+    .await?;
+    let progress = ProgressBar::new(total)
         .with_style(
             ProgressStyle::with_template("{prefix} {msg} {wide_bar} {pos}/{len}")
                 .unwrap()
                 .progress_chars("##-"),
         )
         .with_prefix("[*]")
-        .with_message(format!("Copying {}.{}", table.to_string(), col.to_string()));
+        .with_message(format!("Copying {}", column.name()));
     let progress = mp.add(progress);
 
-    for model in models {
-        progress.inc(1);
-        let q = Query::update()
-            .table(table.clone())
-            .values([(col.clone(), model.1.into())])
-            .and_where(Expr::col(id.clone()).eq(model.0))
-            .to_string(PostgresQueryBuilder);
-        db.query_one(Statement::from_string(DbBackend::Postgres, q))
-            .await?;
+    let update = Query::update()
+        .table(column.table.clone())
+        .value(Alias::new(&tmp), convert_expr(column, direction))
+        .to_owned();
+
+    if total <= batch_size {
+        db.execute(DbBackend::Postgres.build(&update)).await?;
+        progress.inc(total);
+    } else {
+        let mut last: Option<String> = None;
+        loop {
+            let mut batch = Query::select()
+                .column(column.id.clone())
+                .from(column.table.clone())
+                .order_by(column.id.clone(), Order::Asc)
+                .limit(batch_size)
+                .to_owned();
+            if let Some(last) = &last {
+                batch.and_where(Expr::col(column.id.clone()).gt(last.to_owned()));
+            }
+            let stmt = Query::select()
+                .expr(Expr::col(column.id.clone()).max())
+                .from_subquery(batch, Alias::new("batch"))
+                .to_owned();
+            let upper: Option<String> = match db.query_one(DbBackend::Postgres.build(&stmt)).await?
+            {
+                Some(row) => row.try_get_by_index(0)?,
+                None => None,
+            };
+            let Some(upper) = upper else {
+                break;
+            };
+
+            let mut stmt = update.clone();
+            stmt.and_where(Expr::col(column.id.clone()).lte(upper.to_owned()));
+            if let Some(last) = &last {
+                stmt.and_where(Expr::col(column.id.clone()).gt(last.to_owned()));
+            }
+            let res = db.execute(DbBackend::Postgres.build(&stmt)).await?;
+            tracing::debug!(rows = res.rows_affected(), %upper, "Copied a batch");
+            progress.inc(res.rows_affected());
+            last = Some(upper);
+        }
+    }
+
+    let txn = db.begin().await?;
+
+    // No rows may change between the final copy and the swap
+    txn.execute(Statement::from_string(
+        DbBackend::Postgres,
+        format!("LOCK TABLE \"{}\" IN ACCESS EXCLUSIVE MODE", table),
+    ))
+    .await?;
+
+    let (array, json) = match direction {
+        Direction::ToJson => (column.col.clone(), Alias::new(&tmp).into_iden()),
+        Direction::ToArray => (Alias::new(&tmp).into_iden(), column.col.clone()),
+    };
+
+    // Rows inserted or updated during the copy
+    let mut stmt = update.clone();
+    stmt.cond_where(
+        Cond::any()
+            .add(Expr::col(Alias::new(&tmp)).is_null())
+            .add(mismatch_expr(array.clone(), json.clone())),
+    );
+    let res = txn.execute(DbBackend::Postgres.build(&stmt)).await?;
+    tracing::debug!(
+        rows = res.rows_affected(),
+        "Copied rows changed during the copy"
+    );
+
+    let mismatched = count(
+        &txn,
+        Query::select()
+            .expr(Expr::asterisk().count())
+            .from(column.table.clone())
+            .cond_where(
+                Cond::any()
+                    .add(Expr::col(Alias::new(&tmp)).is_null())
+                    .add(mismatch_expr(array, json)),
+            )
+            .to_owned(),
+    )
+    .await?;
+    if mismatched > 0 {
+        tracing::error!(mismatched, "Rows were not converted");
+        txn.rollback().await?;
+        progress.abandon_with_message(format!("Failed {}", column.name()));
+        return Err(DbErr::Custom(format!(
+            "{} rows of {} were not converted",
+            mismatched,
+            column.name()
+        )));
     }
-    progress.finish_with_message(format!("Done {}.{}", table.to_string(), col.to_string()));
+
+    let mut col_def = ColumnDef::new(column.col.clone());
+    col_def.not_null();
+    match direction {
+        Direction::ToJson => col_def.default(json!([])),
+        Direction::ToArray => col_def.default("{}"),
+    };
+    let stmts = [
+        Table::alter()
+            .table(column.table.clone())
+            .drop_column(column.col.clone())
+            .to_owned(),
+        Table::alter()
+            .table(column.table.clone())
+            .rename_column(Alias::new(&tmp), column.col.clone())
+            .to_owned(),
+        Table::alter()
+            .table(column.table.clone())
+            .modify_column(&mut col_def)
+            .to_owned(),
+    ];
+    for stmt in stmts {
+        txn.execute(DbBackend::Postgres.build(&stmt)).await?;
+    }
+    txn.commit().await?;
+    tracing::debug!(total, "Converted");
+
+    progress.finish_with_message(format!("Done {}", column.name()));
 
     Ok(())
 }
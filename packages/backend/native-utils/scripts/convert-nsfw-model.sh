@@ -0,0 +1,24 @@
+#!/bin/sh
+# Converts the bundled TensorFlow.js model of nsfwjs to the ONNX model used by
+# `sensitiveMediaModel` in the config. The first argument is the output path,
+# `nsfw-model.onnx` by default.
+#
+# Requires Python with tensorflowjs and tf2onnx:
+#   pip install tensorflowjs tf2onnx
+set -eu
+
+model="$(dirname "$0")/../../nsfw-model/model.json"
+output="${1:-nsfw-model.onnx}"
+saved_model="$(mktemp -d)"
+trap 'rm -rf "$saved_model"' EXIT
+
+# the layers model is converted to a SavedModel first, as tf2onnx only reads
+# graph models of TensorFlow.js
+tensorflowjs_converter \
+	--input_format=tfjs_layers_model \
+	--output_format=keras_saved_model \
+	"$model" "$saved_model"
+python3 -m tf2onnx.convert \
+	--saved-model "$saved_model" \
+	--opset 13 \
+	--output "$output"
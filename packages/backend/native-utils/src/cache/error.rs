@@ -0,0 +1,30 @@
+use redis::RedisError;
+
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("The cache connection has not been initialized yet")]
+    Uninitialized,
+    #[error("Redis error: {0}")]
+    RedisError(#[from] RedisError),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::Uninitialized => ErrorCode::NotInitialized,
+            Self::RedisError(e) => redis_error_code(e),
+        }
+    }
+}
+
+pub(crate) fn redis_error_code(err: &RedisError) -> ErrorCode {
+    match err.is_connection_refusal() || err.is_connection_dropped() || err.is_timeout() {
+        true => ErrorCode::ServiceUnavailable,
+        false => ErrorCode::InternalError,
+    }
+}
+
+impl_into_napi_error!(Error);
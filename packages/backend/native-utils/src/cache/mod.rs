@@ -0,0 +1,91 @@
+pub mod error;
+
+use cfg_if::cfg_if;
+use error::Error;
+use redis::{aio::ConnectionManager, AsyncCommands, FromRedisValue, RedisResult};
+
+use crate::metrics;
+
+static CACHE: once_cell::sync::OnceCell<Cache> = once_cell::sync::OnceCell::new();
+
+/// Connection to the Redis server used as the cache, along with the key
+/// prefix shared with the `ioredis` client in `db/redis.ts`.
+pub struct Cache {
+    conn: ConnectionManager,
+    prefix: String,
+}
+
+impl Cache {
+    /// Returns a handle to the multiplexed connection. Cloning it is cheap.
+    pub fn conn(&self) -> ConnectionManager {
+        self.conn.clone()
+    }
+
+    /// Prepends the configured prefix to `key`, as `ioredis` does with
+    /// `keyPrefix`.
+    pub fn key(&self, key: impl AsRef<str>) -> String {
+        prefixed_key(&self.prefix, key.as_ref())
+    }
+
+    /// Returns the value of `key`, which is prefixed, or [None] if it is
+    /// missing. The read is recorded as a hit or a miss of `cache`.
+    pub async fn get<T: FromRedisValue>(
+        &self,
+        cache: &str,
+        key: impl AsRef<str>,
+    ) -> RedisResult<Option<T>> {
+        let value: Option<T> = self.conn().get(self.key(key)).await?;
+        metrics::record_cache_read(cache, value.is_some());
+        Ok(value)
+    }
+}
+
+fn prefixed_key(prefix: &str, key: &str) -> String {
+    format!("{}:{}", prefix, key)
+}
+
+pub async fn init_cache(
+    conn_uri: impl Into<String>,
+    prefix: impl Into<String>,
+) -> Result<(), Error> {
+    let client = redis::Client::open(conn_uri.into())?;
+    let conn = ConnectionManager::new(client).await?;
+    let prefix = prefix.into();
+    CACHE.get_or_init(move || Cache { conn, prefix });
+    Ok(())
+}
+
+pub fn get_cache() -> Result<&'static Cache, Error> {
+    CACHE.get().ok_or(Error::Uninitialized)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        #[napi]
+        pub async fn native_init_cache(conn_uri: String, prefix: String) -> napi::Result<()> {
+            init_cache(conn_uri, prefix).await.map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{error::Error, get_cache, prefixed_key};
+
+    #[test]
+    fn error_uninitialized() {
+        assert!(matches!(get_cache(), Err(Error::Uninitialized)));
+    }
+
+    #[test]
+    fn key_has_prefix() {
+        assert_eq!(
+            prefixed_key("example.com", "antennaTimeline:9fil64s6g7cskdrb"),
+            "example.com:antennaTimeline:9fil64s6g7cskdrb"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Charts saved natively, declared as in `services/chart/charts/entities`.
+
+use super::schema::{ChartSchema, Column};
+
+/// As `charts/ap-request.ts`.
+pub const AP_REQUEST: ChartSchema = ChartSchema {
+    name: "apRequest",
+    grouped: false,
+    columns: &[
+        Column::count("deliverFailed"),
+        Column::count("deliverSucceeded"),
+        Column::count("inboxReceived"),
+    ],
+};
+
+/// For testing, as `charts/test.ts`.
+pub const TEST: ChartSchema = ChartSchema {
+    name: "test",
+    grouped: false,
+    columns: &[
+        Column::count("foo.total").accumulate(),
+        Column::count("foo.inc"),
+        Column::count("foo.dec"),
+    ],
+};
+
+/// For testing, as `charts/test-grouped.ts`.
+pub const TEST_GROUPED: ChartSchema = ChartSchema {
+    name: "testGrouped",
+    grouped: true,
+    ..TEST
+};
+
+/// For testing, as `charts/test-unique.ts`.
+pub const TEST_UNIQUE: ChartSchema = ChartSchema {
+    name: "testUnique",
+    grouped: false,
+    columns: &[Column::unique("foo")],
+};
+
+/// For testing, as `charts/test-intersection.ts`.
+pub const TEST_INTERSECTION: ChartSchema = ChartSchema {
+    name: "testIntersection",
+    grouped: false,
+    columns: &[
+        Column::unique("a"),
+        Column::unique("b"),
+        Column::intersection("aAndB", &["a", "b"]),
+    ],
+};
+
+pub const ALL: [&ChartSchema; 5] = [
+    &AP_REQUEST,
+    &TEST,
+    &TEST_GROUPED,
+    &TEST_UNIQUE,
+    &TEST_INTERSECTION,
+];
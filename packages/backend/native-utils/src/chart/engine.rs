@@ -0,0 +1,638 @@
+//! Buffering and saving of the changes of the charts, and the queries of
+//! their logs, replacing `Chart` of `services/chart/core.ts`.
+//!
+//! The changes are buffered by the hour they were committed at, so they are
+//! not counted in the next hour if the chart is saved after the hour ends.
+//! Each log is written by an upsert, whose row lock serializes the updates of
+//! the sketches instead of the insert locks of `misc/app-lock.ts`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::sea_query::{
+    Alias, Expr, Func, OnConflict, Order, Query, SelectStatement, SimpleExpr,
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseTransaction, DbErr, EntityTrait, QueryFilter, Set,
+    TransactionError, TransactionTrait,
+};
+use tokio::task::JoinSet;
+
+use super::error::Error;
+use super::hll::{intersection_count, HyperLogLog};
+use super::schema::{ChartSchema, Kind, Span};
+use crate::database;
+use crate::metrics;
+use crate::model::entity::chart_sketch;
+
+/// Number of the days of the groups saved concurrently, as limited by
+/// `promiseLimit` in TS.
+const CONCURRENCY: usize = 25;
+
+/// Changes committed to a chart.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diff {
+    /// Amounts added to the count columns.
+    pub counts: HashMap<String, i64>,
+    /// Items added to the unique columns.
+    pub uniques: HashMap<String, HashSet<String>>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty() && self.uniques.is_empty()
+    }
+
+    pub fn merge(&mut self, other: Diff) {
+        for (key, amount) in other.counts {
+            *self.counts.entry(key).or_default() += amount;
+        }
+        for (key, items) in other.uniques {
+            self.uniques.entry(key).or_default().extend(items);
+        }
+    }
+}
+
+/// A log read from a table, with the values in the order of the columns of
+/// the schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log {
+    /// Unix timestamp in seconds of the start of the log.
+    pub date: i64,
+    pub values: Vec<i64>,
+}
+
+/// Values of the logs by the keys of the columns, newest first.
+pub type ChartResult = HashMap<String, Vec<i64>>;
+
+type Group = Option<String>;
+
+pub struct Chart {
+    schema: &'static ChartSchema,
+    /// Changes not saved yet, by the group and the hour they were committed
+    /// at.
+    buffer: Mutex<HashMap<(Group, i64), Diff>>,
+}
+
+impl Chart {
+    pub fn new(schema: &'static ChartSchema) -> Self {
+        Self {
+            schema,
+            buffer: Default::default(),
+        }
+    }
+
+    pub fn schema(&self) -> &'static ChartSchema {
+        self.schema
+    }
+
+    fn check_group(&self, group: Option<&str>) -> Result<Group, Error> {
+        match (self.schema.grouped, group) {
+            (true, None) => Err(Error::MissingGroup(self.schema.name)),
+            (true, Some(group)) => Ok(Some(group.to_string())),
+            (false, _) => Ok(None),
+        }
+    }
+
+    fn check_column(&self, key: &str, kind: Kind) -> Result<(), Error> {
+        match self.schema.column(key) {
+            None => Err(Error::NoSuchColumn {
+                chart: self.schema.name,
+                key: key.to_string(),
+            }),
+            Some(column) if column.kind == kind => Ok(()),
+            Some(_) => Err(Error::InvalidColumn(key.to_string())),
+        }
+    }
+
+    /// Buffers `diff` to be saved by [Chart::save]. `group` is ignored if
+    /// the chart is not grouped.
+    pub fn commit(
+        &self,
+        mut diff: Diff,
+        group: Option<&str>,
+        at: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let group = self.check_group(group)?;
+        for key in diff.counts.keys() {
+            self.check_column(key, Kind::Count)?;
+        }
+        for key in diff.uniques.keys() {
+            self.check_column(key, Kind::Unique)?;
+        }
+        diff.counts.retain(|_, amount| *amount != 0);
+        diff.uniques.retain(|_, items| !items.is_empty());
+        if diff.is_empty() {
+            return Ok(());
+        }
+
+        let mut buffer = self.buffer.lock().expect("Chart buffer lock poisoned");
+        buffer
+            .entry((group, Span::Hour.floor(at)))
+            .or_default()
+            .merge(diff);
+        Ok(())
+    }
+
+    fn restore(&self, group: Group, hours: Vec<(i64, Diff)>) {
+        let mut buffer = self.buffer.lock().expect("Chart buffer lock poisoned");
+        for (hour, diff) in hours {
+            buffer.entry((group.clone(), hour)).or_default().merge(diff);
+        }
+    }
+
+    /// Writes the buffered changes to the logs. The changes of each group
+    /// in each day are written in a transaction, and are buffered again if
+    /// it fails. Returns the number of the groups written.
+    #[tracing::instrument(skip(self), fields(chart = self.schema.name))]
+    pub async fn save(&self) -> Result<usize, Error> {
+        let buffer = std::mem::take(&mut *self.buffer.lock().expect("Chart buffer lock poisoned"));
+        if buffer.is_empty() {
+            tracing::debug!("Write skipped");
+            return Ok(0);
+        }
+
+        let mut days: HashMap<(Group, i64), Vec<(i64, Diff)>> = HashMap::new();
+        for ((group, hour), diff) in buffer {
+            days.entry((group, Span::Day.floor_timestamp(hour)))
+                .or_default()
+                .push((hour, diff));
+        }
+
+        let schema = self.schema;
+        let mut days = days.into_iter();
+        let mut tasks = JoinSet::new();
+        let mut saved = 0;
+        let mut error = None;
+        loop {
+            while tasks.len() < CONCURRENCY {
+                let Some(((group, day), mut hours)) = days.next() else {
+                    break;
+                };
+                // the logs are locked in the same order by every transaction
+                hours.sort_by_key(|(hour, _)| *hour);
+                tasks.spawn(async move {
+                    let result = save_day(schema, group.clone(), day, hours.clone()).await;
+                    (group, hours, result)
+                });
+            }
+            let Some(joined) = tasks.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((_, _, Ok(()))) => saved += 1,
+                Ok((group, hours, Err(e))) => {
+                    tracing::warn!(group, "Failed to save the chart: {}", e);
+                    self.restore(group, hours);
+                    error.get_or_insert(e);
+                }
+                Err(e) => tracing::error!("Failed to save the chart: {}", e),
+            }
+        }
+
+        tracing::info!(saved, "Saved the chart");
+        match error {
+            Some(e) => Err(e),
+            None => Ok(saved),
+        }
+    }
+
+    /// Sets the values of the logs that `at` belongs to, as `tickMajor` and
+    /// `tickMinor` do. `group` is ignored if the chart is not grouped.
+    #[tracing::instrument(skip(self, values), fields(chart = self.schema.name))]
+    pub async fn tick(
+        &self,
+        values: HashMap<String, i64>,
+        group: Option<&str>,
+        at: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let group = self.check_group(group)?;
+        for key in values.keys() {
+            self.check_column(key, Kind::Count)?;
+        }
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        let schema = self.schema;
+        let db = &database::get_database()?;
+        db.transaction::<_, (), Error>(|txn| {
+            Box::pin(async move {
+                for span in Span::ALL {
+                    let date = span.floor(at);
+                    let diff = Diff::default();
+                    write_log(txn, schema, span, date, group.as_deref(), &diff, &values).await?;
+                }
+                Ok(())
+            })
+        })
+        .await
+        .map_err(from_transaction_error)
+    }
+
+    /// Deletes the sketches of the logs older than a day, which are not
+    /// updated anymore. Returns the number of the sketches deleted.
+    #[tracing::instrument(skip(self), fields(chart = self.schema.name))]
+    pub async fn clean(&self, now: DateTime<Utc>) -> Result<u64, Error> {
+        let tables = Span::ALL.map(|span| self.schema.table(span));
+        let result = chart_sketch::Entity::delete_many()
+            .filter(chart_sketch::Column::Chart.is_in(tables))
+            .filter(chart_sketch::Column::Date.lt((now - Duration::days(1)).timestamp()))
+            .exec(&database::get_database()?)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Returns the values of the `amount` logs of `span` up to the one that
+    /// `cursor` belongs to. Missing logs are filled with the accumulated
+    /// values of the previous ones and zero.
+    #[tracing::instrument(skip(self), fields(chart = self.schema.name))]
+    pub async fn range(
+        &self,
+        span: Span,
+        amount: usize,
+        cursor: DateTime<Utc>,
+        group: Option<&str>,
+    ) -> Result<ChartResult, Error> {
+        let group = self.check_group(group)?;
+        let end = span.floor(cursor);
+        let start = end - span.seconds() * (amount.max(1) as i64 - 1);
+
+        let mut query = self.select_logs(span, group.as_deref());
+        query.and_where(Expr::col(Alias::new("date")).between(start, end));
+        let mut logs = self.query_logs(query).await?;
+
+        // the log before the range is needed to fill the oldest ones
+        if logs.last().is_none_or(|log| log.date != start) {
+            let mut query = self.select_logs(span, group.as_deref());
+            query
+                .and_where(Expr::col(Alias::new("date")).lt(start))
+                .limit(1);
+            logs.extend(self.query_logs(query).await?);
+        }
+
+        Ok(fill(self.schema, span, amount, end, &logs))
+    }
+
+    fn select_logs(&self, span: Span, group: Option<&str>) -> SelectStatement {
+        let mut query = Query::select();
+        query.expr_as(bigint(Alias::new("date")), Alias::new("date"));
+        for column in self.schema.columns {
+            let name = column.name();
+            query.expr_as(bigint(Alias::new(&name)), Alias::new(&name));
+        }
+        query
+            .from(Alias::new(&self.schema.table(span)))
+            .order_by(Alias::new("date"), Order::Desc);
+        if let Some(group) = group {
+            query.and_where(Expr::col(Alias::new("group")).eq(group));
+        }
+        query
+    }
+
+    async fn query_logs(&self, query: SelectStatement) -> Result<Vec<Log>, Error> {
+        let db = &database::get_read_database()?;
+        let stmt = db.get_database_backend().build(&query);
+        let rows = metrics::observe_query("chart", db.query_all(stmt)).await?;
+        let logs = rows
+            .iter()
+            .map(|row| {
+                Ok(Log {
+                    date: row.try_get("", "date")?,
+                    values: self
+                        .schema
+                        .columns
+                        .iter()
+                        .map(|c| row.try_get("", &c.name()))
+                        .collect::<Result<_, DbErr>>()?,
+                })
+            })
+            .collect::<Result<_, DbErr>>()?;
+        Ok(logs)
+    }
+}
+
+/// Reads a column as `bigint`, as the columns may be of smaller types.
+fn bigint(col: Alias) -> SimpleExpr {
+    Func::cast_as(Expr::col(col), Alias::new("bigint")).into()
+}
+
+fn from_transaction_error(e: TransactionError<Error>) -> Error {
+    match e {
+        TransactionError::Connection(e) => e.into(),
+        TransactionError::Transaction(e) => e,
+    }
+}
+
+async fn save_day(
+    schema: &'static ChartSchema,
+    group: Group,
+    day: i64,
+    hours: Vec<(i64, Diff)>,
+) -> Result<(), Error> {
+    let mut total = Diff::default();
+    for (_, diff) in &hours {
+        total.merge(diff.clone());
+    }
+
+    let db = &database::get_database()?;
+    let sets = HashMap::new();
+    db.transaction::<_, (), Error>(|txn| {
+        Box::pin(async move {
+            let group = group.as_deref();
+            for (hour, diff) in &hours {
+                write_log(txn, schema, Span::Hour, *hour, group, diff, &sets).await?;
+            }
+            write_log(txn, schema, Span::Day, day, group, &total, &sets).await
+        })
+    })
+    .await
+    .map_err(from_transaction_error)
+}
+
+/// Adds `diff` to the log of `date` and sets the counts of `sets`, creating
+/// the log if there is none.
+async fn write_log(
+    txn: &DatabaseTransaction,
+    schema: &ChartSchema,
+    span: Span,
+    date: i64,
+    group: Option<&str>,
+    diff: &Diff,
+    sets: &HashMap<String, i64>,
+) -> Result<(), Error> {
+    let backend = txn.get_database_backend();
+    let table = Alias::new(&schema.table(span));
+
+    // values taken over by a new log
+    let mut baseline: HashMap<&str, i64> = HashMap::new();
+    let accumulated: Vec<_> = schema.columns.iter().filter(|c| c.accumulate).collect();
+    if !accumulated.is_empty() {
+        let mut query = Query::select();
+        for column in &accumulated {
+            let name = column.name();
+            query.expr_as(bigint(Alias::new(&name)), Alias::new(&name));
+        }
+        query
+            .from(table.clone())
+            .and_where(Expr::col(Alias::new("date")).lt(date))
+            .order_by(Alias::new("date"), Order::Desc)
+            .limit(1);
+        if let Some(group) = group {
+            query.and_where(Expr::col(Alias::new("group")).eq(group));
+        }
+        if let Some(row) = txn.query_one(backend.build(&query)).await? {
+            for column in &accumulated {
+                baseline.insert(column.key, row.try_get("", &column.name())?);
+            }
+        }
+    }
+
+    let mut keys = vec![Alias::new("date")];
+    let mut values: Vec<SimpleExpr> = vec![date.into()];
+    if let Some(group) = group {
+        keys.push(Alias::new("group"));
+        values.push(group.into());
+    }
+    let mut on_conflict = OnConflict::columns(keys.clone());
+    let mut columns = keys;
+    // `OnConflict::value` replaces the previous values
+    let mut updates: Vec<(Alias, SimpleExpr)> = Vec::new();
+    for column in schema.columns.iter().filter(|c| c.kind == Kind::Count) {
+        let name = Alias::new(&column.name());
+        let amount = diff.counts.get(column.key).copied().unwrap_or(0);
+        let initial = match sets.get(column.key) {
+            Some(value) => {
+                updates.push((name.clone(), (*value).into()));
+                *value
+            }
+            None => {
+                if amount != 0 {
+                    let expr = Expr::col((table.clone(), name.clone())).add(amount);
+                    updates.push((name.clone(), expr));
+                }
+                baseline.get(column.key).copied().unwrap_or(0) + amount
+            }
+        };
+        columns.push(name);
+        values.push(initial.into());
+    }
+    if updates.is_empty() {
+        // still locks the log
+        on_conflict.update_column(Alias::new("date"));
+    } else {
+        on_conflict.values(updates);
+    }
+    let insert = Query::insert()
+        .into_table(table.clone())
+        .columns(columns)
+        .values_panic(values)
+        .on_conflict(on_conflict)
+        .to_owned();
+    txn.execute(backend.build(&insert)).await?;
+
+    write_uniques(txn, schema, span, date, group, diff).await
+}
+
+/// Adds the items of `diff` to the sketches of the log and updates the
+/// counts of the unique and intersection columns.
+async fn write_uniques(
+    txn: &DatabaseTransaction,
+    schema: &ChartSchema,
+    span: Span,
+    date: i64,
+    group: Option<&str>,
+    diff: &Diff,
+) -> Result<(), Error> {
+    if diff.uniques.is_empty() {
+        return Ok(());
+    }
+    let touched = |key: &str| diff.uniques.contains_key(key);
+    let intersections: Vec<_> = schema
+        .columns
+        .iter()
+        .filter_map(|c| match c.kind {
+            Kind::Intersection(of) if of.iter().any(|k| touched(k)) => Some((c, of)),
+            _ => None,
+        })
+        .collect();
+    let mut keys: HashSet<&str> = diff.uniques.keys().map(String::as_str).collect();
+    keys.extend(intersections.iter().flat_map(|(_, of)| of.iter().copied()));
+
+    let chart = schema.table(span);
+    let group = group.unwrap_or_default();
+    let rows = chart_sketch::Entity::find()
+        .filter(chart_sketch::Column::Chart.eq(chart.as_str()))
+        .filter(chart_sketch::Column::Date.eq(date))
+        .filter(chart_sketch::Column::Group.eq(group))
+        .filter(chart_sketch::Column::Key.is_in(keys.iter().copied()))
+        .all(txn)
+        .await?;
+    let mut sketches: HashMap<String, HyperLogLog> = HashMap::new();
+    for row in rows {
+        match HyperLogLog::from_bytes(&row.sketch) {
+            Some(sketch) => sketches.insert(row.key, sketch),
+            None => {
+                tracing::warn!(chart, date, key = row.key, "Discarded a malformed sketch");
+                continue;
+            }
+        };
+    }
+
+    let mut update = Query::update();
+    update.table(Alias::new(&chart));
+    for (key, items) in &diff.uniques {
+        let sketch = sketches.entry(key.to_owned()).or_default();
+        for item in items {
+            sketch.insert(item);
+        }
+        let model = chart_sketch::ActiveModel {
+            chart: Set(chart.to_owned()),
+            date: Set(date as i32),
+            group: Set(group.to_string()),
+            key: Set(key.to_owned()),
+            sketch: Set(sketch.to_bytes()),
+        };
+        chart_sketch::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    chart_sketch::Column::Chart,
+                    chart_sketch::Column::Date,
+                    chart_sketch::Column::Group,
+                    chart_sketch::Column::Key,
+                ])
+                .update_column(chart_sketch::Column::Sketch)
+                .to_owned(),
+            )
+            .exec(txn)
+            .await?;
+        if let Some(column) = schema.column(key) {
+            update.value(Alias::new(&column.name()), sketch.count() as i64);
+        }
+    }
+
+    let empty = HyperLogLog::default();
+    for (column, of) in intersections {
+        let sources: Vec<_> = of
+            .iter()
+            .map(|k| sketches.get(*k).unwrap_or(&empty))
+            .collect();
+        update.value(
+            Alias::new(&column.name()),
+            intersection_count(&sources) as i64,
+        );
+    }
+
+    update.and_where(Expr::col(Alias::new("date")).eq(date));
+    if schema.grouped {
+        update.and_where(Expr::col(Alias::new("group")).eq(group));
+    }
+    txn.execute(txn.get_database_backend().build(&update))
+        .await?;
+    Ok(())
+}
+
+/// Returns the values of the `amount` logs of `span` up to `end`, taking
+/// them from `logs` sorted from the newest. The missing ones take over the
+/// accumulated values of the previous log.
+pub fn fill(
+    schema: &ChartSchema,
+    span: Span,
+    amount: usize,
+    end: i64,
+    logs: &[Log],
+) -> ChartResult {
+    let mut result: ChartResult = schema
+        .columns
+        .iter()
+        .map(|c| (c.key.to_string(), Vec::with_capacity(amount)))
+        .collect();
+
+    for i in 0..amount {
+        let date = end - span.seconds() * i as i64;
+        let log = logs.iter().find(|log| log.date == date);
+        let previous = logs.iter().find(|log| log.date < date);
+        for (j, column) in schema.columns.iter().enumerate() {
+            let value = match (log, previous) {
+                (Some(log), _) => log.values[j],
+                (None, Some(previous)) if column.accumulate => previous.values[j],
+                _ => 0,
+            };
+            if let Some(values) = result.get_mut(column.key) {
+                values.push(value);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod unit_test {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{fill, Diff, Log};
+    use crate::chart::charts::TEST;
+    use crate::chart::schema::Span;
+
+    const HOUR: i64 = 60 * 60;
+
+    fn result(total: [i64; 3], inc: [i64; 3], dec: [i64; 3]) -> HashMap<String, Vec<i64>> {
+        HashMap::from([
+            ("foo.total".to_string(), total.to_vec()),
+            ("foo.inc".to_string(), inc.to_vec()),
+            ("foo.dec".to_string(), dec.to_vec()),
+        ])
+    }
+
+    #[test]
+    fn fill_missing_logs() {
+        let logs = vec![
+            Log {
+                date: 4 * HOUR,
+                values: vec![2, 1, 0],
+            },
+            Log {
+                date: 0,
+                values: vec![1, 1, 0],
+            },
+        ];
+        assert_eq!(
+            fill(&TEST, Span::Hour, 3, 5 * HOUR, &logs),
+            result([2, 2, 1], [0, 1, 0], [0, 0, 0])
+        );
+        assert_eq!(
+            fill(&TEST, Span::Hour, 3, 2 * HOUR, &logs[1..]),
+            result([1, 1, 1], [0, 0, 1], [0, 0, 0])
+        );
+        assert_eq!(
+            fill(&TEST, Span::Hour, 3, 2 * HOUR, &[]),
+            result([0, 0, 0], [0, 0, 0], [0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn merge_diffs() {
+        let mut diff = Diff {
+            counts: HashMap::from([("foo.inc".to_string(), 1)]),
+            uniques: HashMap::from([("foo".to_string(), ["alice".to_string()].into())]),
+        };
+        diff.merge(Diff {
+            counts: HashMap::from([("foo.inc".to_string(), 2), ("foo.dec".to_string(), 1)]),
+            uniques: HashMap::from([("foo".to_string(), ["bob".to_string()].into())]),
+        });
+        assert_eq!(
+            diff,
+            Diff {
+                counts: HashMap::from([("foo.inc".to_string(), 3), ("foo.dec".to_string(), 1)]),
+                uniques: HashMap::from([(
+                    "foo".to_string(),
+                    ["alice".to_string(), "bob".to_string()].into()
+                )]),
+            }
+        );
+    }
+}
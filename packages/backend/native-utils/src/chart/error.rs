@@ -0,0 +1,39 @@
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("Failed to get database connection: {0}")]
+    DbConnError(#[from] crate::database::error::Error),
+    #[error("Database operation error: {0}")]
+    DbOperationError(#[from] sea_orm::DbErr),
+    #[error("No such chart: {0}")]
+    NoSuchChart(String),
+    #[error("Chart {chart} has no column {key}")]
+    NoSuchColumn { chart: &'static str, key: String },
+    #[error("Column {0} can not be changed this way")]
+    InvalidColumn(String),
+    #[error("Chart {0} is grouped and needs a group")]
+    MissingGroup(&'static str),
+    #[error("Invalid span: {0}")]
+    InvalidSpan(String),
+    #[error("Invalid time: {0}")]
+    InvalidTime(i64),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::NoSuchChart(_)
+            | Self::NoSuchColumn { .. }
+            | Self::InvalidColumn(_)
+            | Self::MissingGroup(_)
+            | Self::InvalidSpan(_)
+            | Self::InvalidTime(_) => ErrorCode::InvalidParam,
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
@@ -0,0 +1,204 @@
+//! HyperLogLog sketches counting the distinct items of the unique columns,
+//! which are much smaller than the sets of the items kept by the charts of
+//! `services/chart/core.ts`.
+//!
+//! Small sets, as counted by most of the logs, are counted almost exactly
+//! thanks to linear counting, and are stored sparsely.
+
+use sha2::{Digest, Sha256};
+
+/// Number of the bits of the hash choosing the register.
+const PRECISION: u32 = 12;
+const REGISTERS: usize = 1 << PRECISION;
+
+const DENSE: u8 = 0;
+const SPARSE: u8 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            registers: vec![0; REGISTERS],
+        }
+    }
+}
+
+impl HyperLogLog {
+    pub fn insert(&mut self, item: &str) {
+        let digest = Sha256::digest(item.as_bytes());
+        let mut hash = [0; 8];
+        hash.copy_from_slice(&digest[..8]);
+        let hash = u64::from_be_bytes(hash);
+
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // the guard bit bounds the rank when the rest is all zero
+        let rest = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    /// Returns the estimated number of the distinct items inserted.
+    pub fn count(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|r| 2_f64.powi(-i32::from(*r)))
+            .sum();
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / sum;
+
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+
+    /// Serializes the sketch, listing only the registers set if that is
+    /// smaller.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let set = self.registers.iter().filter(|r| **r != 0).count();
+        if set * 3 >= REGISTERS {
+            let mut bytes = Vec::with_capacity(REGISTERS + 1);
+            bytes.push(DENSE);
+            bytes.extend_from_slice(&self.registers);
+            return bytes;
+        }
+
+        let mut bytes = Vec::with_capacity(set * 3 + 1);
+        bytes.push(SPARSE);
+        for (index, register) in self.registers.iter().enumerate() {
+            if *register != 0 {
+                bytes.extend_from_slice(&(index as u16).to_be_bytes());
+                bytes.push(*register);
+            }
+        }
+        bytes
+    }
+
+    /// Deserializes a sketch serialized by [HyperLogLog::to_bytes]. Returns
+    /// [None] if `bytes` is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (tag, body) = bytes.split_first()?;
+        match *tag {
+            DENSE if body.len() == REGISTERS => Some(Self {
+                registers: body.to_vec(),
+            }),
+            SPARSE if body.len() % 3 == 0 => {
+                let mut sketch = Self::default();
+                for entry in body.chunks_exact(3) {
+                    let index = u16::from_be_bytes([entry[0], entry[1]]) as usize;
+                    *sketch.registers.get_mut(index)? = entry[2];
+                }
+                Some(sketch)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the estimated number of the items inserted into all of
+/// `sketches`, by the inclusion–exclusion principle over their unions.
+pub fn intersection_count(sketches: &[&HyperLogLog]) -> u64 {
+    let Some(min) = sketches.iter().map(|s| s.count()).min() else {
+        return 0;
+    };
+
+    let mut count: i64 = 0;
+    for subset in 1_u32..(1 << sketches.len()) {
+        let mut union = HyperLogLog::default();
+        for (i, sketch) in sketches.iter().enumerate() {
+            if subset & (1 << i) != 0 {
+                union.merge(sketch);
+            }
+        }
+        let sign = if subset.count_ones() % 2 == 1 { 1 } else { -1 };
+        count += sign * union.count() as i64;
+    }
+    (count.max(0) as u64).min(min)
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{intersection_count, HyperLogLog};
+
+    fn sketch(items: &[&str]) -> HyperLogLog {
+        let mut sketch = HyperLogLog::default();
+        for item in items {
+            sketch.insert(item);
+        }
+        sketch
+    }
+
+    #[test]
+    fn count_small_sets() {
+        assert_eq!(HyperLogLog::default().count(), 0);
+        assert_eq!(sketch(&["alice", "alice", "bob"]).count(), 2);
+        let items: Vec<String> = (0..100).map(|i| format!("user{}", i)).collect();
+        let items: Vec<&str> = items.iter().map(String::as_str).collect();
+        assert!(sketch(&items).count().abs_diff(100) <= 2);
+    }
+
+    #[test]
+    fn count_large_sets_approximately() {
+        let mut sketch = HyperLogLog::default();
+        for i in 0..100_000 {
+            sketch.insert(&format!("user{}", i));
+        }
+        let error = (sketch.count() as f64 - 100_000.0).abs() / 100_000.0;
+        assert!(error < 0.05, "error {} is too large", error);
+    }
+
+    #[test]
+    fn merge_sketches() {
+        let mut a = sketch(&["alice", "bob"]);
+        a.merge(&sketch(&["bob", "carol"]));
+        assert_eq!(a, sketch(&["alice", "bob", "carol"]));
+        assert_eq!(a.count(), 3);
+    }
+
+    #[test]
+    fn serialize_sketches() {
+        let small = sketch(&["alice", "bob"]);
+        assert_eq!(small.to_bytes().len(), 7);
+        assert_eq!(HyperLogLog::from_bytes(&small.to_bytes()), Some(small));
+
+        let mut large = HyperLogLog::default();
+        for i in 0..10_000 {
+            large.insert(&i.to_string());
+        }
+        assert_eq!(large.to_bytes().len(), 4097);
+        assert_eq!(HyperLogLog::from_bytes(&large.to_bytes()), Some(large));
+
+        assert_eq!(HyperLogLog::from_bytes(&[]), None);
+        assert_eq!(HyperLogLog::from_bytes(&[1, 0xff, 0xff, 1]), None);
+        assert_eq!(HyperLogLog::from_bytes(&[0, 1, 2]), None);
+    }
+
+    #[test]
+    fn count_intersections() {
+        let a = sketch(&["alice", "bob"]);
+        assert_eq!(intersection_count(&[&a, &sketch(&["carol"])]), 0);
+        assert_eq!(intersection_count(&[&a, &sketch(&["carol", "alice"])]), 1);
+        assert_eq!(
+            intersection_count(&[&a, &sketch(&["alice", "bob"]), &sketch(&["bob"])]),
+            1
+        );
+        assert_eq!(intersection_count(&[]), 0);
+    }
+}
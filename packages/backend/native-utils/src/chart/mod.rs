@@ -0,0 +1,121 @@
+//! Native chart engine, which saves the charts declared in [charts] in place
+//! of `services/chart/core.ts`.
+
+pub mod charts;
+pub mod engine;
+pub mod error;
+pub mod hll;
+pub mod schema;
+
+use std::collections::HashMap;
+
+use cfg_if::cfg_if;
+use once_cell::sync::Lazy;
+
+pub use engine::{Chart, ChartResult, Diff};
+pub use schema::{ChartSchema, Column, Kind, Range, Span};
+
+use error::Error;
+
+static CHARTS: Lazy<HashMap<&'static str, Chart>> = Lazy::new(|| {
+    charts::ALL
+        .into_iter()
+        .map(|schema| (schema.name, Chart::new(schema)))
+        .collect()
+});
+
+/// Returns the chart named `name`, whose buffer is shared by the process.
+pub fn get_chart(name: &str) -> Result<&'static Chart, Error> {
+    CHARTS
+        .get(name)
+        .ok_or_else(|| Error::NoSuchChart(name.to_string()))
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use chrono::{DateTime, TimeZone, Utc};
+        use napi_derive::napi;
+
+        /// Converts a timestamp in milliseconds, as returned by `Date.now()`,
+        /// which the tests fake.
+        fn to_time(timestamp: i64) -> Result<DateTime<Utc>, Error> {
+            Utc.timestamp_millis_opt(timestamp)
+                .single()
+                .ok_or(Error::InvalidTime(timestamp))
+        }
+
+        /// Calls [Chart::commit] inside.
+        #[napi]
+        pub fn native_chart_commit(
+            name: String,
+            group: Option<String>,
+            counts: HashMap<String, i64>,
+            uniques: HashMap<String, Vec<String>>,
+            at: i64,
+        ) -> napi::Result<()> {
+            let diff = Diff {
+                counts,
+                uniques: uniques
+                    .into_iter()
+                    .map(|(key, items)| (key, items.into_iter().collect()))
+                    .collect(),
+            };
+            get_chart(&name)
+                .and_then(|chart| chart.commit(diff, group.as_deref(), to_time(at)?))
+                .map_err(Into::into)
+        }
+
+        /// Calls [Chart::save] inside.
+        #[napi]
+        pub async fn native_chart_save(name: String) -> napi::Result<u32> {
+            match get_chart(&name) {
+                Ok(chart) => chart.save().await.map(|n| n as u32).map_err(Into::into),
+                Err(e) => Err(e.into()),
+            }
+        }
+
+        /// Calls [Chart::tick] inside.
+        #[napi]
+        pub async fn native_chart_tick(
+            name: String,
+            group: Option<String>,
+            values: HashMap<String, i64>,
+            at: i64,
+        ) -> napi::Result<()> {
+            let result = async {
+                get_chart(&name)?
+                    .tick(values, group.as_deref(), to_time(at)?)
+                    .await
+            };
+            result.await.map_err(Into::into)
+        }
+
+        /// Calls [Chart::clean] inside.
+        #[napi]
+        pub async fn native_chart_clean(name: String, now: i64) -> napi::Result<i64> {
+            let result = async { get_chart(&name)?.clean(to_time(now)?).await };
+            match result.await {
+                Ok(count) => Ok(count as i64),
+                Err(e) => Err(e.into()),
+            }
+        }
+
+        /// Calls [Chart::range] inside, and resolves to the values by the
+        /// flat keys of the columns.
+        #[napi]
+        pub async fn native_chart_range(
+            name: String,
+            span: String,
+            amount: u32,
+            cursor: i64,
+            group: Option<String>,
+        ) -> napi::Result<HashMap<String, Vec<i64>>> {
+            let result = async {
+                get_chart(&name)?
+                    .range(span.parse()?, amount as usize, to_time(cursor)?, group.as_deref())
+                    .await
+            };
+            result.await.map_err(Into::into)
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Declaration of the charts, which corresponds to `Schema` of
+//! `services/chart/core.ts`.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Alias, ColumnDef, Index, Table, TableCreateStatement};
+
+use super::error::Error;
+
+/// Prefix of the columns of the values.
+pub const COLUMN_PREFIX: &str = "___";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Span {
+    Hour,
+    Day,
+}
+
+impl FromStr for Span {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hour" => Ok(Self::Hour),
+            "day" => Ok(Self::Day),
+            _ => Err(Error::InvalidSpan(s.to_string())),
+        }
+    }
+}
+
+impl Span {
+    pub const ALL: [Span; 2] = [Span::Hour, Span::Day];
+
+    pub fn seconds(self) -> i64 {
+        match self {
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+        }
+    }
+
+    /// Returns the date of the log that `time` belongs to, as a Unix
+    /// timestamp in seconds.
+    pub fn floor(self, time: DateTime<Utc>) -> i64 {
+        self.floor_timestamp(time.timestamp())
+    }
+
+    pub fn floor_timestamp(self, timestamp: i64) -> i64 {
+        timestamp - timestamp.rem_euclid(self.seconds())
+    }
+}
+
+/// Type of the column, which limits the values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Range {
+    Small,
+    #[default]
+    Medium,
+    Big,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// Value incremented and decremented by the commits.
+    Count,
+    /// Number of the distinct items committed.
+    Unique,
+    /// Number of the items committed to all of the unique columns.
+    Intersection(&'static [&'static str]),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Column {
+    /// Name of the value, in which `.` separates the nested keys.
+    pub key: &'static str,
+    pub kind: Kind,
+    pub range: Range,
+    /// Whether a new log starts with the value of the previous log instead
+    /// of zero.
+    pub accumulate: bool,
+}
+
+impl Column {
+    pub const fn count(key: &'static str) -> Self {
+        Self {
+            key,
+            kind: Kind::Count,
+            range: Range::Medium,
+            accumulate: false,
+        }
+    }
+
+    pub const fn unique(key: &'static str) -> Self {
+        Self {
+            kind: Kind::Unique,
+            ..Self::count(key)
+        }
+    }
+
+    pub const fn intersection(key: &'static str, of: &'static [&'static str]) -> Self {
+        Self {
+            kind: Kind::Intersection(of),
+            ..Self::count(key)
+        }
+    }
+
+    pub const fn range(self, range: Range) -> Self {
+        Self { range, ..self }
+    }
+
+    pub const fn accumulate(self) -> Self {
+        Self {
+            accumulate: true,
+            ..self
+        }
+    }
+
+    /// Returns the name of the column in the tables.
+    pub fn name(&self) -> String {
+        format!("{}{}", COLUMN_PREFIX, self.key.replace('.', "_"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChartSchema {
+    /// Name of the chart in camel case, as passed to the constructor of
+    /// `Chart` in TS.
+    pub name: &'static str,
+    /// Whether the logs are kept for each group, such as a user.
+    pub grouped: bool,
+    pub columns: &'static [Column],
+}
+
+impl ChartSchema {
+    /// Returns the name of the table of the logs of `span`.
+    pub fn table(&self, span: Span) -> String {
+        let prefix = match span {
+            Span::Hour => "__chart__",
+            Span::Day => "__chart_day__",
+        };
+        let mut table = prefix.to_string();
+        for c in self.name.chars() {
+            if c.is_ascii_uppercase() {
+                table.push('_');
+                table.push(c.to_ascii_lowercase());
+            } else {
+                table.push(c);
+            }
+        }
+        table
+    }
+
+    pub fn column(&self, key: &str) -> Option<&'static Column> {
+        self.columns.iter().find(|c| c.key == key)
+    }
+
+    /// Returns the statement to create the table of `span`, which has the
+    /// same columns as the ones created by `Chart.schemaToEntity` except
+    /// the `unique_temp___` arrays.
+    pub fn create_table(&self, span: Span) -> TableCreateStatement {
+        let mut table = Table::create();
+        table
+            .table(Alias::new(&self.table(span)))
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Alias::new("id"))
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Alias::new("date")).integer().not_null());
+        let mut index = Index::create();
+        index
+            .name(&format!("IDX_{}_date", self.table(span)))
+            .unique()
+            .col(Alias::new("date"));
+        if self.grouped {
+            table.col(
+                ColumnDef::new(Alias::new("group"))
+                    .string_len(128)
+                    .not_null(),
+            );
+            index.col(Alias::new("group"));
+        }
+        for column in self.columns {
+            let mut def = ColumnDef::new(Alias::new(&column.name()));
+            match column.range {
+                Range::Small => def.small_integer(),
+                Range::Medium => def.integer(),
+                Range::Big => def.big_integer(),
+            };
+            table.col(def.not_null().default(0));
+        }
+        table.index(&mut index).to_owned()
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use chrono::{TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+
+    use super::{ChartSchema, Column, Span};
+    use crate::chart::charts::TEST_GROUPED;
+
+    #[test]
+    fn table_names() {
+        assert_eq!(TEST_GROUPED.table(Span::Hour), "__chart__test_grouped");
+        assert_eq!(TEST_GROUPED.table(Span::Day), "__chart_day__test_grouped");
+        assert_eq!(Column::count("foo.total").name(), "___foo_total");
+        let schema = ChartSchema {
+            name: "perUserNotes",
+            grouped: true,
+            columns: &[],
+        };
+        assert_eq!(schema.table(Span::Hour), "__chart__per_user_notes");
+    }
+
+    #[test]
+    fn floor_dates() {
+        let time = Utc.with_ymd_and_hms(2000, 1, 1, 5, 30, 10).unwrap();
+        assert_eq!(
+            Span::Hour.floor(time),
+            Utc.with_ymd_and_hms(2000, 1, 1, 5, 0, 0)
+                .unwrap()
+                .timestamp()
+        );
+        assert_eq!(
+            Span::Day.floor(time),
+            Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0)
+                .unwrap()
+                .timestamp()
+        );
+    }
+}
@@ -1,13 +1,28 @@
 use sea_orm::error::DbErr;
 
+use crate::error::{ErrorCode, HasErrorCode};
 use crate::impl_into_napi_error;
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 pub enum Error {
     #[error("The database connections have not been initialized yet")]
     Uninitialized,
+    #[error("Invalid database URI: {0}")]
+    InvalidUri(#[from] url::ParseError),
+    #[error("Invalid SSL mode: {0}")]
+    InvalidSslMode(String),
     #[error("ORM error: {0}")]
     OrmError(#[from] DbErr),
 }
 
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::Uninitialized => ErrorCode::NotInitialized,
+            Self::InvalidUri(_) | Self::InvalidSslMode(_) => ErrorCode::InvalidParam,
+            Self::OrmError(e) => e.code(),
+        }
+    }
+}
+
 impl_into_napi_error!(Error);
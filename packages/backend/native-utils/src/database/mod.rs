@@ -1,38 +1,522 @@
 pub mod error;
 
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
 use cfg_if::cfg_if;
 use error::Error;
-use sea_orm::{Database, DbConn};
+use parse_display::{Display, FromStr};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DbBackend, DbConn, DbErr, ExecResult, QueryResult,
+    Statement,
+};
+use url::Url;
+
+/// `sslmode` of PostgreSQL connections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display, FromStr)]
+#[display(style = "kebab-case")]
+pub enum SslMode {
+    Disable,
+    Allow,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+/// Options of the connection pools, applied to the primary and the replicas
+/// alike. Unset options keep the defaults of SeaORM.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DatabaseOptions {
+    pub max_connections: Option<u32>,
+    pub min_connections: Option<u32>,
+    pub connect_timeout: Option<Duration>,
+    /// Timeout of acquiring a connection from the pool.
+    pub acquire_timeout: Option<Duration>,
+    pub idle_timeout: Option<Duration>,
+    pub max_lifetime: Option<Duration>,
+    /// Logs the statements through the `log` crate.
+    pub statement_logging: bool,
+    /// Overrides `sslmode` in the URIs of PostgreSQL.
+    pub ssl_mode: Option<SslMode>,
+    /// Path to the PEM encoded certificates of the CAs that the server
+    /// certificate is verified with, overriding `sslrootcert`.
+    pub ssl_root_cert: Option<String>,
+    /// Makes PostgreSQL abort statements running longer than this.
+    pub statement_timeout: Option<Duration>,
+    /// URIs of the read replicas, which receive the read-only queries.
+    pub replicas: Vec<String>,
+}
+
+/// Pools of the primary and the read replicas.
+struct Connections {
+    primary: DbConn,
+    replicas: Vec<DbConn>,
+    next_replica: AtomicUsize,
+}
+
+/// Current connections, replaced by [init_database] and removed by
+/// [close_database]. The pools are closed once they are replaced, and freed
+/// when the last [Db] of them is dropped.
+static DB_CONN: RwLock<Option<Arc<Connections>>> = RwLock::new(None);
+
+fn get_connections() -> Result<Arc<Connections>, Error> {
+    DB_CONN.read().unwrap().clone().ok_or(Error::Uninitialized)
+}
+
+/// The connection to the primary or a replica returned by [get_database] and
+/// [get_read_database], which can be used as a [DbConn].
+#[derive(Clone)]
+pub struct Db {
+    conns: Arc<Connections>,
+    /// `None` for the primary, or the index of the replica.
+    replica: Option<usize>,
+}
+
+impl std::fmt::Debug for Db {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Db")
+            .field("replica", &self.replica)
+            .finish()
+    }
+}
+
+impl Deref for Db {
+    type Target = DbConn;
+
+    fn deref(&self) -> &DbConn {
+        match self.replica {
+            None => &self.conns.primary,
+            Some(i) => &self.conns.replicas[i],
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for Db {
+    fn get_database_backend(&self) -> DbBackend {
+        self.deref().get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.deref().execute(stmt).await
+    }
 
-static DB_CONN: once_cell::sync::OnceCell<DbConn> = once_cell::sync::OnceCell::new();
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.deref().execute_unprepared(sql).await
+    }
 
-pub async fn init_database(conn_uri: impl Into<String>) -> Result<(), Error> {
-    let conn = Database::connect(conn_uri.into()).await?;
-    DB_CONN.get_or_init(move || conn);
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.deref().query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.deref().query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.deref().support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.deref().is_mock_connection()
+    }
+}
+
+impl Connections {
+    fn all(&self) -> impl Iterator<Item = &DbConn> {
+        std::iter::once(&self.primary).chain(&self.replicas)
+    }
+
+    /// Closes the pools after the connections in use are released.
+    async fn close(&self) {
+        for conn in self.all() {
+            match conn {
+                DbConn::SqlxPostgresPoolConnection(_) => {
+                    conn.get_postgres_connection_pool().close().await
+                }
+                DbConn::SqlxSqlitePoolConnection(_) => {
+                    conn.get_sqlite_connection_pool().close().await
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns the PostgreSQL `uri` with the query parameters in `params`,
+/// replacing the ones in it. Other URIs are returned as is.
+fn with_params(uri: &str, params: &[(&str, String)]) -> Result<String, Error> {
+    let mut url = Url::parse(uri)?;
+    if params.is_empty() || !url.scheme().starts_with("postgres") {
+        return Ok(uri.to_string());
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| params.iter().all(|(key, _)| k != key))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .extend_pairs(params);
+    Ok(url.into())
+}
+
+fn connect_options(uri: &str, options: &DatabaseOptions) -> Result<ConnectOptions, Error> {
+    let mut params = Vec::new();
+    if let Some(ssl_mode) = options.ssl_mode {
+        params.push(("sslmode", ssl_mode.to_string()));
+    }
+    if let Some(path) = &options.ssl_root_cert {
+        params.push(("sslrootcert", path.to_owned()));
+    }
+    if let Some(timeout) = options.statement_timeout {
+        params.push((
+            "options[statement_timeout]",
+            timeout.as_millis().to_string(),
+        ));
+    }
+    let mut opt = ConnectOptions::new(with_params(uri, &params)?);
+    if let Some(n) = options.max_connections {
+        opt.max_connections(n);
+    }
+    if let Some(n) = options.min_connections {
+        opt.min_connections(n);
+    }
+    if let Some(timeout) = options.connect_timeout {
+        opt.connect_timeout(timeout);
+    }
+    if let Some(timeout) = options.acquire_timeout {
+        opt.acquire_timeout(timeout);
+    }
+    if let Some(timeout) = options.idle_timeout {
+        opt.idle_timeout(timeout);
+    }
+    if let Some(lifetime) = options.max_lifetime {
+        opt.max_lifetime(lifetime);
+    }
+    opt.sqlx_logging(options.statement_logging);
+    Ok(opt)
+}
+
+/// Connects to the primary and the replicas in `options`. Calling it again
+/// replaces the connections, e.g. after a failover, and closes the old ones.
+#[tracing::instrument(skip_all, fields(replicas = options.replicas.len()))]
+pub async fn init_database(
+    conn_uri: impl Into<String>,
+    options: &DatabaseOptions,
+) -> Result<(), Error> {
+    let primary = Database::connect(connect_options(&conn_uri.into(), options)?).await?;
+    let mut replicas = Vec::with_capacity(options.replicas.len());
+    for uri in &options.replicas {
+        replicas.push(Database::connect(connect_options(uri, options)?).await?);
+    }
+    let conns = Arc::new(Connections {
+        primary,
+        replicas,
+        next_replica: AtomicUsize::new(0),
+    });
+    let old = DB_CONN.write().unwrap().replace(conns);
+    tracing::info!("Connected to the database");
+    if let Some(old) = old {
+        old.close().await;
+        tracing::info!("Closed the previous database connections");
+    }
     Ok(())
 }
 
-pub fn get_database() -> Result<&'static DbConn, Error> {
-    DB_CONN.get().ok_or(Error::Uninitialized)
+/// Closes the connections, after which [get_database] fails until
+/// [init_database] is called again.
+#[tracing::instrument]
+pub async fn close_database() {
+    let old = DB_CONN.write().unwrap().take();
+    if let Some(old) = old {
+        old.close().await;
+        tracing::info!("Closed the database connections");
+    }
+}
+
+/// Returns the connection to the primary, which must be used for writes and
+/// for reads that need to see them.
+pub fn get_database() -> Result<Db, Error> {
+    let conns = get_connections()?;
+    Ok(Db {
+        conns,
+        replica: None,
+    })
+}
+
+/// Returns the connection to one of the read replicas in turn, or to the
+/// primary if there are none. Replicas may lag behind the primary.
+pub fn get_read_database() -> Result<Db, Error> {
+    let conns = get_connections()?;
+    let replica = match conns.replicas.len() {
+        0 => None,
+        n => Some(conns.next_replica.fetch_add(1, Ordering::Relaxed) % n),
+    };
+    Ok(Db { conns, replica })
+}
+
+/// Returns the round trip time of a trivial query.
+#[tracing::instrument(skip_all)]
+async fn ping(conn: &DbConn) -> Result<Duration, Error> {
+    let start = Instant::now();
+    conn.execute(Statement::from_string(
+        conn.get_database_backend(),
+        "SELECT 1".to_string(),
+    ))
+    .await?;
+    Ok(start.elapsed())
+}
+
+/// Returns the round trip time of a trivial query to the primary.
+pub async fn ping_database() -> Result<Duration, Error> {
+    let db = get_database()?;
+    ping(&db).await
+}
+
+/// State of the pool of the primary or a replica.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolHealth {
+    /// Round trip time of a trivial query, or the error of it.
+    pub latency: Result<Duration, String>,
+    /// Number of open connections, including the idle ones.
+    pub size: u32,
+    pub idle: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatabaseHealth {
+    pub primary: PoolHealth,
+    pub replicas: Vec<PoolHealth>,
+}
+
+impl DatabaseHealth {
+    pub fn is_healthy(&self) -> bool {
+        std::iter::once(&self.primary)
+            .chain(&self.replicas)
+            .all(|pool| pool.latency.is_ok())
+    }
+}
+
+/// Returns the numbers of open and idle connections of the pool.
+fn pool_size(conn: &DbConn) -> (u32, u32) {
+    let (size, idle) = match conn {
+        DbConn::SqlxPostgresPoolConnection(_) => {
+            let pool = conn.get_postgres_connection_pool();
+            (pool.size(), pool.num_idle())
+        }
+        DbConn::SqlxSqlitePoolConnection(_) => {
+            let pool = conn.get_sqlite_connection_pool();
+            (pool.size(), pool.num_idle())
+        }
+        _ => (0, 0),
+    };
+    (size, idle as u32)
+}
+
+/// Returns the numbers of open and idle connections of the primary, followed
+/// by the ones of the replicas, without pinging them.
+pub fn pool_sizes() -> Result<Vec<(u32, u32)>, Error> {
+    Ok(get_connections()?.all().map(pool_size).collect())
+}
+
+async fn pool_health(conn: &DbConn) -> PoolHealth {
+    let latency = ping(conn).await.map_err(|e| e.to_string());
+    let (size, idle) = pool_size(conn);
+    PoolHealth {
+        latency,
+        size,
+        idle,
+    }
+}
+
+/// Pings the primary and every replica, and reports the state of the pools.
+pub async fn check_database_health() -> Result<DatabaseHealth, Error> {
+    let conns = get_connections()?;
+    let primary = pool_health(&conns.primary).await;
+    let mut replicas = Vec::with_capacity(conns.replicas.len());
+    for replica in &conns.replicas {
+        replicas.push(pool_health(replica).await);
+    }
+    Ok(DatabaseHealth { primary, replicas })
 }
 
 cfg_if! {
     if #[cfg(feature = "napi")] {
         use napi_derive::napi;
 
+        /// For NAPI because [Duration] is not supported. Timeouts are in
+        /// milliseconds.
+        #[napi(object)]
+        #[derive(Default)]
+        pub struct NativeDatabaseOptions {
+            pub max_connections: Option<u32>,
+            pub min_connections: Option<u32>,
+            pub connect_timeout: Option<u32>,
+            pub acquire_timeout: Option<u32>,
+            pub idle_timeout: Option<u32>,
+            pub max_lifetime: Option<u32>,
+            pub statement_logging: Option<bool>,
+            pub ssl_mode: Option<String>,
+            pub ssl_root_cert: Option<String>,
+            pub statement_timeout: Option<u32>,
+            pub replicas: Option<Vec<String>>,
+        }
+
+        impl TryFrom<NativeDatabaseOptions> for DatabaseOptions {
+            type Error = Error;
+
+            fn try_from(options: NativeDatabaseOptions) -> Result<Self, Self::Error> {
+                let millis = |ms: Option<u32>| ms.map(|ms| Duration::from_millis(ms.into()));
+                let ssl_mode = match options.ssl_mode {
+                    None => None,
+                    Some(mode) => Some(mode.parse().map_err(|_| Error::InvalidSslMode(mode))?),
+                };
+                Ok(Self {
+                    max_connections: options.max_connections,
+                    min_connections: options.min_connections,
+                    connect_timeout: millis(options.connect_timeout),
+                    acquire_timeout: millis(options.acquire_timeout),
+                    idle_timeout: millis(options.idle_timeout),
+                    max_lifetime: millis(options.max_lifetime),
+                    statement_logging: options.statement_logging.unwrap_or_default(),
+                    ssl_mode,
+                    ssl_root_cert: options.ssl_root_cert,
+                    statement_timeout: millis(options.statement_timeout),
+                    replicas: options.replicas.unwrap_or_default(),
+                })
+            }
+        }
+
         #[napi]
-        pub async fn native_init_database(conn_uri: String) -> napi::Result<()> {
-            init_database(conn_uri).await.map_err(Into::into)
+        pub async fn native_init_database(
+            conn_uri: String,
+            options: Option<NativeDatabaseOptions>,
+        ) -> napi::Result<()> {
+            let options = DatabaseOptions::try_from(options.unwrap_or_default())
+                .map_err(Into::<napi::Error>::into)?;
+            init_database(conn_uri, &options).await.map_err(Into::into)
+        }
+
+        #[napi]
+        pub async fn native_close_database() {
+            close_database().await
+        }
+
+        /// Returns the round trip time to the primary in milliseconds.
+        #[napi]
+        pub async fn native_ping_database() -> napi::Result<f64> {
+            let latency = ping_database().await.map_err(Into::<napi::Error>::into)?;
+            Ok(latency.as_secs_f64() * 1000.0)
+        }
+
+        /// For NAPI because [Duration] and [Result] are not supported. The
+        /// latency is in milliseconds.
+        #[napi(object)]
+        pub struct NativePoolHealth {
+            pub latency: Option<f64>,
+            pub error: Option<String>,
+            pub size: u32,
+            pub idle: u32,
+        }
+
+        impl From<PoolHealth> for NativePoolHealth {
+            fn from(health: PoolHealth) -> Self {
+                let (latency, error) = match health.latency {
+                    Ok(latency) => (Some(latency.as_secs_f64() * 1000.0), None),
+                    Err(e) => (None, Some(e)),
+                };
+                Self {
+                    latency,
+                    error,
+                    size: health.size,
+                    idle: health.idle,
+                }
+            }
+        }
+
+        #[napi(object)]
+        pub struct NativeDatabaseHealth {
+            pub healthy: bool,
+            pub primary: NativePoolHealth,
+            pub replicas: Vec<NativePoolHealth>,
+        }
+
+        #[napi]
+        pub async fn native_check_database_health() -> napi::Result<NativeDatabaseHealth> {
+            let health = check_database_health().await.map_err(Into::<napi::Error>::into)?;
+            Ok(NativeDatabaseHealth {
+                healthy: health.is_healthy(),
+                primary: health.primary.into(),
+                replicas: health.replicas.into_iter().map(Into::into).collect(),
+            })
         }
     }
 }
 
 #[cfg(test)]
 mod unit_test {
-    use super::{error::Error, get_database};
+    use pretty_assertions::assert_eq;
+
+    use super::{
+        connect_options, error::Error, get_database, get_read_database, with_params,
+        DatabaseOptions, SslMode,
+    };
 
     #[test]
     fn error_uninitialized() {
         assert_eq!(get_database().unwrap_err(), Error::Uninitialized);
+        assert_eq!(get_read_database().unwrap_err(), Error::Uninitialized);
+    }
+
+    #[test]
+    fn uri_params() {
+        let ssl_mode = |mode: SslMode| [("sslmode", mode.to_string())];
+        assert_eq!("verify-full".parse(), Ok(SslMode::VerifyFull));
+        assert!("verify_full".parse::<SslMode>().is_err());
+        assert_eq!(
+            with_params(
+                "postgres://u:p@localhost:5432/db",
+                &ssl_mode(SslMode::Require)
+            )
+            .unwrap(),
+            "postgres://u:p@localhost:5432/db?sslmode=require"
+        );
+        assert_eq!(
+            with_params(
+                "postgres://localhost/db?sslmode=disable&application_name=a",
+                &ssl_mode(SslMode::VerifyCa)
+            )
+            .unwrap(),
+            "postgres://localhost/db?application_name=a&sslmode=verify-ca"
+        );
+        assert_eq!(
+            with_params(
+                "postgres://localhost/db",
+                &[("options[statement_timeout]", "10000".to_string())]
+            )
+            .unwrap(),
+            "postgres://localhost/db?options%5Bstatement_timeout%5D=10000"
+        );
+        assert_eq!(
+            with_params("sqlite::memory:", &ssl_mode(SslMode::Require)).unwrap(),
+            "sqlite::memory:"
+        );
+
+        let options = DatabaseOptions {
+            ssl_mode: Some(SslMode::VerifyFull),
+            ssl_root_cert: Some("/tmp/ca.pem".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            connect_options("postgres://localhost/db", &options)
+                .unwrap()
+                .get_url(),
+            "postgres://localhost/db?sslmode=verify-full&sslrootcert=%2Ftmp%2Fca.pem"
+        );
     }
 }
@@ -0,0 +1,278 @@
+//! Sharing of the stored objects among the drive files with the same content,
+//! which are found by `md5` and `size`.
+//!
+//! The rows sharing objects in the object storage have the same access keys,
+//! and `drive_blob` counts the references to the objects by `access_key`.
+//! Files stored before the counting have no entries and are referenced only
+//! by themselves. An entry whose count drops to zero is kept until no file
+//! has its key, so that the deleted objects are not shared in the meantime.
+//!
+//! The objects in the internal storage are hard-linked to new keys instead,
+//! as `server/file/send-drive-file.ts` finds the file to serve by its key.
+
+use cfg_if::cfg_if;
+use sea_orm::sea_query::{Expr, OnConflict, Query};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QueryResult, Statement,
+};
+
+use super::error::Error;
+use super::storage::{FileKind, InternalStorage, Storage};
+use crate::database;
+use crate::metrics;
+use crate::model::entity::{drive_blob, drive_file};
+
+/// Stored objects of a drive file, which are copied to the files sharing
+/// them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Blob {
+    pub stored_internal: bool,
+    pub url: String,
+    pub thumbnail_url: Option<String>,
+    pub webpublic_url: Option<String>,
+    pub access_key: String,
+    pub thumbnail_access_key: Option<String>,
+    pub webpublic_access_key: Option<String>,
+    pub webpublic_type: Option<String>,
+}
+
+/// Adds `delta` to the count of `key`, inserting `initial` if there is no
+/// entry. The count is left as is if `only_positive` and it is zero.
+/// Returns the new count, or [None] if it was left.
+async fn add_ref_count(
+    key: &str,
+    initial: i32,
+    delta: i32,
+    only_positive: bool,
+) -> Result<Option<i32>, Error> {
+    let ref_count = (drive_blob::Entity, drive_blob::Column::RefCount);
+    let mut on_conflict = OnConflict::column(drive_blob::Column::Key);
+    on_conflict.value(
+        drive_blob::Column::RefCount,
+        Expr::col(ref_count).add(delta),
+    );
+    if only_positive {
+        on_conflict.action_and_where(Expr::col(ref_count).gt(0));
+    }
+    let query = Query::insert()
+        .into_table(drive_blob::Entity)
+        .columns([drive_blob::Column::Key, drive_blob::Column::RefCount])
+        .values_panic([key.into(), initial.into()])
+        .on_conflict(on_conflict)
+        .returning_col(drive_blob::Column::RefCount)
+        .to_owned();
+
+    let db = &database::get_database()?;
+    let stmt: Statement = db.get_database_backend().build(&query);
+    let row: Option<QueryResult> = db.query_one(stmt).await?;
+    Ok(row.map(|row| row.try_get("", "refCount")).transpose()?)
+}
+
+/// Finds the stored objects of a file with the same content and takes a
+/// reference to them. Returns [None] if there is no such file, and then the
+/// objects have to be stored as usual.
+///
+/// If `generate_web`, which is the case of local files, only the objects of
+/// files that had their webpublic image generated are shared, so that the
+/// metadata is stripped. Objects in `internal` are shared by new keys.
+#[tracing::instrument(skip(internal))]
+pub async fn acquire(
+    internal: &InternalStorage,
+    md5: &str,
+    size: i32,
+    generate_web: bool,
+) -> Result<Option<Blob>, Error> {
+    let db = &database::get_database()?;
+    let mut query = drive_file::Entity::find()
+        .filter(drive_file::Column::Md5.eq(md5))
+        .filter(drive_file::Column::Size.eq(size))
+        .filter(drive_file::Column::IsLink.eq(false))
+        .filter(drive_file::Column::AccessKey.is_not_null());
+    if generate_web {
+        query = query.filter(drive_file::Column::Uri.is_null());
+    }
+    let query = query.order_by_desc(drive_file::Column::Id).one(db);
+    let Some(file) = metrics::observe_query("drive_blob", query).await? else {
+        return Ok(None);
+    };
+    let Some(access_key) = file.access_key.to_owned() else {
+        return Ok(None);
+    };
+    if file.stored_internal {
+        return link_internal(internal, file, access_key).await;
+    }
+
+    // the file found and the new one, unless the objects are being deleted
+    let Some(ref_count) = add_ref_count(&access_key, 2, 1, true).await? else {
+        return Ok(None);
+    };
+    tracing::debug!(access_key, ref_count, "Shared the objects");
+
+    Ok(Some(Blob {
+        stored_internal: file.stored_internal,
+        url: file.url,
+        thumbnail_url: file.thumbnail_url,
+        webpublic_url: file.webpublic_url,
+        access_key,
+        thumbnail_access_key: file.thumbnail_access_key,
+        webpublic_access_key: file.webpublic_access_key,
+        webpublic_type: file.webpublic_type,
+    }))
+}
+
+/// Links the objects of the internal `file` to new keys. Returns [None] if
+/// they can not be linked, e.g. because they are being deleted.
+async fn link_internal(
+    internal: &InternalStorage,
+    file: drive_file::Model,
+    access_key: String,
+) -> Result<Option<Blob>, Error> {
+    let mut linked = Vec::new();
+    let keys = async {
+        let thumbnail_key = file.thumbnail_url.and(file.thumbnail_access_key);
+        let webpublic_key = file.webpublic_url.and(file.webpublic_access_key);
+        let access_key = link(internal, &mut linked, FileKind::Original, &access_key).await?;
+        let thumbnail_access_key = match thumbnail_key {
+            Some(key) => Some(link(internal, &mut linked, FileKind::Thumbnail, &key).await?),
+            None => None,
+        };
+        let webpublic_access_key = match webpublic_key {
+            Some(key) => Some(link(internal, &mut linked, FileKind::Webpublic, &key).await?),
+            None => None,
+        };
+        Ok::<_, Error>((access_key, thumbnail_access_key, webpublic_access_key))
+    }
+    .await;
+
+    let (access_key, thumbnail_access_key, webpublic_access_key) = match keys {
+        Ok(keys) => keys,
+        Err(e) => {
+            tracing::debug!(id = file.id, "Failed to link the objects: {}", e);
+            for key in linked {
+                internal.delete(&key).await?;
+            }
+            return Ok(None);
+        }
+    };
+    tracing::debug!(id = file.id, access_key, "Linked the objects");
+
+    Ok(Some(Blob {
+        stored_internal: true,
+        url: internal.url(&access_key),
+        thumbnail_url: thumbnail_access_key.as_ref().map(|k| internal.url(k)),
+        webpublic_url: webpublic_access_key.as_ref().map(|k| internal.url(k)),
+        access_key,
+        thumbnail_access_key,
+        webpublic_access_key,
+        webpublic_type: file.webpublic_type,
+    }))
+}
+
+/// Links the object of `key` to a new key of `kind`, which is also pushed to
+/// `linked`.
+async fn link(
+    internal: &InternalStorage,
+    linked: &mut Vec<String>,
+    kind: FileKind,
+    key: &str,
+) -> Result<String, Error> {
+    let new_key = internal.generate_key(kind, None);
+    internal.link(key, &new_key).await?;
+    linked.push(new_key.to_owned());
+    Ok(new_key)
+}
+
+/// Drops a reference to the objects of `access_key`. Returns whether it was
+/// the last one, in which case the objects have to be deleted.
+#[tracing::instrument]
+pub async fn release(access_key: &str) -> Result<bool, Error> {
+    let ref_count = add_ref_count(access_key, 0, -1, false).await?;
+    Ok(ref_count.is_none_or(|count| count <= 0))
+}
+
+/// Deletes the entries of deleted objects that no file has the key of.
+/// Returns the number of the deleted entries.
+#[tracing::instrument]
+pub async fn prune() -> Result<u64, Error> {
+    let referenced = Query::select()
+        .expr(Expr::val(1))
+        .from(drive_file::Entity)
+        .and_where(
+            Expr::col((drive_file::Entity, drive_file::Column::AccessKey))
+                .equals((drive_blob::Entity, drive_blob::Column::Key)),
+        )
+        .and_where(drive_file::Column::IsLink.eq(false))
+        .to_owned();
+    let result = drive_blob::Entity::delete_many()
+        .filter(drive_blob::Column::RefCount.lte(0))
+        .filter(Expr::exists(referenced).not())
+        .exec(&database::get_database()?)
+        .await?;
+    Ok(result.rows_affected)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        use super::get_drive_config;
+
+        #[napi(object)]
+        pub struct NativeDriveBlob {
+            pub stored_internal: bool,
+            pub url: String,
+            pub thumbnail_url: Option<String>,
+            pub webpublic_url: Option<String>,
+            pub access_key: String,
+            pub thumbnail_access_key: Option<String>,
+            pub webpublic_access_key: Option<String>,
+            pub webpublic_type: Option<String>,
+        }
+
+        impl From<Blob> for NativeDriveBlob {
+            fn from(blob: Blob) -> Self {
+                Self {
+                    stored_internal: blob.stored_internal,
+                    url: blob.url,
+                    thumbnail_url: blob.thumbnail_url,
+                    webpublic_url: blob.webpublic_url,
+                    access_key: blob.access_key,
+                    thumbnail_access_key: blob.thumbnail_access_key,
+                    webpublic_access_key: blob.webpublic_access_key,
+                    webpublic_type: blob.webpublic_type,
+                }
+            }
+        }
+
+        /// Calls [acquire] inside. The reference has to be released with
+        /// [native_release_drive_blob] if the new file is not inserted.
+        #[napi]
+        pub async fn native_acquire_drive_blob(
+            md5: String,
+            size: i32,
+            generate_web: bool,
+        ) -> napi::Result<Option<NativeDriveBlob>> {
+            let internal = InternalStorage::new(get_drive_config());
+            match acquire(&internal, &md5, size, generate_web).await {
+                Ok(blob) => Ok(blob.map(Into::into)),
+                Err(e) => Err(e.into()),
+            }
+        }
+
+        /// Calls [release] inside, and resolves to whether the objects have
+        /// to be deleted.
+        #[napi]
+        pub async fn native_release_drive_blob(access_key: String) -> napi::Result<bool> {
+            release(&access_key).await.map_err(Into::into)
+        }
+
+        /// Calls [prune] inside.
+        #[napi]
+        pub async fn native_prune_drive_blobs() -> napi::Result<i64> {
+            match prune().await {
+                Ok(count) => Ok(count as i64),
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+}
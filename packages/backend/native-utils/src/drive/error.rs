@@ -0,0 +1,67 @@
+use crate::cache::error::redis_error_code;
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Image error: {0}")]
+    ImageError(#[from] image::ImageError),
+    #[error("Image has {0} pixels, which exceeds the limit")]
+    TooManyPixels(u64),
+    #[error("Failed to encode WebP: {0}")]
+    WebpError(String),
+    #[error("Failed to get database connection: {0}")]
+    DbConnError(#[from] crate::database::error::Error),
+    #[error("Database operation error: {0}")]
+    DbOperationError(#[from] sea_orm::DbErr),
+    #[error("Failed to get cache connection: {0}")]
+    CacheConnError(#[from] crate::cache::error::Error),
+    #[error("Cache operation error: {0}")]
+    CacheOperationError(#[from] redis::RedisError),
+    #[error("No free space in the drive")]
+    NoFreeSpace,
+    #[error("Invalid key of the storage: {0}")]
+    InvalidKey(String),
+    #[error("Object storage is not configured properly: {0}")]
+    NotConfigured(&'static str),
+    #[error("HTTP error: {0}")]
+    HttpError(#[from] reqwest::Error),
+    #[error("Object storage responded with {status}: {message}")]
+    ObjectStorageError { status: u16, message: String },
+    #[error("Model error: {0}")]
+    ModelError(#[from] tract_onnx::prelude::TractError),
+    #[error("FFmpeg error: {0}")]
+    FfmpegError(String),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::IoError(e) if e.kind() == std::io::ErrorKind::NotFound => ErrorCode::NoSuchObject,
+            Self::ImageError(
+                image::ImageError::Decoding(_) | image::ImageError::Unsupported(_),
+            )
+            | Self::TooManyPixels(_)
+            | Self::InvalidKey(_) => ErrorCode::InvalidParam,
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::CacheConnError(e) => e.code(),
+            Self::CacheOperationError(e) => redis_error_code(e),
+            Self::NoFreeSpace => ErrorCode::NoFreeSpace,
+            Self::HttpError(e) if e.is_connect() || e.is_timeout() => ErrorCode::ServiceUnavailable,
+            Self::ObjectStorageError { status: 404, .. } => ErrorCode::NoSuchObject,
+            Self::IoError(_)
+            | Self::ImageError(_)
+            | Self::WebpError(_)
+            | Self::NotConfigured(_)
+            | Self::HttpError(_)
+            | Self::ObjectStorageError { .. }
+            | Self::ModelError(_)
+            | Self::FfmpegError(_) => ErrorCode::InternalError,
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
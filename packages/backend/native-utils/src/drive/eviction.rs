@@ -0,0 +1,477 @@
+//! Eviction of the cached files of remote users, replacing the sweep of
+//! `queue/processors/object-storage/clean-remote-files.ts`.
+//!
+//! The evicted files become links to their `uri` as the expired files of
+//! `services/drive/delete-file.ts` do, and are fetched again on demand. The
+//! last use of each file is cached as `driveFileAccess:<fileId>`, and the
+//! files used before are assumed to be last used when they were created.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cfg_if::cfg_if;
+use chrono::Utc;
+use redis::AsyncCommands;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use super::error::Error;
+use super::storage::{self, InternalStorage, Storage};
+use super::{blob, fetch_meta, get_drive_config, quota};
+use crate::cache;
+use crate::database;
+use crate::metrics;
+use crate::model::entity::drive_file;
+
+/// Expiration of the cached last uses in seconds. Files used before that are
+/// treated as if they were used when created.
+const ACCESS_TTL: usize = 90 * 24 * 60 * 60;
+/// Number of the files evicted concurrently, and of the last uses read at
+/// once.
+const BATCH_SIZE: usize = 100;
+/// Number of the cached files read from the database at once.
+const PAGE_SIZE: u64 = 1000;
+
+/// Which cached files to evict, least recently used first. Files matching
+/// either condition are evicted, and nothing is evicted if neither is set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EvictionPolicy {
+    /// Evicts the files not used for this long.
+    pub max_idle: Option<Duration>,
+    /// Evicts files until the rest fit in this many bytes.
+    pub budget: Option<i64>,
+}
+
+/// Result of an eviction, or of what would be evicted in a dry run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EvictionReport {
+    pub evicted_files: u32,
+    pub evicted_bytes: i64,
+    /// Files that were to be evicted but failed, which are still cached.
+    pub failed_files: u32,
+    pub kept_files: u32,
+    pub kept_bytes: i64,
+    /// The evicted files, for the drive charts. Empty in a dry run.
+    pub evicted: Vec<EvictedFile>,
+}
+
+/// A file turned into a link by [evict_file].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvictedFile {
+    pub id: String,
+    pub user_id: Option<String>,
+    pub user_host: Option<String>,
+    pub size: i32,
+}
+
+/// A cached file with the time of its last use in milliseconds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedFile {
+    pub id: String,
+    pub size: i32,
+    pub stored_internal: bool,
+    pub last_used: i64,
+}
+
+fn access_key(cache: &cache::Cache, file_id: &str) -> String {
+    cache.key(format!("driveFileAccess:{}", file_id))
+}
+
+/// Records that the file is used now, e.g. attached to a note.
+pub async fn record_access(file_id: &str) -> Result<(), Error> {
+    let cache = cache::get_cache()?;
+    cache
+        .conn()
+        .set_ex::<_, _, ()>(
+            access_key(cache, file_id),
+            Utc::now().timestamp_millis(),
+            ACCESS_TTL,
+        )
+        .await?;
+    Ok(())
+}
+
+/// Returns up to [PAGE_SIZE] cached files of remote users that can be
+/// fetched again, with the time of their creation as the last use. The files
+/// are ordered by ID, starting after `after`.
+pub async fn cached_files(after: Option<&str>) -> Result<Vec<CachedFile>, Error> {
+    let db = &database::get_read_database()?;
+    let mut query = drive_file::Entity::find();
+    if let Some(after) = after {
+        query = query.filter(drive_file::Column::Id.gt(after));
+    }
+    let query = query
+        .select_only()
+        .column(drive_file::Column::Id)
+        .column(drive_file::Column::Size)
+        .column(drive_file::Column::StoredInternal)
+        .column(drive_file::Column::CreatedAt)
+        .filter(drive_file::Column::UserHost.is_not_null())
+        .filter(drive_file::Column::IsLink.eq(false))
+        .filter(drive_file::Column::Uri.is_not_null())
+        .order_by_asc(drive_file::Column::Id)
+        .limit(PAGE_SIZE)
+        .into_tuple::<(String, i32, bool, chrono::DateTime<chrono::FixedOffset>)>()
+        .all(db);
+    let files = metrics::observe_query("drive_cached_files", query).await?;
+    Ok(files
+        .into_iter()
+        .map(|(id, size, stored_internal, created_at)| CachedFile {
+            id,
+            size,
+            stored_internal,
+            last_used: created_at.timestamp_millis(),
+        })
+        .collect())
+}
+
+/// Updates the last uses of the files with the recorded ones.
+async fn load_last_uses(files: &mut [CachedFile]) -> Result<(), Error> {
+    let cache = cache::get_cache()?;
+    let mut conn = cache.conn();
+    for files in files.chunks_mut(BATCH_SIZE) {
+        let keys: Vec<String> = files.iter().map(|f| access_key(cache, &f.id)).collect();
+        let used: Vec<Option<i64>> = redis::cmd("MGET").arg(keys).query_async(&mut conn).await?;
+        for (file, used) in files.iter_mut().zip(used) {
+            file.last_used = file.last_used.max(used.unwrap_or(0));
+        }
+    }
+    Ok(())
+}
+
+/// Splits the files into the ones to evict and the ones to keep, both least
+/// recently used first. `now` is in milliseconds.
+fn select(
+    mut files: Vec<CachedFile>,
+    policy: &EvictionPolicy,
+    now: i64,
+) -> (Vec<CachedFile>, Vec<CachedFile>) {
+    files.sort_by(|a, b| a.last_used.cmp(&b.last_used).then(a.id.cmp(&b.id)));
+    let idle_before = policy
+        .max_idle
+        .map(|idle| now - i64::try_from(idle.as_millis()).unwrap_or(i64::MAX));
+    let mut remaining: i64 = files.iter().map(|f| i64::from(f.size)).sum();
+
+    let split = files
+        .iter()
+        .position(|file| {
+            let idle = idle_before.is_some_and(|before| file.last_used < before);
+            let over_budget = policy.budget.is_some_and(|budget| remaining > budget);
+            remaining -= i64::from(file.size);
+            !(idle || over_budget)
+        })
+        .unwrap_or(files.len());
+    let kept = files.split_off(split);
+    (files, kept)
+}
+
+/// Deletes the objects of the file unless they are shared, and turns it into
+/// a link to its `uri`.
+pub async fn evict_file(
+    storage: &dyn Storage,
+    file: drive_file::Model,
+) -> Result<EvictedFile, Error> {
+    if let Some(key) = &file.access_key {
+        if blob::release(key).await? {
+            let alts = [
+                (&file.thumbnail_access_key, &file.thumbnail_url),
+                (&file.webpublic_access_key, &file.webpublic_url),
+            ];
+            let alt_keys = alts
+                .into_iter()
+                .filter_map(|(key, url)| url.as_ref().and(key.as_ref()));
+            for key in std::iter::once(key).chain(alt_keys) {
+                storage.delete(key).await?;
+            }
+        }
+    }
+
+    let evicted = EvictedFile {
+        id: file.id.to_owned(),
+        user_id: file.user_id.to_owned(),
+        user_host: file.user_host.to_owned(),
+        size: file.size,
+    };
+    let uri = file.uri.to_owned().unwrap_or_default();
+    let key = Uuid::new_v4();
+    let mut active = file.into_active_model();
+    active.is_link = Set(true);
+    active.url = Set(uri);
+    active.thumbnail_url = Set(None);
+    active.webpublic_url = Set(None);
+    active.stored_internal = Set(false);
+    // for the local proxy
+    active.access_key = Set(Some(key.to_string()));
+    active.thumbnail_access_key = Set(Some(format!("thumbnail-{}", key)));
+    active.webpublic_access_key = Set(Some(format!("webpublic-{}", key)));
+    active.update(&database::get_database()?).await?;
+
+    // the cache is fixed later by the reconciliation if this fails
+    let forgotten = async {
+        if let Some(user_id) = &evicted.user_id {
+            quota::update_usage(user_id, -i64::from(evicted.size)).await?;
+        }
+        let cache = cache::get_cache()?;
+        cache
+            .conn()
+            .del::<_, ()>(access_key(cache, &evicted.id))
+            .await?;
+        Ok::<_, Error>(())
+    };
+    if let Err(e) = forgotten.await {
+        tracing::warn!(
+            evicted.id,
+            "Failed to update the cache of the evicted file: {}",
+            e
+        );
+    }
+    Ok(evicted)
+}
+
+/// Evicts the cached files of remote users according to the policy, in
+/// batches. Nothing is evicted if `dry_run`. The files are read by pages, but
+/// all of them are kept to be ordered by their last uses.
+#[tracing::instrument]
+pub async fn evict(policy: EvictionPolicy, dry_run: bool) -> Result<EvictionReport, Error> {
+    let mut files: Vec<CachedFile> = Vec::new();
+    loop {
+        let mut page = cached_files(files.last().map(|f| f.id.as_str())).await?;
+        if page.is_empty() {
+            break;
+        }
+        load_last_uses(&mut page).await?;
+        files.append(&mut page);
+    }
+    let (evicted, kept) = select(files, &policy, Utc::now().timestamp_millis());
+
+    let mut report = EvictionReport {
+        kept_files: kept.len() as u32,
+        kept_bytes: kept.iter().map(|f| i64::from(f.size)).sum(),
+        ..Default::default()
+    };
+    if dry_run {
+        report.evicted_files = evicted.len() as u32;
+        report.evicted_bytes = evicted.iter().map(|f| i64::from(f.size)).sum();
+        return Ok(report);
+    }
+
+    let internal: Arc<dyn Storage> = Arc::new(InternalStorage::new(get_drive_config()));
+    let object: Option<Arc<dyn Storage>> = match evicted.iter().any(|f| !f.stored_internal) {
+        true => Some(storage::object_storage(&fetch_meta().await?)?),
+        false => None,
+    };
+
+    let db = &database::get_database()?;
+    for batch in evicted.chunks(BATCH_SIZE) {
+        let ids = batch.iter().map(|f| f.id.to_owned());
+        // skip the files deleted or evicted in the meantime
+        let files = drive_file::Entity::find()
+            .filter(drive_file::Column::Id.is_in(ids))
+            .filter(drive_file::Column::IsLink.eq(false))
+            .all(db)
+            .await?;
+        report.failed_files += (batch.len() - files.len()) as u32;
+
+        let mut tasks = JoinSet::new();
+        for file in files {
+            let storage = match (file.stored_internal, &object) {
+                (true, _) => internal.clone(),
+                (false, Some(object)) => object.clone(),
+                (false, None) => {
+                    report.failed_files += 1;
+                    continue;
+                }
+            };
+            tasks.spawn(async move {
+                let id = file.id.to_owned();
+                (id, evict_file(storage.as_ref(), file).await)
+            });
+        }
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok((_, Ok(file))) => {
+                    report.evicted_files += 1;
+                    report.evicted_bytes += i64::from(file.size);
+                    report.evicted.push(file);
+                }
+                Ok((id, Err(e))) => {
+                    tracing::warn!(id, "Failed to evict the file: {}", e);
+                    report.failed_files += 1;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to evict a file: {}", e);
+                    report.failed_files += 1;
+                }
+            }
+        }
+    }
+
+    tracing::info!(
+        report.evicted_files,
+        report.evicted_bytes,
+        report.failed_files,
+        "Evicted cached remote files"
+    );
+    Ok(report)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeEvictionPolicy {
+            /// Evicts the files not used for this many days.
+            pub max_idle_days: Option<u32>,
+            /// Evicts files until the rest fit in this many bytes.
+            pub budget: Option<i64>,
+        }
+
+        impl From<NativeEvictionPolicy> for EvictionPolicy {
+            fn from(policy: NativeEvictionPolicy) -> Self {
+                Self {
+                    max_idle: policy
+                        .max_idle_days
+                        .map(|days| Duration::from_secs(u64::from(days) * 24 * 60 * 60)),
+                    budget: policy.budget,
+                }
+            }
+        }
+
+        #[napi(object)]
+        pub struct NativeEvictedFile {
+            pub id: String,
+            pub user_id: Option<String>,
+            pub user_host: Option<String>,
+            pub size: i32,
+        }
+
+        impl From<EvictedFile> for NativeEvictedFile {
+            fn from(file: EvictedFile) -> Self {
+                Self {
+                    id: file.id,
+                    user_id: file.user_id,
+                    user_host: file.user_host,
+                    size: file.size,
+                }
+            }
+        }
+
+        #[napi(object)]
+        pub struct NativeEvictionReport {
+            pub evicted_files: u32,
+            pub evicted_bytes: i64,
+            pub failed_files: u32,
+            pub kept_files: u32,
+            pub kept_bytes: i64,
+            pub evicted: Vec<NativeEvictedFile>,
+        }
+
+        impl From<EvictionReport> for NativeEvictionReport {
+            fn from(report: EvictionReport) -> Self {
+                Self {
+                    evicted_files: report.evicted_files,
+                    evicted_bytes: report.evicted_bytes,
+                    failed_files: report.failed_files,
+                    kept_files: report.kept_files,
+                    kept_bytes: report.kept_bytes,
+                    evicted: report.evicted.into_iter().map(Into::into).collect(),
+                }
+            }
+        }
+
+        /// Records that the drive file is used now.
+        #[napi]
+        pub async fn native_record_drive_file_access(file_id: String) -> napi::Result<()> {
+            record_access(&file_id).await.map_err(Into::into)
+        }
+
+        /// Calls [evict] inside.
+        #[napi]
+        pub async fn native_evict_remote_files(
+            policy: NativeEvictionPolicy,
+            dry_run: bool,
+        ) -> napi::Result<NativeEvictionReport> {
+            match evict(policy.into(), dry_run).await {
+                Ok(report) => Ok(report.into()),
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{select, CachedFile, EvictionPolicy};
+
+    fn ids(files: &[CachedFile]) -> Vec<&str> {
+        files.iter().map(|f| f.id.as_str()).collect()
+    }
+
+    #[test]
+    fn select_least_recently_used() {
+        let file = |id: &str, size, last_used| CachedFile {
+            id: id.to_string(),
+            size,
+            stored_internal: true,
+            last_used,
+        };
+        let files = vec![
+            file("a", 100, 4_000),
+            file("b", 200, 1_000),
+            file("c", 300, 3_000),
+            file("d", 400, 2_000),
+        ];
+
+        let (evicted, kept) = select(files.clone(), &EvictionPolicy::default(), 5_000);
+        assert_eq!(
+            (ids(&evicted), ids(&kept)),
+            (vec![], vec!["b", "d", "c", "a"])
+        );
+
+        let lru = EvictionPolicy {
+            max_idle: Some(Duration::from_secs(2)),
+            budget: None,
+        };
+        let (evicted, kept) = select(files.clone(), &lru, 5_000);
+        assert_eq!(
+            (ids(&evicted), ids(&kept)),
+            (vec!["b", "d"], vec!["c", "a"])
+        );
+
+        let budget = EvictionPolicy {
+            max_idle: None,
+            budget: Some(400),
+        };
+        let (evicted, kept) = select(files.clone(), &budget, 5_000);
+        assert_eq!(
+            (ids(&evicted), ids(&kept)),
+            (vec!["b", "d"], vec!["c", "a"])
+        );
+
+        let both = EvictionPolicy {
+            max_idle: Some(Duration::from_secs(1)),
+            budget: Some(400),
+        };
+        let (evicted, _) = select(files.clone(), &both, 5_000);
+        assert_eq!(ids(&evicted), vec!["b", "d", "c"]);
+
+        let all = EvictionPolicy {
+            max_idle: None,
+            budget: Some(0),
+        };
+        let (evicted, kept) = select(files, &all, 5_000);
+        assert_eq!(
+            (ids(&evicted), ids(&kept)),
+            (vec!["b", "d", "c", "a"], vec![])
+        );
+    }
+}
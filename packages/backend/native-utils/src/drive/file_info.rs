@@ -0,0 +1,469 @@
+//! Port of `misc/get-file-info.ts`, except for the detection of sensitive
+//! media. The file is read once; only images and possible SVG files are kept
+//! in memory to read their dimensions and encode their blurhash.
+
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use cfg_if::cfg_if;
+use md5::{Digest, Md5};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::error::Error;
+
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+/// Larger files are not checked for SVG, as in `checkSvg`.
+const MAX_SVG_SIZE: u64 = 1024 * 1024;
+/// Images wider or taller than this are treated as
+/// `application/octet-stream`.
+const MAX_IMAGE_DIMENSION: u32 = 16383;
+/// Images are downscaled to fit in this size before encoding the blurhash.
+const BLURHASH_IMAGE_SIZE: u32 = 64;
+const BLURHASH_COMPONENTS: u32 = 7;
+
+/// Types of which the dimensions are read.
+const SIZED_TYPES: [&str; 10] = [
+    "image/jpeg",
+    "image/gif",
+    "image/png",
+    "image/apng",
+    "image/webp",
+    "image/bmp",
+    "image/tiff",
+    "image/svg+xml",
+    "image/vnd.adobe.photoshop",
+    "image/avif",
+];
+
+/// Types of which the blurhash is encoded. The blurhash of SVG and AVIF,
+/// which the `image` crate can not decode, is encoded with sharp by
+/// `misc/get-file-info.ts`.
+const BLURHASH_TYPES: [&str; 5] = [
+    "image/jpeg",
+    "image/gif",
+    "image/png",
+    "image/apng",
+    "image/webp",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileType {
+    pub mime: String,
+    pub ext: Option<String>,
+}
+
+impl FileType {
+    fn new(mime: &str, ext: &str) -> Self {
+        Self {
+            mime: mime.to_string(),
+            ext: Some(ext.to_string()),
+        }
+    }
+
+    fn octet_stream() -> Self {
+        Self {
+            mime: "application/octet-stream".to_string(),
+            ext: None,
+        }
+    }
+
+    fn svg() -> Self {
+        Self::new("image/svg+xml", "svg")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileInfo {
+    pub size: u64,
+    /// Hex digest of the contents.
+    pub md5: String,
+    pub file_type: FileType,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Value of the EXIF orientation tag, from 1 to 8.
+    pub orientation: Option<u32>,
+    pub blurhash: Option<String>,
+    /// Steps that failed without failing the whole probe.
+    pub warnings: Vec<String>,
+}
+
+/// Reads until `buf` is full or the end of the file.
+fn read_full(file: &mut File, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match file.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// Probes the file at `path`, which is blocking.
+pub fn get_file_info(path: impl AsRef<Path>) -> Result<FileInfo, Error> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut hasher = Md5::new();
+    let mut buf = vec![0; READ_BUFFER_SIZE];
+    let n = read_full(&mut file, &mut buf)?;
+    hasher.update(&buf[..n]);
+    let mut size = n as u64;
+
+    let sniffed = infer::get(&buf[..n]);
+    let keep = match sniffed {
+        Some(t) if t.mime_type() != "text/xml" => SIZED_TYPES.contains(&t.mime_type()),
+        _ => len <= MAX_SVG_SIZE,
+    };
+    let mut data = match keep {
+        true => buf[..n].to_vec(),
+        false => Vec::new(),
+    };
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        size += n as u64;
+        if keep {
+            data.extend_from_slice(&buf[..n]);
+        }
+    }
+
+    let mut info = FileInfo {
+        size,
+        md5: format!("{:x}", hasher.finalize()),
+        file_type: detect_type(sniffed, &data, size),
+        width: None,
+        height: None,
+        orientation: None,
+        blurhash: None,
+        warnings: Vec::new(),
+    };
+
+    if SIZED_TYPES.contains(&info.file_type.mime.as_str()) {
+        read_dimensions(&mut info, &data);
+    }
+    if BLURHASH_TYPES.contains(&info.file_type.mime.as_str()) {
+        match encode_blurhash(&data) {
+            Ok(blurhash) => info.blurhash = Some(blurhash),
+            Err(e) => info.warnings.push(format!("getBlurhash failed: {}", e)),
+        }
+    }
+
+    Ok(info)
+}
+
+/// Detects the type from the magic number, or by checking that the file is
+/// SVG. `data` is empty unless the file may be an image.
+fn detect_type(sniffed: Option<infer::Type>, data: &[u8], size: u64) -> FileType {
+    if size == 0 {
+        return FileType::octet_stream();
+    }
+    match sniffed {
+        // XML may be SVG
+        Some(t) if t.mime_type() == "text/xml" => match is_svg(data) {
+            true => FileType::svg(),
+            false => FileType::new("application/xml", "xml"),
+        },
+        Some(t) if t.mime_type() == "image/png" && is_apng(data) => {
+            FileType::new("image/apng", "apng")
+        }
+        Some(t) => FileType::new(t.mime_type(), t.extension()),
+        None if is_svg(data) => FileType::svg(),
+        None => FileType::octet_stream(),
+    }
+}
+
+static SVG_START: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?s)^\s*(?:<\?xml[^>]*\?>\s*)?(?:(?:<!--.*?-->|<!DOCTYPE[^>]*>)\s*)*<svg[\s>/]")
+        .unwrap()
+});
+
+/// Checks that the root element is `svg`.
+fn is_svg(data: &[u8]) -> bool {
+    if data.is_empty() || data.len() as u64 > MAX_SVG_SIZE {
+        return false;
+    }
+    let Ok(text) = std::str::from_utf8(data) else {
+        return false;
+    };
+    SVG_START.is_match(text.trim_start_matches('\u{feff}'))
+}
+
+/// Checks that the PNG has an animation control chunk before the image data.
+fn is_apng(data: &[u8]) -> bool {
+    let mut pos = 8;
+    while pos + 8 <= data.len() {
+        let len = u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]);
+        match &data[pos + 4..pos + 8] {
+            b"acTL" => return true,
+            b"IDAT" => return false,
+            _ => pos += 12 + len as usize,
+        }
+    }
+    false
+}
+
+static SVG_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)<svg\s[^>]*>").unwrap());
+static SVG_LENGTH: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*([\d.]+)\s*([a-z%]*)\s*$").unwrap());
+static SVG_WIDTH: Lazy<Regex> = Lazy::new(|| svg_attribute_regex("width"));
+static SVG_HEIGHT: Lazy<Regex> = Lazy::new(|| svg_attribute_regex("height"));
+static SVG_VIEW_BOX: Lazy<Regex> = Lazy::new(|| svg_attribute_regex("viewBox"));
+
+fn svg_attribute_regex(name: &str) -> Regex {
+    Regex::new(&format!(r#"\s{}\s*=\s*(?:"([^"]*)"|'([^']*)')"#, name)).unwrap()
+}
+
+fn svg_attribute<'a>(tag: &'a str, attribute: &Regex) -> Option<&'a str> {
+    let caps = attribute.captures(tag)?;
+    caps.get(1).or(caps.get(2)).map(|m| m.as_str())
+}
+
+/// Returns the width, height and unit of the root element, preferring the
+/// `width` and `height` attributes to `viewBox`.
+fn svg_size(data: &[u8]) -> Result<(f64, f64, String), String> {
+    let text = std::str::from_utf8(data).map_err(|e| e.to_string())?;
+    let tag = SVG_TAG.find(text).ok_or("svg element not found")?.as_str();
+
+    let length = |value: &str| -> Option<(f64, String)> {
+        let caps = SVG_LENGTH.captures(value)?;
+        Some((caps[1].parse().ok()?, caps[2].to_string()))
+    };
+    if let (Some(width), Some(height)) = (
+        svg_attribute(tag, &SVG_WIDTH),
+        svg_attribute(tag, &SVG_HEIGHT),
+    ) {
+        let (width, unit) = length(width).ok_or("invalid width")?;
+        let (height, _) = length(height).ok_or("invalid height")?;
+        let unit = match unit.as_str() {
+            "" => "px".to_string(),
+            _ => unit,
+        };
+        return Ok((width, height, unit));
+    }
+
+    let view_box: Vec<f64> = svg_attribute(tag, &SVG_VIEW_BOX)
+        .ok_or("no width, height or viewBox")?
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<f64>().map_err(|e| e.to_string()))
+        .collect::<Result<_, _>>()?;
+    match view_box[..] {
+        [_, _, width, height] => Ok((width, height, "px".to_string())),
+        _ => Err("invalid viewBox".to_string()),
+    }
+}
+
+pub(crate) fn read_orientation(data: &[u8]) -> Option<u32> {
+    let exif = exif::Reader::new()
+        .read_from_container(&mut Cursor::new(data))
+        .ok()?;
+    exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?
+        .value
+        .get_uint(0)
+}
+
+/// Sets the dimensions, or changes the type to `application/octet-stream`
+/// if they can not be read or exceed [MAX_IMAGE_DIMENSION].
+fn read_dimensions(info: &mut FileInfo, data: &[u8]) {
+    let size = match info.file_type.mime.as_str() {
+        "image/svg+xml" => svg_size(data),
+        _ => imagesize::blob_size(data)
+            .map(|s| (s.width as f64, s.height as f64, "px".to_string()))
+            .map_err(|e| e.to_string()),
+    };
+    let (width, height, unit) = match size {
+        Ok(size) => size,
+        Err(e) => {
+            info.warnings.push(format!("detectImageSize failed: {}", e));
+            info.warnings
+                .push("cannot detect image dimensions".to_string());
+            info.file_type = FileType::octet_stream();
+            return;
+        }
+    };
+    if unit != "px" {
+        info.warnings
+            .push(format!("unsupported unit type: {}", unit));
+        return;
+    }
+
+    let (width, height) = (width.round() as u32, height.round() as u32);
+    info.width = Some(width);
+    info.height = Some(height);
+    info.orientation = read_orientation(data);
+    if width > MAX_IMAGE_DIMENSION || height > MAX_IMAGE_DIMENSION {
+        info.warnings
+            .push("image dimensions exceeds limits".to_string());
+        info.file_type = FileType::octet_stream();
+    }
+}
+
+/// Encodes the blurhash of the image downscaled to fit in
+/// [BLURHASH_IMAGE_SIZE].
+fn encode_blurhash(data: &[u8]) -> Result<String, String> {
+    let image = image::load_from_memory(data).map_err(|e| e.to_string())?;
+    let image = image
+        .thumbnail(BLURHASH_IMAGE_SIZE, BLURHASH_IMAGE_SIZE)
+        .to_rgba8();
+    blurhash::encode(
+        BLURHASH_COMPONENTS,
+        BLURHASH_COMPONENTS,
+        image.width(),
+        image.height(),
+        image.as_raw(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi::bindgen_prelude::AsyncTask;
+        use napi::{Env, Task};
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeFileType {
+            pub mime: String,
+            pub ext: Option<String>,
+        }
+
+        /// For NAPI because [u64] is not supported. Same as `FileInfo` of
+        /// `misc/get-file-info.ts` without `sensitive` and `porn`.
+        #[napi(object)]
+        pub struct NativeFileInfo {
+            pub size: i64,
+            pub md5: String,
+            #[napi(js_name = "type")]
+            pub file_type: NativeFileType,
+            pub width: Option<u32>,
+            pub height: Option<u32>,
+            pub orientation: Option<u32>,
+            pub blurhash: Option<String>,
+            pub warnings: Vec<String>,
+        }
+
+        impl From<FileInfo> for NativeFileInfo {
+            fn from(info: FileInfo) -> Self {
+                Self {
+                    size: info.size as i64,
+                    md5: info.md5,
+                    file_type: NativeFileType {
+                        mime: info.file_type.mime,
+                        ext: info.file_type.ext,
+                    },
+                    width: info.width,
+                    height: info.height,
+                    orientation: info.orientation,
+                    blurhash: info.blurhash,
+                    warnings: info.warnings,
+                }
+            }
+        }
+
+        pub struct GetFileInfo {
+            path: String,
+        }
+
+        #[napi]
+        impl Task for GetFileInfo {
+            type Output = FileInfo;
+            type JsValue = NativeFileInfo;
+
+            fn compute(&mut self) -> napi::Result<Self::Output> {
+                get_file_info(&self.path).map_err(Into::into)
+            }
+
+            fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+                Ok(output.into())
+            }
+        }
+
+        /// Calls [get_file_info] in the thread pool of libuv.
+        #[napi(ts_return_type = "Promise<NativeFileInfo>")]
+        pub fn native_get_file_info(path: String) -> AsyncTask<GetFileInfo> {
+            AsyncTask::new(GetFileInfo { path })
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use std::io::{Cursor, Write};
+
+    use image::{ImageOutputFormat, RgbaImage};
+    use md5::{Digest, Md5};
+    use pretty_assertions::assert_eq;
+
+    use super::{get_file_info, is_apng, is_svg, svg_size, FileType};
+
+    #[test]
+    fn detect_svg() {
+        assert!(is_svg(b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>"));
+        assert!(is_svg(
+            b"<?xml version=\"1.0\"?>\n<!-- comment -->\n<!DOCTYPE svg>\n<svg>"
+        ));
+        assert!(!is_svg(b"<html><svg></svg></html>"));
+        assert!(!is_svg(b"<svgfoo>"));
+
+        assert_eq!(
+            svg_size(br#"<svg width="120" height='80.4px'>"#),
+            Ok((120.0, 80.4, "px".to_string()))
+        );
+        assert_eq!(
+            svg_size(br#"<svg viewBox="0 0 24,12">"#),
+            Ok((24.0, 12.0, "px".to_string()))
+        );
+        assert_eq!(
+            svg_size(br#"<svg width="10em" height="5em">"#),
+            Ok((10.0, 5.0, "em".to_string()))
+        );
+    }
+
+    #[test]
+    fn detect_apng() {
+        let signature = b"\x89PNG\r\n\x1a\n";
+        let chunk = |name: &[u8]| [&[0, 0, 0, 0], name, &[0, 0, 0, 0]].concat();
+        let apng = [
+            &signature[..],
+            &chunk(b"IHDR"),
+            &chunk(b"acTL"),
+            &chunk(b"IDAT"),
+        ]
+        .concat();
+        let png = [
+            &signature[..],
+            &chunk(b"IHDR"),
+            &chunk(b"IDAT"),
+            &chunk(b"acTL"),
+        ]
+        .concat();
+        assert!(is_apng(&apng));
+        assert!(!is_apng(&png));
+    }
+
+    #[test]
+    fn probe_png() {
+        let image = RgbaImage::from_fn(32, 16, |x, y| {
+            image::Rgba([x as u8 * 8, y as u8 * 16, 0, 255])
+        });
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png)
+            .unwrap();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&png).unwrap();
+
+        let info = get_file_info(file.path()).unwrap();
+        assert_eq!(info.size, png.len() as u64);
+        assert_eq!(info.md5, format!("{:x}", Md5::digest(&png)));
+        assert_eq!(info.file_type, FileType::new("image/png", "png"));
+        assert_eq!((info.width, info.height), (Some(32), Some(16)));
+        assert!(info.blurhash.is_some());
+        assert_eq!(info.warnings, Vec::<String>::new());
+    }
+}
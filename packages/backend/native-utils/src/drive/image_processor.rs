@@ -0,0 +1,376 @@
+//! Port of `services/drive/image-processor.ts` and of the generation of the
+//! thumbnail and webpublic images in `services/drive/add-file.ts`. The
+//! images are converted to WebP after applying the EXIF orientation, which
+//! strips the metadata, and only the first frame of animated images is kept.
+
+use std::io::Cursor;
+
+use cfg_if::cfg_if;
+use image::codecs::gif::GifDecoder;
+use image::imageops::FilterType;
+use image::io::{Limits, Reader};
+use image::{AnimationDecoder, DynamicImage};
+
+use super::error::Error;
+use super::file_info::read_orientation;
+use super::get_drive_config;
+
+pub const WEBP_MIME: &str = "image/webp";
+pub const WEBP_EXT: &str = "webp";
+
+const DEFAULT_QUALITY: f32 = 85.0;
+const WEBPUBLIC_SIZE: u32 = 2048;
+const THUMBNAIL_WIDTH: u32 = 996;
+const THUMBNAIL_HEIGHT: u32 = 560;
+/// Bytes per pixel of the widest color type, 32-bit float RGBA.
+const MAX_BYTES_PER_PIXEL: u64 = 16;
+
+/// Types of which the webpublic image is generated if the original is not
+/// suitable for browsers.
+const WEBPUBLIC_TYPES: [&str; 3] = ["image/jpeg", "image/png", "image/webp"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebpImage {
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// The images stored with a drive file, which are `webpublic_url` and
+/// `thumbnail_url` of `drive_file`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImageAlts {
+    pub webpublic: Option<WebpImage>,
+    pub thumbnail: Option<WebpImage>,
+}
+
+/// Decodes the first frame of the image, or returns `None` if the format is
+/// not supported, such as SVG and AVIF.
+pub(crate) fn decode(data: &[u8], max_pixels: u64) -> Result<Option<DynamicImage>, Error> {
+    let reader = Reader::new(Cursor::new(data)).with_guessed_format()?;
+    match reader.format() {
+        Some(format) if format.reading_enabled() => {}
+        _ => return Ok(None),
+    }
+
+    // the header is read to check the size before allocating the pixels
+    let (width, height) = reader.into_dimensions()?;
+    let pixels = width as u64 * height as u64;
+    if pixels > max_pixels {
+        return Err(Error::TooManyPixels(pixels));
+    }
+
+    let mut reader = Reader::new(Cursor::new(data)).with_guessed_format()?;
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(width);
+    limits.max_image_height = Some(height);
+    limits.max_alloc = Some(max_pixels.saturating_mul(MAX_BYTES_PER_PIXEL));
+    reader.limits(limits);
+    Ok(Some(reader.decode()?))
+}
+
+/// Rotates and flips the image as specified by the EXIF orientation.
+fn apply_orientation(image: DynamicImage, orientation: Option<u32>) -> DynamicImage {
+    match orientation {
+        Some(2) => image.fliph(),
+        Some(3) => image.rotate180(),
+        Some(4) => image.flipv(),
+        Some(5) => image.rotate90().fliph(),
+        Some(6) => image.rotate90(),
+        Some(7) => image.rotate270().fliph(),
+        Some(8) => image.rotate270(),
+        _ => image,
+    }
+}
+
+/// Resizes the image to fit inside `width` x `height` keeping the aspect
+/// ratio, without enlarging it.
+fn fit_inside(image: DynamicImage, width: u32, height: u32) -> DynamicImage {
+    if image.width() <= width && image.height() <= height {
+        return image;
+    }
+    image.resize(width, height, FilterType::Lanczos3)
+}
+
+fn encode_webp(image: &DynamicImage, quality: f32) -> Result<WebpImage, Error> {
+    let (width, height) = (image.width(), image.height());
+    let memory = match image.color().has_alpha() {
+        true => {
+            let rgba = image.to_rgba8();
+            webp::Encoder::from_rgba(&rgba, width, height).encode_simple(false, quality)
+        }
+        false => {
+            let rgb = image.to_rgb8();
+            webp::Encoder::from_rgb(&rgb, width, height).encode_simple(false, quality)
+        }
+    }
+    .map_err(|e| Error::WebpError(format!("{:?}", e)))?;
+    Ok(WebpImage {
+        data: memory.to_vec(),
+        width,
+        height,
+    })
+}
+
+/// Converts the image to WebP fitting inside `width` x `height`, or returns
+/// `None` if the format is not supported.
+pub fn convert_to_webp(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    quality: f32,
+) -> Result<Option<WebpImage>, Error> {
+    let Some(image) = decode(data, get_drive_config().max_image_pixels)? else {
+        return Ok(None);
+    };
+    let image = apply_orientation(image, read_orientation(data));
+    Ok(Some(encode_webp(
+        &fit_inside(image, width, height),
+        quality,
+    )?))
+}
+
+fn is_animated(data: &[u8], mime: &str) -> Result<bool, Error> {
+    match mime {
+        "image/apng" => Ok(true),
+        "image/gif" => Ok(GifDecoder::new(Cursor::new(data))?
+            .into_frames()
+            .take(2)
+            .count()
+            > 1),
+        // the animation flag of the VP8X chunk
+        "image/webp" => Ok(data.len() > 20 && &data[12..16] == b"VP8X" && data[20] & 0x02 != 0),
+        _ => Ok(false),
+    }
+}
+
+/// Checks for EXIF, XMP and IPTC metadata, which should not be served.
+fn has_metadata(data: &[u8]) -> bool {
+    let contains = |marker: &[u8]| data.windows(marker.len()).any(|w| w == marker);
+    read_orientation(data).is_some()
+        || exif::Reader::new()
+            .read_from_container(&mut Cursor::new(data))
+            .is_ok()
+        || contains(b"http://ns.adobe.com/xap/1.0/")
+        || contains(b"Photoshop 3.0\0")
+}
+
+/// Generates the thumbnail, and the webpublic image if `generate_web` is
+/// `true` and the original is not suitable for browsers. Animated images
+/// only have a thumbnail of the first frame.
+pub fn generate_alts(data: &[u8], mime: &str, generate_web: bool) -> Result<ImageAlts, Error> {
+    let Some(image) = decode(data, get_drive_config().max_image_pixels)? else {
+        tracing::debug!(
+            mime,
+            "web image and thumbnail not created (unsupported format)"
+        );
+        return Ok(ImageAlts::default());
+    };
+
+    let satisfies_webpublic = mime != "image/webp"
+        && !has_metadata(data)
+        && image.width() <= WEBPUBLIC_SIZE
+        && image.height() <= WEBPUBLIC_SIZE;
+    let image = apply_orientation(image, read_orientation(data));
+
+    let webpublic = if !generate_web {
+        tracing::debug!("web image not created (from remote)");
+        None
+    } else if !WEBPUBLIC_TYPES.contains(&mime) || is_animated(data, mime)? {
+        tracing::debug!(mime, "web image not created (not an required image)");
+        None
+    } else if satisfies_webpublic {
+        tracing::debug!("web image not created (original satisfies webpublic)");
+        None
+    } else {
+        let quality = match mime {
+            "image/png" => 100.0,
+            _ => DEFAULT_QUALITY,
+        };
+        let image = fit_inside(image.clone(), WEBPUBLIC_SIZE, WEBPUBLIC_SIZE);
+        Some(encode_webp(&image, quality)?)
+    };
+
+    let image = fit_inside(image, THUMBNAIL_WIDTH, THUMBNAIL_HEIGHT);
+    let thumbnail = Some(encode_webp(&image, DEFAULT_QUALITY)?);
+
+    Ok(ImageAlts {
+        webpublic,
+        thumbnail,
+    })
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi::bindgen_prelude::{AsyncTask, Buffer};
+        use napi::{Env, Task};
+        use napi_derive::napi;
+
+        /// Same as `IImage` of `services/drive/image-processor.ts`.
+        #[napi(object)]
+        pub struct NativeImage {
+            pub data: Buffer,
+            pub ext: String,
+            #[napi(js_name = "type")]
+            pub mime: String,
+        }
+
+        impl From<WebpImage> for NativeImage {
+            fn from(image: WebpImage) -> Self {
+                Self {
+                    data: image.data.into(),
+                    ext: WEBP_EXT.to_string(),
+                    mime: WEBP_MIME.to_string(),
+                }
+            }
+        }
+
+        #[napi(object)]
+        pub struct NativeImageAlts {
+            pub webpublic: Option<NativeImage>,
+            pub thumbnail: Option<NativeImage>,
+        }
+
+        pub struct ConvertToWebp {
+            path: String,
+            width: u32,
+            height: u32,
+            quality: f32,
+        }
+
+        #[napi]
+        impl Task for ConvertToWebp {
+            type Output = Option<WebpImage>;
+            type JsValue = Option<NativeImage>;
+
+            fn compute(&mut self) -> napi::Result<Self::Output> {
+                std::fs::read(&self.path)
+                    .map_err(Error::from)
+                    .and_then(|data| convert_to_webp(&data, self.width, self.height, self.quality))
+                    .map_err(Into::into)
+            }
+
+            fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+                Ok(output.map(Into::into))
+            }
+        }
+
+        /// Calls [convert_to_webp] in the thread pool of libuv. Resolves to
+        /// `null` if the format is not supported.
+        #[napi(ts_return_type = "Promise<NativeImage | null>")]
+        pub fn native_convert_to_webp(
+            path: String,
+            width: u32,
+            height: u32,
+            quality: Option<u32>,
+        ) -> AsyncTask<ConvertToWebp> {
+            AsyncTask::new(ConvertToWebp {
+                path,
+                width,
+                height,
+                quality: quality.map_or(DEFAULT_QUALITY, |q| q as f32),
+            })
+        }
+
+        pub struct GenerateAlts {
+            path: String,
+            mime: String,
+            generate_web: bool,
+        }
+
+        #[napi]
+        impl Task for GenerateAlts {
+            type Output = ImageAlts;
+            type JsValue = NativeImageAlts;
+
+            fn compute(&mut self) -> napi::Result<Self::Output> {
+                std::fs::read(&self.path)
+                    .map_err(Error::from)
+                    .and_then(|data| generate_alts(&data, &self.mime, self.generate_web))
+                    .map_err(Into::into)
+            }
+
+            fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+                Ok(NativeImageAlts {
+                    webpublic: output.webpublic.map(Into::into),
+                    thumbnail: output.thumbnail.map(Into::into),
+                })
+            }
+        }
+
+        /// Calls [generate_alts] in the thread pool of libuv.
+        #[napi(ts_return_type = "Promise<NativeImageAlts>")]
+        pub fn native_generate_image_alts(
+            path: String,
+            mime: String,
+            generate_web: bool,
+        ) -> AsyncTask<GenerateAlts> {
+            AsyncTask::new(GenerateAlts {
+                path,
+                mime,
+                generate_web,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use std::io::Cursor;
+
+    use image::{DynamicImage, ImageOutputFormat, RgbImage};
+    use pretty_assertions::assert_eq;
+
+    use super::{apply_orientation, decode, generate_alts, is_animated};
+    use crate::drive::error::Error;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = RgbImage::from_fn(width, height, |x, y| image::Rgb([x as u8, y as u8, 0]));
+        let mut data = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut data), ImageOutputFormat::Png)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn orientation() {
+        let image = DynamicImage::new_rgb8(3, 2);
+        let rotated = apply_orientation(image.clone(), Some(6));
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        let transposed = apply_orientation(image.clone(), Some(5));
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        let flipped = apply_orientation(image, Some(2));
+        assert_eq!((flipped.width(), flipped.height()), (3, 2));
+    }
+
+    #[test]
+    fn pixel_limit() {
+        let data = png(100, 100);
+        assert!(decode(&data, 10000).unwrap().is_some());
+        assert!(matches!(
+            decode(&data, 9999),
+            Err(Error::TooManyPixels(10000))
+        ));
+        assert!(decode(b"<svg></svg>", 10000).unwrap().is_none());
+    }
+
+    #[test]
+    fn generate_thumbnail_and_webpublic() {
+        let data = png(3000, 1000);
+        assert!(!is_animated(&data, "image/png").unwrap());
+
+        let alts = generate_alts(&data, "image/png", true).unwrap();
+        let webpublic = alts.webpublic.unwrap();
+        assert_eq!((webpublic.width, webpublic.height), (2048, 683));
+        assert_eq!(&webpublic.data[8..12], b"WEBP");
+        let thumbnail = alts.thumbnail.unwrap();
+        assert_eq!((thumbnail.width, thumbnail.height), (996, 332));
+
+        // small images are served as is, and not enlarged
+        let alts = generate_alts(&png(100, 50), "image/png", true).unwrap();
+        assert_eq!(alts.webpublic, None);
+        let thumbnail = alts.thumbnail.unwrap();
+        assert_eq!((thumbnail.width, thumbnail.height), (100, 50));
+    }
+}
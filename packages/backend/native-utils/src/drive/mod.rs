@@ -0,0 +1,117 @@
+//! Processing of the files of the drive.
+
+pub mod blob;
+pub mod error;
+pub mod eviction;
+pub mod file_info;
+pub mod image_processor;
+pub mod quota;
+pub mod sensitive;
+pub mod storage;
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::OnceCell;
+use sea_orm::{DbErr, EntityTrait};
+
+use crate::database;
+use crate::model::entity::meta;
+use error::Error;
+
+static CONFIG: OnceCell<DriveConfig> = OnceCell::new();
+static META: Mutex<Option<(Instant, meta::Model)>> = Mutex::new(None);
+
+/// How long the fetched `meta` is used, which is the interval of the
+/// refresh of `misc/fetch-meta.ts`.
+const META_TTL: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DriveConfig {
+    /// Images with more pixels than this are not decoded, against
+    /// decompression bombs.
+    pub max_image_pixels: u64,
+    /// Directory of the files stored internally.
+    pub files_dir: PathBuf,
+    /// `url` of the config, which the URLs of the internal files start with.
+    pub url: String,
+    /// ONNX export of the model classifying sensitive media. The media are
+    /// not classified natively unless set.
+    pub sensitive_media_model: Option<PathBuf>,
+    /// `proxy` of the config, used by the object storage if
+    /// `objectStorageUseProxy` is enabled.
+    pub proxy: Option<String>,
+    /// `proxyBypassHosts` of the config.
+    pub proxy_bypass_hosts: Vec<String>,
+}
+
+impl Default for DriveConfig {
+    /// The limit is the default one of sharp.
+    fn default() -> Self {
+        Self {
+            max_image_pixels: 0x3FFF * 0x3FFF,
+            files_dir: PathBuf::from("files"),
+            url: String::new(),
+            sensitive_media_model: None,
+            proxy: None,
+            proxy_bypass_hosts: vec![],
+        }
+    }
+}
+
+/// Sets the configuration used by the NAPI functions. Only the first call
+/// has an effect.
+pub fn init_drive(config: DriveConfig) {
+    CONFIG.get_or_init(move || config);
+}
+
+/// Returns the configuration given to [init_drive], or the default one.
+pub fn get_drive_config() -> &'static DriveConfig {
+    CONFIG.get_or_init(DriveConfig::default)
+}
+
+/// Returns the row of `meta` fetched from the primary within [META_TTL], as
+/// `fetchMeta()`.
+pub(crate) async fn fetch_meta() -> Result<meta::Model, Error> {
+    if let Some((fetched_at, meta)) = META.lock().unwrap().as_ref() {
+        if fetched_at.elapsed() < META_TTL {
+            return Ok(meta.clone());
+        }
+    }
+
+    let db = &database::get_database()?;
+    let meta = meta::Entity::find()
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("meta".to_string()))?;
+    *META.lock().unwrap() = Some((Instant::now(), meta.clone()));
+    Ok(meta)
+}
+
+#[cfg(feature = "napi")]
+#[napi_derive::napi(object)]
+pub struct NativeDriveConfig {
+    pub max_image_pixels: Option<u32>,
+    pub files_dir: Option<String>,
+    pub url: Option<String>,
+    pub sensitive_media_model: Option<String>,
+    pub proxy: Option<String>,
+    pub proxy_bypass_hosts: Option<Vec<String>>,
+}
+
+#[cfg(feature = "napi")]
+#[napi_derive::napi]
+pub fn native_init_drive(config: NativeDriveConfig) {
+    let default = DriveConfig::default();
+    init_drive(DriveConfig {
+        max_image_pixels: config
+            .max_image_pixels
+            .map_or(default.max_image_pixels, Into::into),
+        files_dir: config.files_dir.map_or(default.files_dir, Into::into),
+        url: config.url.unwrap_or(default.url),
+        sensitive_media_model: config.sensitive_media_model.map(Into::into),
+        proxy: config.proxy,
+        proxy_bypass_hosts: config.proxy_bypass_hosts.unwrap_or_default(),
+    });
+}
@@ -0,0 +1,313 @@
+//! Drive capacity of the users, replacing `calcDriveUsageOf` of
+//! `models/repositories/drive-file.ts` and the check of the usage in
+//! `services/drive/add-file.ts`.
+//!
+//! The usage of each user is cached as `driveUsage:<userId>` for a day. It is
+//! adjusted as files are added and deleted, and [reconcile_usages] fixes the
+//! entries that drifted from the sum of `drive_file.size`. The usage is
+//! computed on every read if the cache is not initialized.
+
+use std::collections::HashMap;
+
+use cfg_if::cfg_if;
+use once_cell::sync::Lazy;
+use redis::{AsyncCommands, Script};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+use super::error::Error;
+use super::fetch_meta;
+use crate::cache;
+use crate::database;
+use crate::metrics;
+use crate::model::entity::{drive_file, meta, user};
+
+const MB: i64 = 1024 * 1024;
+/// Expiration of the cached usages in seconds.
+const USAGE_TTL: usize = 24 * 60 * 60;
+/// Number of cached usages compared at once by [reconcile_usages].
+const RECONCILE_BATCH_SIZE: usize = 1000;
+
+/// Adds `ARGV[1]` to the usage only if it is cached, so that a missing entry
+/// is computed from scratch instead of starting from the delta.
+static INCR_IF_CACHED: Lazy<Script> = Lazy::new(|| {
+    Script::new(
+        r"
+        if redis.call('EXISTS', KEYS[1]) == 1 then
+            return redis.call('INCRBY', KEYS[1], ARGV[1])
+        end
+        return false
+        ",
+    )
+});
+
+/// Caches `ARGV[1]` for `ARGV[2]` seconds unless the usage has been cached in
+/// the meantime, and returns the cached usage.
+static SET_IF_MISSING: Lazy<Script> = Lazy::new(|| {
+    Script::new(
+        r"
+        if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'EX', ARGV[2]) then
+            return tonumber(ARGV[1])
+        end
+        return tonumber(redis.call('GET', KEYS[1]))
+        ",
+    )
+});
+
+/// Key of the cached usage of the user, without the prefix of the cache.
+fn usage_key(user_id: &str) -> String {
+    format!("driveUsage:{}", user_id)
+}
+
+/// Sums the sizes of the files of the user, excluding the links to remote
+/// files.
+pub async fn compute_usage(user_id: &str) -> Result<i64, Error> {
+    let db = &database::get_read_database()?;
+    let query = drive_file::Entity::find()
+        .select_only()
+        .column_as(Expr::col(drive_file::Column::Size).sum(), "usage")
+        .filter(drive_file::Column::UserId.eq(user_id))
+        .filter(drive_file::Column::IsLink.eq(false))
+        .into_tuple::<Option<i64>>()
+        .one(db);
+    let usage = metrics::observe_query("drive_usage", query).await?;
+    Ok(usage.flatten().unwrap_or(0))
+}
+
+/// Returns the usage of the user in bytes, computing it unless cached. The
+/// usage is always computed if the cache is not initialized.
+pub async fn get_usage(user_id: &str) -> Result<i64, Error> {
+    let Ok(cache) = cache::get_cache() else {
+        return compute_usage(user_id).await;
+    };
+    let cached: Option<i64> = cache.get("drive_usage", usage_key(user_id)).await?;
+    if let Some(usage) = cached {
+        return Ok(usage);
+    }
+
+    let usage = compute_usage(user_id).await?;
+    let mut conn = cache.conn();
+    let key = cache.key(usage_key(user_id));
+    // keep the usage cached by a concurrent call, which may have been
+    // updated since
+    let cached: Option<i64> = SET_IF_MISSING
+        .key(key)
+        .arg(usage)
+        .arg(USAGE_TTL)
+        .invoke_async(&mut conn)
+        .await?;
+    Ok(cached.unwrap_or(usage))
+}
+
+/// Adds `delta` bytes to the cached usage of the user, which is the size of
+/// an added file or the negated size of a deleted one. Nothing is cached if
+/// the cache is not initialized.
+pub async fn update_usage(user_id: &str, delta: i64) -> Result<(), Error> {
+    let Ok(cache) = cache::get_cache() else {
+        return Ok(());
+    };
+    INCR_IF_CACHED
+        .key(cache.key(usage_key(user_id)))
+        .arg(delta)
+        .invoke_async::<_, Option<i64>>(&mut cache.conn())
+        .await?;
+    Ok(())
+}
+
+/// Returns the capacity of the drive of the user in bytes, which is
+/// `drive_capacity_override_mb` for local users if set.
+pub fn capacity_of(user: &user::Model, meta: &meta::Model) -> i64 {
+    let mb = match (&user.host, user.drive_capacity_override_mb) {
+        (None, Some(mb)) => mb,
+        (None, None) => meta.local_drive_capacity_mb,
+        (Some(_), _) => meta.remote_drive_capacity_mb,
+    };
+    i64::from(mb) * MB
+}
+
+/// Checks that a new file of `size` bytes fits in the drive of the user.
+/// Local users get [Error::NoFreeSpace], while the IDs of the files of
+/// remote users to expire to make room are returned, oldest first.
+#[tracing::instrument]
+pub async fn check_capacity(user_id: &str, size: i64) -> Result<Vec<String>, Error> {
+    let user = user::Entity::find_by_id(user_id)
+        .one(&database::get_database()?)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound(format!("user {}", user_id)))?;
+    let capacity = capacity_of(&user, &fetch_meta().await?);
+    let usage = get_usage(user_id).await?;
+    tracing::debug!(usage, capacity, "Checked drive usage");
+
+    if usage + size <= capacity {
+        return Ok(vec![]);
+    }
+    match user.host {
+        None => Err(Error::NoFreeSpace),
+        Some(_) => files_to_expire(&user, capacity - size).await,
+    }
+}
+
+/// Picks the oldest files of the user to delete so that the rest fit in
+/// `capacity` bytes, keeping the avatar and the banner.
+pub async fn files_to_expire(user: &user::Model, capacity: i64) -> Result<Vec<String>, Error> {
+    let mut query = drive_file::Entity::find()
+        .select_only()
+        .column(drive_file::Column::Id)
+        .column(drive_file::Column::Size)
+        .filter(drive_file::Column::UserId.eq(user.id.to_owned()))
+        .filter(drive_file::Column::IsLink.eq(false))
+        .order_by_desc(drive_file::Column::Id);
+    for id in [&user.avatar_id, &user.banner_id].into_iter().flatten() {
+        query = query.filter(drive_file::Column::Id.ne(id.to_owned()));
+    }
+
+    let db = &database::get_read_database()?;
+    let files: Vec<(String, i32)> =
+        metrics::observe_query("drive_files_to_expire", query.into_tuple().all(db)).await?;
+    Ok(select_expired(files, capacity))
+}
+
+/// Returns the files, given newest first, that exceed `capacity` when their
+/// sizes are accumulated from the newest one. The result is oldest first.
+fn select_expired(files: Vec<(String, i32)>, capacity: i64) -> Vec<String> {
+    let mut usage = 0;
+    let mut expired: Vec<String> = files
+        .into_iter()
+        .filter_map(|(id, size)| {
+            usage += i64::from(size);
+            (usage > capacity).then_some(id)
+        })
+        .collect();
+    expired.reverse();
+    expired
+}
+
+/// Compares the cached usages with the sums of `drive_file.size` and fixes
+/// the ones that drifted. Returns the number of fixed entries.
+#[tracing::instrument]
+pub async fn reconcile_usages() -> Result<u32, Error> {
+    let Ok(cache) = cache::get_cache() else {
+        return Ok(0);
+    };
+    let db = &database::get_read_database()?;
+    let query = drive_file::Entity::find()
+        .select_only()
+        .column(drive_file::Column::UserId)
+        .column_as(Expr::col(drive_file::Column::Size).sum(), "usage")
+        .filter(drive_file::Column::UserId.is_not_null())
+        .filter(drive_file::Column::IsLink.eq(false))
+        .group_by(drive_file::Column::UserId)
+        .into_tuple()
+        .all(db);
+    let usages: HashMap<String, i64> = metrics::observe_query("drive_usages", query)
+        .await?
+        .into_iter()
+        .collect();
+
+    let mut conn = cache.conn();
+    let prefix = cache.key(usage_key(""));
+    let keys: Vec<String> = {
+        let mut iter = conn.scan_match(format!("{}*", prefix)).await?;
+        let mut keys = Vec::new();
+        while let Some(key) = iter.next_item().await {
+            keys.push(key);
+        }
+        keys
+    };
+
+    let mut fixed = 0;
+    for keys in keys.chunks(RECONCILE_BATCH_SIZE) {
+        let cached: Vec<Option<i64>> = redis::cmd("MGET").arg(keys).query_async(&mut conn).await?;
+        let mut pipe = redis::pipe();
+        for (key, cached) in keys.iter().zip(cached) {
+            let usage = usages.get(&key[prefix.len()..]).copied().unwrap_or(0);
+            // entries expired in the meantime are computed again when read
+            if cached.is_some_and(|cached| cached != usage) {
+                pipe.set_ex(key, usage, USAGE_TTL).ignore();
+                fixed += 1;
+            }
+        }
+        pipe.query_async::<_, ()>(&mut conn).await?;
+    }
+
+    tracing::info!(fixed, cached = keys.len(), "Reconciled drive usages");
+    Ok(fixed)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        /// Returns the usage of the drive of the user in bytes.
+        #[napi]
+        pub async fn native_get_drive_usage(user_id: String) -> napi::Result<i64> {
+            get_usage(&user_id).await.map_err(Into::into)
+        }
+
+        /// Adds `delta` bytes to the cached usage of the drive of the user.
+        #[napi]
+        pub async fn native_update_drive_usage(user_id: String, delta: i64) -> napi::Result<()> {
+            update_usage(&user_id, delta).await.map_err(Into::into)
+        }
+
+        /// Calls [check_capacity] inside. Rejects with `NO_FREE_SPACE` for
+        /// local users, and resolves to the IDs of the files to expire for
+        /// remote users.
+        #[napi]
+        pub async fn native_check_drive_capacity(
+            user_id: String,
+            size: i64,
+        ) -> napi::Result<Vec<String>> {
+            check_capacity(&user_id, size).await.map_err(Into::into)
+        }
+
+        /// Calls [reconcile_usages] inside.
+        #[napi]
+        pub async fn native_reconcile_drive_usages() -> napi::Result<u32> {
+            reconcile_usages().await.map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{capacity_of, select_expired, MB};
+    use crate::model::entity::{meta, user};
+
+    #[test]
+    fn capacity_of_users() {
+        let meta = meta::Model {
+            local_drive_capacity_mb: 1024,
+            remote_drive_capacity_mb: 32,
+            ..Default::default()
+        };
+        let local = user::Model::default();
+        assert_eq!(capacity_of(&local, &meta), 1024 * MB);
+        let overridden = user::Model {
+            drive_capacity_override_mb: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(capacity_of(&overridden, &meta), 0);
+        let remote = user::Model {
+            host: Some("example.com".to_string()),
+            drive_capacity_override_mb: Some(2048),
+            ..Default::default()
+        };
+        assert_eq!(capacity_of(&remote, &meta), 32 * MB);
+    }
+
+    #[test]
+    fn expire_oldest_files() {
+        let files = vec![
+            ("d".to_string(), 10),
+            ("c".to_string(), 20),
+            ("b".to_string(), 30),
+            ("a".to_string(), 40),
+        ];
+        assert_eq!(select_expired(files.clone(), 100), Vec::<String>::new());
+        assert_eq!(select_expired(files.clone(), 30), vec!["a", "b"]);
+        assert_eq!(select_expired(files, 0), vec!["a", "b", "c", "d"]);
+    }
+}
@@ -0,0 +1,457 @@
+//! Classification of sensitive media, replacing `services/detect-sensitive.ts`
+//! and the judgement in `misc/get-file-info.ts`.
+//!
+//! The model is an ONNX export of the bundled `nsfw-model`, converted by
+//! `scripts/convert-nsfw-model.sh`. It takes RGB images of 299x299 pixels
+//! scaled to `[0, 1]` and is run on the CPU with tract.
+//! The frames of videos are sampled with `ffmpeg`.
+
+use std::io::{ErrorKind, Read};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use cfg_if::cfg_if;
+use image::imageops::FilterType;
+use image::{DynamicImage, RgbImage};
+use once_cell::sync::OnceCell;
+use tract_onnx::prelude::*;
+
+use super::error::Error;
+use super::get_drive_config;
+use super::image_processor::decode;
+use crate::model::entity::sea_orm_active_enums::{
+    MetaSensitivemediadetectionEnum, MetaSensitivemediadetectionsensitivityEnum,
+};
+
+/// Width and height of the input of the model.
+const INPUT_SIZE: u32 = 299;
+/// Bytes of a frame given by `ffmpeg` in `rgb24`.
+const FRAME_LEN: usize = (INPUT_SIZE * INPUT_SIZE * 3) as usize;
+/// Threshold of `Porn` to judge an image as porn, regardless of the
+/// sensitivity.
+const PORN_THRESHOLD: f32 = 0.75;
+const IMAGE_TYPES: [&str; 3] = ["image/jpeg", "image/png", "image/webp"];
+
+static CLASSIFIER: OnceCell<Classifier> = OnceCell::new();
+
+/// Probabilities of the classes of the model, in the order of the output.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Predictions {
+    pub drawing: f32,
+    pub hentai: f32,
+    pub neutral: f32,
+    pub porn: f32,
+    pub sexy: f32,
+}
+
+/// Probabilities above which a file is judged as sensitive or porn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Thresholds {
+    pub sensitive: f32,
+    pub porn: f32,
+}
+
+impl From<&MetaSensitivemediadetectionsensitivityEnum> for Thresholds {
+    /// The higher the sensitivity is, the lower the threshold is.
+    fn from(sensitivity: &MetaSensitivemediadetectionsensitivityEnum) -> Self {
+        use MetaSensitivemediadetectionsensitivityEnum::*;
+        let sensitive = match sensitivity {
+            VeryHigh => 0.1,
+            High => 0.3,
+            Medium => 0.5,
+            Low => 0.7,
+            VeryLow => 0.9,
+        };
+        Self {
+            sensitive,
+            porn: PORN_THRESHOLD,
+        }
+    }
+}
+
+/// Same as `maybe_sensitive` and `maybe_porn` of `drive_file`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Judgement {
+    pub sensitive: bool,
+    pub porn: bool,
+}
+
+impl Predictions {
+    pub fn judge(&self, thresholds: &Thresholds) -> Judgement {
+        Judgement {
+            sensitive: [self.sexy, self.hentai, self.porn]
+                .into_iter()
+                .any(|p| p > thresholds.sensitive),
+            porn: self.porn > thresholds.porn,
+        }
+    }
+}
+
+/// Judges a video as sensitive or porn if at least the ratio of the
+/// threshold of the sampled frames are. A video without frames is not.
+fn judge_frames(frames: &[Judgement], thresholds: &Thresholds) -> Judgement {
+    if frames.is_empty() {
+        return Judgement::default();
+    }
+    let at_least = |threshold: f32| (frames.len() as f32 * threshold).ceil() as usize;
+    Judgement {
+        sensitive: frames.iter().filter(|j| j.sensitive).count() >= at_least(thresholds.sensitive),
+        porn: frames.iter().filter(|j| j.porn).count() >= at_least(thresholds.porn),
+    }
+}
+
+/// Whether the files of the user are classified under `detection` of `meta`.
+/// `is_local_user` is `None` for files without users, which are not.
+pub fn should_detect(
+    detection: &MetaSensitivemediadetectionEnum,
+    is_local_user: Option<bool>,
+) -> bool {
+    use MetaSensitivemediadetectionEnum as Detection;
+    match (detection, is_local_user) {
+        (_, None) | (Detection::None, _) => false,
+        (Detection::All, _) => true,
+        (Detection::Local, Some(is_local)) => is_local,
+        (Detection::Remote, Some(is_local)) => !is_local,
+    }
+}
+
+pub struct Classifier {
+    model: TypedRunnableModel<TypedModel>,
+}
+
+impl Classifier {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let input = f32::fact([1, INPUT_SIZE as usize, INPUT_SIZE as usize, 3]);
+        let model = tract_onnx::onnx()
+            .model_for_path(path)?
+            .with_input_fact(0, input.into())?
+            .into_optimized()?
+            .into_runnable()?;
+        Ok(Self { model })
+    }
+
+    /// Scales the image to the size of the input with the bilinear filter, as
+    /// nsfwjs does.
+    pub fn classify(&self, image: &DynamicImage) -> Result<Predictions, Error> {
+        let image = image
+            .resize_exact(INPUT_SIZE, INPUT_SIZE, FilterType::Triangle)
+            .into_rgb8();
+        self.classify_rgb(&image)
+    }
+
+    /// Classifies an image of the size of the input.
+    fn classify_rgb(&self, image: &RgbImage) -> Result<Predictions, Error> {
+        let size = INPUT_SIZE as usize;
+        let input: Tensor =
+            tract_ndarray::Array4::from_shape_fn((1, size, size, 3), |(_, y, x, c)| {
+                f32::from(image.get_pixel(x as u32, y as u32)[c]) / 255.0
+            })
+            .into();
+        let output = self.model.run(tvec!(input.into()))?;
+        let probabilities = output[0].to_array_view::<f32>()?;
+        match probabilities.as_slice() {
+            Some(&[drawing, hentai, neutral, porn, sexy]) => Ok(Predictions {
+                drawing,
+                hentai,
+                neutral,
+                porn,
+                sexy,
+            }),
+            _ => Err(TractError::msg(format!(
+                "Unexpected output of shape {:?}",
+                probabilities.shape()
+            ))
+            .into()),
+        }
+    }
+}
+
+/// Returns the classifier of the model configured by
+/// [super::init_drive], loading it on the first call.
+pub fn get_classifier() -> Result<&'static Classifier, Error> {
+    CLASSIFIER.get_or_try_init(|| {
+        let path = get_drive_config()
+            .sensitive_media_model
+            .as_ref()
+            .ok_or(Error::NotConfigured("sensitive media model"))?;
+        Classifier::load(path)
+    })
+}
+
+/// Indices of the sampled frames, which are 0 and the Fibonacci numbers so
+/// that long videos are not classified frame by frame.
+fn sampled_frames() -> impl Iterator<Item = usize> {
+    std::iter::once(0)
+        .chain(std::iter::successors(Some((1, 2)), |&(a, b)| Some((b, a + b))).map(|(a, _)| a))
+}
+
+/// Classifies the sampled key frames of the video that are not mostly
+/// black, which are scaled to the size of the input by `ffmpeg`.
+fn classify_video(
+    classifier: &Classifier,
+    path: &Path,
+    thresholds: &Thresholds,
+) -> Result<Vec<Judgement>, Error> {
+    let filters = format!(
+        "select='eq(pict_type,PICT_TYPE_I)',blackframe=amount=0,\
+         metadata=mode=select:key=lavfi.blackframe.pblack:value=50:function=less,\
+         scale={size}:{size}",
+        size = INPUT_SIZE
+    );
+    let mut child = Command::new("ffmpeg")
+        .args(["-v", "error", "-skip_frame", "nokey", "-lowres", "3", "-i"])
+        .arg(path)
+        .args(["-an", "-vf", &filters, "-vsync", "0"])
+        .args(["-f", "rawvideo", "-pix_fmt", "rgb24", "pipe:1"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    // drained while the frames are read so that ffmpeg does not block on it
+    let stderr = std::thread::spawn(move || {
+        let mut message = Vec::new();
+        stderr.read_to_end(&mut message).map(|_| message)
+    });
+    let mut sampled = sampled_frames().peekable();
+    let mut judgements = Vec::new();
+    let mut frame = vec![0; FRAME_LEN];
+    for index in 0.. {
+        match stdout.read_exact(&mut frame) {
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+            result => result?,
+        }
+        if sampled.next_if_eq(&index).is_none() {
+            continue;
+        }
+        let image = RgbImage::from_raw(INPUT_SIZE, INPUT_SIZE, frame.clone())
+            .expect("the buffer has the size of a frame");
+        judgements.push(classifier.classify_rgb(&image)?.judge(thresholds));
+    }
+
+    let status = child.wait()?;
+    let message = stderr.join().ok().and_then(Result::ok).unwrap_or_default();
+    let message = String::from_utf8_lossy(&message);
+    if !status.success() {
+        tracing::debug!(%status, "ffmpeg failed: {}", message.trim());
+        if judgements.is_empty() {
+            return Err(Error::FfmpegError(message.trim().to_string()));
+        }
+    }
+    Ok(judgements)
+}
+
+/// Judges the image or the video with the configured model. Videos and APNG
+/// are judged only if `analyze_video`, and other files are not sensitive.
+#[tracing::instrument]
+pub fn detect(
+    path: &Path,
+    mime: &str,
+    thresholds: &Thresholds,
+    analyze_video: bool,
+) -> Result<Judgement, Error> {
+    if IMAGE_TYPES.contains(&mime) {
+        let data = std::fs::read(path)?;
+        let Some(image) = decode(&data, get_drive_config().max_image_pixels)? else {
+            return Ok(Judgement::default());
+        };
+        return Ok(get_classifier()?.classify(&image)?.judge(thresholds));
+    }
+    if analyze_video && (mime == "image/apng" || mime.starts_with("video/")) {
+        let frames = classify_video(get_classifier()?, path, thresholds)?;
+        tracing::debug!(frames = frames.len(), "Classified the video");
+        return Ok(judge_frames(&frames, thresholds));
+    }
+    Ok(Judgement::default())
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi::bindgen_prelude::AsyncTask;
+        use napi::{Env, Task};
+        use napi_derive::napi;
+        use sea_orm::ActiveEnum;
+
+        #[napi(object)]
+        pub struct NativeSensitiveThresholds {
+            pub sensitive: f64,
+            pub porn: f64,
+        }
+
+        #[napi(object)]
+        pub struct NativeSensitiveJudgement {
+            pub sensitive: bool,
+            pub porn: bool,
+        }
+
+        /// Returns the thresholds of `sensitive_media_detection_sensitivity`
+        /// of `meta`.
+        #[napi]
+        pub fn native_sensitive_thresholds(sensitivity: String) -> napi::Result<NativeSensitiveThresholds> {
+            let sensitivity = MetaSensitivemediadetectionsensitivityEnum::try_from_value(&sensitivity)
+                .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+            let thresholds = Thresholds::from(&sensitivity);
+            Ok(NativeSensitiveThresholds {
+                sensitive: thresholds.sensitive.into(),
+                porn: thresholds.porn.into(),
+            })
+        }
+
+        /// Whether the files of the user are classified under
+        /// `sensitive_media_detection` of `meta`.
+        #[napi]
+        pub fn native_should_detect_sensitive(
+            detection: String,
+            is_local_user: Option<bool>,
+        ) -> napi::Result<bool> {
+            let detection = MetaSensitivemediadetectionEnum::try_from_value(&detection)
+                .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+            Ok(should_detect(&detection, is_local_user))
+        }
+
+        pub struct DetectSensitive {
+            path: String,
+            mime: String,
+            thresholds: Thresholds,
+            analyze_video: bool,
+        }
+
+        impl Task for DetectSensitive {
+            type Output = Judgement;
+            type JsValue = NativeSensitiveJudgement;
+
+            fn compute(&mut self) -> napi::Result<Self::Output> {
+                detect(
+                    Path::new(&self.path),
+                    &self.mime,
+                    &self.thresholds,
+                    self.analyze_video,
+                )
+                .map_err(Into::into)
+            }
+
+            fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+                Ok(NativeSensitiveJudgement {
+                    sensitive: output.sensitive,
+                    porn: output.porn,
+                })
+            }
+        }
+
+        /// Calls [detect] in the thread pool of libuv. Rejects with
+        /// `INTERNAL_ERROR` if the model is not configured.
+        #[napi(ts_return_type = "Promise<NativeSensitiveJudgement>")]
+        pub fn native_detect_sensitive(
+            path: String,
+            mime: String,
+            thresholds: NativeSensitiveThresholds,
+            analyze_video: bool,
+        ) -> AsyncTask<DetectSensitive> {
+            AsyncTask::new(DetectSensitive {
+                path,
+                mime,
+                thresholds: Thresholds {
+                    sensitive: thresholds.sensitive as f32,
+                    porn: thresholds.porn as f32,
+                },
+                analyze_video,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{
+        judge_frames, sampled_frames, should_detect, Judgement, Predictions, Thresholds,
+        PORN_THRESHOLD,
+    };
+    use crate::model::entity::sea_orm_active_enums::{
+        MetaSensitivemediadetectionEnum as Detection,
+        MetaSensitivemediadetectionsensitivityEnum as Sensitivity,
+    };
+
+    #[test]
+    fn thresholds_of_sensitivities() {
+        assert_eq!(Thresholds::from(&Sensitivity::VeryHigh).sensitive, 0.1);
+        assert_eq!(Thresholds::from(&Sensitivity::Medium).sensitive, 0.5);
+        assert_eq!(Thresholds::from(&Sensitivity::VeryLow).sensitive, 0.9);
+        assert_eq!(Thresholds::from(&Sensitivity::Low).porn, PORN_THRESHOLD);
+    }
+
+    #[test]
+    fn judge_predictions() {
+        let thresholds = Thresholds::from(&Sensitivity::Medium);
+        let judge = |predictions: Predictions| predictions.judge(&thresholds);
+        let neutral = Predictions {
+            neutral: 0.9,
+            drawing: 0.1,
+            ..Default::default()
+        };
+        assert_eq!(judge(neutral), Judgement::default());
+        let sexy = Predictions {
+            sexy: 0.6,
+            neutral: 0.4,
+            ..Default::default()
+        };
+        assert_eq!(
+            judge(sexy),
+            Judgement {
+                sensitive: true,
+                porn: false
+            }
+        );
+        let porn = Predictions {
+            porn: 0.8,
+            sexy: 0.2,
+            ..Default::default()
+        };
+        assert_eq!(
+            judge(porn),
+            Judgement {
+                sensitive: true,
+                porn: true
+            }
+        );
+    }
+
+    #[test]
+    fn judge_videos() {
+        let thresholds = Thresholds::from(&Sensitivity::Medium);
+        let frame = |sensitive, porn| Judgement { sensitive, porn };
+        assert_eq!(judge_frames(&[], &thresholds), Judgement::default());
+        assert_eq!(
+            judge_frames(
+                &[frame(true, true), frame(true, false), frame(false, false)],
+                &thresholds
+            ),
+            frame(true, false)
+        );
+        assert_eq!(
+            judge_frames(&[frame(true, true), frame(true, true)], &thresholds),
+            frame(true, true)
+        );
+    }
+
+    #[test]
+    fn detection_of_users() {
+        assert!(should_detect(&Detection::All, Some(false)));
+        assert!(should_detect(&Detection::Local, Some(true)));
+        assert!(!should_detect(&Detection::Local, Some(false)));
+        assert!(should_detect(&Detection::Remote, Some(false)));
+        assert!(!should_detect(&Detection::None, Some(true)));
+        assert!(!should_detect(&Detection::All, None));
+    }
+
+    #[test]
+    fn sample_fibonacci_frames() {
+        assert_eq!(
+            sampled_frames().take(8).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 5, 8, 13, 21]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{FileKind, Storage};
+use crate::drive::error::Error;
+use crate::drive::DriveConfig;
+
+/// Files in a local directory, served at `/files/<key>` by
+/// `server/file/send-drive-file.ts`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InternalStorage {
+    dir: PathBuf,
+    url: String,
+}
+
+impl InternalStorage {
+    pub fn new(config: &DriveConfig) -> Self {
+        Self {
+            dir: config.files_dir.clone(),
+            url: config.url.clone(),
+        }
+    }
+
+    /// Returns the path of the file, rejecting keys that would escape the
+    /// directory.
+    fn path(&self, key: &str) -> Result<PathBuf, Error> {
+        if key.is_empty() || key.contains(['/', '\\']) || key == "." || key == ".." {
+            return Err(Error::InvalidKey(key.to_string()));
+        }
+        Ok(self.dir.join(key))
+    }
+
+    /// Makes the object of `key` also readable as `new_key` by a hard link,
+    /// so it is deleted once both keys are.
+    pub async fn link(&self, key: &str, new_key: &str) -> Result<(), Error> {
+        tokio::fs::hard_link(self.path(key)?, self.path(new_key)?).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage for InternalStorage {
+    fn is_internal(&self) -> bool {
+        true
+    }
+
+    /// Generates `<uuid>`, `thumbnail-<uuid>` or `webpublic-<uuid>`. The
+    /// extension is omitted as the type is detected when the file is sent.
+    fn generate_key(&self, kind: FileKind, _ext: Option<&str>) -> String {
+        let id = Uuid::new_v4();
+        match kind {
+            FileKind::Original => id.to_string(),
+            FileKind::Thumbnail => format!("thumbnail-{}", id),
+            FileKind::Webpublic => format!("webpublic-{}", id),
+        }
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/files/{}", self.url, key)
+    }
+
+    async fn put(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        _mime: &str,
+        _filename: Option<&str>,
+    ) -> Result<String, Error> {
+        let path = self.path(key)?;
+        tokio::fs::create_dir_all(&self.dir).await?;
+        tokio::fs::write(path, data).await?;
+        Ok(self.url(key))
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, Error> {
+        Ok(tokio::fs::read(self.path(key)?).await?)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The files are public, so the URL does not expire.
+    fn presign(&self, key: &str, _expires_in: Duration) -> Result<String, Error> {
+        self.path(key)?;
+        Ok(self.url(key))
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::InternalStorage;
+    use crate::drive::error::Error;
+    use crate::drive::storage::{FileKind, Storage};
+    use crate::drive::DriveConfig;
+
+    #[tokio::test]
+    async fn put_get_and_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = InternalStorage::new(&DriveConfig {
+            files_dir: dir.path().join("files"),
+            url: "https://example.com".to_string(),
+            ..Default::default()
+        });
+
+        let key = storage.generate_key(FileKind::Thumbnail, Some("webp"));
+        assert!(key.starts_with("thumbnail-"));
+        let url = storage
+            .put(&key, b"data".to_vec(), "image/webp", None)
+            .await;
+        assert_eq!(url.unwrap(), format!("https://example.com/files/{}", key));
+        assert_eq!(storage.get(&key).await.unwrap(), b"data");
+        assert_eq!(
+            storage.presign(&key, Duration::from_secs(60)).unwrap(),
+            format!("https://example.com/files/{}", key)
+        );
+
+        storage.delete(&key).await.unwrap();
+        storage.delete(&key).await.unwrap();
+        assert!(matches!(storage.get(&key).await, Err(Error::IoError(_))));
+        assert!(matches!(
+            storage.get("../secret").await,
+            Err(Error::InvalidKey(_))
+        ));
+    }
+}
@@ -0,0 +1,231 @@
+//! Storage of the drive files, either in the local filesystem or in an
+//! S3-compatible object storage, which are `services/drive/internal-storage.ts`
+//! and `services/drive/s3.ts`.
+
+pub mod internal;
+pub mod object;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cfg_if::cfg_if;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use super::error::Error;
+use super::{fetch_meta, get_drive_config};
+use crate::model::entity::meta;
+pub use internal::InternalStorage;
+pub use object::ObjectStorage;
+
+/// Kinds of the objects stored for a drive file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileKind {
+    /// `access_key` of `drive_file`.
+    Original,
+    /// `thumbnail_access_key` of `drive_file`.
+    Thumbnail,
+    /// `webpublic_access_key` of `drive_file`.
+    Webpublic,
+}
+
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Whether the objects are in the local filesystem, which is
+    /// `stored_internal` of `drive_file`.
+    fn is_internal(&self) -> bool;
+    /// Generates a new key, with the extension `ext` if given.
+    fn generate_key(&self, kind: FileKind, ext: Option<&str>) -> String;
+    /// Returns the public URL of the object.
+    fn url(&self, key: &str) -> String;
+    /// Stores the object and returns its public URL. `filename` is sent back
+    /// in `Content-Disposition`.
+    async fn put(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        mime: &str,
+        filename: Option<&str>,
+    ) -> Result<String, Error>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, Error>;
+    /// Deletes the object. Deleting a missing object succeeds.
+    async fn delete(&self, key: &str) -> Result<(), Error>;
+    /// Returns a URL to read the object, which expires after `expires_in`
+    /// unless the storage is public.
+    fn presign(&self, key: &str, expires_in: Duration) -> Result<String, Error>;
+}
+
+/// The object storage with the `meta` it was configured from, so that its
+/// client is reused until the settings change.
+static OBJECT_STORAGE: Mutex<Option<(meta::Model, Arc<ObjectStorage>)>> = Mutex::new(None);
+
+/// Returns the object storage configured in `meta`.
+pub fn object_storage(meta: &meta::Model) -> Result<Arc<ObjectStorage>, Error> {
+    let mut cached = OBJECT_STORAGE.lock().unwrap();
+    if let Some((configured, storage)) = cached.as_ref() {
+        if configured == meta {
+            return Ok(storage.clone());
+        }
+    }
+    let storage = Arc::new(ObjectStorage::from_meta(meta, get_drive_config())?);
+    *cached = Some((meta.clone(), storage.clone()));
+    Ok(storage)
+}
+
+/// Returns the storage of new files configured in `meta`.
+pub fn storage_from_meta(meta: &meta::Model) -> Result<Arc<dyn Storage>, Error> {
+    match meta.use_object_storage {
+        true => Ok(object_storage(meta)?),
+        false => Ok(Arc::new(InternalStorage::new(get_drive_config()))),
+    }
+}
+
+/// Fetches `meta` and returns the storage of new files.
+pub async fn fetch_storage() -> Result<Arc<dyn Storage>, Error> {
+    storage_from_meta(&fetch_meta().await?)
+}
+
+/// Returns the storage of an existing file, which is the object storage of
+/// `meta` unless `stored_internal`.
+pub async fn fetch_file_storage(stored_internal: bool) -> Result<Arc<dyn Storage>, Error> {
+    match stored_internal {
+        true => Ok(Arc::new(InternalStorage::new(get_drive_config()))),
+        false => Ok(object_storage(&fetch_meta().await?)?),
+    }
+}
+
+/// `attr-char` of RFC 5987.
+const ATTR_CHAR: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'!')
+    .remove(b'#')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'^')
+    .remove(b'_')
+    .remove(b'`')
+    .remove(b'|')
+    .remove(b'~');
+
+/// Same as `contentDisposition("inline", filename)` of
+/// `misc/content-disposition.ts`.
+pub(crate) fn content_disposition(filename: &str) -> String {
+    let fallback: String = filename
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() || "_.-".contains(c) {
+            true => c,
+            false => '_',
+        })
+        .collect();
+    match fallback == filename {
+        true => format!(r#"inline; filename="{}""#, filename),
+        false => format!(
+            r#"inline; filename="{}"; filename*=UTF-8''{}"#,
+            fallback,
+            utf8_percent_encode(filename, ATTR_CHAR)
+        ),
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi::bindgen_prelude::Buffer;
+        use napi::{Env, JsObject};
+        use napi_derive::napi;
+
+        /// Stores the object in the object storage configured in `meta`, and
+        /// returns its URL. The buffer is copied as it can not be sent to the
+        /// runtime.
+        #[napi(ts_return_type = "Promise<string>")]
+        pub fn native_put_drive_object(
+            env: Env,
+            key: String,
+            data: Buffer,
+            mime: String,
+            filename: Option<String>,
+        ) -> napi::Result<JsObject> {
+            let data = data.to_vec();
+            env.spawn_future(async move {
+                let storage = fetch_file_storage(false).await;
+                match storage {
+                    Ok(storage) => storage.put(&key, data, &mime, filename.as_deref()).await,
+                    Err(e) => Err(e),
+                }
+                .map_err(Into::into)
+            })
+        }
+
+        /// Stores the file at `path` as [native_put_drive_object] does,
+        /// without reading it into the JS heap.
+        #[napi]
+        pub async fn native_put_drive_object_from_path(
+            key: String,
+            path: String,
+            mime: String,
+            filename: Option<String>,
+        ) -> napi::Result<String> {
+            async {
+                let data = tokio::fs::read(&path).await?;
+                let storage = fetch_file_storage(false).await?;
+                storage.put(&key, data, &mime, filename.as_deref()).await
+            }
+            .await
+            .map_err(Into::into)
+        }
+
+        /// Deletes the object of a drive file.
+        #[napi]
+        pub async fn native_delete_drive_object(
+            key: String,
+            stored_internal: bool,
+        ) -> napi::Result<()> {
+            async {
+                let storage = fetch_file_storage(stored_internal).await?;
+                storage.delete(&key).await
+            }
+            .await
+            .map_err(Into::into)
+        }
+
+        /// Returns a URL to read the object of a drive file, which expires
+        /// after `expires_in` seconds.
+        #[napi]
+        pub async fn native_presign_drive_object(
+            key: String,
+            stored_internal: bool,
+            expires_in: u32,
+        ) -> napi::Result<String> {
+            async {
+                let storage = fetch_file_storage(stored_internal).await?;
+                storage.presign(&key, Duration::from_secs(expires_in.into()))
+            }
+            .await
+            .map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::content_disposition;
+
+    #[test]
+    fn disposition_of_filenames() {
+        assert_eq!(
+            content_disposition("image.png"),
+            r#"inline; filename="image.png""#
+        );
+        assert_eq!(
+            content_disposition("my image.png"),
+            r#"inline; filename="my_image.png"; filename*=UTF-8''my%20image.png"#
+        );
+        assert_eq!(
+            content_disposition("画像.png"),
+            r#"inline; filename="__.png"; filename*=UTF-8''%E7%94%BB%E5%83%8F.png"#
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Stable codes of the errors passed to JS. The codes and IDs are the ones of
+//! `ApiError` and `IdentifiableError` in the API, so that endpoints can
+//! forward the errors unchanged. `NOT_INITIALIZED` and `SERVICE_UNAVAILABLE`
+//! have no counterparts there and are documented as new errors in
+//! `server/api/openapi/errors.ts`.
+
+use cfg_if::cfg_if;
+use parse_display::Display;
+use sea_orm::DbErr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+#[display(style = "SNAKE_CASE")]
+pub enum ErrorCode {
+    /// Unexpected failure, e.g. of a query.
+    InternalError,
+    /// Invalid argument, such as a malformed URI or search query.
+    InvalidParam,
+    /// The requested row does not exist.
+    NoSuchObject,
+    /// The requested note does not exist, which is the error of `getNote`.
+    NoSuchNote,
+    /// The drive of the user is full.
+    NoFreeSpace,
+    /// A connection or index has not been initialized yet.
+    NotInitialized,
+    /// The database or cache can not be reached.
+    ServiceUnavailable,
+}
+
+pub const ALL_ERROR_CODES: [ErrorCode; 7] = [
+    ErrorCode::InternalError,
+    ErrorCode::InvalidParam,
+    ErrorCode::NoSuchObject,
+    ErrorCode::NoSuchNote,
+    ErrorCode::NoFreeSpace,
+    ErrorCode::NotInitialized,
+    ErrorCode::ServiceUnavailable,
+];
+
+impl ErrorCode {
+    /// ID of the error in the API, which must never be changed.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::InternalError => "5d37dbcb-891e-41ca-a3d6-e690c97775ac",
+            Self::InvalidParam => "0b5f1631-7c1a-41a6-b399-cce335f34d85",
+            // `NO_SUCH_OBJECT` of `ap/show`
+            Self::NoSuchObject => "dc94d745-1262-4e63-a17d-fecaa57efc82",
+            Self::NoSuchNote => "9725d0ce-ba28-4dde-95a7-2cbb2c15de24",
+            Self::NoFreeSpace => "d08dbc37-a6a9-463a-8c47-96c32ab5f064",
+            Self::NotInitialized => "8cdccf14-2668-4569-82b2-8337deb29dcd",
+            Self::ServiceUnavailable => "b0036050-920f-4dba-99a2-9f27f502c023",
+        }
+    }
+
+    /// HTTP status of the error in the API.
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::InternalError => 500,
+            Self::InvalidParam | Self::NoFreeSpace => 400,
+            Self::NoSuchObject | Self::NoSuchNote => 404,
+            Self::NotInitialized | Self::ServiceUnavailable => 503,
+        }
+    }
+
+    pub fn from_db_err(err: &DbErr) -> Self {
+        match err {
+            DbErr::ConnectionAcquire | DbErr::Conn(_) => Self::ServiceUnavailable,
+            DbErr::RecordNotFound(_) => Self::NoSuchObject,
+            _ => Self::InternalError,
+        }
+    }
+}
+
+/// Errors that are passed to JS with an [ErrorCode].
+pub trait HasErrorCode {
+    fn code(&self) -> ErrorCode;
+}
+
+impl HasErrorCode for DbErr {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::from_db_err(self)
+    }
+}
+
+/// Returns the reason of the errors passed to JS, which holds the fields of
+/// the error as JSON because napi-rs rejects promises with errors that have
+/// no other fields than the message.
+pub fn error_reason<E: HasErrorCode + std::fmt::Display>(err: &E) -> String {
+    let code = err.code();
+    serde_json::json!({
+        "code": code.to_string(),
+        "id": code.id(),
+        "status": code.status(),
+        "message": err.to_string(),
+    })
+    .to_string()
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        /// Converts `err` into an error with the reason of [error_reason],
+        /// which `misc/native-error.ts` turns into a `NativeError`.
+        pub fn to_napi_error<E: HasErrorCode + std::fmt::Display>(err: &E) -> napi::Error {
+            let status = match err.code() {
+                ErrorCode::InvalidParam => napi::Status::InvalidArg,
+                _ => napi::Status::GenericFailure,
+            };
+            napi::Error::new(status, error_reason(err))
+        }
+
+        #[napi(object)]
+        pub struct NativeErrorCode {
+            pub code: String,
+            pub id: String,
+            pub status: u16,
+        }
+
+        impl From<ErrorCode> for NativeErrorCode {
+            fn from(code: ErrorCode) -> Self {
+                Self {
+                    code: code.to_string(),
+                    id: code.id().to_string(),
+                    status: code.status(),
+                }
+            }
+        }
+
+        /// Returns the codes of the errors thrown by native functions.
+        #[napi]
+        pub fn native_error_codes() -> Vec<NativeErrorCode> {
+            ALL_ERROR_CODES.into_iter().map(Into::into).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+    use sea_orm::{DbErr, RuntimeErr};
+
+    use super::{error_reason, ErrorCode, HasErrorCode, ALL_ERROR_CODES};
+    use crate::{database, model, util::id};
+
+    #[test]
+    fn error_codes_are_unique() {
+        let mut ids: Vec<&str> = ALL_ERROR_CODES.iter().map(|c| c.id()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), ALL_ERROR_CODES.len());
+        assert_eq!(ErrorCode::NoSuchNote.to_string(), "NO_SUCH_NOTE");
+    }
+
+    #[test]
+    fn errors_have_codes() {
+        assert_eq!(
+            model::error::Error::NotFound.code(),
+            ErrorCode::NoSuchObject
+        );
+        assert_eq!(
+            model::error::Error::DbConnError(database::error::Error::Uninitialized).code(),
+            ErrorCode::NotInitialized
+        );
+        assert_eq!(
+            model::error::Error::DbOperationError(DbErr::Conn(RuntimeErr::Internal(
+                "refused".to_string()
+            )))
+            .code(),
+            ErrorCode::ServiceUnavailable
+        );
+        assert_eq!(
+            database::error::Error::InvalidSslMode("foo".to_string()).code(),
+            ErrorCode::InvalidParam
+        );
+        assert_eq!(id::ErrorUninitialized.code(), ErrorCode::NotInitialized);
+    }
+
+    #[test]
+    fn reason_has_fields() {
+        let reason: serde_json::Value =
+            serde_json::from_str(&error_reason(&model::error::Error::NotFound)).unwrap();
+        assert_eq!(
+            reason,
+            serde_json::json!({
+                "code": "NO_SUCH_OBJECT",
+                "id": "dc94d745-1262-4e63-a17d-fecaa57efc82",
+                "status": 404,
+                "message": model::error::Error::NotFound.to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Failed to get database connection: {0}")]
+    DbConnError(#[from] crate::database::error::Error),
+    #[error("Database operation error: {0}")]
+    DbOperationError(#[from] sea_orm::DbErr),
+    #[error("HTTP error: {0}")]
+    HttpError(#[from] reqwest::Error),
+    #[error("Invalid JSON: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Invalid URL: {0}")]
+    UrlError(#[from] url::ParseError),
+    #[error("Response from {0} exceeds the size limit")]
+    TooLarge(String),
+    #[error("No NodeInfo link provided")]
+    NoNodeInfo,
+    #[error("Invalid host: {0}")]
+    InvalidHost(String),
+    #[error("No such instance: {0}")]
+    NoSuchInstance(String),
+    #[error("HTTP client has not been initialized yet")]
+    Uninitialized,
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::HttpError(e) if e.is_connect() || e.is_timeout() => ErrorCode::ServiceUnavailable,
+            Self::InvalidHost(_) => ErrorCode::InvalidParam,
+            Self::NoSuchInstance(_) => ErrorCode::NoSuchObject,
+            Self::Uninitialized => ErrorCode::NotInitialized,
+            Self::HttpError(_)
+            | Self::JsonError(_)
+            | Self::UrlError(_)
+            | Self::TooLarge(_)
+            | Self::NoNodeInfo => ErrorCode::InternalError,
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
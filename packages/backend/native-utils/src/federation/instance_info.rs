@@ -0,0 +1,664 @@
+//! Metadata of remote instances, replacing `services/fetch-instance-metadata.ts`.
+//!
+//! The metadata is taken from NodeInfo, the HTML of the top page and the web
+//! app manifest, preferring them in this order. The sources that fail to be
+//! fetched are skipped, and the fields found in none of them are kept, except
+//! that the fields only NodeInfo provides are cleared if it was fetched.
+
+use std::time::Duration;
+
+use cfg_if::cfg_if;
+use chrono::{DateTime, Utc};
+use once_cell::sync::OnceCell;
+use scraper::{Html, Selector};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set, Unchanged};
+use serde_json::Value;
+use url::Url;
+
+use super::error::Error;
+use crate::database;
+use crate::model::entity::instance;
+
+/// `rel` of the NodeInfo links, from the preferred one.
+const NODEINFO_RELS: [&str; 3] = [
+    "http://nodeinfo.diaspora.software/ns/schema/2.1",
+    "http://nodeinfo.diaspora.software/ns/schema/2.0",
+    "http://nodeinfo.diaspora.software/ns/schema/1.0",
+];
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// Maximum size of the responses in bytes.
+const MAX_SIZE: usize = 1024 * 1024;
+/// Minimum interval of the updates of the metadata without `force`.
+const INFO_TTL: chrono::Duration = chrono::Duration::hours(24);
+
+static CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+
+/// Sets up the HTTP client with `config.userAgent`, `config.proxy` and
+/// `config.proxyBypassHosts`. Must be called before [update].
+pub fn init_client(
+    user_agent: &str,
+    proxy: Option<&str>,
+    proxy_bypass_hosts: &[String],
+) -> Result<(), Error> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(TIMEOUT)
+        .user_agent(user_agent);
+    builder = match proxy {
+        Some(proxy) => builder.proxy(
+            reqwest::Proxy::all(proxy)?
+                .no_proxy(reqwest::NoProxy::from_string(&proxy_bypass_hosts.join(","))),
+        ),
+        // not taken from the environment, as `misc/fetch.ts` does
+        None => builder.no_proxy(),
+    };
+    let client = builder.build()?;
+    CLIENT.get_or_init(|| client);
+    Ok(())
+}
+
+fn client() -> Result<&'static reqwest::Client, Error> {
+    CLIENT.get().ok_or(Error::Uninitialized)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InstanceInfo {
+    pub software_name: Option<String>,
+    pub software_version: Option<String>,
+    pub open_registrations: Option<bool>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub maintainer_name: Option<String>,
+    pub maintainer_email: Option<String>,
+    pub icon_url: Option<String>,
+    pub favicon_url: Option<String>,
+    pub theme_color: Option<String>,
+}
+
+/// Metadata found in the HTML of the top page.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Page {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub theme_color: Option<String>,
+    /// Apple touch icon, or the icon otherwise.
+    pub icon_url: Option<String>,
+    pub favicon_url: Option<String>,
+    pub manifest_url: Option<Url>,
+}
+
+/// Metadata found in the web app manifest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub theme_color: Option<String>,
+    pub icon_url: Option<String>,
+}
+
+/// Returns the string at `pointer` of `value` unless it is blank.
+fn str_at(value: &Value, pointer: &str) -> Option<String> {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Truncates `s` to `max` characters, which is the length of the column.
+fn truncate(s: String, max: usize) -> String {
+    match s.char_indices().nth(max) {
+        Some((end, _)) => s[..end].to_string(),
+        None => s,
+    }
+}
+
+/// Converts the name of the software into the form required by NodeInfo
+/// 2.1, such as `firefish` for `Firefish` and `glitch-soc` for `glitch_soc`.
+pub fn normalize_software_name(name: &str) -> Option<String> {
+    let mut normalized = String::new();
+    for c in name.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            normalized.push(c);
+        } else if !normalized.is_empty() && !normalized.ends_with('-') {
+            normalized.push('-');
+        }
+    }
+    let normalized = truncate(normalized, 64);
+    let normalized = normalized.trim_end_matches('-');
+    (!normalized.is_empty()).then(|| normalized.to_string())
+}
+
+/// Converts a color in hex or `rgb()` notation into `#rrggbb`, dropping the
+/// alpha, as `toHexString` of tinycolor does.
+pub fn normalize_color(color: &str) -> Option<String> {
+    let color = color.trim().to_ascii_lowercase();
+    let rgb: Vec<u8> = if let Some(args) = color
+        .strip_prefix("rgba(")
+        .or_else(|| color.strip_prefix("rgb("))
+    {
+        let args: Vec<&str> = args.strip_suffix(')')?.split(',').map(str::trim).collect();
+        if !(3..=4).contains(&args.len()) {
+            return None;
+        }
+        args[..3]
+            .iter()
+            .map(|c| c.parse().ok())
+            .collect::<Option<_>>()?
+    } else {
+        let hex = color.strip_prefix('#').unwrap_or(&color);
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let digits = match hex.len() {
+            3 | 4 => hex.chars().take(3).flat_map(|c| [c, c]).collect(),
+            6 | 8 => hex[..6].to_string(),
+            _ => return None,
+        };
+        hex::decode(digits).ok()?
+    };
+    Some(format!("#{}", hex::encode(rgb)))
+}
+
+/// Returns the URL of the preferred NodeInfo in the document of
+/// `/.well-known/nodeinfo`.
+pub fn nodeinfo_url(wellknown: &Value, base: &Url) -> Option<Url> {
+    let links = wellknown.get("links")?.as_array()?;
+    NODEINFO_RELS.iter().find_map(|rel| {
+        links
+            .iter()
+            .find(|link| link.get("rel").and_then(Value::as_str) == Some(rel))
+            .and_then(|link| str_at(link, "/href"))
+            .and_then(|href| base.join(&href).ok())
+    })
+}
+
+/// Reads the metadata of a NodeInfo document, including the extensions in
+/// `metadata` common among the implementations.
+pub fn parse_nodeinfo(info: &Value) -> InstanceInfo {
+    let first = |pointers: &[&str]| pointers.iter().find_map(|p| str_at(info, p));
+    InstanceInfo {
+        software_name: str_at(info, "/software/name")
+            .and_then(|name| normalize_software_name(&name)),
+        software_version: str_at(info, "/software/version").map(|v| truncate(v, 64)),
+        open_registrations: info.get("openRegistrations").and_then(Value::as_bool),
+        name: first(&["/metadata/nodeName", "/metadata/name"]),
+        description: first(&["/metadata/nodeDescription", "/metadata/description"]),
+        maintainer_name: str_at(info, "/metadata/maintainer/name"),
+        maintainer_email: str_at(info, "/metadata/maintainer/email"),
+        theme_color: str_at(info, "/metadata/themeColor").and_then(|c| normalize_color(&c)),
+        ..Default::default()
+    }
+}
+
+/// Reads the metadata of the HTML at `url`.
+pub fn parse_html(html: &str, url: &Url) -> Page {
+    let doc = Html::parse_document(html);
+    let meta = |attr: &str, value: &str| {
+        let selector = Selector::parse(&format!("meta[{}=\"{}\"]", attr, value)).ok()?;
+        doc.select(&selector)
+            .find_map(|e| e.value().attr("content"))
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+
+    // the last links take precedence, as browsers do
+    let links: Vec<(Vec<String>, Url)> = Selector::parse("link[rel][href]")
+        .map(|selector| {
+            doc.select(&selector)
+                .filter_map(|e| {
+                    let rels = e.value().attr("rel")?.to_ascii_lowercase();
+                    let href = url.join(e.value().attr("href")?.trim()).ok()?;
+                    Some((rels.split_whitespace().map(str::to_string).collect(), href))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let link = |rel: &str| {
+        links
+            .iter()
+            .rev()
+            .find(|(rels, _)| rels.iter().any(|r| r == rel))
+            .map(|(_, href)| href.to_owned())
+    };
+
+    let favicon = link("icon");
+    let icon = link("apple-touch-icon-precomposed")
+        .or_else(|| link("apple-touch-icon"))
+        .or_else(|| favicon.clone());
+    Page {
+        name: meta("property", "og:title"),
+        description: meta("name", "description").or_else(|| meta("property", "og:description")),
+        theme_color: meta("name", "theme-color").and_then(|c| normalize_color(&c)),
+        icon_url: icon.map(String::from),
+        favicon_url: favicon.map(String::from),
+        manifest_url: link("manifest"),
+    }
+}
+
+/// Reads the metadata of the web app manifest at `url`.
+pub fn parse_manifest(manifest: &Value, url: &Url) -> Manifest {
+    Manifest {
+        name: str_at(manifest, "/name").or_else(|| str_at(manifest, "/short_name")),
+        description: str_at(manifest, "/description"),
+        theme_color: str_at(manifest, "/theme_color").and_then(|c| normalize_color(&c)),
+        icon_url: str_at(manifest, "/icons/0/src")
+            .and_then(|src| url.join(&src).ok())
+            .map(String::from),
+    }
+}
+
+/// Fills the metadata missing in NodeInfo with the ones of the page and the
+/// manifest, and limits them to the lengths of the columns.
+pub fn combine(
+    nodeinfo: InstanceInfo,
+    page: Page,
+    manifest: Manifest,
+    favicon_url: Option<String>,
+) -> InstanceInfo {
+    // URLs are useless when truncated
+    let short = |url: Option<String>| url.filter(|url| url.chars().count() <= 256);
+    let favicon_url = short(page.favicon_url).or(short(favicon_url));
+    let icon_url = short(manifest.icon_url)
+        .or(short(page.icon_url))
+        .or_else(|| favicon_url.clone());
+    InstanceInfo {
+        name: nodeinfo
+            .name
+            .or(page.name)
+            .or(manifest.name)
+            .map(|s| truncate(s, 256)),
+        description: nodeinfo
+            .description
+            .or(page.description)
+            .or(manifest.description)
+            .map(|s| truncate(s, 4096)),
+        maintainer_name: nodeinfo.maintainer_name.map(|s| truncate(s, 128)),
+        maintainer_email: nodeinfo.maintainer_email.map(|s| truncate(s, 256)),
+        theme_color: nodeinfo
+            .theme_color
+            .or(page.theme_color)
+            .or(manifest.theme_color),
+        icon_url,
+        favicon_url,
+        ..nodeinfo
+    }
+}
+
+/// Fetches `url`, failing if the response is not successful or too large.
+async fn get(url: &Url) -> Result<Vec<u8>, Error> {
+    let mut response = client()?
+        .get(url.to_owned())
+        .send()
+        .await?
+        .error_for_status()?;
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if body.len() > MAX_SIZE {
+            return Err(Error::TooLarge(url.to_string()));
+        }
+    }
+    Ok(body)
+}
+
+async fn get_json(url: &Url) -> Result<Value, Error> {
+    Ok(serde_json::from_slice(&get(url).await?)?)
+}
+
+async fn fetch_nodeinfo(base: &Url) -> Result<InstanceInfo, Error> {
+    let wellknown = get_json(&base.join("/.well-known/nodeinfo")?).await?;
+    let url = nodeinfo_url(&wellknown, base).ok_or(Error::NoNodeInfo)?;
+    Ok(parse_nodeinfo(&get_json(&url).await?))
+}
+
+async fn fetch_page(base: &Url) -> Result<Page, Error> {
+    let html = get(base).await?;
+    Ok(parse_html(&String::from_utf8_lossy(&html), base))
+}
+
+async fn fetch_manifest(url: &Url) -> Result<Manifest, Error> {
+    Ok(parse_manifest(&get_json(url).await?, url))
+}
+
+/// Returns `/favicon.ico` if it exists.
+async fn fetch_favicon(base: &Url) -> Result<Option<String>, Error> {
+    let url = base.join("/favicon.ico")?;
+    let response = client()?.get(url.to_owned()).send().await?;
+    Ok(response.status().is_success().then(|| url.into()))
+}
+
+/// Fetches the metadata of the instance served at `base`. Returns the
+/// metadata and whether NodeInfo was fetched.
+#[tracing::instrument(skip_all, fields(%base))]
+pub async fn fetch(base: &Url) -> (InstanceInfo, bool) {
+    let (nodeinfo, page) = tokio::join!(fetch_nodeinfo(base), fetch_page(base));
+    let (nodeinfo, has_nodeinfo) = match nodeinfo {
+        Ok(nodeinfo) => (nodeinfo, true),
+        Err(e) => {
+            tracing::info!("Failed to fetch NodeInfo: {}", e);
+            (InstanceInfo::default(), false)
+        }
+    };
+    let page = page.unwrap_or_else(|e| {
+        tracing::info!("Failed to fetch HTML: {}", e);
+        Page::default()
+    });
+
+    let manifest = async {
+        let url = match &page.manifest_url {
+            Some(url) => url.to_owned(),
+            None => base.join("/manifest.json")?,
+        };
+        fetch_manifest(&url).await
+    };
+    let favicon = async {
+        match page.favicon_url {
+            Some(_) => Ok(None),
+            None => fetch_favicon(base).await,
+        }
+    };
+    let (manifest, favicon) = tokio::join!(manifest, favicon);
+    let manifest = manifest.unwrap_or_else(|e| {
+        tracing::debug!("Failed to fetch manifest: {}", e);
+        Manifest::default()
+    });
+    let info = combine(nodeinfo, page, manifest, favicon.ok().flatten());
+    (info, has_nodeinfo)
+}
+
+/// Returns the HTTPS URL of the instance with `host`, which may include the
+/// port.
+fn base_url(host: &str) -> Result<Url, Error> {
+    Url::parse(&format!("https://{}", host))
+        .ok()
+        .filter(|url| {
+            url.username().is_empty() && url.password().is_none() && url.authority() == host
+        })
+        .ok_or_else(|| Error::InvalidHost(host.to_string()))
+}
+
+/// Fetches the metadata of the instance with `host` over HTTPS and saves it,
+/// unless it has been updated within a day and `force` is false. Returns
+/// whether the metadata was updated.
+pub async fn update(host: &str, force: bool) -> Result<bool, Error> {
+    update_from(host, &base_url(host)?, force, Utc::now()).await
+}
+
+/// Same as [update], but fetches the metadata from `base`.
+#[tracing::instrument(skip(base))]
+pub async fn update_from(
+    host: &str,
+    base: &Url,
+    force: bool,
+    now: DateTime<Utc>,
+) -> Result<bool, Error> {
+    let db = &database::get_database()?;
+    let found = instance::Entity::find()
+        .filter(instance::Column::Host.eq(host))
+        .one(db)
+        .await?
+        .ok_or_else(|| Error::NoSuchInstance(host.to_string()))?;
+    if !force
+        && found
+            .info_updated_at
+            .is_some_and(|at| now.signed_duration_since(at) < INFO_TTL)
+    {
+        return Ok(false);
+    }
+
+    let (info, has_nodeinfo) = fetch(base).await;
+    let mut model = instance::ActiveModel {
+        id: Unchanged(found.id),
+        info_updated_at: Set(Some(now.into())),
+        ..Default::default()
+    };
+    if has_nodeinfo {
+        // provided only by NodeInfo, so cleared if missing there
+        model.software_name = Set(info.software_name);
+        model.software_version = Set(info.software_version);
+        model.open_registrations = Set(info.open_registrations);
+        model.maintainer_name = Set(info.maintainer_name);
+        model.maintainer_email = Set(info.maintainer_email);
+    }
+    macro_rules! set_found {
+        ($($field:ident),*) => {
+            $(if let Some(value) = info.$field {
+                model.$field = Set(Some(value));
+            })*
+        };
+    }
+    set_found!(name, description, icon_url, favicon_url, theme_color);
+    model.update(db).await?;
+    tracing::info!("Updated metadata");
+    Ok(true)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        /// Calls [init_client] inside.
+        #[napi]
+        pub fn native_init_federation_client(
+            user_agent: String,
+            proxy: Option<String>,
+            proxy_bypass_hosts: Option<Vec<String>>,
+        ) -> napi::Result<()> {
+            init_client(
+                &user_agent,
+                proxy.as_deref(),
+                &proxy_bypass_hosts.unwrap_or_default(),
+            )
+            .map_err(Into::into)
+        }
+
+        /// Calls [update] inside.
+        #[napi]
+        pub async fn native_update_instance_info(host: String, force: bool) -> napi::Result<bool> {
+            update(&host, force).await.map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use url::Url;
+
+    use super::{
+        base_url, combine, nodeinfo_url, normalize_color, normalize_software_name, parse_html,
+        parse_manifest, parse_nodeinfo, InstanceInfo, Manifest, Page,
+    };
+
+    fn base() -> Url {
+        Url::parse("https://example.com").unwrap()
+    }
+
+    #[test]
+    fn check_hosts() {
+        assert_eq!(
+            base_url("example.com").map(String::from).ok(),
+            Some("https://example.com/".to_string())
+        );
+        assert_eq!(
+            base_url("example.com:8443").map(String::from).ok(),
+            Some("https://example.com:8443/".to_string())
+        );
+        assert!(base_url("EXAMPLE.com").is_err());
+        assert!(base_url("example.com:443").is_err());
+        assert!(base_url("user@example.com").is_err());
+        assert!(base_url("example.com/path").is_err());
+    }
+
+    #[test]
+    fn normalize_software_names() {
+        assert_eq!(
+            normalize_software_name("Firefish"),
+            Some("firefish".to_string())
+        );
+        assert_eq!(
+            normalize_software_name(" glitch_soc "),
+            Some("glitch-soc".to_string())
+        );
+        assert_eq!(
+            normalize_software_name("Mastodon (Hometown)"),
+            Some("mastodon-hometown".to_string())
+        );
+        assert_eq!(normalize_software_name("???"), None);
+    }
+
+    #[test]
+    fn normalize_colors() {
+        assert_eq!(normalize_color("#F0A"), Some("#ff00aa".to_string()));
+        assert_eq!(normalize_color("#31748f"), Some("#31748f".to_string()));
+        assert_eq!(normalize_color("31748fcc"), Some("#31748f".to_string()));
+        assert_eq!(
+            normalize_color("rgb(49, 116, 143)"),
+            Some("#31748f".to_string())
+        );
+        assert_eq!(
+            normalize_color("rgba(0,0,0,0.5)"),
+            Some("#000000".to_string())
+        );
+        assert_eq!(normalize_color("#12345"), None);
+        assert_eq!(normalize_color("rgb(256, 0, 0)"), None);
+        assert_eq!(normalize_color("red"), None);
+    }
+
+    #[test]
+    fn prefer_newer_nodeinfo() {
+        let wellknown = json!({
+            "links": [
+                {
+                    "rel": "http://nodeinfo.diaspora.software/ns/schema/2.0",
+                    "href": "https://example.com/nodeinfo/2.0",
+                },
+                {
+                    "rel": "http://nodeinfo.diaspora.software/ns/schema/2.1",
+                    "href": "/nodeinfo/2.1",
+                },
+            ],
+        });
+        assert_eq!(
+            nodeinfo_url(&wellknown, &base()).map(String::from),
+            Some("https://example.com/nodeinfo/2.1".to_string())
+        );
+        assert_eq!(nodeinfo_url(&json!({ "links": [] }), &base()), None);
+        assert_eq!(nodeinfo_url(&json!({}), &base()), None);
+    }
+
+    #[test]
+    fn parse_nodeinfo_documents() {
+        let info = json!({
+            "version": "2.1",
+            "software": { "name": "Firefish", "version": "1.0.4" },
+            "openRegistrations": false,
+            "metadata": {
+                "nodeName": "Example",
+                "name": "Ignored",
+                "description": "An example",
+                "maintainer": { "name": "Admin", "email": "" },
+                "themeColor": "#31748F",
+            },
+        });
+        assert_eq!(
+            parse_nodeinfo(&info),
+            InstanceInfo {
+                software_name: Some("firefish".to_string()),
+                software_version: Some("1.0.4".to_string()),
+                open_registrations: Some(false),
+                name: Some("Example".to_string()),
+                description: Some("An example".to_string()),
+                maintainer_name: Some("Admin".to_string()),
+                theme_color: Some("#31748f".to_string()),
+                ..Default::default()
+            }
+        );
+
+        let info = json!({ "software": { "name": 1 }, "metadata": null });
+        assert_eq!(parse_nodeinfo(&info), InstanceInfo::default());
+    }
+
+    #[test]
+    fn parse_html_heads() {
+        let html = r##"
+            <html><head>
+                <meta property="og:title" content="Example">
+                <meta property="og:description" content="From OGP">
+                <meta name="theme-color" content="#abc">
+                <link rel="icon" href="/old.png">
+                <link rel="shortcut icon" href="/favicon.png">
+                <link rel="apple-touch-icon" href="https://cdn.example.com/touch.png">
+                <link rel="manifest" href="/manifest.webmanifest">
+            </head></html>
+        "##;
+        assert_eq!(
+            parse_html(html, &base()),
+            Page {
+                name: Some("Example".to_string()),
+                description: Some("From OGP".to_string()),
+                theme_color: Some("#aabbcc".to_string()),
+                icon_url: Some("https://cdn.example.com/touch.png".to_string()),
+                favicon_url: Some("https://example.com/favicon.png".to_string()),
+                manifest_url: Some(Url::parse("https://example.com/manifest.webmanifest").unwrap()),
+            }
+        );
+        assert_eq!(parse_html("not html", &base()), Page::default());
+    }
+
+    #[test]
+    fn parse_manifests() {
+        let url = Url::parse("https://example.com/static/manifest.json").unwrap();
+        let manifest = json!({
+            "short_name": "Ex",
+            "theme_color": "rgb(255, 0, 0)",
+            "icons": [{ "src": "icon-192.png" }, { "src": "icon-512.png" }],
+        });
+        assert_eq!(
+            parse_manifest(&manifest, &url),
+            Manifest {
+                name: Some("Ex".to_string()),
+                description: None,
+                theme_color: Some("#ff0000".to_string()),
+                icon_url: Some("https://example.com/static/icon-192.png".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn combine_sources() {
+        let nodeinfo = InstanceInfo {
+            software_name: Some("mastodon".to_string()),
+            description: Some("From NodeInfo".to_string()),
+            ..Default::default()
+        };
+        let page = Page {
+            name: Some("From HTML".to_string()),
+            description: Some("Ignored".to_string()),
+            icon_url: Some("https://example.com/touch.png".to_string()),
+            ..Default::default()
+        };
+        let manifest = Manifest {
+            name: Some("Ignored".to_string()),
+            theme_color: Some("#ff0000".to_string()),
+            icon_url: Some("x".repeat(300)),
+            ..Default::default()
+        };
+        let favicon = Some("https://example.com/favicon.ico".to_string());
+        assert_eq!(
+            combine(nodeinfo, page, manifest, favicon.clone()),
+            InstanceInfo {
+                software_name: Some("mastodon".to_string()),
+                name: Some("From HTML".to_string()),
+                description: Some("From NodeInfo".to_string()),
+                icon_url: Some("https://example.com/touch.png".to_string()),
+                favicon_url: favicon,
+                theme_color: Some("#ff0000".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,4 @@
+//! Federation with other instances.
+
+pub mod error;
+pub mod instance_info;
@@ -0,0 +1,24 @@
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("Failed to get database connection: {0}")]
+    DbConnError(#[from] crate::database::error::Error),
+    #[error("Database operation error: {0}")]
+    DbOperationError(#[from] sea_orm::DbErr),
+    #[error("Failed to generate ID: {0}")]
+    IdError(#[from] crate::util::id::ErrorUninitialized),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::IdError(e) => e.code(),
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
@@ -0,0 +1,142 @@
+//! Hashtag extraction following the `hashtag` rule of `mfm-js`.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::util::scan::skip_verbatim;
+
+/// Maximum nesting depth of brackets inside a hashtag.
+const MAX_NEST_DEPTH: usize = 20;
+
+const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('「', '」'), ('（', '）')];
+
+fn is_excluded(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            '.' | ','
+                | '!'
+                | '?'
+                | '\''
+                | '"'
+                | '#'
+                | ':'
+                | '/'
+                | '['
+                | ']'
+                | '【'
+                | '】'
+                | '('
+                | ')'
+                | '「'
+                | '」'
+                | '（'
+                | '）'
+                | '<'
+                | '>'
+        )
+}
+
+/// Normalizes a hashtag for storage and search, like
+/// `misc/normalize-for-search.ts` does (NFKC, then lowercase).
+pub fn normalize_for_search(tag: &str) -> String {
+    tag.nfkc().collect::<String>().to_lowercase()
+}
+
+/// Returns the unique hashtags in `text` (without `#`) in order of
+/// appearance. Hashtags in code and URLs are ignored.
+pub fn extract_hashtags(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tags: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if let Some(end) = skip_verbatim(&chars, i) {
+            i = end;
+        } else if chars[i] == '#' && (i == 0 || !chars[i - 1].is_ascii_alphanumeric()) {
+            let end = scan_tag(&chars, i + 1, 0);
+            let tag: String = chars[i + 1..end].iter().collect();
+            if !tag.is_empty() && !tag.chars().all(|c| c.is_ascii_digit()) && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+            i = end.max(i + 1);
+        } else {
+            i += 1;
+        }
+    }
+
+    tags
+}
+
+/// Returns the position right after the hashtag body starting at `start`.
+/// Brackets are allowed in the body as long as they are balanced.
+pub(crate) fn scan_tag(chars: &[char], start: usize, depth: usize) -> usize {
+    let mut i = start;
+    while i < chars.len() {
+        let c = chars[i];
+        if let Some(&(_, close)) = BRACKETS.iter().find(|(open, _)| *open == c) {
+            if depth >= MAX_NEST_DEPTH {
+                break;
+            }
+            let end = scan_tag(chars, i + 1, depth + 1);
+            if end < chars.len() && chars[end] == close {
+                i = end + 1;
+                continue;
+            }
+            break;
+        }
+        if is_excluded(c) {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{extract_hashtags, normalize_for_search};
+
+    #[test]
+    fn extract_basic() {
+        assert_eq!(
+            extract_hashtags("Hello #firefish and #Rust! #firefish again"),
+            vec!["firefish", "Rust"]
+        );
+        assert_eq!(
+            extract_hashtags("#日本語タグ、です"),
+            vec!["日本語タグ、です"]
+        );
+        assert_eq!(extract_hashtags("#タグ。"), vec!["タグ。"]);
+        assert_eq!(extract_hashtags("#tag.end #tag2"), vec!["tag", "tag2"]);
+    }
+
+    #[test]
+    fn extract_rejects() {
+        // numbers only
+        assert_eq!(extract_hashtags("#123 #1a"), vec!["1a"]);
+        // preceded by an alphanumeric character
+        assert_eq!(extract_hashtags("abc#def"), Vec::<String>::new());
+        // inside code and urls
+        assert_eq!(
+            extract_hashtags("`#code` ```\n#block\n``` https://example.com/#anchor #ok"),
+            vec!["ok"]
+        );
+    }
+
+    #[test]
+    fn extract_brackets() {
+        assert_eq!(extract_hashtags("#foo(bar) baz"), vec!["foo(bar)"]);
+        assert_eq!(extract_hashtags("(#foo)"), vec!["foo"]);
+        assert_eq!(extract_hashtags("#foo[bar"), vec!["foo"]);
+        assert_eq!(extract_hashtags("「#foo」"), vec!["foo"]);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_for_search("ＦｉｒｅＦｉｓｈ"), "firefish");
+        assert_eq!(normalize_for_search("ｶﾞｷﾞ"), "ガギ");
+        assert_eq!(normalize_for_search("Straße"), "straße");
+    }
+}
@@ -0,0 +1,78 @@
+//! Hashtag extraction, normalization and statistics.
+
+pub mod error;
+pub mod extract;
+pub mod trend;
+pub mod update;
+
+use cfg_if::cfg_if;
+
+pub use extract::{extract_hashtags, normalize_for_search};
+pub use trend::{hashtag_trends, HashtagTrend, TrendConfig};
+pub use update::{update_hashtag, update_hashtags, update_usertags, HashtagUser, Usage};
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeHashtagTrend {
+            pub tag: String,
+            pub score: f64,
+            pub users_count: u32,
+            pub chart: Vec<u32>,
+        }
+
+        impl From<HashtagTrend> for NativeHashtagTrend {
+            fn from(trend: HashtagTrend) -> Self {
+                Self {
+                    tag: trend.tag,
+                    score: trend.score,
+                    users_count: trend.users_count,
+                    chart: trend.chart,
+                }
+            }
+        }
+
+        #[napi]
+        pub fn native_extract_hashtags(text: String) -> Vec<String> {
+            extract_hashtags(&text)
+        }
+
+        #[napi]
+        pub fn native_normalize_for_search(tag: String) -> String {
+            normalize_for_search(&tag)
+        }
+
+        #[napi]
+        pub async fn native_update_hashtags(
+            user_id: String,
+            user_host: Option<String>,
+            tags: Vec<String>,
+        ) -> napi::Result<()> {
+            let user = HashtagUser { id: user_id, host: user_host };
+            update_hashtags(&user, &tags).await.map_err(Into::into)
+        }
+
+        #[napi]
+        pub async fn native_update_usertags(
+            user_id: String,
+            user_host: Option<String>,
+            tags: Vec<String>,
+            old_tags: Vec<String>,
+        ) -> napi::Result<()> {
+            let user = HashtagUser { id: user_id, host: user_host };
+            update_usertags(&user, &tags, &old_tags)
+                .await
+                .map_err(Into::into)
+        }
+
+        #[napi]
+        pub async fn native_hashtag_trends(hidden_tags: Vec<String>) -> napi::Result<Vec<NativeHashtagTrend>> {
+            hashtag_trends(&hidden_tags, &TrendConfig::default())
+                .await
+                .map(|trends| trends.into_iter().map(Into::into).collect())
+                .map_err(Into::into)
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Trending hashtags, as served by `server/api/endpoints/hashtags/trend.ts`.
+//!
+//! Each user who posted a hashtag within the window contributes to the score
+//! of the hashtag once, with a weight that halves every
+//! [TrendConfig::half_life] since their latest post with it.
+
+use cfg_if::cfg_if;
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::{ConnectionTrait, EntityTrait, FromQueryResult, Statement};
+use std::collections::{HashMap, HashSet};
+
+use super::error::Error;
+use super::normalize_for_search;
+use crate::database;
+use crate::metrics;
+use crate::model::entity::note;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrendConfig {
+    /// Only notes posted within this window count toward the score.
+    pub window: Duration,
+    pub half_life: Duration,
+    /// Maximum number of hashtags returned.
+    pub limit: usize,
+    /// Span of each point of [HashtagTrend::chart].
+    pub chart_interval: Duration,
+    /// Number of points of [HashtagTrend::chart].
+    pub chart_range: usize,
+}
+
+impl Default for TrendConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::minutes(60),
+            half_life: Duration::minutes(30),
+            limit: 5,
+            chart_interval: Duration::minutes(10),
+            chart_range: 20,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HashtagTrend {
+    pub tag: String,
+    pub score: f64,
+    /// Number of users who posted the hashtag within the window.
+    pub users_count: u32,
+    /// Number of users who posted the hashtag in each interval, newest
+    /// first.
+    pub chart: Vec<u32>,
+}
+
+/// Use of a hashtag by a user within a chart interval, as aggregated by
+/// the database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagUse {
+    pub tag: String,
+    pub user_id: String,
+    /// Index of the chart interval, 0 being the newest.
+    pub bucket: i64,
+    /// Latest post of the user with the hashtag in the interval.
+    pub latest: DateTime<Utc>,
+}
+
+#[derive(FromQueryResult)]
+struct TagUseRow {
+    tag: String,
+    user_id: String,
+    bucket: i64,
+    latest: DateTimeWithTimeZone,
+}
+
+cfg_if! {
+    if #[cfg(feature = "noarray")] {
+        const TAG_USES_SQL: &str = r#"
+            SELECT "t"."value" AS "tag", "note"."userId" AS "user_id",
+                CAST((julianday(?) - julianday("note"."createdAt")) * 86400000 / ? AS INTEGER)
+                    AS "bucket",
+                MAX("note"."createdAt") AS "latest"
+            FROM "note", json_each("note"."tags") AS "t"
+            WHERE "note"."createdAt" > ? AND "note"."visibility" IN ('public', 'home')
+            GROUP BY 1, 2, 3"#;
+    } else {
+        const TAG_USES_SQL: &str = r#"
+            SELECT "t"."tag", "note"."userId" AS "user_id",
+                FLOOR(EXTRACT(EPOCH FROM ($1 - "note"."createdAt")) * 1000 / $2)::bigint
+                    AS "bucket",
+                MAX("note"."createdAt") AS "latest"
+            FROM "note", unnest("note"."tags") AS "t"("tag")
+            WHERE "note"."createdAt" > $3 AND "note"."visibility" IN ('public', 'home')
+                AND "note"."tags" != '{}'
+            GROUP BY 1, 2, 3"#;
+    }
+}
+
+/// Computes the trending hashtags from the recent public notes, excluding
+/// `hidden_tags`. The notes are grouped by hashtag, user and chart interval
+/// in the database. The current time is rounded to 5 minutes, so that the
+/// result only changes every 5 minutes.
+#[tracing::instrument(skip_all)]
+pub async fn hashtag_trends(
+    hidden_tags: &[String],
+    config: &TrendConfig,
+) -> Result<Vec<HashtagTrend>, Error> {
+    let now = Utc::now()
+        .duration_round(Duration::minutes(5))
+        .unwrap_or_else(|_| Utc::now());
+    let since = now
+        - std::cmp::max(
+            config.window,
+            config.chart_interval * config.chart_range as i32,
+        );
+
+    let db = &database::get_read_database()?;
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        TAG_USES_SQL,
+        [
+            DateTimeWithTimeZone::from(now).into(),
+            config.chart_interval.num_milliseconds().max(1).into(),
+            DateTimeWithTimeZone::from(since).into(),
+        ],
+    );
+    let query = note::Entity::find()
+        .from_raw_sql(stmt)
+        .into_model::<TagUseRow>()
+        .all(db);
+    let uses: Vec<TagUse> = metrics::observe_query("hashtag_trends", query)
+        .await?
+        .into_iter()
+        .map(|row| TagUse {
+            tag: row.tag,
+            user_id: row.user_id,
+            bucket: row.bucket,
+            latest: row.latest.into(),
+        })
+        .collect();
+    tracing::debug!(uses = uses.len(), "Loaded hashtag uses");
+
+    Ok(compute_trends(&uses, hidden_tags, now, config))
+}
+
+pub fn compute_trends(
+    uses: &[TagUse],
+    hidden_tags: &[String],
+    now: DateTime<Utc>,
+    config: &TrendConfig,
+) -> Vec<HashtagTrend> {
+    let hidden: HashSet<String> = hidden_tags
+        .iter()
+        .map(|t| normalize_for_search(t))
+        .collect();
+    let uses: Vec<&TagUse> = uses.iter().filter(|u| !hidden.contains(&u.tag)).collect();
+    let window_start = now - config.window;
+
+    // Latest post time of each user for each hashtag within the window
+    let mut latest: HashMap<&str, HashMap<&str, DateTime<Utc>>> = HashMap::new();
+    for tag_use in uses.iter().filter(|u| u.latest > window_start) {
+        let users = latest.entry(tag_use.tag.as_str()).or_default();
+        let time = users
+            .entry(tag_use.user_id.as_str())
+            .or_insert(tag_use.latest);
+        *time = std::cmp::max(*time, tag_use.latest);
+    }
+
+    let half_life = config.half_life.num_milliseconds().max(1) as f64;
+    let mut trends: Vec<HashtagTrend> = latest
+        .into_iter()
+        .map(|(tag, users)| {
+            let score = users
+                .values()
+                .map(|time| {
+                    let age = (now - *time).num_milliseconds().max(0) as f64;
+                    0.5_f64.powf(age / half_life)
+                })
+                .sum();
+            HashtagTrend {
+                tag: tag.to_string(),
+                score,
+                users_count: users.len() as u32,
+                chart: vec![],
+            }
+        })
+        .collect();
+    trends.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(b.users_count.cmp(&a.users_count))
+            .then(a.tag.cmp(&b.tag))
+    });
+    trends.truncate(config.limit);
+
+    for trend in trends.iter_mut() {
+        trend.chart = vec![0; config.chart_range];
+        // the database returns each user once per interval
+        for tag_use in uses.iter().filter(|u| u.tag == trend.tag) {
+            if let Some(count) = usize::try_from(tag_use.bucket)
+                .ok()
+                .and_then(|i| trend.chart.get_mut(i))
+            {
+                *count += 1;
+            }
+        }
+    }
+
+    trends
+}
+
+#[cfg(test)]
+mod unit_test {
+    use chrono::{Duration, TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+
+    use super::{compute_trends, TagUse, TrendConfig};
+
+    fn tag_use(user_id: &str, tag: &str, minutes_ago: i64) -> TagUse {
+        TagUse {
+            tag: tag.to_string(),
+            user_id: user_id.to_string(),
+            bucket: minutes_ago / 10,
+            latest: Utc.timestamp_opt(1_690_000_000, 0).unwrap() - Duration::minutes(minutes_ago),
+        }
+    }
+
+    #[test]
+    fn recent_users_weigh_more() {
+        let now = Utc.timestamp_opt(1_690_000_000, 0).unwrap();
+        let uses = vec![
+            tag_use("alice", "old", 50),
+            tag_use("bob", "old", 50),
+            tag_use("carol", "new", 0),
+            tag_use("dave", "new", 5),
+            tag_use("dave", "hidden", 5),
+            tag_use("erin", "outside", 90),
+        ];
+        let trends = compute_trends(&uses, &["HIDDEN".to_string()], now, &TrendConfig::default());
+
+        let tags: Vec<&str> = trends.iter().map(|t| t.tag.as_str()).collect();
+        assert_eq!(tags, vec!["new", "old"]);
+        assert_eq!(trends[0].users_count, 2);
+        assert_eq!(trends[1].users_count, 2);
+        assert!(trends[0].score > trends[1].score);
+        assert_eq!(trends[0].chart.len(), 20);
+        assert_eq!(&trends[0].chart[..2], &[2, 0]);
+        assert_eq!(trends[1].chart[5], 2);
+    }
+}
@@ -0,0 +1,242 @@
+//! Bookkeeping of the users of each hashtag, ported from
+//! `services/update-hashtag.ts`.
+
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbBackend, DbErr,
+    EntityTrait, IntoActiveModel, QueryFilter, QuerySelect, TransactionError, TransactionTrait,
+};
+
+use super::error::Error;
+use super::normalize_for_search;
+use crate::database;
+use crate::metrics;
+use crate::model::entity::hashtag;
+use crate::model::entity::newtype::StringVec;
+use crate::util::id::create_id;
+
+/// The user who used a hashtag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashtagUser {
+    pub id: String,
+    /// `None` for local users.
+    pub host: Option<String>,
+}
+
+/// How a hashtag is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Usage {
+    /// The hashtag appears in a note of the user.
+    Mentioned,
+    /// The hashtag is set in the profile of the user.
+    Attached,
+}
+
+/// Records that `user` used the hashtags of a note.
+pub async fn update_hashtags(user: &HashtagUser, tags: &[String]) -> Result<(), Error> {
+    for tag in tags {
+        update_hashtag(user, tag, Usage::Mentioned, true).await?;
+    }
+    Ok(())
+}
+
+/// Records the hashtags set in the profile of `user`, detaching the ones in
+/// `old_tags` that are no longer in `tags`.
+pub async fn update_usertags(
+    user: &HashtagUser,
+    tags: &[String],
+    old_tags: &[String],
+) -> Result<(), Error> {
+    for tag in tags {
+        update_hashtag(user, tag, Usage::Attached, true).await?;
+    }
+    for tag in old_tags.iter().filter(|t| !tags.contains(t)) {
+        update_hashtag(user, tag, Usage::Attached, false).await?;
+    }
+    Ok(())
+}
+
+/// Adds `user` to (or removes it from if `inc` is `false`) the user sets of
+/// `tag` and updates the counts accordingly. The row is locked for the
+/// duration of the update so concurrent calls do not lose user IDs.
+#[tracing::instrument(skip(user), fields(user_id = %user.id))]
+pub async fn update_hashtag(
+    user: &HashtagUser,
+    tag: &str,
+    usage: Usage,
+    inc: bool,
+) -> Result<(), Error> {
+    let name = normalize_for_search(tag);
+    let user = user.to_owned();
+    let db = &database::get_database()?;
+
+    db.transaction::<_, (), Error>(|txn| {
+        Box::pin(async move {
+            if update_existing(txn, &name, &user, usage, inc).await? || !inc {
+                return Ok(());
+            }
+
+            let mut model = hashtag::Model {
+                id: create_id(0)?,
+                name: name.to_owned(),
+                ..Default::default()
+            };
+            apply(&mut model, &user, usage, inc);
+            let query = hashtag::Entity::insert(model.into_active_model().reset_all())
+                .on_conflict(
+                    OnConflict::column(hashtag::Column::Name)
+                        .do_nothing()
+                        .to_owned(),
+                )
+                .exec(txn);
+            let inserted = metrics::observe_query("hashtag_insert", query).await;
+            match inserted {
+                // Another transaction created the hashtag in the meantime.
+                Err(DbErr::RecordNotInserted) => {
+                    update_existing(txn, &name, &user, usage, inc).await?;
+                    Ok(())
+                }
+                result => result.map(|_| ()).map_err(Error::from),
+            }
+        })
+    })
+    .await
+    .map_err(|e| match e {
+        TransactionError::Connection(e) => e.into(),
+        TransactionError::Transaction(e) => e,
+    })
+}
+
+/// Updates the hashtag named `name` if it exists. Returns `false` if it does
+/// not.
+async fn update_existing(
+    txn: &DatabaseTransaction,
+    name: &str,
+    user: &HashtagUser,
+    usage: Usage,
+    inc: bool,
+) -> Result<bool, Error> {
+    let mut select = hashtag::Entity::find().filter(hashtag::Column::Name.eq(name));
+    if txn.get_database_backend() == DbBackend::Postgres {
+        select = select.lock_exclusive();
+    }
+
+    match metrics::observe_query("hashtag", select.one(txn)).await? {
+        None => Ok(false),
+        Some(mut model) => {
+            if apply(&mut model, user, usage, inc) {
+                let query = model.into_active_model().reset_all().update(txn);
+                metrics::observe_query("hashtag_update", query).await?;
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// Applies the usage to the user sets of `model`. Returns whether anything
+/// changed.
+fn apply(model: &mut hashtag::Model, user: &HashtagUser, usage: Usage, inc: bool) -> bool {
+    let m = model;
+    let (all, local, remote) = match usage {
+        Usage::Mentioned => (
+            (&mut m.mentioned_user_ids, &mut m.mentioned_users_count),
+            (
+                &mut m.mentioned_local_user_ids,
+                &mut m.mentioned_local_users_count,
+            ),
+            (
+                &mut m.mentioned_remote_user_ids,
+                &mut m.mentioned_remote_users_count,
+            ),
+        ),
+        Usage::Attached => (
+            (&mut m.attached_user_ids, &mut m.attached_users_count),
+            (
+                &mut m.attached_local_user_ids,
+                &mut m.attached_local_users_count,
+            ),
+            (
+                &mut m.attached_remote_user_ids,
+                &mut m.attached_remote_users_count,
+            ),
+        ),
+    };
+    let scoped = if user.host.is_none() { local } else { remote };
+
+    let mut changed = false;
+    for (ids, count) in [all, scoped] {
+        changed |= if inc {
+            insert_id(ids, count, &user.id)
+        } else {
+            remove_id(ids, count, &user.id)
+        };
+    }
+    changed
+}
+
+fn insert_id(ids: &mut StringVec, count: &mut i32, id: &str) -> bool {
+    if ids.iter().any(|i| i == id) {
+        return false;
+    }
+    ids.push(id.to_string());
+    *count = ids.len() as i32;
+    true
+}
+
+fn remove_id(ids: &mut StringVec, count: &mut i32, id: &str) -> bool {
+    let len = ids.len();
+    ids.retain(|i| i != id);
+    *count = ids.len() as i32;
+    ids.len() != len
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{apply, HashtagUser, Usage};
+    use crate::model::entity::hashtag;
+
+    #[test]
+    fn mention_once_per_user() {
+        let local = HashtagUser {
+            id: "9fil64s6g7cskdrb".to_string(),
+            host: None,
+        };
+        let remote = HashtagUser {
+            id: "9fil66brl1udxau2".to_string(),
+            host: Some("example.com".to_string()),
+        };
+        let mut model = hashtag::Model::default();
+
+        assert!(apply(&mut model, &local, Usage::Mentioned, true));
+        assert!(!apply(&mut model, &local, Usage::Mentioned, true));
+        assert!(apply(&mut model, &remote, Usage::Mentioned, true));
+
+        assert_eq!(model.mentioned_users_count, 2);
+        assert_eq!(model.mentioned_local_user_ids.to_vec(), vec![local.id]);
+        assert_eq!(model.mentioned_local_users_count, 1);
+        assert_eq!(model.mentioned_remote_user_ids.to_vec(), vec![remote.id]);
+        assert_eq!(model.mentioned_remote_users_count, 1);
+        assert_eq!(model.attached_users_count, 0);
+    }
+
+    #[test]
+    fn attach_and_detach() {
+        let user = HashtagUser {
+            id: "9fil64s6g7cskdrb".to_string(),
+            host: None,
+        };
+        let mut model = hashtag::Model::default();
+
+        assert!(apply(&mut model, &user, Usage::Attached, true));
+        assert_eq!(model.attached_users_count, 1);
+        assert_eq!(model.attached_local_users_count, 1);
+
+        assert!(apply(&mut model, &user, Usage::Attached, false));
+        assert!(!apply(&mut model, &user, Usage::Attached, false));
+        assert_eq!(model.attached_users_count, 0);
+        assert_eq!(model.attached_local_users_count, 0);
+        assert!(model.attached_user_ids.is_empty());
+    }
+}
@@ -1,6 +1,18 @@
+pub mod cache;
+pub mod chart;
 pub mod database;
+pub mod drive;
+pub mod error;
+pub mod federation;
+pub mod hashtag;
+pub mod logger;
 pub mod macros;
+pub mod metrics;
+pub mod mfm;
 pub mod model;
+pub mod search;
+pub mod stats;
+pub mod timeline;
 pub mod util;
 
 #[cfg(feature = "napi")]
@@ -0,0 +1,21 @@
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("Another global subscriber has been set: {0}")]
+    SubscriberConflict(String),
+    #[error("Invalid log level: {0}")]
+    InvalidLevel(String),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::SubscriberConflict(_) => ErrorCode::InternalError,
+            Self::InvalidLevel(_) => ErrorCode::InvalidParam,
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
@@ -0,0 +1,405 @@
+//! Forwarding of `tracing` events, including the `log` records of SQLx, to
+//! the logger of the Node side in `services/logger.ts`.
+
+pub mod error;
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use cfg_if::cfg_if;
+use error::Error;
+use once_cell::sync::OnceCell;
+use tracing::{
+    callsite,
+    field::{Field, Visit},
+    level_filters::LevelFilter,
+    span,
+    subscriber::Interest,
+    Event, Level, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    layer::Context, prelude::*, registry::LookupSpan, util::SubscriberInitExt, Layer,
+};
+
+/// An event, or a span that took longer than
+/// [LoggerOptions::slow_span_threshold].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    pub level: Level,
+    /// Target of the event, which is the module path unless specified.
+    pub module: String,
+    pub message: String,
+    pub fields: BTreeMap<String, String>,
+}
+
+pub type Sink = Box<dyn Fn(LogRecord) + Send + Sync>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoggerOptions {
+    /// Most verbose level of the events passed to the sink.
+    pub level: Level,
+    /// Spans of native-utils taking longer than this, e.g. of database
+    /// queries, are passed to the sink as warnings. Only the spans at the
+    /// `INFO` level or above are timed.
+    pub slow_span_threshold: Option<Duration>,
+}
+
+/// Most verbose level of the spans timed for
+/// [LoggerOptions::slow_span_threshold].
+const SLOW_SPAN_LEVEL: Level = Level::INFO;
+
+impl LoggerOptions {
+    /// Whether the events or spans of the callsite are forwarded, which only
+    /// depends on the metadata and the options.
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        match metadata.is_span() {
+            true => {
+                self.slow_span_threshold.is_some()
+                    && metadata.level() <= &SLOW_SPAN_LEVEL
+                    && is_own_target(metadata.target())
+            }
+            false => metadata.level() <= &self.level,
+        }
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        match self.slow_span_threshold {
+            Some(_) => LevelFilter::from_level(std::cmp::max(self.level, SLOW_SPAN_LEVEL)),
+            None => LevelFilter::from_level(self.level),
+        }
+    }
+}
+
+fn is_own_target(target: &str) -> bool {
+    let crate_name = env!("CARGO_CRATE_NAME");
+    target == crate_name
+        || target
+            .strip_prefix(crate_name)
+            .is_some_and(|path| path.starts_with("::"))
+}
+
+impl Default for LoggerOptions {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            slow_span_threshold: None,
+        }
+    }
+}
+
+struct Logger {
+    sink: Sink,
+    options: LoggerOptions,
+}
+
+static LOGGER: RwLock<Option<Logger>> = RwLock::new(None);
+static SUBSCRIBER: OnceCell<()> = OnceCell::new();
+
+/// Passes the events to `sink`. Calling it again replaces the sink and the
+/// options.
+pub fn init_logger(sink: Sink, options: LoggerOptions) -> Result<(), Error> {
+    let old = LOGGER.write().unwrap().replace(Logger { sink, options });
+    let result = SUBSCRIBER.get_or_try_init(|| {
+        tracing_subscriber::registry()
+            .with(ForwardLayer)
+            .try_init()
+            .map_err(|e| Error::SubscriberConflict(e.to_string()))
+    });
+    if let Err(e) = result {
+        *LOGGER.write().unwrap() = old;
+        return Err(e);
+    }
+    // the interest of each callsite is cached for the options
+    callsite::rebuild_interest_cache();
+    Ok(())
+}
+
+/// Collects the message and the other fields of events and spans.
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl FieldVisitor {
+    fn record(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "message" => self.message = value,
+            // metadata of `log` records, which is in the one of the event
+            name if name.starts_with("log.") => {}
+            name => {
+                self.fields.insert(name.to_string(), value);
+            }
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record(field, format!("{:?}", value));
+    }
+}
+
+/// Start time and fields of a span, kept while slow spans are recorded.
+struct SpanTiming {
+    start: Instant,
+    fields: BTreeMap<String, String>,
+}
+
+struct ForwardLayer;
+
+impl<S> Layer<S> for ForwardLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    // `init_logger` rebuilds the cache of the interests when the options
+    // change
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        match LOGGER.read().unwrap().as_ref() {
+            Some(logger) if logger.options.enabled(metadata) => Interest::always(),
+            _ => Interest::never(),
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        match LOGGER.read().unwrap().as_ref() {
+            None => false,
+            Some(logger) => logger.options.enabled(metadata),
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        match LOGGER.read().unwrap().as_ref() {
+            None => Some(LevelFilter::OFF),
+            Some(logger) => Some(logger.options.max_level()),
+        }
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanTiming {
+            start: Instant::now(),
+            fields: visitor.fields,
+        });
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            let mut visitor = FieldVisitor::default();
+            values.record(&mut visitor);
+            timing.fields.append(&mut visitor.fields);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let logger = LOGGER.read().unwrap();
+        let Some(logger) = logger.as_ref() else {
+            return;
+        };
+        let metadata = event.metadata();
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        (logger.sink)(LogRecord {
+            level: *metadata.level(),
+            module: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let logger = LOGGER.read().unwrap();
+        let Some(logger) = logger.as_ref() else {
+            return;
+        };
+        let Some(threshold) = logger.options.slow_span_threshold else {
+            return;
+        };
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<SpanTiming>() else {
+            return;
+        };
+        let elapsed = timing.start.elapsed();
+        if elapsed < threshold {
+            return;
+        }
+        let mut fields = timing.fields.clone();
+        fields.insert(
+            "elapsed_ms".to_string(),
+            format!("{:.1}", elapsed.as_secs_f64() * 1000.0),
+        );
+        (logger.sink)(LogRecord {
+            level: Level::WARN,
+            module: span.metadata().target().to_string(),
+            message: format!("Slow {}", span.name()),
+            fields,
+        });
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use std::collections::HashMap;
+
+        use napi::threadsafe_function::{
+            ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+        };
+        use napi::{Env, JsFunction};
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeLogRecord {
+            /// One of `error`, `warn`, `info`, `debug` and `trace`.
+            pub level: String,
+            pub module: String,
+            pub message: String,
+            pub fields: HashMap<String, String>,
+        }
+
+        impl From<LogRecord> for NativeLogRecord {
+            fn from(record: LogRecord) -> Self {
+                Self {
+                    level: record.level.as_str().to_lowercase(),
+                    module: record.module,
+                    message: record.message,
+                    fields: record.fields.into_iter().collect(),
+                }
+            }
+        }
+
+        /// For NAPI because [Level] and [Duration] are not supported. The
+        /// threshold is in milliseconds.
+        #[napi(object)]
+        pub struct NativeLoggerOptions {
+            pub level: Option<String>,
+            pub slow_span_threshold: Option<u32>,
+        }
+
+        impl TryFrom<NativeLoggerOptions> for LoggerOptions {
+            type Error = Error;
+
+            fn try_from(options: NativeLoggerOptions) -> Result<Self, Self::Error> {
+                let level = match options.level {
+                    None => Level::INFO,
+                    Some(level) => level.parse().map_err(|_| Error::InvalidLevel(level))?,
+                };
+                Ok(Self {
+                    level,
+                    slow_span_threshold: options
+                        .slow_span_threshold
+                        .map(|ms| Duration::from_millis(ms.into())),
+                })
+            }
+        }
+
+        /// Passes the logs of native-utils to `callback`, which is called on
+        /// the main thread and does not keep the process alive.
+        #[napi(
+            ts_args_type = "callback: (record: NativeLogRecord) => void, options?: NativeLoggerOptions"
+        )]
+        pub fn native_init_logger(
+            env: Env,
+            callback: JsFunction,
+            options: Option<NativeLoggerOptions>,
+        ) -> napi::Result<()> {
+            let options = match options {
+                None => LoggerOptions::default(),
+                Some(options) => options.try_into().map_err(Into::<napi::Error>::into)?,
+            };
+            let mut tsfn: ThreadsafeFunction<NativeLogRecord, ErrorStrategy::Fatal> =
+                callback.create_threadsafe_function(0, |ctx| Ok(vec![ctx.value]))?;
+            tsfn.unref(&env)?;
+            let sink = move |record: LogRecord| {
+                tsfn.call(record.into(), ThreadsafeFunctionCallMode::NonBlocking);
+            };
+            init_logger(Box::new(sink), options).map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use tracing::{level_filters::LevelFilter, Level};
+
+    use super::{init_logger, is_own_target, LogRecord, LoggerOptions};
+
+    #[test]
+    fn static_filters() {
+        assert!(is_own_target("native_utils"));
+        assert!(is_own_target("native_utils::database"));
+        assert!(!is_own_target("native_utils_extra"));
+        assert!(!is_own_target("sqlx::query"));
+
+        let mut options = LoggerOptions {
+            level: Level::WARN,
+            slow_span_threshold: None,
+        };
+        assert_eq!(options.max_level(), LevelFilter::WARN);
+        options.slow_span_threshold = Some(Duration::ZERO);
+        assert_eq!(options.max_level(), LevelFilter::INFO);
+        options.level = Level::DEBUG;
+        assert_eq!(options.max_level(), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn forward_events_and_slow_spans() {
+        let records: Arc<Mutex<Vec<LogRecord>>> = Default::default();
+        let sink_records = records.clone();
+        init_logger(
+            Box::new(move |record| {
+                // other tests may log at the same time
+                if record.module == module_path!() {
+                    sink_records.lock().unwrap().push(record);
+                }
+            }),
+            LoggerOptions {
+                level: Level::INFO,
+                slow_span_threshold: Some(Duration::ZERO),
+            },
+        )
+        .unwrap();
+
+        tracing::info_span!("query", table = "note").in_scope(|| {
+            tracing::warn!(rows = 2, "Something happened");
+            tracing::debug!("Too verbose");
+        });
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0],
+            LogRecord {
+                level: Level::WARN,
+                module: module_path!().to_string(),
+                message: "Something happened".to_string(),
+                fields: BTreeMap::from([("rows".to_string(), "2".to_string())]),
+            }
+        );
+        assert_eq!(records[1].level, Level::WARN);
+        assert_eq!(records[1].message, "Slow query");
+        assert_eq!(records[1].fields["table"], "note");
+        assert!(records[1].fields.contains_key("elapsed_ms"));
+    }
+}
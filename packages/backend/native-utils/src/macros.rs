@@ -4,7 +4,7 @@ macro_rules! impl_into_napi_error {
         #[cfg(feature = "napi")]
         impl Into<napi::Error> for $a {
             fn into(self) -> napi::Error {
-                napi::Error::from_reason(self.to_string())
+                $crate::error::to_napi_error(&self)
             }
         }
     };
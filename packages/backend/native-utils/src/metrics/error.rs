@@ -0,0 +1,16 @@
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Failed to encode metrics: {0}")]
+    EncodeError(#[from] prometheus::Error),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::InternalError
+    }
+}
+
+impl_into_napi_error!(Error);
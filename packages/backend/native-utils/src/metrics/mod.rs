@@ -0,0 +1,221 @@
+//! Prometheus metrics of the packers, database queries, caches and the ID
+//! generator, exported in the text format by [encode_metrics].
+
+pub mod error;
+
+use std::future::Future;
+
+use cfg_if::cfg_if;
+use error::Error;
+use once_cell::sync::Lazy;
+use prometheus::{
+    core::Collector, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+
+use crate::database;
+
+/// Buckets in seconds, from 1ms to 10s.
+const DURATION_BUCKETS: [f64; 12] = [
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 10.0,
+];
+
+pub struct Metrics {
+    registry: Registry,
+    /// Duration of packs, labeled by `entity` and `method` (`pack` or
+    /// `pack_by_id`).
+    pub pack_duration: HistogramVec,
+    pub pack_errors: IntCounterVec,
+    /// Duration of database queries, labeled by `query`.
+    pub query_duration: HistogramVec,
+    pub query_errors: IntCounterVec,
+    /// Reads of caches, labeled by `cache` and `result` (`hit` or `miss`).
+    pub cache_reads: IntCounterVec,
+    pub ids_generated: IntCounter,
+    /// Open connections of the pools, labeled by `pool` (`primary` or
+    /// `replica<n>`) and `state` (`idle` or `active`). Updated on export.
+    db_pool_connections: IntGaugeVec,
+}
+
+static METRICS: Lazy<Metrics> = Lazy::new(Metrics::new);
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some("native".to_string()), None).unwrap();
+        let duration_opts = |name: &str, help: &str| {
+            HistogramOpts::new(name, help).buckets(DURATION_BUCKETS.to_vec())
+        };
+
+        let metrics = Self {
+            pack_duration: HistogramVec::new(
+                duration_opts("pack_duration_seconds", "Duration of packs"),
+                &["entity", "method"],
+            )
+            .unwrap(),
+            pack_errors: IntCounterVec::new(
+                Opts::new("pack_errors_total", "Number of failed packs"),
+                &["entity", "method"],
+            )
+            .unwrap(),
+            query_duration: HistogramVec::new(
+                duration_opts("db_query_duration_seconds", "Duration of database queries"),
+                &["query"],
+            )
+            .unwrap(),
+            query_errors: IntCounterVec::new(
+                Opts::new("db_query_errors_total", "Number of failed database queries"),
+                &["query"],
+            )
+            .unwrap(),
+            cache_reads: IntCounterVec::new(
+                Opts::new("cache_reads_total", "Number of cache reads"),
+                &["cache", "result"],
+            )
+            .unwrap(),
+            ids_generated: IntCounter::new("ids_generated_total", "Number of generated IDs")
+                .unwrap(),
+            db_pool_connections: IntGaugeVec::new(
+                Opts::new("db_pool_connections", "Number of open database connections"),
+                &["pool", "state"],
+            )
+            .unwrap(),
+            registry,
+        };
+
+        let collectors: [Box<dyn Collector>; 7] = [
+            Box::new(metrics.pack_duration.clone()),
+            Box::new(metrics.pack_errors.clone()),
+            Box::new(metrics.query_duration.clone()),
+            Box::new(metrics.query_errors.clone()),
+            Box::new(metrics.cache_reads.clone()),
+            Box::new(metrics.ids_generated.clone()),
+            Box::new(metrics.db_pool_connections.clone()),
+        ];
+        for collector in collectors {
+            metrics.registry.register(collector).unwrap();
+        }
+
+        metrics
+    }
+
+    fn update_pool_connections(&self) {
+        self.db_pool_connections.reset();
+        // no pools before the database is initialized
+        let Ok(sizes) = database::pool_sizes() else {
+            return;
+        };
+        for (i, (size, idle)) in sizes.into_iter().enumerate() {
+            let pool = match i {
+                0 => "primary".to_string(),
+                i => format!("replica{}", i - 1),
+            };
+            self.db_pool_connections
+                .with_label_values(&[&pool, "idle"])
+                .set(idle.into());
+            self.db_pool_connections
+                .with_label_values(&[&pool, "active"])
+                .set(size.saturating_sub(idle).into());
+        }
+    }
+}
+
+pub fn get_metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// Runs `pack`, recording its duration and whether it failed.
+pub async fn observe_pack<T, E>(
+    entity: &str,
+    method: &str,
+    pack: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let timer = METRICS
+        .pack_duration
+        .with_label_values(&[entity, method])
+        .start_timer();
+    let result = pack.await;
+    timer.observe_duration();
+    if result.is_err() {
+        METRICS
+            .pack_errors
+            .with_label_values(&[entity, method])
+            .inc();
+    }
+    result
+}
+
+/// Runs `query`, recording its duration and whether it failed.
+pub async fn observe_query<T, E>(
+    query: &str,
+    future: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let timer = METRICS
+        .query_duration
+        .with_label_values(&[query])
+        .start_timer();
+    let result = future.await;
+    timer.observe_duration();
+    if result.is_err() {
+        METRICS.query_errors.with_label_values(&[query]).inc();
+    }
+    result
+}
+
+/// Records a read of `cache`, e.g. of [crate::cache::Cache::get] or of a
+/// cache of the Node side.
+pub fn record_cache_read(cache: &str, hit: bool) {
+    let result = match hit {
+        true => "hit",
+        false => "miss",
+    };
+    METRICS
+        .cache_reads
+        .with_label_values(&[cache, result])
+        .inc();
+}
+
+/// Returns the metrics in the Prometheus text exposition format.
+pub fn encode_metrics() -> Result<String, Error> {
+    METRICS.update_pool_connections();
+    Ok(TextEncoder::new().encode_to_string(&METRICS.registry.gather())?)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        /// Calls [encode_metrics] inside.
+        #[napi]
+        pub fn native_encode_metrics() -> napi::Result<String> {
+            encode_metrics().map_err(Into::into)
+        }
+
+        /// Calls [record_cache_read] inside, for the caches of the Node side.
+        #[napi]
+        pub fn native_record_cache_read(cache: String, hit: bool) {
+            record_cache_read(&cache, hit)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{encode_metrics, observe_pack, observe_query, record_cache_read};
+
+    #[tokio::test]
+    async fn encode_recorded_metrics() {
+        let res: Result<(), ()> = observe_pack("unit_test", "pack", async { Ok(()) }).await;
+        assert_eq!(res, Ok(()));
+        let res: Result<(), ()> = observe_query("unit_test", async { Err(()) }).await;
+        assert_eq!(res, Err(()));
+        record_cache_read("unit_test", true);
+
+        let text = encode_metrics().unwrap();
+        assert!(text
+            .contains(r#"native_pack_duration_seconds_count{entity="unit_test",method="pack"} 1"#));
+        assert!(text.contains(r#"native_db_query_errors_total{query="unit_test"} 1"#));
+        assert!(text.contains(r#"native_cache_reads_total{cache="unit_test",result="hit"} 1"#));
+    }
+}
@@ -0,0 +1,102 @@
+//! Detection of Unicode emoji sequences, matching what the twemoji regular
+//! expression used by `mfm-js` accepts.
+
+use unicode_properties::emoji::{
+    is_emoji_presentation_selector, is_regional_indicator, is_tag_character, is_zwj, UnicodeEmoji,
+};
+
+/// Cancel tag ending an emoji tag sequence such as 🏴󠁧󠁢󠁳󠁣󠁴󠁿.
+const CANCEL_TAG: char = '\u{e007f}';
+const COMBINING_KEYCAP: char = '\u{20e3}';
+
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c, '\u{1f3fb}'..='\u{1f3ff}')
+}
+
+/// Returns the position after the emoji sequence starting at `start`.
+pub(crate) fn scan_emoji(chars: &[char], start: usize) -> Option<usize> {
+    let c = *chars.get(start)?;
+    let at = |i: usize| chars.get(i).copied();
+
+    // keycap sequences like #️⃣
+    if matches!(c, '#' | '*' | '0'..='9') {
+        let mut i = start + 1;
+        if at(i).is_some_and(is_emoji_presentation_selector) {
+            i += 1;
+        }
+        return (at(i) == Some(COMBINING_KEYCAP)).then_some(i + 1);
+    }
+    // flags
+    if is_regional_indicator(c) {
+        return at(start + 1)
+            .is_some_and(is_regional_indicator)
+            .then_some(start + 2);
+    }
+
+    let mut i = scan_element(chars, start)?;
+    // tag sequences
+    if at(i).is_some_and(|c| is_tag_character(c) && c != CANCEL_TAG) {
+        while at(i).is_some_and(|c| is_tag_character(c) && c != CANCEL_TAG) {
+            i += 1;
+        }
+        if at(i) != Some(CANCEL_TAG) {
+            return None;
+        }
+        i += 1;
+    }
+    // ZWJ sequences
+    while at(i).is_some_and(is_zwj) {
+        match scan_element(chars, i + 1) {
+            Some(end) => i = end,
+            None => break,
+        }
+    }
+    Some(i)
+}
+
+/// Returns the position after an emoji character along with its modifier or
+/// variation selector. Like twemoji, characters that default to the text
+/// presentation such as © are emojis too.
+fn scan_element(chars: &[char], start: usize) -> Option<usize> {
+    let c = *chars.get(start)?;
+    if c.is_ascii() || !c.is_emoji_char() || is_regional_indicator(c) {
+        return None;
+    }
+    match chars.get(start + 1) {
+        Some(&next) if is_emoji_presentation_selector(next) || is_emoji_modifier(next) => {
+            Some(start + 2)
+        }
+        _ => Some(start + 1),
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::scan_emoji;
+
+    fn emojis(text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            match scan_emoji(&chars, i) {
+                Some(end) => {
+                    found.push(chars[i..end].iter().collect());
+                    i = end;
+                }
+                None => i += 1,
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn sequences() {
+        assert_eq!(
+            emojis("a😇b #️⃣ 1 🇯🇵 👍🏽 ❤️ ❤ © 👨‍👩‍👧 🏴󠁧󠁢󠁳󠁣󠁴󠁿 🏳️‍🌈"),
+            vec!["😇", "#️⃣", "🇯🇵", "👍🏽", "❤️", "❤", "©", "👨‍👩‍👧", "🏴󠁧󠁢󠁳󠁣󠁴󠁿", "🏳️‍🌈"]
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! HTML to MFM conversion for remote notes, ported from `mfm/from-html.ts`.
+
+use ego_tree::NodeRef;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{Html, Node};
+
+/// Some AP servers like Pixelfed use `<br>` as well as line breaks.
+static BR_NEWLINE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s?/?>\r?\n").unwrap());
+
+/// `[\w/:%#@$&?!()\[\]~.,=+\-]` of `from-html.ts`.
+fn is_url_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_/:%#@$&?!()[]~.,=+-".contains(c)
+}
+
+/// Returns the part of `href` after the scheme if it starts with one.
+fn strip_scheme(href: &str) -> Option<&str> {
+    href.strip_prefix("https://")
+        .or_else(|| href.strip_prefix("http://"))
+}
+
+/// Whether the whole `href` consists of URL characters.
+fn is_full_url(href: &str) -> bool {
+    strip_scheme(href).is_some_and(|rest| !rest.is_empty() && rest.chars().all(is_url_char))
+}
+
+/// Whether `href` starts with URL characters.
+fn is_url(href: &str) -> bool {
+    strip_scheme(href).is_some_and(|rest| rest.chars().next().is_some_and(is_url_char))
+}
+
+/// Converts HTML into MFM text. Links whose text is one of `hashtag_names`
+/// (case-insensitive) are converted into hashtags.
+pub fn from_html(html: &str, hashtag_names: Option<&[String]>) -> String {
+    let html = BR_NEWLINE.replace_all(html, "\n");
+    let dom = Html::parse_fragment(&html);
+
+    let converter = Converter {
+        hashtag_names: hashtag_names.map(|names| names.iter().map(|n| n.to_lowercase()).collect()),
+    };
+    let mut text = String::new();
+    converter.append_children(*dom.root_element(), &mut text);
+    text.trim().to_string()
+}
+
+struct Converter {
+    hashtag_names: Option<Vec<String>>,
+}
+
+fn get_text(node: NodeRef<Node>) -> String {
+    match node.value() {
+        Node::Text(text) => text.to_string(),
+        Node::Element(element) if element.name() == "br" => "\n".to_string(),
+        Node::Element(_) => node.children().map(get_text).collect(),
+        _ => String::new(),
+    }
+}
+
+impl Converter {
+    fn append_children(&self, node: NodeRef<Node>, text: &mut String) {
+        for child in node.children() {
+            self.append(child, text);
+        }
+    }
+
+    fn append(&self, node: NodeRef<Node>, text: &mut String) {
+        let element = match node.value() {
+            Node::Text(t) => {
+                text.push_str(t);
+                return;
+            }
+            Node::Element(element) => element,
+            // skip comment or document type node
+            _ => return,
+        };
+
+        match element.name() {
+            "br" => text.push('\n'),
+            "a" => self.append_link(node, element.attr("rel"), element.attr("href"), text),
+            "h1" => {
+                text.push('【');
+                self.append_children(node, text);
+                text.push_str("】\n");
+            }
+            "b" | "strong" => self.append_wrapped(node, "**", "**", text),
+            "small" => self.append_wrapped(node, "<small>", "</small>", text),
+            "s" | "del" => self.append_wrapped(node, "~~", "~~", text),
+            "i" | "em" => self.append_wrapped(node, "<i>", "</i>", text),
+            // block code (<pre><code>)
+            "pre" => {
+                let mut children = node.children();
+                match (children.next(), children.next()) {
+                    (Some(code), None)
+                        if code
+                            .value()
+                            .as_element()
+                            .is_some_and(|e| e.name() == "code") =>
+                    {
+                        text.push_str("\n```\n");
+                        text.push_str(&get_text(code));
+                        text.push_str("\n```\n");
+                    }
+                    _ => self.append_children(node, text),
+                }
+            }
+            // inline code (<code>)
+            "code" => self.append_wrapped(node, "`", "`", text),
+            "blockquote" => {
+                let t = get_text(node);
+                if !t.is_empty() {
+                    text.push_str("\n> ");
+                    text.push_str(&t.split('\n').collect::<Vec<_>>().join("\n> "));
+                }
+            }
+            "p" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                text.push_str("\n\n");
+                self.append_children(node, text);
+            }
+            // other block elements
+            "div" | "header" | "footer" | "article" | "li" | "dt" | "dd" => {
+                text.push('\n');
+                self.append_children(node, text);
+            }
+            // includes inline elements
+            _ => self.append_children(node, text),
+        }
+    }
+
+    fn append_wrapped(&self, node: NodeRef<Node>, open: &str, close: &str, text: &mut String) {
+        text.push_str(open);
+        self.append_children(node, text);
+        text.push_str(close);
+    }
+
+    fn append_link(
+        &self,
+        node: NodeRef<Node>,
+        rel: Option<&str>,
+        href: Option<&str>,
+        text: &mut String,
+    ) {
+        let txt = get_text(node);
+
+        // hashtag
+        if let (Some(names), Some(_)) = (&self.hashtag_names, href) {
+            if names.contains(&txt.to_lowercase()) {
+                text.push_str(&txt);
+                return;
+            }
+        }
+
+        // mention
+        if txt.starts_with('@') && !rel.is_some_and(|rel| rel.starts_with("me ")) {
+            match txt.split('@').count() {
+                2 => {
+                    // restore the omitted host
+                    let host = href
+                        .and_then(|href| url::Url::parse(href).ok())
+                        .and_then(|url| url.host_str().map(str::to_string));
+                    if let Some(host) = host {
+                        text.push_str(&format!("{}@{}", txt, host));
+                    }
+                }
+                3 => text.push_str(&txt),
+                _ => {}
+            }
+            return;
+        }
+
+        let link = match href {
+            None => txt,
+            Some(href) if txt.is_empty() || txt == href => {
+                // #6383: Missing text node
+                if is_full_url(href) {
+                    href.to_string()
+                } else {
+                    format!("<{}>", href)
+                }
+            }
+            // #6846
+            Some(href) if is_url(href) && !is_full_url(href) => format!("[{}](<{}>)", txt, href),
+            Some(href) => format!("[{}]({})", txt, href),
+        };
+        text.push_str(&link);
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::from_html;
+
+    fn convert(html: &str) -> String {
+        from_html(html, None)
+    }
+
+    #[test]
+    fn blocks() {
+        assert_eq!(convert("<p>a</p><p>b</p>"), "a\n\nb");
+        assert_eq!(convert("<div>a</div><div>b</div>"), "a\nb");
+        assert_eq!(convert("<ul><li>a</li><li>b</li></ul>"), "a\nb");
+        assert_eq!(convert("<pre><code>a\nb</code></pre>"), "```\na\nb\n```");
+        assert_eq!(convert("<code>a</code>"), "`a`");
+        assert_eq!(convert("<blockquote>a\nb</blockquote>"), "> a\n> b");
+        assert_eq!(convert("<p>abc<br><br/>d</p>"), "abc\n\nd");
+        assert_eq!(convert("<p>a<br>\nb</p>"), "a\nb");
+        assert_eq!(
+            convert("<h1>t</h1><b>b</b> <em>i</em>"),
+            "【t】\n**b** <i>i</i>"
+        );
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            convert(r#"<p>a <a href="https://joinfirefish.org/b">c</a> d</p>"#),
+            "a [c](https://joinfirefish.org/b) d"
+        );
+        assert_eq!(
+            convert(r#"<p>a <a href="https://joinfirefish.org/ä">c</a> d</p>"#),
+            "a [c](<https://joinfirefish.org/ä>) d"
+        );
+        assert_eq!(
+            convert(
+                r#"<p>a <a href="https://joinfirefish.org/b">https://joinfirefish.org/b</a> d</p>"#
+            ),
+            "a https://joinfirefish.org/b d"
+        );
+        assert_eq!(
+            convert(
+                r#"<p>a <a href="https://joinfirefish.org/ä">https://joinfirefish.org/ä</a> d</p>"#
+            ),
+            "a <https://joinfirefish.org/ä> d"
+        );
+        assert_eq!(convert(r#"<p>a <a href="b">c</a> d</p>"#), "a [c](b) d");
+        assert_eq!(convert("<p>a <a>c</a> d</p>"), "a c d");
+        assert_eq!(
+            convert(r#"<p>a <a href="https://joinfirefish.org/b"></a> d</p>"#),
+            "a https://joinfirefish.org/b d"
+        );
+        assert_eq!(convert("<p>a <a></a> d</p>"), "a  d");
+    }
+
+    #[test]
+    fn mention_and_hashtag() {
+        assert_eq!(
+            convert(
+                r#"<p>a <a href="https://joinfirefish.org/@user" class="u-url mention">@user</a> d</p>"#
+            ),
+            "a @user@joinfirefish.org d"
+        );
+        assert_eq!(
+            from_html(
+                r##"<p>a <a href="https://joinfirefish.org/tags/a">#a</a> d</p>"##,
+                Some(&["#a".to_string()])
+            ),
+            "a #a d"
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! MFM (Misskey Flavored Markdown) parsing and conversion from and to HTML.
+
+mod emoji;
+pub mod from_html;
+pub mod node;
+pub mod parser;
+pub mod to_html;
+
+use cfg_if::cfg_if;
+
+pub use from_html::from_html;
+pub use node::{to_json, to_plain_text, Node};
+pub use parser::parse;
+pub use to_html::{to_html, MentionedRemoteUser};
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeMentionedRemoteUser {
+            pub uri: String,
+            pub url: Option<String>,
+            pub username: String,
+            pub host: String,
+        }
+
+        impl From<NativeMentionedRemoteUser> for MentionedRemoteUser {
+            fn from(user: NativeMentionedRemoteUser) -> Self {
+                Self {
+                    uri: user.uri,
+                    url: user.url,
+                    username: user.username,
+                    host: user.host,
+                }
+            }
+        }
+
+        /// Returns the syntax tree of `text` in the shape of `mfm-js` nodes.
+        #[napi]
+        pub fn native_parse_mfm(text: String) -> serde_json::Value {
+            to_json(&parse(&text))
+        }
+
+        /// Renders `text` as HTML for the `content` of ActivityPub objects.
+        #[napi]
+        pub fn native_mfm_to_html(
+            text: String,
+            base_url: String,
+            mentioned_remote_users: Vec<NativeMentionedRemoteUser>,
+        ) -> String {
+            let users: Vec<MentionedRemoteUser> =
+                mentioned_remote_users.into_iter().map(Into::into).collect();
+            to_html(&parse(&text), &base_url, &users)
+        }
+
+        /// Converts the HTML of a remote note into MFM text.
+        #[napi]
+        pub fn native_html_to_mfm(html: String, hashtag_names: Option<Vec<String>>) -> String {
+            from_html(&html, hashtag_names.as_deref())
+        }
+
+        /// Returns `text` without MFM markup.
+        #[napi]
+        pub fn native_mfm_to_plain_text(text: String) -> String {
+            to_plain_text(&parse(&text))
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! MFM syntax tree, in the shape of `mfm-js` nodes.
+
+use serde_json::{json, Map, Value};
+
+/// A node of the MFM syntax tree. [Node::to_json] gives the same JSON as the
+/// corresponding `mfm-js` node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Node {
+    // block nodes
+    Quote {
+        children: Vec<Node>,
+    },
+    Search {
+        query: String,
+        content: String,
+    },
+    BlockCode {
+        code: String,
+        lang: Option<String>,
+    },
+    MathBlock {
+        formula: String,
+    },
+    Center {
+        children: Vec<Node>,
+    },
+
+    // inline nodes
+    UnicodeEmoji {
+        emoji: String,
+    },
+    EmojiCode {
+        name: String,
+    },
+    Bold {
+        children: Vec<Node>,
+    },
+    Small {
+        children: Vec<Node>,
+    },
+    Italic {
+        children: Vec<Node>,
+    },
+    Strike {
+        children: Vec<Node>,
+    },
+    InlineCode {
+        code: String,
+    },
+    MathInline {
+        formula: String,
+    },
+    Mention {
+        username: String,
+        host: Option<String>,
+        acct: String,
+    },
+    Hashtag {
+        hashtag: String,
+    },
+    Url {
+        url: String,
+        /// Whether the URL was written as `<https://...>`.
+        brackets: bool,
+    },
+    Link {
+        /// Whether the link was written as `?[label](url)`.
+        silent: bool,
+        url: String,
+        children: Vec<Node>,
+    },
+    Fn {
+        name: String,
+        /// Arguments in order of appearance. Flags have no value.
+        args: Vec<(String, Option<String>)>,
+        children: Vec<Node>,
+    },
+    Plain {
+        children: Vec<Node>,
+    },
+    Text {
+        text: String,
+    },
+}
+
+impl Node {
+    pub(crate) fn text(text: impl Into<String>) -> Self {
+        Node::Text { text: text.into() }
+    }
+
+    /// Returns the children of the node, which are empty for leaf nodes.
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Node::Quote { children }
+            | Node::Center { children }
+            | Node::Bold { children }
+            | Node::Small { children }
+            | Node::Italic { children }
+            | Node::Strike { children }
+            | Node::Link { children, .. }
+            | Node::Fn { children, .. }
+            | Node::Plain { children } => children,
+            _ => &[],
+        }
+    }
+
+    /// Returns the node as the JSON produced by `mfm-js`.
+    pub fn to_json(&self) -> Value {
+        let (kind, props) = match self {
+            Node::Quote { .. } => ("quote", None),
+            Node::Search { query, content } => (
+                "search",
+                Some(json!({ "query": query, "content": content })),
+            ),
+            Node::BlockCode { code, lang } => {
+                ("blockCode", Some(json!({ "code": code, "lang": lang })))
+            }
+            Node::MathBlock { formula } => ("mathBlock", Some(json!({ "formula": formula }))),
+            Node::Center { .. } => ("center", None),
+            Node::UnicodeEmoji { emoji } => ("unicodeEmoji", Some(json!({ "emoji": emoji }))),
+            Node::EmojiCode { name } => ("emojiCode", Some(json!({ "name": name }))),
+            Node::Bold { .. } => ("bold", None),
+            Node::Small { .. } => ("small", None),
+            Node::Italic { .. } => ("italic", None),
+            Node::Strike { .. } => ("strike", None),
+            Node::InlineCode { code } => ("inlineCode", Some(json!({ "code": code }))),
+            Node::MathInline { formula } => ("mathInline", Some(json!({ "formula": formula }))),
+            Node::Mention {
+                username,
+                host,
+                acct,
+            } => (
+                "mention",
+                Some(json!({ "username": username, "host": host, "acct": acct })),
+            ),
+            Node::Hashtag { hashtag } => ("hashtag", Some(json!({ "hashtag": hashtag }))),
+            Node::Url { url, brackets } => {
+                let mut props = json!({ "url": url });
+                if *brackets {
+                    props["brackets"] = Value::Bool(true);
+                }
+                ("url", Some(props))
+            }
+            Node::Link { silent, url, .. } => {
+                ("link", Some(json!({ "silent": silent, "url": url })))
+            }
+            Node::Fn { name, args, .. } => {
+                let args: Map<String, Value> = args
+                    .iter()
+                    .map(|(k, v)| {
+                        let v = v.clone().map_or(Value::Bool(true), Value::String);
+                        (k.clone(), v)
+                    })
+                    .collect();
+                ("fn", Some(json!({ "name": name, "args": args })))
+            }
+            Node::Plain { .. } => ("plain", None),
+            Node::Text { text } => ("text", Some(json!({ "text": text }))),
+        };
+
+        let mut node = json!({ "type": kind });
+        if let Some(props) = props {
+            node["props"] = props;
+        }
+        if has_children(self) {
+            node["children"] = to_json(self.children());
+        }
+        node
+    }
+}
+
+fn has_children(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Quote { .. }
+            | Node::Center { .. }
+            | Node::Bold { .. }
+            | Node::Small { .. }
+            | Node::Italic { .. }
+            | Node::Strike { .. }
+            | Node::Link { .. }
+            | Node::Fn { .. }
+            | Node::Plain { .. }
+    )
+}
+
+/// Returns the nodes as the JSON produced by `mfm-js`.
+pub fn to_json(nodes: &[Node]) -> Value {
+    Value::Array(nodes.iter().map(Node::to_json).collect())
+}
+
+/// Appends `node` to `nodes`, merging adjacent text nodes like `mergeText`
+/// of `mfm-js` does.
+pub(crate) fn push_node(nodes: &mut Vec<Node>, node: Node) {
+    if let Node::Text { text } = &node {
+        if let Some(Node::Text { text: last }) = nodes.last_mut() {
+            last.push_str(text);
+            return;
+        }
+    }
+    nodes.push(node);
+}
+
+/// Returns the text content of the nodes without any markup, for search
+/// indexing and previews.
+pub fn to_plain_text(nodes: &[Node]) -> String {
+    let mut text = String::new();
+    append_plain_text(nodes, &mut text);
+    text.trim().to_string()
+}
+
+fn append_plain_text(nodes: &[Node], text: &mut String) {
+    for node in nodes {
+        let block = matches!(
+            node,
+            Node::Quote { .. }
+                | Node::Search { .. }
+                | Node::BlockCode { .. }
+                | Node::MathBlock { .. }
+                | Node::Center { .. }
+        );
+        if block && !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        match node {
+            Node::Search { content, .. } => text.push_str(content),
+            Node::BlockCode { code, .. } | Node::InlineCode { code } => text.push_str(code),
+            Node::MathBlock { formula } | Node::MathInline { formula } => text.push_str(formula),
+            Node::UnicodeEmoji { emoji } => text.push_str(emoji),
+            Node::EmojiCode { name } => {
+                text.push(':');
+                text.push_str(name);
+                text.push(':');
+            }
+            Node::Mention { acct, .. } => text.push_str(acct),
+            Node::Hashtag { hashtag } => {
+                text.push('#');
+                text.push_str(hashtag);
+            }
+            Node::Url { url, .. } => text.push_str(url),
+            Node::Text { text: t } => text.push_str(t),
+            _ => append_plain_text(node.children(), text),
+        }
+        if block {
+            text.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::to_plain_text;
+    use crate::mfm::parser::parse;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(
+            to_plain_text(&parse(
+                "**hi** @alice $[spin :blobcat:] #tag\n> quoted\n[link](https://example.com)"
+            )),
+            "hi @alice :blobcat: #tag\nquoted\nlink"
+        );
+        assert_eq!(to_plain_text(&parse("a\n```\ncode\n```\nb")), "a\ncode\nb");
+    }
+}
@@ -0,0 +1,860 @@
+//! MFM parser following the grammar of `mfm-js` 0.23.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::hashtag::extract::scan_tag;
+use crate::mfm::emoji::scan_emoji;
+use crate::mfm::node::{push_node, Node};
+use crate::util::acct::scan_mention;
+
+/// Maximum nesting depth of nodes, like `nestLimit` of `mfm-js`.
+const NEST_LIMIT: usize = 20;
+
+/// Number of steps the parser may take for each character of the input.
+/// Backtracking over unclosed markup like `<b><b><b>...` would otherwise take
+/// exponential time; once the steps run out, the rest is parsed as text.
+const STEPS_PER_CHAR: usize = 200;
+const MIN_STEPS: usize = 100_000;
+
+type Rule<'a> = fn(&mut Parser<'a>, usize) -> Option<(Node, usize)>;
+
+/// Parses MFM text into a syntax tree.
+pub fn parse(text: &str) -> Vec<Node> {
+    let chars: Vec<char> = text.chars().collect();
+    let steps = Rc::new(Cell::new(MIN_STEPS.max(chars.len() * STEPS_PER_CHAR)));
+    let mut parser = Parser::new(&chars, 0, steps);
+    parser.nodes(Parser::FULL)
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{3000}' | '\t')
+}
+
+fn is_url_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || ".,_/:%#@$&?!~=+-".contains(c)
+}
+
+struct Parser<'a> {
+    input: &'a [char],
+    depth: usize,
+    link_label: bool,
+    /// Steps left, shared with the parsers of quotes.
+    steps: Rc<Cell<usize>>,
+}
+
+impl<'a> Parser<'a> {
+    const FULL: &'a [Rule<'a>] = &[
+        Self::unicode_emoji,
+        Self::center_tag,
+        Self::small_tag,
+        Self::plain_tag,
+        Self::bold_tag,
+        Self::italic_tag,
+        Self::strike_tag,
+        Self::url_alt,
+        Self::big,
+        Self::bold_asta,
+        Self::italic_asta,
+        Self::bold_under,
+        Self::italic_under,
+        Self::code_block,
+        Self::inline_code,
+        Self::quote,
+        Self::math_block,
+        Self::math_inline,
+        Self::strike_wave,
+        Self::func,
+        Self::mention,
+        Self::hashtag,
+        Self::emoji_code,
+        Self::link,
+        Self::url,
+        Self::search,
+    ];
+
+    const INLINE: &'a [Rule<'a>] = &[
+        Self::unicode_emoji,
+        Self::small_tag,
+        Self::plain_tag,
+        Self::bold_tag,
+        Self::italic_tag,
+        Self::strike_tag,
+        Self::url_alt,
+        Self::big,
+        Self::bold_asta,
+        Self::italic_asta,
+        Self::bold_under,
+        Self::italic_under,
+        Self::inline_code,
+        Self::math_inline,
+        Self::strike_wave,
+        Self::func,
+        Self::mention,
+        Self::hashtag,
+        Self::emoji_code,
+        Self::link,
+        Self::url,
+    ];
+
+    fn new(input: &'a [char], depth: usize, steps: Rc<Cell<usize>>) -> Self {
+        Self {
+            input,
+            depth,
+            link_label: false,
+            steps,
+        }
+    }
+
+    /// Takes a step, returning `false` if there are none left.
+    fn step(&self) -> bool {
+        match self.steps.get().checked_sub(1) {
+            Some(left) => {
+                self.steps.set(left);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Parses the whole input with `rules`.
+    fn nodes(&mut self, rules: &[Rule<'a>]) -> Vec<Node> {
+        let mut nodes = Vec::new();
+        let mut i = 0;
+        while i < self.input.len() {
+            let (node, end) = self.nest(i, rules);
+            push_node(&mut nodes, node);
+            i = end;
+        }
+        nodes
+    }
+
+    /// Parses a node with `rules` one level deeper, or a single character as
+    /// text once the nesting limit is reached or the steps run out.
+    fn nest(&mut self, i: usize, rules: &[Rule<'a>]) -> (Node, usize) {
+        if self.step() && self.depth < NEST_LIMIT {
+            self.depth += 1;
+            let result = rules.iter().find_map(|rule| rule(self, i));
+            self.depth -= 1;
+            if let Some(result) = result {
+                return result;
+            }
+        }
+        (Node::text(self.input[i]), i + 1)
+    }
+
+    /// Parses inline nodes from `i` until `stop` matches or the input ends.
+    fn nodes_until(
+        &mut self,
+        mut i: usize,
+        stop: &dyn Fn(&Self, usize) -> bool,
+    ) -> (Vec<Node>, usize) {
+        let mut nodes = Vec::new();
+        while i < self.input.len() && self.steps.get() > 0 && !stop(self, i) {
+            let (node, end) = self.nest(i, Self::INLINE);
+            push_node(&mut nodes, node);
+            i = end;
+        }
+        (nodes, i)
+    }
+
+    /// Returns the text from `i` until `stop` matches, the input ends or the
+    /// steps run out.
+    fn text_until(&self, i: usize, stop: &dyn Fn(&Self, usize) -> bool) -> (String, usize) {
+        let mut end = i;
+        while end < self.input.len() && !stop(self, end) && self.step() {
+            end += 1;
+        }
+        (self.input[i..end].iter().collect(), end)
+    }
+
+    fn char_at(&self, i: usize) -> Option<char> {
+        self.input.get(i).copied()
+    }
+
+    /// Returns the position after `s` if it starts at `i`.
+    fn str_at(&self, i: usize, s: &str) -> Option<usize> {
+        let mut end = i;
+        for c in s.chars() {
+            if self.char_at(end) != Some(c) {
+                return None;
+            }
+            end += 1;
+        }
+        Some(end)
+    }
+
+    /// Like [Self::str_at], ignoring ASCII case.
+    fn str_at_ignore_case(&self, i: usize, s: &str) -> Option<usize> {
+        let mut end = i;
+        for c in s.chars() {
+            if !self
+                .char_at(end)
+                .is_some_and(|d| d.eq_ignore_ascii_case(&c))
+            {
+                return None;
+            }
+            end += 1;
+        }
+        Some(end)
+    }
+
+    /// Returns the position after a line break starting at `i`.
+    fn newline_at(&self, i: usize) -> Option<usize> {
+        match self.char_at(i)? {
+            '\r' if self.char_at(i + 1) == Some('\n') => Some(i + 2),
+            '\r' | '\n' => Some(i + 1),
+            _ => None,
+        }
+    }
+
+    /// Skips an optional line break.
+    fn opt_newline(&self, i: usize) -> usize {
+        self.newline_at(i).unwrap_or(i)
+    }
+
+    fn line_begin(&self, i: usize) -> bool {
+        i == 0 || matches!(self.input[i - 1], '\r' | '\n')
+    }
+
+    fn line_end(&self, i: usize) -> bool {
+        i == self.input.len() || self.newline_at(i).is_some()
+    }
+
+    /// Whether an ASCII alphanumeric character precedes `i`.
+    fn after_alnum(&self, i: usize) -> bool {
+        i > 0 && self.input[i - 1].is_ascii_alphanumeric()
+    }
+
+    /// `open`, inline nodes, `close`.
+    fn wrapped(&mut self, i: usize, open: &str, close: &str) -> Option<(Vec<Node>, usize)> {
+        let j = self.str_at(i, open)?;
+        let (children, j) = self.nodes_until(j, &|p, k| p.str_at(k, close).is_some());
+        if children.is_empty() {
+            return None;
+        }
+        Some((children, self.str_at(j, close)?))
+    }
+
+    /// `mark`, alphanumerics and spaces, `mark`.
+    fn alnum_wrapped(&self, i: usize, mark: char) -> Option<(String, usize)> {
+        if self.char_at(i) != Some(mark) {
+            return None;
+        }
+        let (text, j) = self.text_until(i + 1, &|p, k| {
+            !(p.input[k].is_ascii_alphanumeric() || is_space(p.input[k]))
+        });
+        if text.is_empty() || self.char_at(j) != Some(mark) {
+            return None;
+        }
+        Some((text, j + 1))
+    }
+
+    fn unicode_emoji(&mut self, i: usize) -> Option<(Node, usize)> {
+        let end = scan_emoji(self.input, i)?;
+        let node = Node::UnicodeEmoji {
+            emoji: self.input[i..end].iter().collect(),
+        };
+        Some((node, end))
+    }
+
+    fn quote(&mut self, i: usize) -> Option<(Node, usize)> {
+        let mut j = self.opt_newline(self.opt_newline(i));
+        if !self.line_begin(j) {
+            return None;
+        }
+        let mut lines = Vec::new();
+        while let Some(k) = self.str_at(j, ">") {
+            let k = if self.char_at(k).is_some_and(is_space) {
+                k + 1
+            } else {
+                k
+            };
+            let (line, k) = self.text_until(k, &|p, l| p.newline_at(l).is_some());
+            lines.push(line);
+            j = k;
+            match self.newline_at(j) {
+                Some(next) if self.str_at(next, ">").is_some() => j = next,
+                _ => break,
+            }
+        }
+        if lines.is_empty() || (lines.len() == 1 && lines[0].is_empty()) {
+            return None;
+        }
+        let j = self.opt_newline(self.opt_newline(j));
+
+        let content: Vec<char> = lines.join("\n").chars().collect();
+        let children =
+            Parser::new(&content, self.depth, Rc::clone(&self.steps)).nodes(Parser::FULL);
+        Some((Node::Quote { children }, j))
+    }
+
+    fn search(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.opt_newline(i);
+        if !self.line_begin(j) {
+            return None;
+        }
+        let button = |p: &Self, k: usize| -> Option<usize> {
+            ["[検索]", "[search]", "検索", "search"]
+                .iter()
+                .find_map(|b| p.str_at_ignore_case(k, b))
+        };
+        let button_at = |p: &Self, k: usize| -> Option<usize> {
+            if !p.char_at(k).is_some_and(is_space) {
+                return None;
+            }
+            button(p, k + 1).filter(|&end| p.line_end(end))
+        };
+        let (query, k) = self.text_until(j, &|p, k| {
+            p.newline_at(k).is_some() || button_at(p, k).is_some()
+        });
+        if query.is_empty() {
+            return None;
+        }
+        let end = button_at(self, k)?;
+        let content = format!("{}{}", query, self.input[k..end].iter().collect::<String>());
+        Some((Node::Search { query, content }, self.opt_newline(end)))
+    }
+
+    fn code_block(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.opt_newline(i);
+        if !self.line_begin(j) {
+            return None;
+        }
+        let j = self.str_at(j, "```")?;
+        let (lang, j) = self.text_until(j, &|p, k| p.newline_at(k).is_some());
+        let j = self.newline_at(j)?;
+        let is_close = |p: &Self, k: usize| {
+            p.newline_at(k)
+                .and_then(|l| p.str_at(l, "```"))
+                .is_some_and(|l| p.line_end(l))
+        };
+        let (code, j) = self.text_until(j, &is_close);
+        if code.is_empty() || !is_close(self, j) {
+            return None;
+        }
+        let j = self.str_at(self.newline_at(j)?, "```")?;
+        let lang = lang.trim();
+        let node = Node::BlockCode {
+            code,
+            lang: (!lang.is_empty()).then(|| lang.to_string()),
+        };
+        Some((node, self.opt_newline(j)))
+    }
+
+    fn math_block(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.opt_newline(i);
+        if !self.line_begin(j) {
+            return None;
+        }
+        let j = self.opt_newline(self.str_at(j, "\\[")?);
+        let (formula, j) = self.text_until(j, &|p, k| p.str_at(p.opt_newline(k), "\\]").is_some());
+        if formula.is_empty() {
+            return None;
+        }
+        let j = self.str_at(self.opt_newline(j), "\\]")?;
+        if !self.line_end(j) {
+            return None;
+        }
+        Some((Node::MathBlock { formula }, self.opt_newline(j)))
+    }
+
+    fn center_tag(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.opt_newline(i);
+        if !self.line_begin(j) {
+            return None;
+        }
+        let j = self.opt_newline(self.str_at(j, "<center>")?);
+        let (children, j) =
+            self.nodes_until(j, &|p, k| p.str_at(p.opt_newline(k), "</center>").is_some());
+        if children.is_empty() {
+            return None;
+        }
+        let j = self.str_at(self.opt_newline(j), "</center>")?;
+        if !self.line_end(j) {
+            return None;
+        }
+        Some((Node::Center { children }, self.opt_newline(j)))
+    }
+
+    fn big(&mut self, i: usize) -> Option<(Node, usize)> {
+        let (children, j) = self.wrapped(i, "***", "***")?;
+        let node = Node::Fn {
+            name: "tada".to_string(),
+            args: Vec::new(),
+            children,
+        };
+        Some((node, j))
+    }
+
+    fn bold_asta(&mut self, i: usize) -> Option<(Node, usize)> {
+        let (children, j) = self.wrapped(i, "**", "**")?;
+        Some((Node::Bold { children }, j))
+    }
+
+    fn bold_tag(&mut self, i: usize) -> Option<(Node, usize)> {
+        let (children, j) = self.wrapped(i, "<b>", "</b>")?;
+        Some((Node::Bold { children }, j))
+    }
+
+    fn bold_under(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.str_at(i, "__")?;
+        let (text, j) = self.text_until(j, &|p, k| {
+            !(p.input[k].is_ascii_alphanumeric() || is_space(p.input[k]))
+        });
+        if text.is_empty() {
+            return None;
+        }
+        let j = self.str_at(j, "__")?;
+        let node = Node::Bold {
+            children: vec![Node::text(text)],
+        };
+        Some((node, j))
+    }
+
+    fn small_tag(&mut self, i: usize) -> Option<(Node, usize)> {
+        let (children, j) = self.wrapped(i, "<small>", "</small>")?;
+        Some((Node::Small { children }, j))
+    }
+
+    fn italic_tag(&mut self, i: usize) -> Option<(Node, usize)> {
+        let (children, j) = self.wrapped(i, "<i>", "</i>")?;
+        Some((Node::Italic { children }, j))
+    }
+
+    fn italic_asta(&mut self, i: usize) -> Option<(Node, usize)> {
+        self.italic_alnum(i, '*')
+    }
+
+    fn italic_under(&mut self, i: usize) -> Option<(Node, usize)> {
+        self.italic_alnum(i, '_')
+    }
+
+    fn italic_alnum(&self, i: usize, mark: char) -> Option<(Node, usize)> {
+        let (text, j) = self.alnum_wrapped(i, mark)?;
+        if self.after_alnum(i) {
+            return None;
+        }
+        let node = Node::Italic {
+            children: vec![Node::text(text)],
+        };
+        Some((node, j))
+    }
+
+    fn strike_tag(&mut self, i: usize) -> Option<(Node, usize)> {
+        let (children, j) = self.wrapped(i, "<s>", "</s>")?;
+        Some((Node::Strike { children }, j))
+    }
+
+    fn strike_wave(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.str_at(i, "~~")?;
+        let (children, j) = self.nodes_until(j, &|p, k| {
+            p.str_at(k, "~~").is_some() || p.newline_at(k).is_some()
+        });
+        if children.is_empty() {
+            return None;
+        }
+        Some((Node::Strike { children }, self.str_at(j, "~~")?))
+    }
+
+    fn plain_tag(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.opt_newline(self.str_at(i, "<plain>")?);
+        let (text, j) =
+            self.text_until(j, &|p, k| p.str_at(p.opt_newline(k), "</plain>").is_some());
+        if text.is_empty() {
+            return None;
+        }
+        let j = self.str_at(self.opt_newline(j), "</plain>")?;
+        let node = Node::Plain {
+            children: vec![Node::text(text)],
+        };
+        Some((node, j))
+    }
+
+    fn inline_code(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.str_at(i, "`")?;
+        let (code, j) = self.text_until(j, &|p, k| {
+            matches!(p.input[k], '`' | '´') || p.newline_at(k).is_some()
+        });
+        if code.is_empty() {
+            return None;
+        }
+        Some((Node::InlineCode { code }, self.str_at(j, "`")?))
+    }
+
+    fn math_inline(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.str_at(i, "\\(")?;
+        let (formula, j) = self.text_until(j, &|p, k| {
+            p.str_at(k, "\\)").is_some() || p.newline_at(k).is_some()
+        });
+        if formula.is_empty() {
+            return None;
+        }
+        Some((Node::MathInline { formula }, self.str_at(j, "\\)")?))
+    }
+
+    fn func(&mut self, i: usize) -> Option<(Node, usize)> {
+        let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        let is_value_char = |c: char| c.is_ascii_alphanumeric() || "_.-".contains(c);
+
+        let j = self.str_at(i, "$[")?;
+        let (name, mut j) = self.text_until(j, &|p, k| !is_name_char(p.input[k]));
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut args = Vec::new();
+        if self.char_at(j) == Some('.') {
+            let mut k = j + 1;
+            loop {
+                let (key, end) = self.text_until(k, &|p, l| !is_name_char(p.input[l]));
+                if key.is_empty() {
+                    break;
+                }
+                let mut value = None;
+                let mut end = end;
+                if self.char_at(end) == Some('=') {
+                    let (v, v_end) = self.text_until(end + 1, &|p, l| !is_value_char(p.input[l]));
+                    if !v.is_empty() {
+                        value = Some(v);
+                        end = v_end;
+                    }
+                }
+                args.push((key, value));
+                k = end;
+                j = end;
+                if self.char_at(k) != Some(',') {
+                    break;
+                }
+                k += 1;
+            }
+            if args.is_empty() {
+                return None;
+            }
+        }
+
+        let j = self.str_at(j, " ")?;
+        let (children, j) = self.nodes_until(j, &|p, k| p.input[k] == ']');
+        if children.is_empty() {
+            return None;
+        }
+        let node = Node::Fn {
+            name,
+            args,
+            children,
+        };
+        Some((node, self.str_at(j, "]")?))
+    }
+
+    fn mention(&mut self, i: usize) -> Option<(Node, usize)> {
+        if self.link_label || self.char_at(i) != Some('@') || self.after_alnum(i) {
+            return None;
+        }
+        let (mention, end) = scan_mention(self.input, i + 1)?;
+        let node = match mention {
+            Some(mention) => Node::Mention {
+                username: mention.username,
+                host: mention.host,
+                acct: mention.acct,
+            },
+            None => Node::Text {
+                text: self.input[i..end].iter().collect(),
+            },
+        };
+        Some((node, end))
+    }
+
+    fn hashtag(&mut self, i: usize) -> Option<(Node, usize)> {
+        if self.link_label || self.char_at(i) != Some('#') || self.after_alnum(i) {
+            return None;
+        }
+        let end = scan_tag(self.input, i + 1, 0);
+        let hashtag: String = self.input[i + 1..end].iter().collect();
+        if hashtag.is_empty() || hashtag.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Some((Node::Hashtag { hashtag }, end))
+    }
+
+    fn emoji_code(&mut self, i: usize) -> Option<(Node, usize)> {
+        let j = self.str_at(i, ":")?;
+        let (name, j) = self.text_until(j, &|p, k| {
+            !(p.input[k].is_ascii_alphanumeric() || "_+-".contains(p.input[k]))
+        });
+        if name.is_empty() {
+            return None;
+        }
+        let j = self.str_at(j, ":")?;
+        if !self.line_end(j) && self.input[j].is_ascii_alphanumeric() {
+            return None;
+        }
+        Some((Node::EmojiCode { name }, j))
+    }
+
+    fn link(&mut self, i: usize) -> Option<(Node, usize)> {
+        if self.link_label {
+            return None;
+        }
+        let (silent, j) = match self.str_at(i, "?[") {
+            Some(j) => (true, j),
+            None => (false, self.str_at(i, "[")?),
+        };
+        self.link_label = true;
+        let (children, j) =
+            self.nodes_until(j, &|p, k| p.input[k] == ']' || p.newline_at(k).is_some());
+        self.link_label = false;
+        if children.is_empty() {
+            return None;
+        }
+        let j = self.str_at(j, "](")?;
+        let (url, j) = self.url_alt(j).or_else(|| self.url(j))?;
+        let Node::Url { url, .. } = url else {
+            return None;
+        };
+        let node = Node::Link {
+            silent,
+            url,
+            children,
+        };
+        Some((node, self.str_at(j, ")")?))
+    }
+
+    /// Returns the position after the scheme of a URL starting at `i`.
+    fn scheme_at(&self, i: usize) -> Option<usize> {
+        self.str_at(i, "https://")
+            .or_else(|| self.str_at(i, "http://"))
+    }
+
+    fn url_alt(&mut self, i: usize) -> Option<(Node, usize)> {
+        if self.link_label {
+            return None;
+        }
+        let j = self.scheme_at(self.str_at(i, "<")?)?;
+        let (_, j) = self.text_until(j, &|p, k| p.input[k] == '>' || is_space(p.input[k]));
+        let end = self.str_at(j, ">")?;
+        let node = Node::Url {
+            url: self.input[i + 1..j].iter().collect(),
+            brackets: true,
+        };
+        Some((node, end))
+    }
+
+    fn url(&mut self, i: usize) -> Option<(Node, usize)> {
+        if self.link_label {
+            return None;
+        }
+        let mut j = self.scheme_at(i)?;
+        let body = j;
+        while let Some(end) = self.url_item(j, self.depth) {
+            j = end;
+        }
+        if j == body {
+            return None;
+        }
+        // remove the ".," at the right end
+        while j > body && matches!(self.input[j - 1], '.' | ',') {
+            j -= 1;
+        }
+        if j == body {
+            return None;
+        }
+        let node = Node::Url {
+            url: self.input[i..j].iter().collect(),
+            brackets: false,
+        };
+        Some((node, j))
+    }
+
+    /// Returns the position after a URL character or a balanced bracket pair
+    /// of them starting at `i`.
+    fn url_item(&self, i: usize, depth: usize) -> Option<usize> {
+        let c = self.char_at(i)?;
+        if depth < NEST_LIMIT {
+            if let Some(close) = match c {
+                '(' => Some(')'),
+                '[' => Some(']'),
+                _ => None,
+            } {
+                let mut j = i + 1;
+                while let Some(end) = self.url_item(j, depth + 1) {
+                    j = end;
+                }
+                if self.char_at(j) == Some(close) {
+                    return Some(j + 1);
+                }
+            }
+        }
+        is_url_char(c).then_some(i + 1)
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::time::{Duration, Instant};
+
+    use super::parse;
+    use crate::mfm::node::{to_json, to_plain_text, Node};
+
+    fn assert_parse(input: &str, expected: serde_json::Value) {
+        assert_eq!(to_json(&parse(input)), expected, "input: {:?}", input);
+    }
+
+    fn text(text: &str) -> serde_json::Value {
+        json!({ "type": "text", "props": { "text": text } })
+    }
+
+    #[test]
+    fn inline() {
+        assert_parse("abc", json!([text("abc")]));
+        assert_parse(
+            "a **bold** b",
+            json!([text("a "), { "type": "bold", "children": [text("bold")] }, text(" b")]),
+        );
+        assert_parse(
+            "***big***",
+            json!([{ "type": "fn", "props": { "name": "tada", "args": {} }, "children": [text("big")] }]),
+        );
+        assert_parse(
+            "<small>s</small><i>i</i><s>x</s>~~y~~",
+            json!([
+                { "type": "small", "children": [text("s")] },
+                { "type": "italic", "children": [text("i")] },
+                { "type": "strike", "children": [text("x")] },
+                { "type": "strike", "children": [text("y")] },
+            ]),
+        );
+        assert_parse("a*b*", json!([text("a*b*")]));
+        assert_parse(
+            "a *b c*",
+            json!([text("a "), { "type": "italic", "children": [text("b c")] }]),
+        );
+        assert_parse(
+            "__a b__",
+            json!([{ "type": "bold", "children": [text("a b")] }]),
+        );
+        assert_parse(
+            "`x` \\(y\\)",
+            json!([
+                { "type": "inlineCode", "props": { "code": "x" } },
+                text(" "),
+                { "type": "mathInline", "props": { "formula": "y" } },
+            ]),
+        );
+        assert_parse("**unclosed", json!([text("**unclosed")]));
+    }
+
+    #[test]
+    fn mention_hashtag_emoji() {
+        assert_parse(
+            "@a @b@example.com. #tag :smile:",
+            json!([
+                { "type": "mention", "props": { "username": "a", "host": null, "acct": "@a" } },
+                text(" "),
+                { "type": "mention", "props": { "username": "b", "host": "example.com", "acct": "@b@example.com" } },
+                text(". "),
+                { "type": "hashtag", "props": { "hashtag": "tag" } },
+                text(" "),
+                { "type": "emojiCode", "props": { "name": "smile" } },
+            ]),
+        );
+        assert_parse("a@b #123 :a:b", json!([text("a@b #123 :a:b")]));
+    }
+
+    #[test]
+    fn url_and_link() {
+        assert_parse(
+            "see https://example.com/a_(b). <https://example.com/ä>",
+            json!([
+                text("see "),
+                { "type": "url", "props": { "url": "https://example.com/a_(b)" } },
+                text(". "),
+                { "type": "url", "props": { "url": "https://example.com/ä", "brackets": true } },
+            ]),
+        );
+        assert_parse(
+            "?[label @a](https://example.com)",
+            json!([{
+                "type": "link",
+                "props": { "silent": true, "url": "https://example.com" },
+                "children": [text("label @a")],
+            }]),
+        );
+    }
+
+    #[test]
+    fn func() {
+        assert_parse(
+            "$[spin.speed=1.5s,left x]",
+            json!([{
+                "type": "fn",
+                "props": { "name": "spin", "args": { "speed": "1.5s", "left": true } },
+                "children": [text("x")],
+            }]),
+        );
+        assert_parse("$[spin. x]", json!([text("$[spin. x]")]));
+    }
+
+    #[test]
+    fn blocks() {
+        assert_parse(
+            "a\n```js\nb\n```\nc",
+            json!([
+                text("a"),
+                { "type": "blockCode", "props": { "code": "b", "lang": "js" } },
+                text("c"),
+            ]),
+        );
+        assert_parse(
+            "> a\n> **b**\nc",
+            json!([
+                { "type": "quote", "children": [text("a\n"), { "type": "bold", "children": [text("b")] }] },
+                text("c"),
+            ]),
+        );
+        assert_parse(
+            "<center>\nx\n</center>\n\\[\ny\n\\]",
+            json!([
+                { "type": "center", "children": [text("x")] },
+                { "type": "mathBlock", "props": { "formula": "y" } },
+            ]),
+        );
+        assert_parse(
+            "firefish 検索",
+            json!([{ "type": "search", "props": { "query": "firefish", "content": "firefish 検索" } }]),
+        );
+        assert_parse(
+            "<plain>**a**</plain>",
+            json!([{ "type": "plain", "children": [text("**a**")] }]),
+        );
+    }
+
+    #[test]
+    fn pathological_input() {
+        // backtracking over unclosed markup stops once the steps run out
+        let started = Instant::now();
+        for input in [
+            "<b>".repeat(3000),
+            "?[".repeat(3000),
+            "$[a ".repeat(3000),
+            "**<i>~~$[x <small>[".repeat(500),
+            format!("https://example.com/{}", "(".repeat(3000)),
+            format!("#{}", "(".repeat(3000)),
+            "> ".repeat(3000),
+            format!("{}a{}", "<b>".repeat(10000), "</b>".repeat(10000)),
+        ] {
+            assert!(!parse(&input).is_empty());
+        }
+        assert!(started.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn nest_limit() {
+        // the innermost tags are left as text instead of overflowing the stack
+        let input = format!("{}a{}", "<b>".repeat(30), "</b>".repeat(30));
+        let nodes = parse(&input);
+        assert!(matches!(nodes[0], Node::Bold { .. }));
+        assert!(to_plain_text(&nodes).contains("<b>a</b>"));
+    }
+}
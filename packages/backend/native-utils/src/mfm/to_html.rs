@@ -0,0 +1,262 @@
+//! MFM to HTML rendering for the `content` of ActivityPub objects, ported
+//! from `mfm/to-html.ts`.
+//!
+//! Only a fixed set of elements is produced and all text and attributes are
+//! escaped, so the output is safe to embed as is.
+
+use crate::mfm::node::Node;
+
+/// A remote user mentioned in a note, as stored in `note.mentionedRemoteUsers`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct MentionedRemoteUser {
+    pub uri: String,
+    pub url: Option<String>,
+    pub username: String,
+    pub host: String,
+}
+
+const SEARCH_URL: &str = "https://search.annoyingorange.xyz/search?q=";
+
+/// Renders the nodes as HTML wrapped in `<p>`. `base_url` is the URL of this
+/// server, used for hashtag and local mention links.
+pub fn to_html(
+    nodes: &[Node],
+    base_url: &str,
+    mentioned_remote_users: &[MentionedRemoteUser],
+) -> String {
+    let renderer = Renderer {
+        base_url,
+        mentioned_remote_users,
+    };
+    let mut html = String::from("<p>");
+    renderer.append_nodes(nodes, &mut html);
+    html.push_str("</p>");
+    html
+}
+
+struct Renderer<'a> {
+    base_url: &'a str,
+    mentioned_remote_users: &'a [MentionedRemoteUser],
+}
+
+fn escape_text(text: &str, html: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '\u{a0}' => html.push_str("&nbsp;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+fn escape_attr(value: &str, html: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '"' => html.push_str("&quot;"),
+            '\u{a0}' => html.push_str("&nbsp;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+impl Renderer<'_> {
+    fn append_nodes(&self, nodes: &[Node], html: &mut String) {
+        for node in nodes {
+            self.append_node(node, html);
+        }
+    }
+
+    fn append_element(&self, tag: &str, children: &[Node], html: &mut String) {
+        html.push('<');
+        html.push_str(tag);
+        html.push('>');
+        self.append_nodes(children, html);
+        html.push_str("</");
+        html.push_str(tag);
+        html.push('>');
+    }
+
+    fn append_text_element(&self, tag: &str, text: &str, html: &mut String) {
+        html.push('<');
+        html.push_str(tag);
+        html.push('>');
+        escape_text(text, html);
+        html.push_str("</");
+        html.push_str(tag);
+        html.push('>');
+    }
+
+    /// Appends `<a>` with the attributes in order. The content is either
+    /// `text` or, if [None], `children`.
+    fn append_link(
+        &self,
+        attrs: &[(&str, &str)],
+        text: Option<&str>,
+        children: &[Node],
+        html: &mut String,
+    ) {
+        html.push_str("<a");
+        for (name, value) in attrs {
+            html.push(' ');
+            html.push_str(name);
+            html.push_str("=\"");
+            escape_attr(value, html);
+            html.push('"');
+        }
+        html.push('>');
+        match text {
+            Some(text) => escape_text(text, html),
+            None => self.append_nodes(children, html),
+        }
+        html.push_str("</a>");
+    }
+
+    fn append_node(&self, node: &Node, html: &mut String) {
+        match node {
+            Node::Bold { children } => self.append_element("b", children, html),
+            Node::Small { children } => self.append_element("small", children, html),
+            Node::Strike { children } => self.append_element("del", children, html),
+            Node::Italic { children } | Node::Fn { children, .. } => {
+                self.append_element("i", children, html)
+            }
+            Node::BlockCode { code, .. } => {
+                html.push_str("<pre>");
+                self.append_text_element("code", code, html);
+                html.push_str("</pre>");
+            }
+            Node::Center { children } => self.append_element("div", children, html),
+            Node::EmojiCode { name } => escape_text(&format!("\u{200b}:{}:\u{200b}", name), html),
+            Node::UnicodeEmoji { emoji } => escape_text(emoji, html),
+            Node::Hashtag { hashtag } => {
+                let href = format!("{}/tags/{}", self.base_url, hashtag);
+                let text = format!("#{}", hashtag);
+                self.append_link(&[("href", &href), ("rel", "tag")], Some(&text), &[], html);
+            }
+            Node::InlineCode { code } => self.append_text_element("code", code, html),
+            Node::MathInline { formula } | Node::MathBlock { formula } => {
+                self.append_text_element("code", formula, html)
+            }
+            Node::Link { url, children, .. } => {
+                self.append_link(&[("href", url)], None, children, html)
+            }
+            Node::Mention {
+                username,
+                host,
+                acct,
+            } => {
+                let remote_url = self
+                    .mentioned_remote_users
+                    .iter()
+                    .find(|user| &user.username == username && Some(&user.host) == host.as_ref())
+                    .map(|user| user.url.as_ref().unwrap_or(&user.uri));
+                let href = match remote_url {
+                    Some(url) => url.clone(),
+                    None => format!("{}/{}", self.base_url, acct),
+                };
+                self.append_link(
+                    &[("href", &href), ("class", "u-url mention")],
+                    Some(acct),
+                    &[],
+                    html,
+                );
+            }
+            Node::Quote { children } => self.append_element("blockquote", children, html),
+            Node::Text { text } => {
+                html.push_str("<span>");
+                let text = text.replace("\r\n", "\n").replace('\r', "\n");
+                for (i, line) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        html.push_str("<br>");
+                    }
+                    escape_text(line, html);
+                }
+                html.push_str("</span>");
+            }
+            Node::Url { url, .. } => self.append_link(&[("href", url)], Some(url), &[], html),
+            Node::Search { query, content } => {
+                let href = format!("{}{}", SEARCH_URL, query);
+                self.append_link(&[("href", &href)], Some(content), &[], html);
+            }
+            Node::Plain { children } => self.append_element("span", children, html),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{to_html, MentionedRemoteUser};
+    use crate::mfm::parser::parse;
+
+    const BASE_URL: &str = "https://local.example";
+
+    fn render(input: &str) -> String {
+        to_html(&parse(input), BASE_URL, &[])
+    }
+
+    #[test]
+    fn br() {
+        assert_eq!(
+            render("foo\nbar\nbaz"),
+            "<p><span>foo<br>bar<br>baz</span></p>"
+        );
+        assert_eq!(
+            render("foo\r\nbar\rbaz"),
+            "<p><span>foo<br>bar<br>baz</span></p>"
+        );
+    }
+
+    #[test]
+    fn elements() {
+        assert_eq!(
+            render("**a** ~~b~~ <small>c</small> $[spin d]"),
+            "<p><b><span>a</span></b><span> </span><del><span>b</span></del><span> </span>\
+             <small><span>c</span></small><span> </span><i><span>d</span></i></p>"
+        );
+        assert_eq!(
+            render("```\n<a>\n```"),
+            "<p><pre><code>&lt;a&gt;</code></pre></p>"
+        );
+        assert_eq!(
+            render("> q"),
+            "<p><blockquote><span>q</span></blockquote></p>"
+        );
+        assert_eq!(render(":blobcat:"), "<p>\u{200b}:blobcat:\u{200b}</p>");
+        assert_eq!(render("<script>"), "<p><span>&lt;script&gt;</span></p>");
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            render("#tag"),
+            "<p><a href=\"https://local.example/tags/tag\" rel=\"tag\">#tag</a></p>"
+        );
+        assert_eq!(
+            render("[a](<https://example.com/?a=\"&b>)"),
+            "<p><a href=\"https://example.com/?a=&quot;&amp;b\"><span>a</span></a></p>"
+        );
+        assert_eq!(
+            render("https://example.com"),
+            "<p><a href=\"https://example.com\">https://example.com</a></p>"
+        );
+        assert_eq!(
+            render("@alice"),
+            "<p><a href=\"https://local.example/@alice\" class=\"u-url mention\">@alice</a></p>"
+        );
+
+        let users = [MentionedRemoteUser {
+            uri: "https://remote.example/users/1".to_string(),
+            url: Some("https://remote.example/@bob".to_string()),
+            username: "bob".to_string(),
+            host: "remote.example".to_string(),
+        }];
+        assert_eq!(
+            to_html(&parse("@bob@remote.example"), BASE_URL, &users),
+            "<p><a href=\"https://remote.example/@bob\" class=\"u-url mention\">@bob@remote.example</a></p>"
+        );
+    }
+}
@@ -4,10 +4,12 @@ pub mod prelude;
 
 pub mod abuse_user_report;
 pub mod access_token;
+pub mod active_user_sketch;
 pub mod ad;
 pub mod announcement;
 pub mod announcement_read;
 pub mod antenna;
+pub mod antenna_note;
 pub mod app;
 pub mod attestation_challenge;
 pub mod auth_session;
@@ -15,8 +17,10 @@ pub mod blocking;
 pub mod channel;
 pub mod channel_following;
 pub mod channel_note_pining;
+pub mod chart_sketch;
 pub mod clip;
 pub mod clip_note;
+pub mod drive_blob;
 pub mod drive_file;
 pub mod drive_folder;
 pub mod emoji;
@@ -26,6 +30,7 @@ pub mod gallery_like;
 pub mod gallery_post;
 pub mod hashtag;
 pub mod instance;
+pub mod instance_delivery;
 pub mod messaging_message;
 pub mod meta;
 pub mod migrations;
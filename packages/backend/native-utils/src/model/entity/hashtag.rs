@@ -9,6 +9,7 @@ use super::newtype::StringVec;
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: String,
+    #[sea_orm(unique)]
     pub name: String,
     #[sea_orm(column_name = "mentionedUserIds")]
     pub mentioned_user_ids: StringVec,
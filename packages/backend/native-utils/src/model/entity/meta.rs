@@ -193,6 +193,8 @@ pub struct Model {
     pub enable_server_machine_stats: bool,
     #[sea_orm(column_name = "enableIdenticonGeneration")]
     pub enable_identicon_generation: bool,
+    #[sea_orm(column_name = "donationLink")]
+    pub donation_link: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
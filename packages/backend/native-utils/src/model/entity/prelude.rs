@@ -2,6 +2,7 @@
 
 pub use super::abuse_user_report::Entity as AbuseUserReport;
 pub use super::access_token::Entity as AccessToken;
+pub use super::active_user_sketch::Entity as ActiveUserSketch;
 pub use super::ad::Entity as Ad;
 pub use super::announcement::Entity as Announcement;
 pub use super::announcement_read::Entity as AnnouncementRead;
@@ -13,8 +14,10 @@ pub use super::blocking::Entity as Blocking;
 pub use super::channel::Entity as Channel;
 pub use super::channel_following::Entity as ChannelFollowing;
 pub use super::channel_note_pining::Entity as ChannelNotePining;
+pub use super::chart_sketch::Entity as ChartSketch;
 pub use super::clip::Entity as Clip;
 pub use super::clip_note::Entity as ClipNote;
+pub use super::drive_blob::Entity as DriveBlob;
 pub use super::drive_file::Entity as DriveFile;
 pub use super::drive_folder::Entity as DriveFolder;
 pub use super::emoji::Entity as Emoji;
@@ -24,6 +27,7 @@ pub use super::gallery_like::Entity as GalleryLike;
 pub use super::gallery_post::Entity as GalleryPost;
 pub use super::hashtag::Entity as Hashtag;
 pub use super::instance::Entity as Instance;
+pub use super::instance_delivery::Entity as InstanceDelivery;
 pub use super::messaging_message::Entity as MessagingMessage;
 pub use super::meta::Entity as Meta;
 pub use super::migrations::Entity as Migrations;
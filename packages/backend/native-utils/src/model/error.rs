@@ -1,3 +1,4 @@
+use crate::error::{ErrorCode, HasErrorCode};
 use crate::impl_into_napi_error;
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
@@ -12,4 +13,15 @@ pub enum Error {
     NotFound,
 }
 
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::ParseError(_) => ErrorCode::InvalidParam,
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::NotFound => ErrorCode::NoSuchObject,
+        }
+    }
+}
+
 impl_into_napi_error!(Error);
@@ -18,15 +18,19 @@ mod macros {
     /// Provides the default implementation of
     /// [crate::model::repository::Repository::pack_by_id].
     macro_rules! impl_pack_by_id {
-        ($a:ty, $b:ident) => {
-            match <$a>::find_by_id($b)
-                .one(crate::database::get_database()?)
-                .await?
-            {
-                None => Err(Error::NotFound),
-                Some(m) => m.pack().await,
-            }
-        };
+        ($a:ty, $b:ident) => {{
+            let entity = <$a>::default();
+            let entity = sea_orm::EntityName::table_name(&entity);
+            crate::metrics::observe_pack(entity, "pack_by_id", async move {
+                let db = &crate::database::get_read_database()?;
+                let query = <$a>::find_by_id($b).one(db);
+                match crate::metrics::observe_query(entity, query).await? {
+                    None => Err(Error::NotFound),
+                    Some(m) => m.pack().await,
+                }
+            })
+            .await
+        }};
     }
 
     pub(crate) use impl_pack_by_id;
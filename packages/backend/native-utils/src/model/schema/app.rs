@@ -98,16 +98,14 @@ mod unit_test {
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
-    use crate::util::id::{create_id, init_id};
     use crate::util::random::gen_string;
 
     use super::VALIDATOR;
 
     #[test]
     fn app_valid() {
-        init_id(16, "");
         let instance = json!({
-            "id": create_id(0).unwrap(),
+            "id": "9f7j4c1kx0000000",
             "name": "Test App",
             "secret": gen_string(24),
             "callbackUrl": "urn:ietf:wg:oauth:2.0:oob",
@@ -119,9 +117,8 @@ mod unit_test {
 
     #[test]
     fn app_invalid() {
-        init_id(16, "");
         let instance = json!({
-            "id": create_id(0).unwrap(),
+            "id": "9f7j4c1kx0000000",
             // "name" is required
             "name": null,
             // "permission" must be one of the app permissions
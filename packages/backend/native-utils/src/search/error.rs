@@ -0,0 +1,36 @@
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("The search index has not been initialized yet")]
+    Uninitialized,
+    #[error("Failed to open index directory: {0}")]
+    OpenDirectoryError(#[from] tantivy::directory::error::OpenDirectoryError),
+    #[error("Index error: {0}")]
+    IndexError(#[from] tantivy::TantivyError),
+    #[error("Indexing task failed: {0}")]
+    TaskError(#[from] tokio::task::JoinError),
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Failed to get database connection: {0}")]
+    DbConnError(#[from] crate::database::error::Error),
+    #[error("Database operation error: {0}")]
+    DbOperationError(#[from] sea_orm::DbErr),
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::Uninitialized => ErrorCode::NotInitialized,
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::OpenDirectoryError(_)
+            | Self::IndexError(_)
+            | Self::TaskError(_)
+            | Self::IoError(_) => ErrorCode::InternalError,
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
@@ -0,0 +1,601 @@
+use chrono::Utc;
+use sea_orm::ActiveEnum;
+use std::fs;
+use std::ops::Bound;
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use tantivy::collector::TopDocs;
+use tantivy::directory::error::LockError;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{
+    BooleanQuery, Occur, Query, QueryParser, RangeQuery, TermQuery, TermSetQuery,
+};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, Value, FAST, INDEXED, STORED, STRING, TEXT,
+};
+use tantivy::{
+    DateTime, Index, IndexReader, IndexWriter, Order, ReloadPolicy, TantivyDocument, TantivyError,
+    Term,
+};
+
+use super::error::Error;
+use crate::model::entity::note;
+use crate::model::entity::sea_orm_active_enums::NoteVisibilityEnum;
+
+/// Memory shared by the indexing threads of the writer.
+const WRITER_MEMORY_BUDGET: usize = 50_000_000;
+
+/// How long to wait for another process to release the index writer.
+const WRITER_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+const WRITER_LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The user who is searching, used to apply the visibility rules of
+/// `server/api/common/generate-visibility-query.ts`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Viewer {
+    pub id: String,
+    /// IDs of the users the viewer follows.
+    pub followee_ids: Vec<String>,
+}
+
+/// Filters of `server/api/endpoints/notes/search.ts`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub limit: usize,
+    pub offset: usize,
+    /// Only returns notes newer than this ID, oldest first unless `until_id`
+    /// is also set.
+    pub since_id: Option<String>,
+    /// Only returns notes older than this ID.
+    pub until_id: Option<String>,
+    /// Only returns notes from this host if set.
+    pub user_host: Option<String>,
+    /// Only returns local notes if `true`.
+    pub local_only: bool,
+    pub user_id: Option<String>,
+    pub channel_id: Option<String>,
+    /// Excludes the notes of these users and the replies and renotes of
+    /// their notes, e.g. the users muted by the viewer.
+    pub excluded_user_ids: Vec<String>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            limit: 10,
+            offset: 0,
+            since_id: None,
+            until_id: None,
+            user_host: None,
+            local_only: false,
+            user_id: None,
+            channel_id: None,
+            excluded_user_ids: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Fields {
+    id: Field,
+    text: Field,
+    cw: Field,
+    tags: Field,
+    user_id: Field,
+    user_host: Field,
+    created_at: Field,
+    visibility: Field,
+    visible_user_ids: Field,
+    mentions: Field,
+    reply_user_id: Field,
+    renote_user_id: Field,
+    channel_id: Field,
+    local: Field,
+    /// Milliseconds since the epoch when the note was last indexed.
+    indexed_at: Field,
+}
+
+fn build_schema() -> (Schema, Fields) {
+    let mut builder = Schema::builder();
+    let fields = Fields {
+        id: builder.add_text_field("id", STRING | STORED),
+        text: builder.add_text_field("text", TEXT),
+        cw: builder.add_text_field("cw", TEXT),
+        tags: builder.add_text_field("tags", STRING),
+        user_id: builder.add_text_field("user_id", STRING),
+        user_host: builder.add_text_field("user_host", STRING),
+        created_at: builder.add_date_field("created_at", INDEXED | FAST),
+        visibility: builder.add_text_field("visibility", STRING),
+        visible_user_ids: builder.add_text_field("visible_user_ids", STRING),
+        mentions: builder.add_text_field("mentions", STRING),
+        reply_user_id: builder.add_text_field("reply_user_id", STRING),
+        renote_user_id: builder.add_text_field("renote_user_id", STRING),
+        channel_id: builder.add_text_field("channel_id", STRING),
+        local: builder.add_bool_field("local", INDEXED),
+        indexed_at: builder.add_u64_field("indexed_at", INDEXED | FAST),
+    };
+    (builder.build(), fields)
+}
+
+/// Full-text index of notes built on [tantivy].
+///
+/// Every worker process opens the same index, so the writer is only held
+/// while a change is being committed. All methods that write block the
+/// current thread.
+pub struct SearchIndex {
+    index: Index,
+    reader: IndexReader,
+    /// Serializes the writes of this process.
+    write_lock: Mutex<()>,
+    fields: Fields,
+}
+
+impl SearchIndex {
+    /// Opens the index stored in the directory at `path`, creating it if
+    /// needed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        fs::create_dir_all(&path)?;
+        let (schema, fields) = build_schema();
+        let index = Index::open_or_create(MmapDirectory::open(path)?, schema)?;
+        Self::new(index, fields)
+    }
+
+    /// Creates an index that only lives in memory.
+    pub fn open_in_ram() -> Result<Self, Error> {
+        let (schema, fields) = build_schema();
+        Self::new(Index::create_in_ram(schema), fields)
+    }
+
+    fn new(index: Index, fields: Fields) -> Result<Self, Error> {
+        // picks up the commits of the other processes
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::OnCommitWithDelay)
+            .try_into()?;
+        Ok(Self {
+            index,
+            reader,
+            write_lock: Mutex::new(()),
+            fields,
+        })
+    }
+
+    /// Adds the notes to the index, replacing the ones already indexed.
+    pub fn add_notes(&self, notes: &[note::Model]) -> Result<(), Error> {
+        let indexed_at = Utc::now().timestamp_millis() as u64;
+        self.write(|writer| {
+            for note in notes {
+                writer.delete_term(Term::from_field_text(self.fields.id, &note.id));
+                writer.add_document(self.document(note, indexed_at))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes the notes with the given IDs from the index.
+    pub fn delete_notes(&self, note_ids: &[String]) -> Result<(), Error> {
+        self.write(|writer| {
+            for id in note_ids {
+                writer.delete_term(Term::from_field_text(self.fields.id, id));
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes the notes that have not been (re)indexed since `timestamp`,
+    /// in milliseconds since the epoch.
+    pub fn delete_notes_indexed_before(&self, timestamp: u64) -> Result<(), Error> {
+        self.write(|writer| {
+            writer.delete_query(Box::new(RangeQuery::new_u64_bounds(
+                "indexed_at".to_string(),
+                Bound::Unbounded,
+                Bound::Excluded(timestamp),
+            )))?;
+            Ok(())
+        })
+    }
+
+    /// Applies `operations` and commits them, waiting for other processes
+    /// that are writing to the index.
+    fn write(
+        &self,
+        operations: impl FnOnce(&mut IndexWriter) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let _guard = self.write_lock.lock().expect("Index write lock poisoned");
+        let started = Instant::now();
+        let mut writer: IndexWriter = loop {
+            match self.index.writer(WRITER_MEMORY_BUDGET) {
+                Err(TantivyError::LockFailure(LockError::LockBusy, _))
+                    if started.elapsed() < WRITER_LOCK_TIMEOUT =>
+                {
+                    thread::sleep(WRITER_LOCK_RETRY_INTERVAL)
+                }
+                result => break result?,
+            }
+        };
+        operations(&mut writer)?;
+        writer.commit()?;
+        // releases the lock once the segments have been merged
+        writer.wait_merging_threads()?;
+        self.reader.reload()?;
+        Ok(())
+    }
+
+    /// Returns the IDs of the notes matching `query` that `viewer` is allowed
+    /// to see, newest first. Only public and home notes are returned if
+    /// `viewer` is `None`. Invalid parts of `query`, such as an unclosed
+    /// quote, are ignored.
+    pub fn search(
+        &self,
+        query: &str,
+        viewer: Option<&Viewer>,
+        options: &SearchOptions,
+    ) -> Result<Vec<String>, Error> {
+        if options.limit == 0 {
+            return Ok(vec![]);
+        }
+
+        let f = &self.fields;
+        let mut parser = QueryParser::for_index(&self.index, vec![f.text, f.cw, f.tags]);
+        parser.set_conjunction_by_default();
+
+        let (query, errors) = parser.parse_query_lenient(query);
+        if !errors.is_empty() {
+            tracing::debug!(?errors, "Ignored invalid parts of the query");
+        }
+
+        let mut clauses = vec![
+            (Occur::Must, query),
+            (Occur::Must, self.visibility_query(viewer)),
+        ];
+        if options.since_id.is_some() || options.until_id.is_some() {
+            clauses.push((
+                Occur::Must,
+                Box::new(RangeQuery::new_str_bounds(
+                    "id".to_string(),
+                    exclusive_bound(&options.since_id),
+                    exclusive_bound(&options.until_id),
+                )),
+            ));
+        }
+        if let Some(host) = &options.user_host {
+            clauses.push((Occur::Must, term_query(f.user_host, host)));
+        }
+        if options.local_only {
+            clauses.push((
+                Occur::Must,
+                Box::new(TermQuery::new(
+                    Term::from_field_bool(f.local, true),
+                    IndexRecordOption::Basic,
+                )),
+            ));
+        }
+        if let Some(user_id) = &options.user_id {
+            clauses.push((Occur::Must, term_query(f.user_id, user_id)));
+        }
+        if let Some(channel_id) = &options.channel_id {
+            clauses.push((Occur::Must, term_query(f.channel_id, channel_id)));
+        }
+        if !options.excluded_user_ids.is_empty() {
+            for field in [f.user_id, f.reply_user_id, f.renote_user_id] {
+                let excluded = TermSetQuery::new(
+                    options
+                        .excluded_user_ids
+                        .iter()
+                        .map(|id| Term::from_field_text(field, id)),
+                );
+                clauses.push((Occur::MustNot, Box::new(excluded)));
+            }
+        }
+
+        // same order as `makePaginationQuery`
+        let order = match (&options.since_id, &options.until_id) {
+            (Some(_), None) => Order::Asc,
+            _ => Order::Desc,
+        };
+        let collector = TopDocs::with_limit(options.limit)
+            .and_offset(options.offset)
+            .order_by_fast_field::<DateTime>("created_at", order);
+        let searcher = self.reader.searcher();
+        let hits = searcher.search(&BooleanQuery::new(clauses), &collector)?;
+
+        let mut note_ids = Vec::with_capacity(hits.len());
+        for (_, address) in hits {
+            let doc: TantivyDocument = searcher.doc(address)?;
+            if let Some(id) = doc.get_first(f.id).and_then(|v| v.as_str()) {
+                note_ids.push(id.to_string());
+            }
+        }
+        Ok(note_ids)
+    }
+
+    fn visibility_query(&self, viewer: Option<&Viewer>) -> Box<dyn Query> {
+        let f = &self.fields;
+        let mut clauses = vec![
+            (
+                Occur::Should,
+                visibility_query(f, NoteVisibilityEnum::Public),
+            ),
+            (Occur::Should, visibility_query(f, NoteVisibilityEnum::Home)),
+        ];
+
+        if let Some(viewer) = viewer {
+            let followees = TermSetQuery::new(
+                viewer
+                    .followee_ids
+                    .iter()
+                    .map(|id| Term::from_field_text(f.user_id, id)),
+            );
+            let followers_only = BooleanQuery::new(vec![
+                (
+                    Occur::Must,
+                    visibility_query(f, NoteVisibilityEnum::Followers),
+                ),
+                (
+                    Occur::Must,
+                    Box::new(BooleanQuery::new(vec![
+                        (Occur::Should, Box::new(followees)),
+                        (Occur::Should, term_query(f.reply_user_id, &viewer.id)),
+                    ])),
+                ),
+            ]);
+
+            clauses.extend([
+                (Occur::Should, term_query(f.user_id, &viewer.id)),
+                (Occur::Should, term_query(f.visible_user_ids, &viewer.id)),
+                (Occur::Should, term_query(f.mentions, &viewer.id)),
+                (Occur::Should, Box::new(followers_only) as Box<dyn Query>),
+            ]);
+        }
+
+        Box::new(BooleanQuery::new(clauses))
+    }
+
+    fn document(&self, note: &note::Model, indexed_at: u64) -> TantivyDocument {
+        let f = &self.fields;
+        let mut doc = TantivyDocument::default();
+
+        doc.add_text(f.id, &note.id);
+        if let Some(text) = &note.text {
+            doc.add_text(f.text, text);
+        }
+        if let Some(cw) = &note.cw {
+            doc.add_text(f.cw, cw);
+        }
+        for tag in note.tags.iter() {
+            doc.add_text(f.tags, tag);
+        }
+        doc.add_text(f.user_id, &note.user_id);
+        match &note.user_host {
+            Some(host) => doc.add_text(f.user_host, host),
+            None => doc.add_bool(f.local, true),
+        }
+        doc.add_date(
+            f.created_at,
+            DateTime::from_timestamp_millis(note.created_at.timestamp_millis()),
+        );
+        doc.add_text(f.visibility, note.visibility.to_value());
+        for id in note.visible_user_ids.iter() {
+            doc.add_text(f.visible_user_ids, id);
+        }
+        for id in note.mentions.iter() {
+            doc.add_text(f.mentions, id);
+        }
+        if let Some(id) = &note.reply_user_id {
+            doc.add_text(f.reply_user_id, id);
+        }
+        if let Some(id) = &note.renote_user_id {
+            doc.add_text(f.renote_user_id, id);
+        }
+        if let Some(id) = &note.channel_id {
+            doc.add_text(f.channel_id, id);
+        }
+        doc.add_u64(f.indexed_at, indexed_at);
+
+        doc
+    }
+}
+
+fn term_query(field: Field, value: &str) -> Box<dyn Query> {
+    Box::new(TermQuery::new(
+        Term::from_field_text(field, value),
+        IndexRecordOption::Basic,
+    ))
+}
+
+fn exclusive_bound(id: &Option<String>) -> Bound<&str> {
+    match id {
+        Some(id) => Bound::Excluded(id),
+        None => Bound::Unbounded,
+    }
+}
+
+fn visibility_query(fields: &Fields, visibility: NoteVisibilityEnum) -> Box<dyn Query> {
+    term_query(fields.visibility, &visibility.to_value())
+}
+
+#[cfg(test)]
+mod unit_test {
+    use chrono::{TimeZone, Utc};
+    use pretty_assertions::assert_eq;
+
+    use super::{SearchIndex, SearchOptions, Viewer};
+    use crate::model::entity::note;
+    use crate::model::entity::sea_orm_active_enums::NoteVisibilityEnum;
+
+    fn note(id: &str, user_id: &str, text: &str, visibility: NoteVisibilityEnum) -> note::Model {
+        let created_at = Utc.timestamp_millis_opt(1_690_000_000_000).unwrap()
+            + chrono::Duration::seconds(id[2..].parse().unwrap());
+        note::Model {
+            id: id.to_string(),
+            created_at: created_at.into(),
+            user_id: user_id.to_string(),
+            text: Some(text.to_string()),
+            visibility,
+            ..Default::default()
+        }
+    }
+
+    // `visible_user_ids` is a `JsonStringVec` only with the `noarray` feature.
+    #[allow(clippy::useless_conversion)]
+    fn prepare() -> SearchIndex {
+        let index = SearchIndex::open_in_ram().unwrap();
+        let mut specified = note("n-4", "alice", "hello carol", NoteVisibilityEnum::Specified);
+        specified.visible_user_ids = vec!["carol".to_string()].into();
+        let mut remote = note("n-5", "dave", "hello from afar", NoteVisibilityEnum::Public);
+        remote.user_host = Some("example.com".to_string());
+        index
+            .add_notes(&[
+                note("n-1", "alice", "hello world", NoteVisibilityEnum::Public),
+                note("n-2", "alice", "hello home", NoteVisibilityEnum::Home),
+                note(
+                    "n-3",
+                    "alice",
+                    "hello followers",
+                    NoteVisibilityEnum::Followers,
+                ),
+                specified,
+                remote,
+                note("n-6", "bob", "goodbye world", NoteVisibilityEnum::Public),
+            ])
+            .unwrap();
+        index
+    }
+
+    #[test]
+    fn anonymous_search() {
+        let index = prepare();
+        let result = index
+            .search("hello", None, &SearchOptions::default())
+            .unwrap();
+        assert_eq!(result, vec!["n-5", "n-2", "n-1"]);
+    }
+
+    #[test]
+    fn visibility_rules() {
+        let index = prepare();
+        let follower = Viewer {
+            id: "bob".to_string(),
+            followee_ids: vec!["alice".to_string()],
+        };
+        let result = index
+            .search("hello", Some(&follower), &SearchOptions::default())
+            .unwrap();
+        assert_eq!(result, vec!["n-5", "n-3", "n-2", "n-1"]);
+
+        let recipient = Viewer {
+            id: "carol".to_string(),
+            followee_ids: vec![],
+        };
+        let result = index
+            .search("hello", Some(&recipient), &SearchOptions::default())
+            .unwrap();
+        assert_eq!(result, vec!["n-5", "n-4", "n-2", "n-1"]);
+    }
+
+    #[test]
+    fn options_and_deletion() {
+        let index = prepare();
+        let options = SearchOptions {
+            user_host: Some("example.com".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(index.search("hello", None, &options).unwrap(), vec!["n-5"]);
+
+        let options = SearchOptions {
+            limit: 1,
+            offset: 1,
+            ..Default::default()
+        };
+        assert_eq!(index.search("hello", None, &options).unwrap(), vec!["n-2"]);
+
+        index.delete_notes(&["n-2".to_string()]).unwrap();
+        let result = index
+            .search("hello", None, &SearchOptions::default())
+            .unwrap();
+        assert_eq!(result, vec!["n-5", "n-1"]);
+    }
+
+    #[test]
+    fn filters() {
+        let index = prepare();
+        let mut channel = note("n-7", "bob", "hello channel", NoteVisibilityEnum::Public);
+        channel.channel_id = Some("channel".to_string());
+        index.add_notes(&[channel]).unwrap();
+
+        let search = |options: SearchOptions| index.search("hello", None, &options).unwrap();
+        assert_eq!(
+            search(SearchOptions {
+                since_id: Some("n-1".to_string()),
+                until_id: Some("n-5".to_string()),
+                ..Default::default()
+            }),
+            vec!["n-2"]
+        );
+        assert_eq!(
+            search(SearchOptions {
+                since_id: Some("n-1".to_string()),
+                ..Default::default()
+            }),
+            vec!["n-2", "n-5", "n-7"]
+        );
+        assert_eq!(
+            search(SearchOptions {
+                local_only: true,
+                ..Default::default()
+            }),
+            vec!["n-7", "n-2", "n-1"]
+        );
+        assert_eq!(
+            search(SearchOptions {
+                user_id: Some("dave".to_string()),
+                ..Default::default()
+            }),
+            vec!["n-5"]
+        );
+        assert_eq!(
+            search(SearchOptions {
+                channel_id: Some("channel".to_string()),
+                ..Default::default()
+            }),
+            vec!["n-7"]
+        );
+        assert_eq!(
+            search(SearchOptions {
+                excluded_user_ids: vec!["alice".to_string(), "bob".to_string()],
+                ..Default::default()
+            }),
+            vec!["n-5"]
+        );
+    }
+
+    #[test]
+    fn invalid_query() {
+        let index = prepare();
+        for query in ["\"hello", "text:", "hello AND"] {
+            assert!(index.search(query, None, &SearchOptions::default()).is_ok());
+        }
+    }
+
+    #[test]
+    fn delete_stale_notes() {
+        let index = prepare();
+        let started = Utc::now().timestamp_millis() as u64 + 1;
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        index
+            .add_notes(&[note(
+                "n-1",
+                "alice",
+                "hello again",
+                NoteVisibilityEnum::Public,
+            )])
+            .unwrap();
+        index.delete_notes_indexed_before(started).unwrap();
+        let result = index
+            .search("hello", None, &SearchOptions::default())
+            .unwrap();
+        assert_eq!(result, vec!["n-1"]);
+    }
+}
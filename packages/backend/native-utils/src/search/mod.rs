@@ -0,0 +1,210 @@
+//! Embedded full-text search of notes, used when no external search service
+//! is configured.
+
+pub mod error;
+pub mod index;
+
+use cfg_if::cfg_if;
+use chrono::Utc;
+use error::Error;
+use once_cell::sync::OnceCell;
+use sea_orm::{ColumnTrait, CursorTrait, EntityTrait, QueryFilter, QuerySelect};
+use std::path::Path;
+
+pub use index::{SearchIndex, SearchOptions, Viewer};
+
+use crate::database;
+use crate::metrics;
+use crate::model::entity::{blocking, following, muting, note};
+
+static SEARCH_INDEX: OnceCell<SearchIndex> = OnceCell::new();
+
+/// Number of notes read from the database at once by [reindex_all_notes].
+const REINDEX_BATCH_SIZE: u64 = 10000;
+
+/// Opens the index stored at `path`. Must be called before any other
+/// function of this module.
+pub fn init_search(path: impl AsRef<Path>) -> Result<(), Error> {
+    SEARCH_INDEX.get_or_try_init(|| SearchIndex::open(path))?;
+    Ok(())
+}
+
+pub fn get_search_index() -> Result<&'static SearchIndex, Error> {
+    SEARCH_INDEX.get().ok_or(Error::Uninitialized)
+}
+
+/// Loads the notes with the given IDs and adds them to the index.
+#[tracing::instrument(skip_all, fields(notes = note_ids.len()))]
+pub async fn index_notes(note_ids: Vec<String>) -> Result<(), Error> {
+    let index = get_search_index()?;
+    let db = &database::get_database()?;
+    let query = note::Entity::find()
+        .filter(note::Column::Id.is_in(note_ids))
+        .all(db);
+    let notes = metrics::observe_query("index_notes", query).await?;
+    tokio::task::spawn_blocking(move || index.add_notes(&notes)).await?
+}
+
+/// Removes the notes with the given IDs from the index.
+#[tracing::instrument(skip_all, fields(notes = note_ids.len()))]
+pub async fn delete_notes(note_ids: Vec<String>) -> Result<(), Error> {
+    let index = get_search_index()?;
+    tokio::task::spawn_blocking(move || index.delete_notes(&note_ids)).await?
+}
+
+/// Searches notes as the user with `viewer_id`, or anonymously if `None`.
+/// The notes of the users muted by the viewer or blocking the viewer are
+/// excluded as well.
+// the query is not recorded as it may contain private words
+#[tracing::instrument(skip(query, options))]
+pub async fn search_notes(
+    query: &str,
+    viewer_id: Option<String>,
+    options: &SearchOptions,
+) -> Result<Vec<String>, Error> {
+    let index = get_search_index()?;
+    let mut options = options.to_owned();
+    let viewer = match viewer_id {
+        None => None,
+        Some(id) => {
+            let db = &database::get_read_database()?;
+            let query = following::Entity::find()
+                .select_only()
+                .column(following::Column::FolloweeId)
+                .filter(following::Column::FollowerId.eq(id.to_owned()))
+                .into_tuple()
+                .all(db);
+            let followee_ids: Vec<String> = metrics::observe_query("followees", query).await?;
+
+            let query = muting::Entity::find()
+                .select_only()
+                .column(muting::Column::MuteeId)
+                .filter(muting::Column::MuterId.eq(id.to_owned()))
+                .into_tuple()
+                .all(db);
+            let mutee_ids: Vec<String> = metrics::observe_query("mutees", query).await?;
+            let query = blocking::Entity::find()
+                .select_only()
+                .column(blocking::Column::BlockerId)
+                .filter(blocking::Column::BlockeeId.eq(id.to_owned()))
+                .into_tuple()
+                .all(db);
+            let blocker_ids: Vec<String> = metrics::observe_query("blockers", query).await?;
+            options.excluded_user_ids.extend(mutee_ids);
+            options.excluded_user_ids.extend(blocker_ids);
+
+            Some(Viewer { id, followee_ids })
+        }
+    };
+    index.search(query, viewer.as_ref(), &options)
+}
+
+/// Rebuilds the whole index from the `note` table and returns the number of
+/// indexed notes. The notes stay searchable while they are reindexed, and the
+/// deleted ones are only removed once every note has been indexed again.
+#[tracing::instrument]
+pub async fn reindex_all_notes() -> Result<u64, Error> {
+    let index = get_search_index()?;
+    let db = &database::get_read_database()?;
+    let started = Utc::now().timestamp_millis() as u64;
+
+    let mut indexed: u64 = 0;
+    let mut last_id: Option<String> = None;
+    loop {
+        let mut cursor = note::Entity::find().cursor_by(note::Column::Id);
+        if let Some(id) = last_id {
+            cursor.after(id);
+        }
+        let notes =
+            metrics::observe_query("reindex_notes", cursor.first(REINDEX_BATCH_SIZE).all(db))
+                .await?;
+        let Some(last) = notes.last() else {
+            break;
+        };
+        last_id = Some(last.id.to_owned());
+
+        let count = notes.len() as u64;
+        tokio::task::spawn_blocking(move || index.add_notes(&notes)).await??;
+        indexed += count;
+        tracing::debug!(indexed, "Indexed a batch of notes");
+        if count < REINDEX_BATCH_SIZE {
+            break;
+        }
+    }
+    tokio::task::spawn_blocking(move || index.delete_notes_indexed_before(started)).await??;
+
+    tracing::info!(indexed, "Rebuilt the search index");
+    Ok(indexed)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        /// Calls [init_search] inside.
+        #[napi]
+        pub fn native_init_search(path: String) -> napi::Result<()> {
+            init_search(path).map_err(Into::into)
+        }
+
+        #[napi]
+        pub async fn native_index_notes(note_ids: Vec<String>) -> napi::Result<()> {
+            index_notes(note_ids).await.map_err(Into::into)
+        }
+
+        #[napi]
+        pub async fn native_delete_notes_from_index(note_ids: Vec<String>) -> napi::Result<()> {
+            delete_notes(note_ids).await.map_err(Into::into)
+        }
+
+        /// Same as the parameters of `server/api/endpoints/notes/search.ts`.
+        #[napi(object)]
+        pub struct NativeSearchOptions {
+            pub limit: u32,
+            pub offset: u32,
+            pub since_id: Option<String>,
+            pub until_id: Option<String>,
+            pub host: Option<String>,
+            /// Only returns local notes, i.e. `host` is `null`.
+            pub local_only: bool,
+            pub user_id: Option<String>,
+            pub channel_id: Option<String>,
+        }
+
+        impl From<NativeSearchOptions> for SearchOptions {
+            fn from(options: NativeSearchOptions) -> Self {
+                Self {
+                    limit: options.limit as usize,
+                    offset: options.offset as usize,
+                    since_id: options.since_id,
+                    until_id: options.until_id,
+                    user_host: options.host,
+                    local_only: options.local_only,
+                    user_id: options.user_id,
+                    channel_id: options.channel_id,
+                    excluded_user_ids: vec![],
+                }
+            }
+        }
+
+        #[napi]
+        pub async fn native_search_notes(
+            query: String,
+            viewer_id: Option<String>,
+            options: NativeSearchOptions,
+        ) -> napi::Result<Vec<String>> {
+            search_notes(&query, viewer_id, &options.into())
+                .await
+                .map_err(Into::into)
+        }
+
+        /// Returns the number of indexed notes.
+        #[napi]
+        pub async fn native_reindex_all_notes() -> napi::Result<i64> {
+            reindex_all_notes()
+                .await
+                .map(|n| n as i64)
+                .map_err(Into::into)
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Daily, weekly and monthly active users, replacing the unique counts of
+//! `services/chart/charts/active-users.ts` on the admin dashboard.
+//!
+//! The activities of the local users are [record]ed as they happen into the
+//! sketch of the day in memory, and [save] merges them into the sketches of
+//! the days in the database.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, DbErr, EntityTrait, QueryFilter, Set, TransactionError, TransactionTrait,
+};
+
+use super::error::Error;
+use crate::chart::hll::HyperLogLog;
+use crate::chart::Span;
+use crate::database;
+use crate::metrics;
+use crate::model::entity::active_user_sketch;
+
+/// Number of the days of the sketches kept, which make up the monthly
+/// active users.
+pub const RETENTION_DAYS: i64 = 30;
+
+/// Sketches of the users active since the last [save], by the day.
+static BUFFER: Lazy<Mutex<HashMap<i64, HyperLogLog>>> = Lazy::new(Default::default);
+
+/// Numbers of the distinct local users active within the last 1, 7 and 30
+/// days, including today.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActiveUsers {
+    pub daily: u64,
+    pub weekly: u64,
+    pub monthly: u64,
+}
+
+/// Buffers the activity of the local user `user_id` at `at` to be saved by
+/// [save].
+pub fn record(user_id: &str, at: DateTime<Utc>) {
+    let mut buffer = BUFFER.lock().expect("Active user buffer lock poisoned");
+    buffer
+        .entry(Span::Day.floor(at))
+        .or_default()
+        .insert(user_id);
+}
+
+fn restore(days: HashMap<i64, HyperLogLog>) {
+    let mut buffer = BUFFER.lock().expect("Active user buffer lock poisoned");
+    for (date, sketch) in days {
+        buffer.entry(date).or_default().merge(&sketch);
+    }
+}
+
+/// Merges the buffered sketches into the saved ones in a transaction, and
+/// buffers them again if it fails. Returns the number of the days written.
+#[tracing::instrument]
+pub async fn save(now: DateTime<Utc>) -> Result<usize, Error> {
+    let days = std::mem::take(&mut *BUFFER.lock().expect("Active user buffer lock poisoned"));
+    let result = write(now, days.clone()).await;
+    if result.is_err() {
+        restore(days);
+    }
+    result
+}
+
+async fn write(now: DateTime<Utc>, mut days: HashMap<i64, HyperLogLog>) -> Result<usize, Error> {
+    let written = days.len();
+    database::get_database()?
+        .transaction::<_, (), DbErr>(|txn| {
+            Box::pin(async move {
+                let mut dates: Vec<i32> = days.keys().map(|&date| date as i32).collect();
+                // locks the sketches before reading them, in the same order by
+                // every transaction, so that the saves of the other workers
+                // wait instead of overwriting the merged sketches
+                dates.sort_unstable();
+                for &date in &dates {
+                    let model = active_user_sketch::ActiveModel {
+                        date: Set(date),
+                        sketch: Set(HyperLogLog::default().to_bytes()),
+                    };
+                    active_user_sketch::Entity::insert(model)
+                        .on_conflict(
+                            OnConflict::column(active_user_sketch::Column::Date)
+                                .update_column(active_user_sketch::Column::Date)
+                                .to_owned(),
+                        )
+                        .exec(txn)
+                        .await?;
+                }
+
+                let query = active_user_sketch::Entity::find()
+                    .filter(active_user_sketch::Column::Date.is_in(dates))
+                    .all(txn);
+                let saved = metrics::observe_query("active_user_sketches", query).await?;
+                for row in saved {
+                    match HyperLogLog::from_bytes(&row.sketch) {
+                        Some(sketch) => {
+                            if let Some(day) = days.get_mut(&i64::from(row.date)) {
+                                day.merge(&sketch);
+                            }
+                        }
+                        None => tracing::warn!(date = row.date, "Discarded a malformed sketch"),
+                    }
+                }
+
+                for (date, sketch) in days {
+                    let model = active_user_sketch::ActiveModel {
+                        date: Set(date as i32),
+                        sketch: Set(sketch.to_bytes()),
+                    };
+                    active_user_sketch::Entity::insert(model)
+                        .on_conflict(
+                            OnConflict::column(active_user_sketch::Column::Date)
+                                .update_column(active_user_sketch::Column::Sketch)
+                                .to_owned(),
+                        )
+                        .exec(txn)
+                        .await?;
+                }
+
+                let expired = Span::Day.floor(now - Duration::days(RETENTION_DAYS));
+                active_user_sketch::Entity::delete_many()
+                    .filter(active_user_sketch::Column::Date.lte(expired))
+                    .exec(txn)
+                    .await?;
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| match e {
+            TransactionError::Connection(e) | TransactionError::Transaction(e) => e,
+        })?;
+    Ok(written)
+}
+
+/// Counts the active users from the sketches of the last 30 days.
+pub async fn count(now: DateTime<Utc>) -> Result<ActiveUsers, Error> {
+    let today = Span::Day.floor(now);
+    let since = today - Span::Day.seconds() * (RETENTION_DAYS - 1);
+    let db = &database::get_read_database()?;
+    let query = active_user_sketch::Entity::find()
+        .filter(active_user_sketch::Column::Date.between(since, today))
+        .all(db);
+    let rows = metrics::observe_query("active_user_sketches", query).await?;
+
+    let mut daily = HyperLogLog::default();
+    let mut weekly = HyperLogLog::default();
+    let mut monthly = HyperLogLog::default();
+    for row in rows {
+        let Some(sketch) = HyperLogLog::from_bytes(&row.sketch) else {
+            tracing::warn!(date = row.date, "Discarded a malformed sketch");
+            continue;
+        };
+        let age = (today - i64::from(row.date)) / Span::Day.seconds();
+        for (days, merged) in [
+            (1, &mut daily),
+            (7, &mut weekly),
+            (RETENTION_DAYS, &mut monthly),
+        ] {
+            if age < days {
+                merged.merge(&sketch);
+            }
+        }
+    }
+    Ok(ActiveUsers {
+        daily: daily.count(),
+        weekly: weekly.count(),
+        monthly: monthly.count(),
+    })
+}
@@ -0,0 +1,183 @@
+//! Deliveries to the instances by day, replacing the delivery counts of
+//! `services/chart/charts/federation.ts` and `ap-request.ts` on the admin
+//! dashboard.
+//!
+//! `queue/processors/deliver.ts` [record]s the result of each delivery, and
+//! [save] adds the buffered counts to the ones in the database.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set};
+
+use super::error::Error;
+use crate::chart::Span;
+use crate::database::{self, Db};
+use crate::metrics;
+use crate::model::entity::instance_delivery;
+
+/// Number of the days of the counts kept.
+pub const RETENTION_DAYS: i64 = 30;
+
+/// Deliveries since the last [save], by the host and the day.
+static BUFFER: Lazy<Mutex<HashMap<(String, i64), Deliveries>>> = Lazy::new(Default::default);
+
+/// Numbers of the deliveries in a period.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Deliveries {
+    pub succeeded: u64,
+    pub failed: u64,
+}
+
+impl Deliveries {
+    fn from_sums(succeeded: Option<i64>, failed: Option<i64>) -> Self {
+        Self {
+            succeeded: succeeded.unwrap_or(0) as u64,
+            failed: failed.unwrap_or(0) as u64,
+        }
+    }
+
+    fn add(&mut self, other: Self) {
+        self.succeeded += other.succeeded;
+        self.failed += other.failed;
+    }
+}
+
+/// Buffers a delivery to `host` at `at` to be saved by [save].
+pub fn record(host: &str, succeeded: bool, at: DateTime<Utc>) {
+    let mut buffer = BUFFER.lock().expect("Delivery buffer lock poisoned");
+    let deliveries = buffer
+        .entry((host.to_string(), Span::Day.floor(at)))
+        .or_default();
+    if succeeded {
+        deliveries.succeeded += 1;
+    } else {
+        deliveries.failed += 1;
+    }
+}
+
+fn restore(deliveries: impl Iterator<Item = ((String, i64), Deliveries)>) {
+    let mut buffer = BUFFER.lock().expect("Delivery buffer lock poisoned");
+    for (key, deliveries) in deliveries {
+        buffer.entry(key).or_default().add(deliveries);
+    }
+}
+
+/// Adds the buffered deliveries to the counts of the instances, and buffers
+/// the ones not written again if it fails. Returns the number of the counts
+/// written.
+#[tracing::instrument]
+pub async fn save(now: DateTime<Utc>) -> Result<usize, Error> {
+    let buffer = std::mem::take(&mut *BUFFER.lock().expect("Delivery buffer lock poisoned"));
+    let db = match database::get_database() {
+        Ok(db) => db,
+        Err(e) => {
+            restore(buffer.into_iter());
+            return Err(e.into());
+        }
+    };
+
+    let mut pending = buffer.into_iter();
+    let mut written = 0;
+    while let Some(((host, date), deliveries)) = pending.next() {
+        if let Err(e) = add(&db, &host, date, deliveries).await {
+            tracing::warn!(host, "Failed to save the deliveries: {}", e);
+            restore(std::iter::once(((host, date), deliveries)).chain(pending));
+            return Err(e.into());
+        }
+        written += 1;
+    }
+
+    let expired = Span::Day.floor(now - Duration::days(RETENTION_DAYS));
+    instance_delivery::Entity::delete_many()
+        .filter(instance_delivery::Column::Date.lte(expired))
+        .exec(&db)
+        .await?;
+    Ok(written)
+}
+
+async fn add(db: &Db, host: &str, date: i64, deliveries: Deliveries) -> Result<(), DbErr> {
+    let model = instance_delivery::ActiveModel {
+        host: Set(host.to_string()),
+        date: Set(date as i32),
+        succeeded: Set(deliveries.succeeded as i32),
+        failed: Set(deliveries.failed as i32),
+    };
+    let query = instance_delivery::Entity::insert(model)
+        .on_conflict(
+            OnConflict::columns([
+                instance_delivery::Column::Host,
+                instance_delivery::Column::Date,
+            ])
+            // `OnConflict::value` replaces the previous values
+            .values([
+                (
+                    instance_delivery::Column::Succeeded,
+                    Expr::col((
+                        instance_delivery::Entity,
+                        instance_delivery::Column::Succeeded,
+                    ))
+                    .add(deliveries.succeeded as i32),
+                ),
+                (
+                    instance_delivery::Column::Failed,
+                    Expr::col((instance_delivery::Entity, instance_delivery::Column::Failed))
+                        .add(deliveries.failed as i32),
+                ),
+            ])
+            .to_owned(),
+        )
+        .exec(db);
+    metrics::observe_query("instance_delivery_update", query).await?;
+    Ok(())
+}
+
+fn select_since(now: DateTime<Utc>, days: i64) -> Select<instance_delivery::Entity> {
+    let since = Span::Day.floor(now) - Span::Day.seconds() * (days - 1);
+    instance_delivery::Entity::find()
+        .select_only()
+        .column_as(
+            Expr::col(instance_delivery::Column::Succeeded).sum(),
+            "succeeded",
+        )
+        .column_as(Expr::col(instance_delivery::Column::Failed).sum(), "failed")
+        .filter(instance_delivery::Column::Date.gte(since))
+}
+
+/// Returns the deliveries to all the instances in the last `days` days
+/// including today.
+pub async fn total(now: DateTime<Utc>, days: i64) -> Result<Deliveries, Error> {
+    let db = &database::get_read_database()?;
+    let query = select_since(now, days).into_tuple().one(db);
+    let sums: Option<(Option<i64>, Option<i64>)> =
+        metrics::observe_query("delivery_total", query).await?;
+    let (succeeded, failed) = sums.unwrap_or_default();
+    Ok(Deliveries::from_sums(succeeded, failed))
+}
+
+/// Returns the deliveries in the last `days` days including today by the
+/// hosts of the instances, the ones with the most failures first.
+pub async fn by_instance(
+    now: DateTime<Utc>,
+    days: i64,
+    limit: u64,
+) -> Result<Vec<(String, Deliveries)>, Error> {
+    let db = &database::get_read_database()?;
+    let query = select_since(now, days)
+        .column(instance_delivery::Column::Host)
+        .group_by(instance_delivery::Column::Host)
+        .order_by_desc(Expr::col(instance_delivery::Column::Failed).sum())
+        .order_by_asc(instance_delivery::Column::Host)
+        .limit(limit)
+        .into_tuple()
+        .all(db);
+    let sums: Vec<(Option<i64>, Option<i64>, String)> =
+        metrics::observe_query("instance_delivery_counts", query).await?;
+    Ok(sums
+        .into_iter()
+        .map(|(succeeded, failed, host)| (host, Deliveries::from_sums(succeeded, failed)))
+        .collect())
+}
@@ -0,0 +1,175 @@
+//! Daily rollups of the statistics shown on the admin dashboard, which are
+//! summarized without scanning `note` and `user` as the charts and
+//! `server/api/endpoints/stats.ts` do.
+//!
+//! The active users and the deliveries are recorded in memory as they
+//! happen, and [save]d with the charts by `services/chart/index.ts`.
+
+pub mod active_users;
+pub mod delivery;
+pub mod error;
+
+use cfg_if::cfg_if;
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter};
+
+pub use active_users::ActiveUsers;
+pub use delivery::Deliveries;
+
+use crate::database;
+use crate::model::entity::instance;
+use error::Error;
+
+/// Number of the days of the deliveries in the summary.
+const DELIVERY_DAYS: i64 = 7;
+/// Number of the instances with failed deliveries in the summary.
+const FAILING_INSTANCES: u64 = 10;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FailingInstance {
+    pub host: String,
+    pub deliveries: Deliveries,
+    pub latest_status: Option<i32>,
+    pub is_not_responding: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub active_users: ActiveUsers,
+    /// Number of the known instances.
+    pub instances: u64,
+    pub not_responding_instances: u64,
+    /// Deliveries in the last 7 days.
+    pub deliveries: Deliveries,
+    /// Instances with the most failed deliveries in the last 7 days.
+    pub failing_instances: Vec<FailingInstance>,
+}
+
+/// Writes the recorded active users and deliveries to the rollups.
+#[tracing::instrument]
+pub async fn save(now: DateTime<Utc>) -> Result<(), Error> {
+    let days = active_users::save(now).await?;
+    let deliveries = delivery::save(now).await?;
+    tracing::info!(days, deliveries, "Saved stats");
+    Ok(())
+}
+
+/// Summarizes the rollups and the states of the instances.
+pub async fn summary(now: DateTime<Utc>) -> Result<Summary, Error> {
+    let db = &database::get_read_database()?;
+    let instances = instance::Entity::find().count(db).await?;
+    let not_responding_instances = instance::Entity::find()
+        .filter(instance::Column::IsNotResponding.eq(true))
+        .count(db)
+        .await?;
+
+    let failing: Vec<_> = delivery::by_instance(now, DELIVERY_DAYS, FAILING_INSTANCES)
+        .await?
+        .into_iter()
+        .filter(|(_, deliveries)| deliveries.failed > 0)
+        .collect();
+    let states = instance::Entity::find()
+        .filter(instance::Column::Host.is_in(failing.iter().map(|(host, _)| host.as_str())))
+        .all(db)
+        .await?;
+    let failing_instances = failing
+        .into_iter()
+        .map(|(host, deliveries)| {
+            let state = states.iter().find(|i| i.host == host);
+            FailingInstance {
+                latest_status: state.and_then(|i| i.latest_status),
+                is_not_responding: state.is_some_and(|i| i.is_not_responding),
+                host,
+                deliveries,
+            }
+        })
+        .collect();
+
+    Ok(Summary {
+        active_users: active_users::count(now).await?,
+        instances,
+        not_responding_instances,
+        deliveries: delivery::total(now, DELIVERY_DAYS).await?,
+        failing_instances,
+    })
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeFailingInstance {
+            pub host: String,
+            pub succeeded: i64,
+            pub failed: i64,
+            pub latest_status: Option<i32>,
+            pub is_not_responding: bool,
+        }
+
+        #[napi(object)]
+        pub struct NativeStatsSummary {
+            pub daily_active_users: i64,
+            pub weekly_active_users: i64,
+            pub monthly_active_users: i64,
+            pub instances: i64,
+            pub not_responding_instances: i64,
+            /// Deliveries in the last 7 days.
+            pub deliveries_succeeded: i64,
+            pub deliveries_failed: i64,
+            pub failing_instances: Vec<NativeFailingInstance>,
+        }
+
+        impl From<Summary> for NativeStatsSummary {
+            fn from(summary: Summary) -> Self {
+                Self {
+                    daily_active_users: summary.active_users.daily as i64,
+                    weekly_active_users: summary.active_users.weekly as i64,
+                    monthly_active_users: summary.active_users.monthly as i64,
+                    instances: summary.instances as i64,
+                    not_responding_instances: summary.not_responding_instances as i64,
+                    deliveries_succeeded: summary.deliveries.succeeded as i64,
+                    deliveries_failed: summary.deliveries.failed as i64,
+                    failing_instances: summary
+                        .failing_instances
+                        .into_iter()
+                        .map(|i| NativeFailingInstance {
+                            host: i.host,
+                            succeeded: i.deliveries.succeeded as i64,
+                            failed: i.deliveries.failed as i64,
+                            latest_status: i.latest_status,
+                            is_not_responding: i.is_not_responding,
+                        })
+                        .collect(),
+                }
+            }
+        }
+
+        /// Calls [active_users::record] inside.
+        #[napi]
+        pub fn native_record_active_user(user_id: String) {
+            active_users::record(&user_id, Utc::now());
+        }
+
+        /// Calls [delivery::record] inside.
+        #[napi]
+        pub fn native_record_delivery(host: String, succeeded: bool) {
+            delivery::record(&host, succeeded, Utc::now());
+        }
+
+        /// Calls [save] inside.
+        #[napi]
+        pub async fn native_save_stats() -> napi::Result<()> {
+            save(Utc::now()).await.map_err(Into::into)
+        }
+
+        /// Calls [summary] inside.
+        #[napi]
+        pub async fn native_get_stats_summary() -> napi::Result<NativeStatsSummary> {
+            match summary(Utc::now()).await {
+                Ok(summary) => Ok(summary.into()),
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::cache::error::redis_error_code;
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Failed to get database connection: {0}")]
+    DbConnError(#[from] crate::database::error::Error),
+    #[error("Database operation error: {0}")]
+    DbOperationError(#[from] sea_orm::DbErr),
+    #[error("Failed to get cache connection: {0}")]
+    CacheConnError(#[from] crate::cache::error::Error),
+    #[error("Cache operation error: {0}")]
+    CacheOperationError(#[from] redis::RedisError),
+    #[error("Requested note not found")]
+    NotFound,
+}
+
+impl HasErrorCode for Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::DbConnError(e) => e.code(),
+            Self::DbOperationError(e) => e.code(),
+            Self::CacheConnError(e) => e.code(),
+            Self::CacheOperationError(e) => redis_error_code(e),
+            Self::NotFound => ErrorCode::NoSuchNote,
+        }
+    }
+}
+
+impl_into_napi_error!(Error);
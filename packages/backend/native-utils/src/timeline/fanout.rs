@@ -0,0 +1,159 @@
+//! Writer side of the timelines, called when a note is created.
+
+use cfg_if::cfg_if;
+use redis::{aio::ConnectionManager, streams::StreamMaxlen, AsyncCommands};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+use super::error::Error;
+use super::{Timeline, TimelineConfig};
+use crate::cache::{self, Cache};
+use crate::database;
+use crate::metrics;
+use crate::model::entity::sea_orm_active_enums::NoteVisibilityEnum;
+use crate::model::entity::{following, note};
+
+/// Pushes `note` into the home timelines of its author and local followers
+/// who can see it, and into the timelines of the antennas in `antenna_ids`.
+#[tracing::instrument(skip_all, fields(note_id = %note.id, antennas = antenna_ids.len()))]
+pub async fn fan_out_note(
+    note: &note::Model,
+    antenna_ids: &[String],
+    config: &TimelineConfig,
+) -> Result<(), Error> {
+    let cache = cache::get_cache()?;
+    let mut conn = cache.conn();
+
+    if note.user_host.is_none() {
+        let key = cache.key(Timeline::Home(note.user_id.to_owned()).to_string());
+        push_note(&mut conn, vec![key], &note.id, config).await?;
+    }
+    push_to_followers(cache, &mut conn, note, config).await?;
+
+    let keys = antenna_ids
+        .iter()
+        .map(|id| cache.key(Timeline::Antenna(id.to_owned()).to_string()))
+        .collect();
+    push_note(&mut conn, keys, &note.id, config).await?;
+
+    Ok(())
+}
+
+/// Loads the note by its id and calls [fan_out_note].
+pub async fn fan_out_note_by_id(
+    note_id: String,
+    antenna_ids: &[String],
+    config: &TimelineConfig,
+) -> Result<(), Error> {
+    let note = note::Entity::find_by_id(note_id)
+        .one(&database::get_database()?)
+        .await?
+        .ok_or(Error::NotFound)?;
+    fan_out_note(&note, antenna_ids, config).await
+}
+
+/// Reads the local followers of the note author in batches of
+/// [TimelineConfig::batch_size] and pushes the note into their home
+/// timelines.
+#[tracing::instrument(skip_all)]
+async fn push_to_followers(
+    cache: &Cache,
+    conn: &mut ConnectionManager,
+    note: &note::Model,
+    config: &TimelineConfig,
+) -> Result<(), Error> {
+    let db = &database::get_database()?;
+
+    let recipients: Option<Vec<String>> = match note.visibility {
+        NoteVisibilityEnum::Hidden => return Ok(()),
+        NoteVisibilityEnum::Specified => Some(note.visible_user_ids.to_vec()),
+        _ => None,
+    };
+    if recipients.as_ref().is_some_and(Vec::is_empty) {
+        return Ok(());
+    }
+
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut query = following::Entity::find()
+            .select_only()
+            .column(following::Column::Id)
+            .column(following::Column::FollowerId)
+            .filter(following::Column::FolloweeId.eq(note.user_id.to_owned()))
+            .filter(following::Column::FollowerHost.is_null())
+            .order_by_asc(following::Column::Id)
+            .limit(config.batch_size);
+        if let Some(ids) = &recipients {
+            query = query.filter(following::Column::FollowerId.is_in(ids.to_owned()));
+        }
+        if let Some(id) = cursor {
+            query = query.filter(following::Column::Id.gt(id));
+        }
+
+        let batch: Vec<(String, String)> =
+            metrics::observe_query("followers", query.into_tuple().all(db)).await?;
+        let batch_len = batch.len() as u64;
+        cursor = batch.last().map(|(id, _)| id.to_owned());
+
+        let keys = batch
+            .into_iter()
+            .map(|(_, follower_id)| cache.key(Timeline::Home(follower_id).to_string()))
+            .collect();
+        push_note(conn, keys, &note.id, config).await?;
+
+        if batch_len < config.batch_size {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends `note_id` to every stream in `keys` in one pipeline. If it fails,
+/// the streams are deleted so that they are read from SQL until written
+/// again, rather than missing the note.
+async fn push_note(
+    conn: &mut ConnectionManager,
+    keys: Vec<String>,
+    note_id: &str,
+    config: &TimelineConfig,
+) -> Result<(), Error> {
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    let mut pipe = redis::pipe();
+    for key in &keys {
+        pipe.xadd_maxlen(
+            key,
+            StreamMaxlen::Approx(config.maxlen),
+            "*",
+            &[("note", note_id)],
+        )
+        .ignore();
+    }
+    if let Err(e) = pipe.query_async::<_, ()>(conn).await {
+        if let Err(e) = conn.del::<_, ()>(keys).await {
+            tracing::warn!("Failed to delete the timelines not pushed to: {}", e);
+        }
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        use super::get_timeline_config;
+
+        /// Calls [fan_out_note_by_id] with the configuration set by
+        /// [super::native_init_timeline].
+        #[napi]
+        pub async fn native_fan_out_note(note_id: String, antenna_ids: Vec<String>) -> napi::Result<()> {
+            fan_out_note_by_id(note_id, &antenna_ids, get_timeline_config())
+                .await
+                .map_err(Into::into)
+        }
+    }
+}
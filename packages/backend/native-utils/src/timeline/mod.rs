@@ -0,0 +1,98 @@
+//! Home and antenna timelines stored in Redis streams.
+//!
+//! Each timeline is a stream of entries with a single `note` field holding
+//! the note ID, trimmed with `XADD ... MAXLEN ~` like the antenna timelines
+//! written by `services/add-note-to-antenna.ts`.
+
+pub mod error;
+pub mod fanout;
+pub mod reader;
+
+use once_cell::sync::OnceCell;
+use std::fmt;
+
+static CONFIG: OnceCell<TimelineConfig> = OnceCell::new();
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Timeline {
+    /// Home timeline of the user with this ID.
+    Home(String),
+    /// Timeline of the antenna with this ID.
+    Antenna(String),
+}
+
+impl Timeline {
+    /// Name of the timelines of this kind in the metrics of cache reads.
+    pub fn cache_name(&self) -> &'static str {
+        match self {
+            Timeline::Home(_) => "home_timeline",
+            Timeline::Antenna(_) => "antenna_timeline",
+        }
+    }
+}
+
+impl fmt::Display for Timeline {
+    /// Formats the key of the stream without the cache prefix.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Timeline::Home(user_id) => write!(f, "homeTimeline:{}", user_id),
+            Timeline::Antenna(antenna_id) => write!(f, "antennaTimeline:{}", antenna_id),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimelineConfig {
+    /// Approximate maximum number of entries kept in each stream.
+    pub maxlen: usize,
+    /// Number of `following` rows read at once while fanning out.
+    pub batch_size: u64,
+}
+
+impl Default for TimelineConfig {
+    fn default() -> Self {
+        Self {
+            maxlen: 200,
+            batch_size: 1000,
+        }
+    }
+}
+
+/// Sets the configuration used by the NAPI functions. Only the first call
+/// has an effect.
+pub fn init_timeline(config: TimelineConfig) {
+    CONFIG.get_or_init(move || config);
+}
+
+/// Returns the configuration given to [init_timeline], or the default one.
+pub fn get_timeline_config() -> &'static TimelineConfig {
+    CONFIG.get_or_init(TimelineConfig::default)
+}
+
+#[cfg(feature = "napi")]
+#[napi_derive::napi]
+pub fn native_init_timeline(maxlen: u32, batch_size: u32) {
+    init_timeline(TimelineConfig {
+        maxlen: maxlen as usize,
+        batch_size: batch_size.into(),
+    });
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::Timeline;
+
+    #[test]
+    fn timeline_keys() {
+        assert_eq!(
+            Timeline::Home("9fil64s6g7cskdrb".to_string()).to_string(),
+            "homeTimeline:9fil64s6g7cskdrb"
+        );
+        assert_eq!(
+            Timeline::Antenna("9fil66brl1udxau2".to_string()).to_string(),
+            "antennaTimeline:9fil66brl1udxau2"
+        );
+    }
+}
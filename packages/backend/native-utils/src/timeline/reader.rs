@@ -0,0 +1,318 @@
+//! Reader side of the timelines.
+//!
+//! Note IDs are time-ordered strings, so entries from several streams are
+//! merged by sorting the note IDs in descending order. The home timeline is
+//! completed from SQL where the stream may miss notes: the notes newer than
+//! its newest entry, which were not pushed if the fan-out failed, and the
+//! ones older than its oldest entry, when the stream has run out of entries
+//! (e.g. it has not been written yet, was lost or was trimmed).
+
+use cfg_if::cfg_if;
+use redis::{streams::StreamRangeReply, AsyncCommands};
+use sea_orm::sea_query::{Expr, Query, SimpleExpr};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+
+use super::error::Error;
+use super::{Timeline, TimelineConfig};
+use crate::cache;
+use crate::database;
+use crate::metrics;
+use crate::model::entity::sea_orm_active_enums::NoteVisibilityEnum;
+use crate::model::entity::{following, note};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Pagination {
+    pub limit: u64,
+    /// Only returns notes whose ID is greater than this.
+    pub since_id: Option<String>,
+    /// Only returns notes whose ID is less than this.
+    pub until_id: Option<String>,
+}
+
+impl Pagination {
+    fn contains(&self, note_id: &str) -> bool {
+        self.since_id
+            .as_ref()
+            .is_none_or(|id| note_id > id.as_str())
+            && self
+                .until_id
+                .as_ref()
+                .is_none_or(|id| note_id < id.as_str())
+    }
+}
+
+/// Returns the part of `pagination` newer than `newest`, the newest note in
+/// the stream, or [None] if the page is entirely older than it. It is also
+/// [None] for an empty stream, which is read from SQL as a whole.
+fn newer_than_stream(newest: Option<&str>, pagination: &Pagination) -> Option<Pagination> {
+    let newest = newest?;
+    if pagination
+        .until_id
+        .as_ref()
+        .is_some_and(|id| id.as_str() <= newest)
+    {
+        return None;
+    }
+    let since_id = match &pagination.since_id {
+        Some(id) if id.as_str() > newest => id.to_owned(),
+        _ => newest.to_string(),
+    };
+    Some(Pagination {
+        limit: pagination.limit,
+        since_id: Some(since_id),
+        until_id: pagination.until_id.to_owned(),
+    })
+}
+
+/// Returns the note IDs of the home timeline of the user, newest first.
+/// Entries missing from the stream are filled from SQL.
+pub async fn read_home_timeline(
+    user_id: &str,
+    pagination: &Pagination,
+    config: &TimelineConfig,
+) -> Result<Vec<String>, Error> {
+    let timeline = Timeline::Home(user_id.to_owned());
+    let stream = read_stream(&timeline, config).await?;
+    let newer = newer_than_stream(stream.iter().max().map(String::as_str), pagination);
+    let mut note_ids = merge_note_ids(vec![stream], pagination);
+    let mut hit = true;
+    if let Some(newer) = newer {
+        let missing = read_home_timeline_from_db(user_id, &newer).await?;
+        if !missing.is_empty() {
+            hit = false;
+            note_ids = merge_note_ids(vec![missing, note_ids], pagination);
+        }
+    }
+
+    let remaining = pagination.limit.saturating_sub(note_ids.len() as u64);
+    metrics::record_cache_read(timeline.cache_name(), hit && remaining == 0);
+    if remaining > 0 {
+        let fallback = Pagination {
+            limit: remaining,
+            since_id: pagination.since_id.to_owned(),
+            until_id: note_ids.last().or(pagination.until_id.as_ref()).cloned(),
+        };
+        note_ids.extend(read_home_timeline_from_db(user_id, &fallback).await?);
+    }
+
+    Ok(note_ids)
+}
+
+/// Merges the streams of `timelines` and returns at most
+/// [Pagination::limit] note IDs, newest first.
+pub async fn read_timelines(
+    timelines: &[Timeline],
+    pagination: &Pagination,
+    config: &TimelineConfig,
+) -> Result<Vec<String>, Error> {
+    let mut streams = Vec::with_capacity(timelines.len());
+    for timeline in timelines {
+        let stream = read_stream(timeline, config).await?;
+        metrics::record_cache_read(timeline.cache_name(), !stream.is_empty());
+        streams.push(stream);
+    }
+
+    Ok(merge_note_ids(streams, pagination))
+}
+
+/// Returns the note IDs in the stream of `timeline`, latest pushed first.
+async fn read_stream(timeline: &Timeline, config: &TimelineConfig) -> Result<Vec<String>, Error> {
+    let cache = cache::get_cache()?;
+    let reply: StreamRangeReply = cache
+        .conn()
+        .xrevrange_count(cache.key(timeline.to_string()), "+", "-", config.maxlen)
+        .await?;
+    Ok(reply
+        .ids
+        .iter()
+        .filter_map(|entry| entry.get("note"))
+        .collect())
+}
+
+fn merge_note_ids(streams: Vec<Vec<String>>, pagination: &Pagination) -> Vec<String> {
+    let mut note_ids: Vec<String> = streams
+        .into_iter()
+        .flatten()
+        .filter(|id| pagination.contains(id))
+        .collect();
+    note_ids.sort_unstable_by(|a, b| b.cmp(a));
+    note_ids.dedup();
+    note_ids.truncate(pagination.limit as usize);
+    note_ids
+}
+
+/// Returns the condition that the note is addressed to the user, which is
+/// how [super::fanout] picks the followers to push `specified` notes to.
+fn is_visible_to(user_id: &str) -> SimpleExpr {
+    cfg_if! {
+        if #[cfg(feature = "noarray")] {
+            Expr::cust_with_values(
+                r#""note"."visibleUserIds" LIKE ?"#,
+                [format!("%\"{}\"%", user_id)],
+            )
+        } else {
+            Expr::cust_with_values(r#"? = ANY("note"."visibleUserIds")"#, [user_id])
+        }
+    }
+}
+
+/// Computes the home timeline from the `note` and `following` tables, with
+/// the same notes as [super::fanout] pushes into the stream.
+#[tracing::instrument(skip(pagination))]
+pub async fn read_home_timeline_from_db(
+    user_id: &str,
+    pagination: &Pagination,
+) -> Result<Vec<String>, Error> {
+    let db = &database::get_read_database()?;
+
+    let followees = Query::select()
+        .column(following::Column::FolloweeId)
+        .from(following::Entity)
+        .and_where(following::Column::FollowerId.eq(user_id))
+        .to_owned();
+    let mut query = note::Entity::find()
+        .select_only()
+        .column(note::Column::Id)
+        .filter(
+            Condition::any().add(note::Column::UserId.eq(user_id)).add(
+                Condition::all()
+                    .add(note::Column::UserId.in_subquery(followees))
+                    .add(
+                        Condition::any()
+                            .add(note::Column::Visibility.is_in([
+                                NoteVisibilityEnum::Public,
+                                NoteVisibilityEnum::Home,
+                                NoteVisibilityEnum::Followers,
+                            ]))
+                            .add(
+                                Condition::all()
+                                    .add(note::Column::Visibility.eq(NoteVisibilityEnum::Specified))
+                                    .add(is_visible_to(user_id)),
+                            ),
+                    ),
+            ),
+        )
+        .order_by_desc(note::Column::Id)
+        .limit(pagination.limit);
+    if let Some(id) = &pagination.since_id {
+        query = query.filter(note::Column::Id.gt(id.to_owned()));
+    }
+    if let Some(id) = &pagination.until_id {
+        query = query.filter(note::Column::Id.lt(id.to_owned()));
+    }
+
+    Ok(metrics::observe_query("home_timeline", query.into_tuple().all(db)).await?)
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        use super::get_timeline_config;
+
+        #[napi]
+        pub async fn native_read_home_timeline(
+            user_id: String,
+            limit: u32,
+            since_id: Option<String>,
+            until_id: Option<String>,
+        ) -> napi::Result<Vec<String>> {
+            let pagination = Pagination { limit: limit.into(), since_id, until_id };
+            read_home_timeline(&user_id, &pagination, get_timeline_config())
+                .await
+                .map_err(Into::into)
+        }
+
+        #[napi]
+        pub async fn native_read_antenna_timeline(
+            antenna_id: String,
+            limit: u32,
+            since_id: Option<String>,
+            until_id: Option<String>,
+        ) -> napi::Result<Vec<String>> {
+            let pagination = Pagination { limit: limit.into(), since_id, until_id };
+            read_timelines(&[Timeline::Antenna(antenna_id)], &pagination, get_timeline_config())
+                .await
+                .map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::{merge_note_ids, newer_than_stream, Pagination};
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn merge_newest_first() {
+        let pagination = Pagination {
+            limit: 4,
+            ..Default::default()
+        };
+        let merged = merge_note_ids(
+            vec![
+                ids(&["9f05", "9f03", "9f01"]),
+                ids(&["9f04", "9f03", "9f02"]),
+            ],
+            &pagination,
+        );
+        assert_eq!(merged, ids(&["9f05", "9f04", "9f03", "9f02"]));
+    }
+
+    #[test]
+    fn merge_with_bounds() {
+        let pagination = Pagination {
+            limit: 10,
+            since_id: Some("9f01".to_string()),
+            until_id: Some("9f05".to_string()),
+        };
+        let merged = merge_note_ids(
+            vec![
+                ids(&["9f06", "9f05", "9f03", "9f01"]),
+                ids(&["9f04", "9f02"]),
+            ],
+            &pagination,
+        );
+        assert_eq!(merged, ids(&["9f04", "9f03", "9f02"]));
+    }
+
+    #[test]
+    fn read_newer_than_stream() {
+        let pagination = Pagination {
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            newer_than_stream(Some("9f03"), &pagination),
+            Some(Pagination {
+                limit: 10,
+                since_id: Some("9f03".to_string()),
+                until_id: None,
+            })
+        );
+        assert_eq!(newer_than_stream(None, &pagination), None);
+
+        let pagination = Pagination {
+            limit: 10,
+            since_id: Some("9f05".to_string()),
+            until_id: Some("9f09".to_string()),
+        };
+        assert_eq!(
+            newer_than_stream(Some("9f03"), &pagination),
+            Some(pagination.clone())
+        );
+        assert_eq!(
+            newer_than_stream(Some("9f07"), &pagination),
+            Some(Pagination {
+                since_id: Some("9f07".to_string()),
+                ..pagination.clone()
+            })
+        );
+        assert_eq!(newer_than_stream(Some("9f09"), &pagination), None);
+    }
+}
@@ -0,0 +1,446 @@
+//! Acct parsing, host normalization and mention extraction, ported from
+//! `misc/acct.ts`, `misc/convert-host.ts` and `misc/extract-mentions.ts`.
+
+use cfg_if::cfg_if;
+use once_cell::sync::OnceCell;
+use std::{fmt, str::FromStr};
+
+use crate::error::{ErrorCode, HasErrorCode};
+use crate::impl_into_napi_error;
+use crate::util::scan::skip_verbatim;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Local host has not been initialized yet")]
+pub struct ErrorUninitialized;
+
+impl HasErrorCode for ErrorUninitialized {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::NotInitialized
+    }
+}
+
+impl_into_napi_error!(ErrorUninitialized);
+
+static LOCAL_HOST: OnceCell<String> = OnceCell::new();
+
+/// Sets the host of this server (`config.host`). Must be called before
+/// [get_full_ap_account] and [is_self_host].
+pub fn init_local_host(host: &str) {
+    LOCAL_HOST.get_or_init(|| to_puny(host));
+}
+
+fn local_host() -> Result<&'static str, ErrorUninitialized> {
+    LOCAL_HOST
+        .get()
+        .map(String::as_str)
+        .ok_or(ErrorUninitialized)
+}
+
+/// A user reference in the form of `username` or `username@host`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acct {
+    pub username: String,
+    /// [None] for local users.
+    pub host: Option<String>,
+}
+
+impl FromStr for Acct {
+    type Err = std::convert::Infallible;
+
+    /// Parses `@username@host`, `username@host` or `username`. Anything after
+    /// a second `@` is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.strip_prefix('@').unwrap_or(s).split('@');
+        let username = split.next().unwrap_or_default().to_string();
+        let host = split
+            .next()
+            .filter(|host| !host.is_empty())
+            .map(str::to_string);
+        Ok(Self { username, host })
+    }
+}
+
+impl fmt::Display for Acct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.host {
+            None => write!(f, "{}", self.username),
+            Some(host) => write!(f, "{}@{}", self.username, host),
+        }
+    }
+}
+
+impl Acct {
+    /// Returns `username@host` with the host punycoded, using the local host
+    /// for local users.
+    pub fn to_full_ap_account(&self) -> Result<String, ErrorUninitialized> {
+        get_full_ap_account(&self.username, self.host.as_deref())
+    }
+}
+
+/// Lowercases `host` and converts it to its ASCII (punycode) form. Hosts that
+/// are not valid IDNs are only lowercased.
+pub fn to_puny(host: &str) -> String {
+    let host = host.to_lowercase();
+    match idna::domain_to_ascii(&host) {
+        Ok(ascii) if !ascii.is_empty() => ascii,
+        _ => host,
+    }
+}
+
+/// Returns `username@host` with the host punycoded. The local host is used if
+/// `host` is [None].
+pub fn get_full_ap_account(
+    username: &str,
+    host: Option<&str>,
+) -> Result<String, ErrorUninitialized> {
+    let host = match host {
+        Some(host) => to_puny(host),
+        None => local_host()?.to_string(),
+    };
+    Ok(format!("{}@{}", username, host))
+}
+
+/// Returns whether `host` is the local host. [None] means local.
+pub fn is_self_host(host: Option<&str>) -> Result<bool, ErrorUninitialized> {
+    match host {
+        None => Ok(true),
+        Some(host) => Ok(local_host()? == to_puny(host)),
+    }
+}
+
+/// Returns the punycoded host of `uri` as stored in the database, or [None]
+/// if `uri` is not a valid URL with a host.
+pub fn extract_db_host(uri: &str) -> Option<String> {
+    let url = url::Url::parse(uri).ok()?;
+    url.host_str().map(to_puny)
+}
+
+/// Returns whether the user identified by `username` and `host` is one of
+/// `accts`, compared case-insensitively as full AP accounts. This is how
+/// antennas with `src = "users"` match note authors.
+pub fn accts_include(
+    accts: &[String],
+    username: &str,
+    host: Option<&str>,
+) -> Result<bool, ErrorUninitialized> {
+    let target = get_full_ap_account(username, host)?.to_lowercase();
+    for acct in accts {
+        let acct: Acct = acct.parse().unwrap();
+        if acct.to_full_ap_account()?.to_lowercase() == target {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// A mention found in MFM text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mention {
+    pub username: String,
+    pub host: Option<String>,
+    /// The mention as written, e.g. `@user@example.com`.
+    pub acct: String,
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+fn is_host_char(c: char) -> bool {
+    is_username_char(c) || c == '.'
+}
+
+/// Returns the unique mentions in `text` in order of appearance, following
+/// the `mention` rule of `mfm-js`. Mentions in code and URLs are ignored.
+pub fn extract_mentions(text: &str) -> Vec<Mention> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut mentions: Vec<Mention> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if let Some(end) = skip_verbatim(&chars, i) {
+            i = end;
+        } else if chars[i] == '@' && (i == 0 || !chars[i - 1].is_ascii_alphanumeric()) {
+            match scan_mention(&chars, i + 1) {
+                Some((mention, end)) => {
+                    if let Some(mention) = mention {
+                        if !mentions.contains(&mention) {
+                            mentions.push(mention);
+                        }
+                    }
+                    i = end;
+                }
+                None => i += 1,
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    mentions
+}
+
+/// Scans the mention whose username starts at `start`, returning it and the
+/// position right after it. An invalid mention (e.g. `@alice@.example`) is
+/// returned as `None` together with the end of the text `mfm-js` consumes
+/// for it.
+pub(crate) fn scan_mention(chars: &[char], start: usize) -> Option<(Option<Mention>, usize)> {
+    let name_len = chars[start..]
+        .iter()
+        .take_while(|&&c| is_username_char(c))
+        .count();
+    if name_len == 0 {
+        return None;
+    }
+    let name: String = chars[start..start + name_len].iter().collect();
+    let mut end = start + name_len;
+
+    let mut host = None;
+    if chars.get(end) == Some(&'@') {
+        let host_len = chars[end + 1..]
+            .iter()
+            .take_while(|&&c| is_host_char(c))
+            .count();
+        if host_len > 0 {
+            host = Some(
+                chars[end + 1..end + 1 + host_len]
+                    .iter()
+                    .collect::<String>(),
+            );
+            end += 1 + host_len;
+        }
+    }
+
+    let mut invalid = false;
+    let host = match host {
+        Some(raw) => {
+            let trimmed = raw.trim_end_matches(['.', '-']);
+            invalid |= trimmed.is_empty() || trimmed.starts_with(['.', '-']);
+            Some(trimmed.to_string()).filter(|h| !h.is_empty())
+        }
+        None => None,
+    };
+    let username = match &host {
+        // the tail of the username cannot be trimmed if there is a host
+        Some(_) => {
+            invalid |= name.ends_with('-');
+            name.as_str()
+        }
+        None => name.trim_end_matches('-'),
+    };
+    invalid |= username.is_empty() || username.starts_with('-');
+    if invalid {
+        return Some((None, end));
+    }
+
+    let acct = match &host {
+        None => format!("@{}", username),
+        Some(host) => format!("@{}@{}", username, host),
+    };
+    let end = start + acct.chars().count() - 1;
+    let mention = Mention {
+        username: username.to_string(),
+        host,
+        acct,
+    };
+    Some((Some(mention), end))
+}
+
+cfg_if! {
+    if #[cfg(feature = "napi")] {
+        use napi_derive::napi;
+
+        #[napi(object)]
+        pub struct NativeAcct {
+            pub username: String,
+            pub host: Option<String>,
+        }
+
+        #[napi(object)]
+        pub struct NativeMention {
+            pub username: String,
+            pub host: Option<String>,
+            pub acct: String,
+        }
+
+        impl From<Mention> for NativeMention {
+            fn from(mention: Mention) -> Self {
+                Self {
+                    username: mention.username,
+                    host: mention.host,
+                    acct: mention.acct,
+                }
+            }
+        }
+
+        /// Calls [init_local_host] inside.
+        #[napi]
+        pub fn native_init_local_host(host: String) {
+            init_local_host(&host);
+        }
+
+        #[napi]
+        pub fn native_parse_acct(acct: String) -> NativeAcct {
+            let acct: Acct = acct.parse().unwrap();
+            NativeAcct { username: acct.username, host: acct.host }
+        }
+
+        #[napi]
+        pub fn native_acct_to_string(acct: NativeAcct) -> String {
+            Acct { username: acct.username, host: acct.host }.to_string()
+        }
+
+        #[napi]
+        pub fn native_to_puny(host: String) -> String {
+            to_puny(&host)
+        }
+
+        #[napi]
+        pub fn native_get_full_ap_account(username: String, host: Option<String>) -> napi::Result<String> {
+            get_full_ap_account(&username, host.as_deref()).map_err(Into::into)
+        }
+
+        #[napi]
+        pub fn native_is_self_host(host: Option<String>) -> napi::Result<bool> {
+            is_self_host(host.as_deref()).map_err(Into::into)
+        }
+
+        #[napi]
+        pub fn native_extract_db_host(uri: String) -> Option<String> {
+            extract_db_host(&uri)
+        }
+
+        #[napi]
+        pub fn native_accts_include(
+            accts: Vec<String>,
+            username: String,
+            host: Option<String>,
+        ) -> napi::Result<bool> {
+            accts_include(&accts, &username, host.as_deref()).map_err(Into::into)
+        }
+
+        #[napi]
+        pub fn native_extract_mentions(text: String) -> Vec<NativeMention> {
+            extract_mentions(&text).into_iter().map(Into::into).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    use super::{
+        accts_include, extract_db_host, extract_mentions, get_full_ap_account, init_local_host,
+        is_self_host, to_puny, Acct,
+    };
+
+    fn acct(username: &str, host: Option<&str>) -> Acct {
+        Acct {
+            username: username.to_string(),
+            host: host.map(str::to_string),
+        }
+    }
+
+    fn mentions(text: &str) -> Vec<String> {
+        extract_mentions(text).into_iter().map(|m| m.acct).collect()
+    }
+
+    #[test]
+    fn parse_acct() {
+        assert_eq!("alice".parse::<Acct>().unwrap(), acct("alice", None));
+        assert_eq!("@alice".parse::<Acct>().unwrap(), acct("alice", None));
+        assert_eq!("@alice@".parse::<Acct>().unwrap(), acct("alice", None));
+        assert_eq!(
+            "@alice@example.com".parse::<Acct>().unwrap(),
+            acct("alice", Some("example.com"))
+        );
+        assert_eq!(
+            "alice@example.com@foo".parse::<Acct>().unwrap(),
+            acct("alice", Some("example.com"))
+        );
+        assert_eq!(
+            acct("alice", Some("example.com")).to_string(),
+            "alice@example.com"
+        );
+        assert_eq!(acct("alice", None).to_string(), "alice");
+    }
+
+    #[test]
+    fn puny() {
+        assert_eq!(to_puny("Example.COM"), "example.com");
+        assert_eq!(to_puny("ドメイン.テスト"), "xn--eckwd4c7c.xn--zckzah");
+        assert_eq!(
+            extract_db_host("https://ドメイン.テスト/users/1").as_deref(),
+            Some("xn--eckwd4c7c.xn--zckzah")
+        );
+        assert_eq!(extract_db_host("not a url"), None);
+    }
+
+    #[test]
+    fn full_ap_account() {
+        init_local_host("Local.Example");
+        assert_eq!(
+            get_full_ap_account("alice", None).unwrap(),
+            "alice@local.example"
+        );
+        assert_eq!(
+            get_full_ap_account("bob", Some("ドメイン.テスト")).unwrap(),
+            "bob@xn--eckwd4c7c.xn--zckzah"
+        );
+        assert!(is_self_host(None).unwrap());
+        assert!(is_self_host(Some("LOCAL.example")).unwrap());
+        assert!(!is_self_host(Some("remote.example")).unwrap());
+
+        let accts = vec!["@Alice".to_string(), "bob@ドメイン.テスト".to_string()];
+        assert!(accts_include(&accts, "alice", None).unwrap());
+        assert!(accts_include(&accts, "alice", Some("local.example")).unwrap());
+        assert!(accts_include(&accts, "BOB", Some("xn--eckwd4c7c.xn--zckzah")).unwrap());
+        assert!(!accts_include(&accts, "bob", None).unwrap());
+    }
+
+    #[test]
+    fn extract() {
+        assert_eq!(
+            mentions("@alice hi @bob@example.com and @alice"),
+            vec!["@alice", "@bob@example.com"]
+        );
+        assert_eq!(mentions("mail@example.com"), Vec::<String>::new());
+        assert_eq!(mentions("(@alice)"), vec!["@alice"]);
+        assert_eq!(mentions("@alice@example.com."), vec!["@alice@example.com"]);
+        assert_eq!(mentions("@alice-@example.com"), Vec::<String>::new());
+        assert_eq!(mentions("@alice@.example.com"), Vec::<String>::new());
+        assert_eq!(mentions("@-alice"), Vec::<String>::new());
+        assert_eq!(
+            mentions("`@alice` ```\n@bob\n``` https://example.com/@carol"),
+            Vec::<String>::new()
+        );
+
+        let mention = &extract_mentions("@bob@Example.com")[0];
+        assert_eq!(mention.username, "bob");
+        assert_eq!(mention.host.as_deref(), Some("Example.com"));
+    }
+
+    proptest! {
+        #[test]
+        fn acct_roundtrip(username in "[A-Za-z0-9_]{1,16}", host in proptest::option::of("[a-z0-9.-]{1,24}")) {
+            let acct = Acct { username, host };
+            prop_assert_eq!(acct.to_string().parse::<Acct>().unwrap(), acct);
+        }
+
+        #[test]
+        fn puny_idempotent(host in "\\PC{0,32}") {
+            let puny = to_puny(&host);
+            prop_assert_eq!(to_puny(&puny), puny);
+        }
+
+        #[test]
+        fn extract_never_panics(text in "\\PC{0,64}") {
+            for mention in extract_mentions(&text) {
+                prop_assert!(text.contains(&mention.acct));
+                prop_assert!(!mention.username.starts_with('-') && !mention.username.ends_with('-'));
+            }
+        }
+    }
+}
@@ -6,12 +6,20 @@ use once_cell::sync::OnceCell;
 use radix_fmt::radix_36;
 use std::cmp;
 
+use crate::error::{ErrorCode, HasErrorCode};
 use crate::impl_into_napi_error;
+use crate::metrics;
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
 #[error("ID generator has not been initialized yet")]
 pub struct ErrorUninitialized;
 
+impl HasErrorCode for ErrorUninitialized {
+    fn code(&self) -> ErrorCode {
+        ErrorCode::NotInitialized
+    }
+}
+
 impl_into_napi_error!(ErrorUninitialized);
 
 static FINGERPRINT: OnceCell<String> = OnceCell::new();
@@ -21,7 +29,7 @@ const TIME_2000: i64 = 946_684_800_000;
 const TIMESTAMP_LENGTH: u16 = 8;
 
 /// Initializes Cuid2 generator. Must be called before any [create_id].
-pub fn init_id<'a>(length: u16, fingerprint: &'a str) {
+pub fn init_id(length: u16, fingerprint: &str) {
     FINGERPRINT.get_or_init(move || format!("{}{}", fingerprint, cuid2::create_id()));
     GENERATOR.get_or_init(move || {
         cuid2::CuidConstructor::new()
@@ -44,6 +52,7 @@ pub fn create_id(date_num: i64) -> Result<String, ErrorUninitialized> {
                 Utc::now().timestamp_millis()
             };
             let time = cmp::max(date_num - TIME_2000, 0);
+            metrics::get_metrics().ids_generated.inc();
             Ok(format!(
                 "{:0>8}{}",
                 radix_36(time).to_string(),
@@ -1,2 +1,4 @@
+pub mod acct;
 pub mod id;
 pub mod random;
+pub(crate) mod scan;
@@ -0,0 +1,47 @@
+//! Helpers to scan MFM text for mentions and hashtags without parsing it.
+
+/// If a code block, inline code or URL starts at `i`, returns the position
+/// right after it. Mentions and hashtags in them are not recognized by MFM.
+pub(crate) fn skip_verbatim(chars: &[char], i: usize) -> Option<usize> {
+    if starts_with(chars, i, "```") {
+        return Some(find(chars, i + 3, "```").map_or(chars.len(), |end| end + 3));
+    }
+    if chars.get(i) == Some(&'`') {
+        let n = chars[i + 1..].iter().position(|&c| c == '`' || c == '\n')?;
+        return (chars[i + 1 + n] == '`').then_some(i + n + 2);
+    }
+    if starts_with(chars, i, "https://") || starts_with(chars, i, "http://") {
+        let len = chars[i..].iter().take_while(|c| !c.is_whitespace()).count();
+        return Some(i + len);
+    }
+    None
+}
+
+pub(crate) fn starts_with(chars: &[char], at: usize, pattern: &str) -> bool {
+    (at..)
+        .zip(pattern.chars())
+        .all(|(i, p)| chars.get(i) == Some(&p))
+}
+
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    (from..chars.len()).find(|&i| starts_with(chars, i, pattern))
+}
+
+#[cfg(test)]
+mod unit_test {
+    use pretty_assertions::assert_eq;
+
+    use super::skip_verbatim;
+
+    #[test]
+    fn skip() {
+        let chars: Vec<char> = "`a` ```b``` https://c.example d".chars().collect();
+        assert_eq!(skip_verbatim(&chars, 0), Some(3));
+        assert_eq!(skip_verbatim(&chars, 4), Some(11));
+        assert_eq!(skip_verbatim(&chars, 12), Some(29));
+        assert_eq!(skip_verbatim(&chars, 30), None);
+        // unclosed inline code
+        let chars: Vec<char> = "`a\nb`".chars().collect();
+        assert_eq!(skip_verbatim(&chars, 0), None);
+    }
+}
@@ -0,0 +1,321 @@
+//! Ports of `test/chart.ts`, with the charts of `services/chart/charts/test*.ts`.
+
+mod int_test {
+    use std::collections::HashMap;
+
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use native_utils::chart::charts::{TEST, TEST_GROUPED, TEST_INTERSECTION, TEST_UNIQUE};
+    use native_utils::chart::{Chart, Diff, Span};
+    use native_utils::database;
+    use pretty_assertions::assert_eq;
+    use sea_orm::ConnectionTrait;
+
+    use crate::{cleanup, prepare};
+
+    async fn create_tables() {
+        let db = &database::get_database().unwrap();
+        for schema in [&TEST, &TEST_GROUPED, &TEST_UNIQUE, &TEST_INTERSECTION] {
+            for span in Span::ALL {
+                let stmt = db.get_database_backend().build(&schema.create_table(span));
+                db.execute(stmt).await.unwrap();
+            }
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn counts(counts: &[(&str, i64)]) -> Diff {
+        Diff {
+            counts: counts.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn uniques(key: &str, item: &str) -> Diff {
+        Diff {
+            uniques: HashMap::from([(key.to_string(), [item.to_string()].into())]),
+            ..Default::default()
+        }
+    }
+
+    fn increment(chart: &Chart, group: Option<&str>, at: DateTime<Utc>) {
+        let diff = counts(&[("foo.total", 1), ("foo.inc", 1)]);
+        chart.commit(diff, group, at).unwrap();
+    }
+
+    fn decrement(chart: &Chart, at: DateTime<Utc>) {
+        let diff = counts(&[("foo.total", -1), ("foo.dec", 1)]);
+        chart.commit(diff, None, at).unwrap();
+    }
+
+    fn foo(total: [i64; 3], inc: [i64; 3], dec: [i64; 3]) -> HashMap<String, Vec<i64>> {
+        HashMap::from([
+            ("foo.total".to_string(), total.to_vec()),
+            ("foo.inc".to_string(), inc.to_vec()),
+            ("foo.dec".to_string(), dec.to_vec()),
+        ])
+    }
+
+    async fn range(
+        chart: &Chart,
+        span: Span,
+        cursor: DateTime<Utc>,
+        group: Option<&str>,
+    ) -> HashMap<String, Vec<i64>> {
+        chart.range(span, 3, cursor, group).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn update_charts() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST);
+        let now = start();
+
+        // empty
+        assert_eq!(
+            range(&chart, Span::Hour, now, None).await,
+            foo([0; 3], [0; 3], [0; 3])
+        );
+        assert_eq!(
+            range(&chart, Span::Day, now, None).await,
+            foo([0; 3], [0; 3], [0; 3])
+        );
+
+        // saved only once
+        increment(&chart, None, now);
+        increment(&chart, None, now);
+        decrement(&chart, now);
+        assert_eq!(chart.save().await, Ok(1));
+        assert_eq!(chart.save().await, Ok(0));
+        let expected = foo([1, 0, 0], [2, 0, 0], [1, 0, 0]);
+        assert_eq!(range(&chart, Span::Hour, now, None).await, expected);
+        assert_eq!(range(&chart, Span::Day, now, None).await, expected);
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn update_charts_at_different_times() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST);
+        let now = start();
+
+        increment(&chart, None, now);
+        chart.save().await.unwrap();
+        increment(&chart, None, now + Duration::hours(1));
+        chart.save().await.unwrap();
+        let now = now + Duration::hours(1);
+        assert_eq!(
+            range(&chart, Span::Hour, now, None).await,
+            foo([2, 1, 0], [1, 1, 0], [0; 3])
+        );
+        assert_eq!(
+            range(&chart, Span::Day, now, None).await,
+            foo([2, 0, 0], [2, 0, 0], [0; 3])
+        );
+
+        // the cursor points at the first hour
+        assert_eq!(
+            range(&chart, Span::Hour, start(), None).await,
+            foo([1, 0, 0], [1, 0, 0], [0; 3])
+        );
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn update_charts_at_different_times_without_save() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST);
+        let now = start();
+
+        increment(&chart, None, now);
+        increment(&chart, None, now + Duration::hours(1));
+        chart.save().await.unwrap();
+        let now = now + Duration::hours(1);
+        assert_eq!(
+            range(&chart, Span::Hour, now, None).await,
+            foo([2, 1, 0], [1, 1, 0], [0; 3])
+        );
+        assert_eq!(
+            range(&chart, Span::Day, now, None).await,
+            foo([2, 0, 0], [2, 0, 0], [0; 3])
+        );
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn pad_charts() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST);
+        let now = start();
+
+        increment(&chart, None, now);
+        chart.save().await.unwrap();
+
+        // no logs within the range
+        let later = now + Duration::hours(5);
+        assert_eq!(
+            range(&chart, Span::Hour, later, None).await,
+            foo([1, 1, 1], [0; 3], [0; 3])
+        );
+        assert_eq!(
+            range(&chart, Span::Day, later, None).await,
+            foo([1, 0, 0], [1, 0, 0], [0; 3])
+        );
+
+        // no log at the start of the range
+        increment(&chart, None, later);
+        chart.save().await.unwrap();
+        assert_eq!(
+            range(&chart, Span::Hour, later, None).await,
+            foo([2, 1, 1], [1, 0, 0], [0; 3])
+        );
+
+        increment(&chart, None, now + Duration::hours(7));
+        chart.save().await.unwrap();
+        assert_eq!(
+            range(&chart, Span::Hour, now + Duration::hours(7), None).await,
+            foo([3, 2, 2], [1, 0, 1], [0; 3])
+        );
+        assert_eq!(
+            range(&chart, Span::Day, now + Duration::hours(7), None).await,
+            foo([3, 0, 0], [3, 0, 0], [0; 3])
+        );
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn update_grouped_charts() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST_GROUPED);
+        let now = start();
+
+        increment(&chart, Some("alice"), now);
+        chart.save().await.unwrap();
+        for span in Span::ALL {
+            assert_eq!(
+                range(&chart, span, now, Some("alice")).await,
+                foo([1, 0, 0], [1, 0, 0], [0; 3])
+            );
+            assert_eq!(
+                range(&chart, span, now, Some("bob")).await,
+                foo([0; 3], [0; 3], [0; 3])
+            );
+        }
+        assert!(chart.commit(Diff::default(), None, now).is_err());
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn count_unique_items() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST_UNIQUE);
+        let now = start();
+
+        for user in ["alice", "alice", "bob"] {
+            chart.commit(uniques("foo", user), None, now).unwrap();
+        }
+        chart.save().await.unwrap();
+        for span in Span::ALL {
+            assert_eq!(
+                range(&chart, span, now, None).await,
+                HashMap::from([("foo".to_string(), vec![2, 0, 0])])
+            );
+        }
+
+        // merged with the saved items
+        for user in ["bob", "carol"] {
+            chart.commit(uniques("foo", user), None, now).unwrap();
+        }
+        chart.save().await.unwrap();
+        assert_eq!(
+            range(&chart, Span::Hour, now, None).await,
+            HashMap::from([("foo".to_string(), vec![3, 0, 0])])
+        );
+
+        // only the recent sketches are kept
+        assert_eq!(chart.clean(now).await, Ok(0));
+        assert_eq!(chart.clean(now + Duration::days(2)).await, Ok(2));
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn count_intersections() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST_INTERSECTION);
+        let now = start();
+        let intersection = |a: i64, b: i64, a_and_b: i64| {
+            HashMap::from([
+                ("a".to_string(), vec![a, 0, 0]),
+                ("b".to_string(), vec![b, 0, 0]),
+                ("aAndB".to_string(), vec![a_and_b, 0, 0]),
+            ])
+        };
+
+        chart.commit(uniques("a", "alice"), None, now).unwrap();
+        chart.commit(uniques("a", "bob"), None, now).unwrap();
+        chart.commit(uniques("b", "carol"), None, now).unwrap();
+        chart.save().await.unwrap();
+        for span in Span::ALL {
+            assert_eq!(range(&chart, span, now, None).await, intersection(2, 1, 0));
+        }
+
+        chart.commit(uniques("b", "alice"), None, now).unwrap();
+        chart.save().await.unwrap();
+        for span in Span::ALL {
+            assert_eq!(range(&chart, span, now, None).await, intersection(2, 2, 1));
+        }
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn resync_charts() {
+        prepare().await;
+        create_tables().await;
+        let chart = Chart::new(&TEST);
+        let now = start();
+        let total = |total: i64| HashMap::from([("foo.total".to_string(), total)]);
+
+        chart.tick(total(1), None, now).await.unwrap();
+        for span in Span::ALL {
+            assert_eq!(
+                range(&chart, span, now, None).await,
+                foo([1, 0, 0], [0; 3], [0; 3])
+            );
+        }
+
+        increment(&chart, None, now);
+        chart.save().await.unwrap();
+        let later = now + Duration::hours(1);
+        chart.tick(total(100), None, later).await.unwrap();
+        assert_eq!(
+            range(&chart, Span::Hour, later, None).await,
+            foo([100, 2, 0], [0, 1, 0], [0; 3])
+        );
+        assert_eq!(
+            range(&chart, Span::Day, later, None).await,
+            foo([100, 0, 0], [1, 0, 0], [0; 3])
+        );
+
+        assert!(chart.tick(total(1), None, later).await.is_ok());
+        let invalid = HashMap::from([("foo".to_string(), 1)]);
+        assert!(chart.tick(invalid, None, later).await.is_err());
+
+        cleanup().await;
+    }
+}
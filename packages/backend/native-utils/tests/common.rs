@@ -1,9 +1,17 @@
-#![cfg(not(feature = "napi"))]
+// SQLite has no array columns, so integration tests need the `noarray` feature.
+#![cfg(all(not(feature = "napi"), feature = "noarray"))]
 
+mod chart;
+mod database;
+mod drive;
+mod federation;
+mod hashtag;
+mod mfm;
 mod model;
+mod stats;
+mod timeline;
 
 use chrono::Utc;
-use native_utils::database;
 use native_utils::model::entity;
 use native_utils::model::entity::sea_orm_active_enums::AntennaSrcEnum;
 use native_utils::util::{
@@ -17,10 +25,11 @@ use sea_orm::{
 
 /// Insert predefined entries in the database.
 async fn prepare() {
-    database::init_database("sqlite::memory:")
+    native_utils::database::init_database("sqlite::memory:", &Default::default())
         .await
         .expect("Unable to initialize database connection");
-    let db = database::get_database().expect("Unable to get database connection from pool");
+    let db = &native_utils::database::get_database()
+        .expect("Unable to get database connection from pool");
     setup_schema(db).await;
     setup_model(db).await;
 }
@@ -41,6 +50,7 @@ async fn setup_schema(db: &DbConn) {
     create_table_statement!(
         abuse_user_report,
         access_token,
+        active_user_sketch,
         ad,
         announcement_read,
         announcement,
@@ -53,8 +63,10 @@ async fn setup_schema(db: &DbConn) {
         channel_following,
         channel_note_pining,
         channel,
+        chart_sketch,
         clip_note,
         clip,
+        drive_blob,
         drive_file,
         drive_folder,
         emoji,
@@ -64,6 +76,7 @@ async fn setup_schema(db: &DbConn) {
         gallery_post,
         hashtag,
         instance,
+        instance_delivery,
         messaging_message,
         meta,
         migrations,
@@ -122,7 +135,8 @@ async fn setup_schema(db: &DbConn) {
 
 /// Delete all entries in the database.
 async fn cleanup() {
-    let db = database::get_database().expect("Unable to get database connection from pool");
+    let db = &native_utils::database::get_database()
+        .expect("Unable to get database connection from pool");
     db.transaction::<_, (), DbErr>(|txn| {
         Box::pin(async move {
             entity::user::Entity::delete_many().exec(txn).await.unwrap();
@@ -0,0 +1,30 @@
+mod int_test {
+    use native_utils::database::{
+        self, check_database_health, close_database, error::Error, ping_database,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_orm::ConnectionTrait;
+
+    use crate::prepare;
+
+    #[tokio::test]
+    async fn can_close_and_reinit() {
+        prepare().await;
+        assert!(ping_database().await.is_ok());
+
+        let health = check_database_health().await.unwrap();
+        assert!(health.is_healthy());
+        assert!(health.primary.size >= 1);
+        assert!(health.replicas.is_empty());
+
+        let db = database::get_database().unwrap();
+        close_database().await;
+        assert_eq!(database::get_database().unwrap_err(), Error::Uninitialized);
+        // the old pool outlives the close while it is held, but refuses queries
+        assert!(db.execute_unprepared("SELECT 1").await.is_err());
+        assert_eq!(ping_database().await.unwrap_err(), Error::Uninitialized);
+
+        prepare().await;
+        assert!(ping_database().await.is_ok());
+    }
+}
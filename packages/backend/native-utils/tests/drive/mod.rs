@@ -0,0 +1,442 @@
+mod int_test {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use native_utils::database;
+    use native_utils::drive::error::Error;
+    use native_utils::drive::storage::{FileKind, InternalStorage, ObjectStorage, Storage};
+    use native_utils::drive::{blob, eviction, quota, DriveConfig};
+    use native_utils::model::entity::{drive_file, meta, user};
+    use native_utils::util::id::create_id;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use sea_orm::{ActiveModelTrait, EntityTrait, IntoActiveModel};
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    use crate::{cleanup, prepare};
+
+    type Objects = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+    /// Serves the objects like MinIO with path-style URLs, rejecting
+    /// requests that are not signed with the access key.
+    async fn serve_object_storage() -> (u16, Objects) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let objects = Objects::default();
+        let store = objects.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut stream = BufReader::new(stream);
+                let mut request_line = String::new();
+                stream.read_line(&mut request_line).await.unwrap();
+                let mut headers = HashMap::new();
+                loop {
+                    let mut line = String::new();
+                    stream.read_line(&mut line).await.unwrap();
+                    match line.trim_end().split_once(": ") {
+                        Some((k, v)) => headers.insert(k.to_lowercase(), v.to_string()),
+                        None => break,
+                    };
+                }
+                let length = headers
+                    .get("content-length")
+                    .map_or(0, |l| l.parse().unwrap());
+                let mut body = vec![0; length];
+                stream.read_exact(&mut body).await.unwrap();
+
+                let mut parts = request_line.split(' ');
+                let (method, path) = (parts.next().unwrap(), parts.next().unwrap());
+                let signed = headers
+                    .get("authorization")
+                    .is_some_and(|a| a.starts_with("AWS4-HMAC-SHA256 Credential=minio/"));
+                let (status, body) = match (signed, method) {
+                    (false, _) => ("403 Forbidden", b"AccessDenied".to_vec()),
+                    (true, "PUT") => {
+                        store.lock().unwrap().insert(path.to_string(), body);
+                        ("200 OK", Vec::new())
+                    }
+                    (true, "GET") => match store.lock().unwrap().get(path) {
+                        Some(data) => ("200 OK", data.clone()),
+                        None => ("404 Not Found", b"NoSuchKey".to_vec()),
+                    },
+                    (true, "DELETE") => {
+                        store.lock().unwrap().remove(path);
+                        ("204 No Content", Vec::new())
+                    }
+                    _ => ("405 Method Not Allowed", Vec::new()),
+                };
+
+                let stream = stream.get_mut();
+                let head = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                stream.write_all(head.as_bytes()).await.unwrap();
+                stream.write_all(&body).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+
+        (port, objects)
+    }
+
+    #[tokio::test]
+    async fn put_get_and_delete_objects() {
+        let (port, objects) = serve_object_storage().await;
+        let storage = ObjectStorage::from_meta(
+            &meta::Model {
+                use_object_storage: true,
+                object_storage_bucket: Some("misskey".to_string()),
+                object_storage_prefix: Some("files".to_string()),
+                object_storage_endpoint: Some("127.0.0.1".to_string()),
+                object_storage_port: Some(port.into()),
+                object_storage_access_key: Some("minio".to_string()),
+                object_storage_secret_key: Some("minio123".to_string()),
+                object_storage_s3_force_path_style: true,
+                ..Default::default()
+            },
+            &DriveConfig::default(),
+        )
+        .unwrap();
+
+        let key = storage.generate_key(FileKind::Original, Some("png"));
+        let url = storage
+            .put(&key, b"image".to_vec(), "image/png", Some("image.png"))
+            .await
+            .unwrap();
+        assert_eq!(url, format!("http://127.0.0.1:{}/misskey/{}", port, key));
+        assert!(objects
+            .lock()
+            .unwrap()
+            .contains_key(&format!("/misskey/{}", key)));
+        assert_eq!(storage.get(&key).await.unwrap(), b"image");
+
+        let presigned = storage.presign(&key, Duration::from_secs(60)).unwrap();
+        assert!(presigned.contains("X-Amz-Signature="));
+
+        storage.delete(&key).await.unwrap();
+        assert!(objects.lock().unwrap().is_empty());
+        assert!(matches!(
+            storage.get(&key).await,
+            Err(Error::ObjectStorageError { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn use_configured_proxy() {
+        // the object storage serves the requests as a forward proxy too
+        let (port, objects) = serve_object_storage().await;
+        let meta = meta::Model {
+            use_object_storage: true,
+            object_storage_bucket: Some("misskey".to_string()),
+            object_storage_endpoint: Some("storage.invalid".to_string()),
+            object_storage_access_key: Some("minio".to_string()),
+            object_storage_secret_key: Some("minio123".to_string()),
+            object_storage_s3_force_path_style: true,
+            object_storage_use_proxy: true,
+            ..Default::default()
+        };
+        let config = DriveConfig {
+            proxy: Some(format!("http://127.0.0.1:{}", port)),
+            ..Default::default()
+        };
+
+        let storage = ObjectStorage::from_meta(&meta, &config).unwrap();
+        storage
+            .put("a.png", b"image".to_vec(), "image/png", None)
+            .await
+            .unwrap();
+        assert!(objects
+            .lock()
+            .unwrap()
+            .contains_key("http://storage.invalid/misskey/a.png"));
+
+        let bypassed = DriveConfig {
+            proxy_bypass_hosts: vec!["storage.invalid".to_string()],
+            ..config.clone()
+        };
+        let storage = ObjectStorage::from_meta(&meta, &bypassed).unwrap();
+        assert!(storage.get("a.png").await.is_err());
+
+        let meta = meta::Model {
+            object_storage_use_proxy: false,
+            ..meta
+        };
+        let storage = ObjectStorage::from_meta(&meta, &config).unwrap();
+        assert!(storage.get("a.png").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn compute_usage_and_files_to_expire() {
+        prepare().await;
+        let db = &database::get_database().unwrap();
+        let user = user::Model {
+            id: create_id(0).unwrap(),
+            created_at: Utc::now().into(),
+            username: "bob".to_string(),
+            username_lower: "bob".to_string(),
+            host: Some("example.com".to_string()),
+            ..Default::default()
+        };
+        user.to_owned()
+            .into_active_model()
+            .reset_all()
+            .insert(db)
+            .await
+            .unwrap();
+        let mut file_ids = Vec::new();
+        for (size, is_link) in [(100, false), (200, false), (400, true), (300, false)] {
+            let file = drive_file::Model {
+                id: create_id(0).unwrap(),
+                created_at: Utc::now().into(),
+                user_id: Some(user.id.to_owned()),
+                user_host: user.host.to_owned(),
+                size,
+                is_link,
+                properties: serde_json::json!({}),
+                ..Default::default()
+            };
+            file_ids.push(file.id.to_owned());
+            file.into_active_model()
+                .reset_all()
+                .insert(db)
+                .await
+                .unwrap();
+        }
+        // the banner is only read from the model
+        let user = user::Model {
+            banner_id: Some(file_ids[0].to_owned()),
+            ..user
+        };
+
+        assert_eq!(quota::compute_usage(&user.id).await.unwrap(), 600);
+        // the usage is not cached without the cache
+        quota::update_usage(&user.id, 50).await.unwrap();
+        assert_eq!(quota::get_usage(&user.id).await.unwrap(), 600);
+        // the banner is kept even though it is the oldest
+        assert_eq!(
+            quota::files_to_expire(&user, 300).await.unwrap(),
+            vec![file_ids[1].to_owned()]
+        );
+        assert_eq!(
+            quota::files_to_expire(&user, 1000).await.unwrap(),
+            Vec::<String>::new()
+        );
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn share_and_release_blobs() {
+        prepare().await;
+        let db = &database::get_database().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let internal = InternalStorage::new(&DriveConfig {
+            files_dir: dir.path().to_owned(),
+            url: "https://example.com".to_string(),
+            ..Default::default()
+        });
+        let file = drive_file::Model {
+            id: create_id(0).unwrap(),
+            created_at: Utc::now().into(),
+            md5: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+            size: 100,
+            stored_internal: false,
+            url: "https://s3.example.com/original".to_string(),
+            access_key: Some("original".to_string()),
+            thumbnail_access_key: Some("thumbnail-original".to_string()),
+            properties: serde_json::json!({}),
+            ..Default::default()
+        };
+        file.to_owned()
+            .into_active_model()
+            .reset_all()
+            .insert(db)
+            .await
+            .unwrap();
+
+        let acquire = |size, generate_web| blob::acquire(&internal, &file.md5, size, generate_web);
+        assert_eq!(acquire(200, true).await.unwrap(), None);
+        let shared = acquire(100, true).await.unwrap().unwrap();
+        assert_eq!(shared.access_key, "original");
+        assert_eq!(shared.url, file.url);
+        assert!(!shared.stored_internal);
+        acquire(100, true).await.unwrap().unwrap();
+
+        assert!(!blob::release("original").await.unwrap());
+        assert!(!blob::release("original").await.unwrap());
+        assert!(blob::release("original").await.unwrap());
+        // the objects are being deleted while the file remains
+        assert_eq!(acquire(100, true).await.unwrap(), None);
+        assert_eq!(blob::prune().await.unwrap(), 0);
+
+        // files stored before the counting are referenced only by themselves
+        assert!(blob::release("unknown").await.unwrap());
+        drive_file::Entity::delete_by_id(file.id.to_owned())
+            .exec(db)
+            .await
+            .unwrap();
+        assert_eq!(blob::prune().await.unwrap(), 2);
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn link_internal_blobs() {
+        prepare().await;
+        let db = &database::get_database().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let internal = InternalStorage::new(&DriveConfig {
+            files_dir: dir.path().to_owned(),
+            url: "https://example.com".to_string(),
+            ..Default::default()
+        });
+        internal
+            .put("original", b"original".to_vec(), "image/png", None)
+            .await
+            .unwrap();
+        internal
+            .put(
+                "thumbnail-original",
+                b"thumbnail".to_vec(),
+                "image/webp",
+                None,
+            )
+            .await
+            .unwrap();
+        // a remote file, which has no webpublic image
+        let file = drive_file::Model {
+            id: create_id(0).unwrap(),
+            created_at: Utc::now().into(),
+            md5: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+            size: 8,
+            stored_internal: true,
+            url: internal.url("original"),
+            thumbnail_url: Some(internal.url("thumbnail-original")),
+            access_key: Some("original".to_string()),
+            thumbnail_access_key: Some("thumbnail-original".to_string()),
+            uri: Some("https://remote.example/files/original.png".to_string()),
+            properties: serde_json::json!({}),
+            ..Default::default()
+        };
+        file.to_owned()
+            .into_active_model()
+            .reset_all()
+            .insert(db)
+            .await
+            .unwrap();
+
+        // local files need the webpublic image without the metadata
+        assert_eq!(
+            blob::acquire(&internal, &file.md5, 8, true).await.unwrap(),
+            None
+        );
+        let shared = blob::acquire(&internal, &file.md5, 8, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(shared.stored_internal);
+        assert_ne!(shared.access_key, "original");
+        assert_eq!(shared.url, internal.url(&shared.access_key));
+        assert_eq!(shared.webpublic_access_key, None);
+        let thumbnail_key = shared.thumbnail_access_key.unwrap();
+        assert!(thumbnail_key.starts_with("thumbnail-"));
+        assert_eq!(internal.get(&thumbnail_key).await.unwrap(), b"thumbnail");
+
+        // the links are kept when the objects of the file are deleted
+        assert!(blob::release("original").await.unwrap());
+        internal.delete("original").await.unwrap();
+        assert_eq!(internal.get(&shared.access_key).await.unwrap(), b"original");
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn evict_cached_remote_files() {
+        prepare().await;
+        let db = &database::get_database().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let storage = InternalStorage::new(&DriveConfig {
+            files_dir: dir.path().to_path_buf(),
+            url: "https://example.com".to_string(),
+            ..Default::default()
+        });
+        let user = user::Model {
+            id: create_id(0).unwrap(),
+            created_at: Utc::now().into(),
+            username: "carol".to_string(),
+            username_lower: "carol".to_string(),
+            host: Some("example.net".to_string()),
+            ..Default::default()
+        };
+        user.to_owned()
+            .into_active_model()
+            .reset_all()
+            .insert(db)
+            .await
+            .unwrap();
+        let file = drive_file::Model {
+            id: create_id(0).unwrap(),
+            created_at: Utc::now().into(),
+            user_id: Some(user.id.to_owned()),
+            user_host: user.host.to_owned(),
+            size: 4,
+            stored_internal: true,
+            url: "https://example.com/files/cached".to_string(),
+            thumbnail_url: Some("https://example.com/files/thumbnail-cached".to_string()),
+            access_key: Some("cached".to_string()),
+            thumbnail_access_key: Some("thumbnail-cached".to_string()),
+            webpublic_access_key: Some("webpublic-cached".to_string()),
+            uri: Some("https://example.net/files/image.png".to_string()),
+            properties: serde_json::json!({}),
+            ..Default::default()
+        };
+        file.to_owned()
+            .into_active_model()
+            .reset_all()
+            .insert(db)
+            .await
+            .unwrap();
+        for key in ["cached", "thumbnail-cached"] {
+            storage
+                .put(key, b"data".to_vec(), "image/png", None)
+                .await
+                .unwrap();
+        }
+
+        let cached = eviction::cached_files(None).await.unwrap();
+        assert_eq!(
+            eviction::cached_files(Some(&file.id)).await.unwrap(),
+            vec![]
+        );
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].id, file.id);
+        assert_eq!(cached[0].last_used, file.created_at.timestamp_millis());
+
+        let evicted = eviction::evict_file(&storage, file.to_owned())
+            .await
+            .unwrap();
+        assert_eq!(evicted.size, 4);
+        assert_eq!(evicted.user_host, user.host);
+        assert!(storage.get("cached").await.is_err());
+        assert!(storage.get("thumbnail-cached").await.is_err());
+        let evicted = drive_file::Entity::find_by_id(file.id.to_owned())
+            .one(db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(evicted.is_link);
+        assert!(!evicted.stored_internal);
+        assert_eq!(Some(evicted.url), file.uri);
+        assert_eq!(evicted.thumbnail_url, None);
+        assert_ne!(evicted.access_key, file.access_key);
+        assert_eq!(eviction::cached_files(None).await.unwrap(), vec![]);
+
+        cleanup().await;
+    }
+}
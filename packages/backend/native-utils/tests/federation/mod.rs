@@ -0,0 +1,178 @@
+mod int_test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use chrono::{Duration, TimeZone, Utc};
+    use native_utils::database;
+    use native_utils::federation::instance_info;
+    use native_utils::model::entity::instance;
+    use native_utils::util::id::create_id;
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    use crate::{cleanup, prepare};
+
+    /// Serves `routes` by the paths, with 404 for the others.
+    async fn serve(routes: impl FnOnce(&str) -> HashMap<&'static str, String>) -> Url {
+        instance_info::init_client("Firefish/test", None, &[]).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let routes = Arc::new(routes(&base));
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let routes = routes.clone();
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    let mut request_line = String::new();
+                    stream.read_line(&mut request_line).await.unwrap();
+                    loop {
+                        let mut line = String::new();
+                        stream.read_line(&mut line).await.unwrap();
+                        if line.trim_end().is_empty() {
+                            break;
+                        }
+                    }
+
+                    let path = request_line.split(' ').nth(1).unwrap_or_default();
+                    let (status, body) = match routes.get(path) {
+                        Some(body) => ("200 OK", body.as_str()),
+                        None => ("404 Not Found", ""),
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    stream.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+        Url::parse(&base).unwrap()
+    }
+
+    async fn insert_instance(host: &str) {
+        instance::Model {
+            id: create_id(0).unwrap(),
+            caught_at: Utc::now().into(),
+            host: host.to_string(),
+            last_communicated_at: Utc::now().into(),
+            name: Some("Old name".to_string()),
+            maintainer_email: Some("old@example.com".to_string()),
+            ..Default::default()
+        }
+        .into_active_model()
+        .reset_all()
+        .insert(&database::get_database().unwrap())
+        .await
+        .unwrap();
+    }
+
+    async fn find_instance(host: &str) -> instance::Model {
+        instance::Entity::find()
+            .filter(instance::Column::Host.eq(host))
+            .one(&database::get_database().unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn update_instance_info() {
+        prepare().await;
+        let base = serve(|base| {
+            HashMap::from([
+                (
+                    "/.well-known/nodeinfo",
+                    format!(
+                        r#"{{"links":[{{"rel":"http://nodeinfo.diaspora.software/ns/schema/2.0","href":"{}/nodeinfo/2.0"}}]}}"#,
+                        base
+                    ),
+                ),
+                (
+                    "/nodeinfo/2.0",
+                    r#"{"software":{"name":"Firefish","version":"1.0.4"},"openRegistrations":true,"metadata":{"nodeName":"Example","maintainer":{"name":"Admin"}}}"#.to_string(),
+                ),
+                (
+                    "/",
+                    r##"<html><head><meta name="theme-color" content="#31748F"><link rel="icon" href="/favicon.png"><link rel="manifest" href="/app.webmanifest"></head></html>"##.to_string(),
+                ),
+                (
+                    "/app.webmanifest",
+                    r#"{"name":"Ignored","icons":[{"src":"/icon-192.png"}]}"#.to_string(),
+                ),
+            ])
+        })
+        .await;
+        insert_instance("example.com").await;
+        let now = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+
+        let updated = instance_info::update_from("example.com", &base, false, now).await;
+        assert!(updated.unwrap());
+        let found = find_instance("example.com").await;
+        assert_eq!(found.software_name.as_deref(), Some("firefish"));
+        assert_eq!(found.software_version.as_deref(), Some("1.0.4"));
+        assert_eq!(found.open_registrations, Some(true));
+        assert_eq!(found.name.as_deref(), Some("Example"));
+        assert_eq!(found.maintainer_name.as_deref(), Some("Admin"));
+        // cleared as NodeInfo was fetched without it
+        assert_eq!(found.maintainer_email, None);
+        assert_eq!(found.theme_color.as_deref(), Some("#31748f"));
+        assert_eq!(
+            found.icon_url,
+            Some(base.join("/icon-192.png").unwrap().into())
+        );
+        assert_eq!(
+            found.favicon_url,
+            Some(base.join("/favicon.png").unwrap().into())
+        );
+        assert_eq!(found.info_updated_at, Some(now.into()));
+
+        // throttled within a day unless forced
+        let later = now + Duration::hours(1);
+        let updated = instance_info::update_from("example.com", &base, false, later).await;
+        assert!(!updated.unwrap());
+        let updated = instance_info::update_from("example.com", &base, true, later).await;
+        assert!(updated.unwrap());
+        assert_eq!(
+            find_instance("example.com").await.info_updated_at,
+            Some(later.into())
+        );
+
+        assert!(
+            instance_info::update_from("unknown.example", &base, true, later)
+                .await
+                .is_err()
+        );
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn keep_instance_info_not_found() {
+        prepare().await;
+        let base = serve(|_| HashMap::from([("/favicon.ico", String::new())])).await;
+        insert_instance("example.com").await;
+        let now = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+
+        let updated = instance_info::update_from("example.com", &base, false, now).await;
+        assert!(updated.unwrap());
+        let found = find_instance("example.com").await;
+        assert_eq!(found.name.as_deref(), Some("Old name"));
+        assert_eq!(found.maintainer_email.as_deref(), Some("old@example.com"));
+        assert_eq!(found.software_name, None);
+        assert_eq!(
+            found.favicon_url,
+            Some(base.join("/favicon.ico").unwrap().into())
+        );
+        assert_eq!(found.icon_url, found.favicon_url);
+        assert_eq!(found.info_updated_at, Some(now.into()));
+
+        cleanup().await;
+    }
+}
@@ -0,0 +1,116 @@
+mod int_test {
+    use chrono::{Duration, Utc};
+    use native_utils::hashtag::{
+        hashtag_trends, update_hashtags, update_usertags, HashtagUser, TrendConfig,
+    };
+    use native_utils::model::entity::sea_orm_active_enums::NoteVisibilityEnum;
+    use native_utils::model::entity::{note, user};
+    use native_utils::util::id::create_id;
+    use native_utils::{database, model::entity::hashtag};
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter};
+
+    use crate::{cleanup, prepare};
+
+    #[tokio::test]
+    async fn can_update_hashtags() {
+        prepare().await;
+        let db = &database::get_database().unwrap();
+
+        let local = HashtagUser {
+            id: "9fil64s6g7cskdrb".to_string(),
+            host: None,
+        };
+        let remote = HashtagUser {
+            id: "9fil66brl1udxau2".to_string(),
+            host: Some("example.com".to_string()),
+        };
+        let tags = vec!["Firefish".to_string(), "ＦＩＲＥＦＩＳＨ".to_string()];
+        update_hashtags(&local, &tags).await.unwrap();
+        update_hashtags(&remote, &tags).await.unwrap();
+        update_usertags(&local, &tags, &[]).await.unwrap();
+        update_usertags(&local, &[], &tags).await.unwrap();
+
+        let models = hashtag::Entity::find()
+            .filter(hashtag::Column::Name.eq("firefish"))
+            .all(db)
+            .await
+            .unwrap();
+        assert_eq!(models.len(), 1);
+        let model = &models[0];
+        assert_eq!(model.mentioned_users_count, 2);
+        assert_eq!(model.mentioned_local_users_count, 1);
+        assert_eq!(model.mentioned_remote_users_count, 1);
+        assert_eq!(model.attached_users_count, 0);
+        assert!(model.attached_local_user_ids.is_empty());
+
+        hashtag::Entity::delete_many().exec(db).await.unwrap();
+        cleanup().await;
+    }
+
+    async fn post(user_id: &str, tags: &[&str], visibility: NoteVisibilityEnum, minutes_ago: i64) {
+        note::Model {
+            id: create_id(0).unwrap(),
+            created_at: (Utc::now() - Duration::minutes(minutes_ago)).into(),
+            user_id: user_id.to_string(),
+            visibility,
+            tags: tags
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .into(),
+            ..Default::default()
+        }
+        .into_active_model()
+        .reset_all()
+        .insert(&database::get_database().unwrap())
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn can_compute_trends() {
+        prepare().await;
+        let db = &database::get_database().unwrap();
+
+        let mut user_ids = vec![];
+        for name in ["trend1", "trend2", "trend3"] {
+            let id = create_id(0).unwrap();
+            user::Model {
+                id: id.to_owned(),
+                created_at: Utc::now().into(),
+                username: name.to_string(),
+                username_lower: name.to_string(),
+                ..Default::default()
+            }
+            .into_active_model()
+            .reset_all()
+            .insert(db)
+            .await
+            .unwrap();
+            user_ids.push(id);
+        }
+
+        let public = NoteVisibilityEnum::Public;
+        post(&user_ids[0], &["firefish", "rust"], public.to_owned(), 1).await;
+        post(&user_ids[0], &["firefish"], public.to_owned(), 15).await;
+        post(&user_ids[1], &["firefish", "hidden"], public.to_owned(), 2).await;
+        post(&user_ids[1], &["rust"], NoteVisibilityEnum::Home, 45).await;
+        post(&user_ids[2], &["secret"], NoteVisibilityEnum::Followers, 1).await;
+        post(&user_ids[2], &["old"], public, 100).await;
+
+        let trends = hashtag_trends(&["hidden".to_string()], &TrendConfig::default())
+            .await
+            .unwrap();
+        let tags: Vec<(&str, u32)> = trends
+            .iter()
+            .map(|t| (t.tag.as_str(), t.users_count))
+            .collect();
+        assert_eq!(tags, vec![("firefish", 2), ("rust", 2)]);
+        assert_eq!(&trends[0].chart[..3], &[2, 1, 0]);
+        assert_eq!(&trends[1].chart[..5], &[1, 0, 0, 0, 1]);
+
+        note::Entity::delete_many().exec(db).await.unwrap();
+        cleanup().await;
+    }
+}
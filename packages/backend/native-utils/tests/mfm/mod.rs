@@ -0,0 +1,52 @@
+//! Golden tests against the output of `mfm-js` 0.23 and `mfm/to-html.ts`.
+
+mod int_test {
+    use native_utils::mfm::{parse, to_html, to_json, MentionedRemoteUser};
+    use pretty_assertions::assert_eq;
+    use serde::Deserialize;
+    use serde_json::Value;
+
+    const BASE_URL: &str = "https://local.example";
+
+    #[derive(Deserialize)]
+    struct ParseCase {
+        input: String,
+        output: Value,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ToHtmlCase {
+        input: String,
+        mentioned_remote_users: Vec<MentionedRemoteUser>,
+        html: String,
+    }
+
+    #[test]
+    fn parse_matches_mfm_js() {
+        let cases: Vec<ParseCase> =
+            serde_json::from_str(include_str!("fixtures/parse.json")).unwrap();
+        for case in cases {
+            assert_eq!(
+                to_json(&parse(&case.input)),
+                case.output,
+                "{:?}",
+                case.input
+            );
+        }
+    }
+
+    #[test]
+    fn to_html_matches_typescript() {
+        let cases: Vec<ToHtmlCase> =
+            serde_json::from_str(include_str!("fixtures/to_html.json")).unwrap();
+        for case in cases {
+            assert_eq!(
+                to_html(&parse(&case.input), BASE_URL, &case.mentioned_remote_users),
+                case.html,
+                "{:?}",
+                case.input
+            );
+        }
+    }
+}
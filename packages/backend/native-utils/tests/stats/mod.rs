@@ -0,0 +1,159 @@
+mod int_test {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use native_utils::database;
+    use native_utils::model::entity::instance;
+    use native_utils::stats::{self, active_users, delivery, ActiveUsers, Deliveries};
+    use native_utils::util::id::create_id;
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, IntoActiveModel};
+
+    use crate::{cleanup, prepare};
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2000, 1, 31, 12, 0, 0).unwrap()
+    }
+
+    async fn insert_instance(host: &str, sent: Option<DateTime<Utc>>, status: Option<i32>) {
+        instance::Model {
+            id: create_id(0).unwrap(),
+            caught_at: start().into(),
+            host: host.to_string(),
+            last_communicated_at: start().into(),
+            latest_request_sent_at: sent.map(Into::into),
+            latest_status: status,
+            is_not_responding: status.is_some_and(|s| s >= 500),
+            ..Default::default()
+        }
+        .into_active_model()
+        .reset_all()
+        .insert(&database::get_database().unwrap())
+        .await
+        .unwrap();
+    }
+
+    fn active(daily: u64, weekly: u64, monthly: u64) -> ActiveUsers {
+        ActiveUsers {
+            daily,
+            weekly,
+            monthly,
+        }
+    }
+
+    #[tokio::test]
+    async fn count_active_users() {
+        prepare().await;
+        let now = start();
+
+        active_users::record("dave", now - Duration::days(20));
+        active_users::save(now - Duration::days(20)).await.unwrap();
+        active_users::record("carol", now - Duration::days(3));
+        active_users::save(now - Duration::days(3)).await.unwrap();
+        active_users::record("bob", now);
+        active_users::record("eve", now - Duration::hours(1));
+        active_users::record("bob", now);
+
+        assert_eq!(active_users::save(now).await, Ok(1));
+        assert_eq!(active_users::count(now).await, Ok(active(2, 3, 4)));
+        assert_eq!(active_users::save(now).await, Ok(0));
+        assert_eq!(active_users::count(now).await, Ok(active(2, 3, 4)));
+
+        // merged into the saved sketch of the day
+        active_users::record("dave", now);
+        active_users::record("eve", now);
+        active_users::save(now).await.unwrap();
+        assert_eq!(active_users::count(now).await, Ok(active(3, 4, 4)));
+
+        let tomorrow = now + Duration::days(1);
+        assert_eq!(active_users::count(tomorrow).await, Ok(active(0, 4, 4)));
+
+        let later = now + Duration::days(active_users::RETENTION_DAYS);
+        active_users::save(later).await.unwrap();
+        assert_eq!(active_users::count(later).await, Ok(active(0, 0, 0)));
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn save_active_users_concurrently() {
+        prepare().await;
+        let now = start();
+
+        active_users::record("alice", now);
+        active_users::save(now).await.unwrap();
+
+        // as the workers of a cluster saving at the same time
+        let save = |user: &'static str| async move {
+            active_users::record(user, now);
+            active_users::save(now).await
+        };
+        let (bob, carol) = tokio::join!(save("bob"), save("carol"));
+        assert_eq!(bob, Ok(1));
+        assert_eq!(carol, Ok(1));
+        assert_eq!(active_users::count(now).await, Ok(active(3, 3, 3)));
+
+        cleanup().await;
+    }
+
+    #[tokio::test]
+    async fn count_deliveries() {
+        prepare().await;
+        let now = start();
+
+        insert_instance("a.example", Some(now), Some(410)).await;
+        insert_instance("b.example", Some(now), Some(503)).await;
+        insert_instance("c.example", Some(now - Duration::hours(1)), None).await;
+        insert_instance("d.example", None, None).await;
+        insert_instance("e.example", Some(now - Duration::days(3)), Some(200)).await;
+
+        delivery::record("a.example", true, now);
+        delivery::record("b.example", false, now);
+        delivery::record("c.example", false, now - Duration::hours(1));
+        delivery::record("a.example", false, now + Duration::minutes(5));
+        delivery::record("e.example", true, now - Duration::days(3));
+
+        assert_eq!(delivery::save(now).await, Ok(4));
+        assert_eq!(delivery::save(now).await, Ok(0));
+        assert_eq!(
+            delivery::total(now, 7).await,
+            Ok(Deliveries {
+                succeeded: 2,
+                failed: 3,
+            })
+        );
+
+        // added to the saved counts
+        delivery::record("a.example", true, now);
+        assert_eq!(delivery::save(now).await, Ok(1));
+        assert_eq!(
+            delivery::total(now, 7).await,
+            Ok(Deliveries {
+                succeeded: 3,
+                failed: 3,
+            })
+        );
+        assert_eq!(
+            delivery::total(now + Duration::days(7), 7).await,
+            Ok(Deliveries::default())
+        );
+
+        let summary = stats::summary(now).await.unwrap();
+        assert_eq!(summary.instances, 5);
+        assert_eq!(summary.not_responding_instances, 1);
+        let failing: Vec<_> = summary
+            .failing_instances
+            .iter()
+            .map(|i| (i.host.as_str(), i.deliveries.failed, i.latest_status))
+            .collect();
+        assert_eq!(
+            failing,
+            vec![
+                ("a.example", 1, Some(410)),
+                ("b.example", 1, Some(503)),
+                ("c.example", 1, None),
+            ]
+        );
+        assert!(summary.failing_instances[1].is_not_responding);
+
+        cleanup().await;
+    }
+}
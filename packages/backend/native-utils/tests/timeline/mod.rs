@@ -0,0 +1,99 @@
+mod int_test {
+    use chrono::Utc;
+    use native_utils::database;
+    use native_utils::model::entity::sea_orm_active_enums::NoteVisibilityEnum;
+    use native_utils::model::entity::{following, note, user};
+    use native_utils::timeline::reader::{read_home_timeline_from_db, Pagination};
+    use native_utils::util::id::create_id;
+    use pretty_assertions::assert_eq;
+    use sea_orm::{ActiveModelTrait, IntoActiveModel};
+    use std::time::Duration;
+
+    use crate::{cleanup, prepare};
+
+    async fn insert_user(name: &str) -> String {
+        let id = create_id(0).unwrap();
+        user::Model {
+            id: id.to_owned(),
+            created_at: Utc::now().into(),
+            username: name.to_string(),
+            username_lower: name.to_string(),
+            ..Default::default()
+        }
+        .into_active_model()
+        .reset_all()
+        .insert(&database::get_database().unwrap())
+        .await
+        .unwrap();
+        id
+    }
+
+    async fn insert_note(
+        user_id: &str,
+        visibility: NoteVisibilityEnum,
+        visible_user_ids: &[&str],
+    ) -> String {
+        // IDs created within the same millisecond are not ordered.
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        let id = create_id(0).unwrap();
+        note::Model {
+            id: id.to_owned(),
+            created_at: Utc::now().into(),
+            user_id: user_id.to_string(),
+            visibility,
+            visible_user_ids: visible_user_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .into(),
+            ..Default::default()
+        }
+        .into_active_model()
+        .reset_all()
+        .insert(&database::get_database().unwrap())
+        .await
+        .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn read_home_timeline_from_db_like_fan_out() {
+        prepare().await;
+        let alice = insert_user("alice2").await;
+        let bob = insert_user("bob").await;
+        let carol = insert_user("carol").await;
+        following::Model {
+            id: create_id(0).unwrap(),
+            created_at: Utc::now().into(),
+            followee_id: bob.to_owned(),
+            follower_id: alice.to_owned(),
+            ..Default::default()
+        }
+        .into_active_model()
+        .reset_all()
+        .insert(&database::get_database().unwrap())
+        .await
+        .unwrap();
+
+        let public = insert_note(&bob, NoteVisibilityEnum::Public, &[]).await;
+        let followers = insert_note(&bob, NoteVisibilityEnum::Followers, &[]).await;
+        let to_alice = insert_note(&bob, NoteVisibilityEnum::Specified, &[&carol, &alice]).await;
+        insert_note(&bob, NoteVisibilityEnum::Specified, &[&carol]).await;
+        insert_note(&bob, NoteVisibilityEnum::Hidden, &[]).await;
+        insert_note(&carol, NoteVisibilityEnum::Public, &[]).await;
+        let own = insert_note(&alice, NoteVisibilityEnum::Specified, &[&bob]).await;
+
+        let pagination = Pagination {
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            read_home_timeline_from_db(&alice, &pagination)
+                .await
+                .unwrap(),
+            vec![own, to_alice, followers, public]
+        );
+
+        cleanup().await;
+    }
+}
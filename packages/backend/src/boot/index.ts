@@ -3,6 +3,7 @@ import chalk from "chalk";
 import Xev from "xev";
 
 import Logger from "@/services/logger.js";
+import { initNativeLogger } from "@/services/native-logger.js";
 import { envOption } from "../env.js";
 
 // for typeorm
@@ -21,6 +22,8 @@ const ev = new Xev();
 export default async function () {
 	process.title = `Firefish (${cluster.isPrimary ? "master" : "worker"})`;
 
+	initNativeLogger();
+
 	if (cluster.isPrimary || envOption.disableClustering) {
 		await masterMain();
 		if (cluster.isPrimary) {
@@ -14,6 +14,7 @@ import { lessThan } from "@/prelude/array.js";
 import { envOption } from "../env.js";
 import { showMachineInfo } from "@/misc/show-machine-info.js";
 import { db, initDb } from "../db/postgre.js";
+import { nativePingDatabase } from "native-utils/built/index.js";
 
 const _filename = fileURLToPath(import.meta.url);
 const _dirname = dirname(_filename);
@@ -161,6 +162,8 @@ async function connectDb(): Promise<void> {
 			.query("SHOW server_version")
 			.then((x) => x[0].server_version);
 		dbLogger.succ(`Connected: v${v}`);
+		const latency = await nativePingDatabase();
+		dbLogger.succ(`Native connection: ${latency.toFixed(1)}ms`);
 	} catch (e) {
 		dbLogger.error("Cannot connect", null, true);
 		dbLogger.error(e);
@@ -14,8 +14,16 @@ export type Source = {
 		user: string;
 		pass: string;
 		disableCache?: boolean;
-		extra?: { [x: string]: string };
+		extra?: { [x: string]: any };
 	};
+	dbReplications?: boolean;
+	dbSlaves?: {
+		host: string;
+		port: number;
+		db: string;
+		user: string;
+		pass: string;
+	}[];
 	redis: {
 		host: string;
 		port: number;
@@ -91,6 +99,11 @@ export type Source = {
 		port: number;
 	};
 
+	metrics?: {
+		enable?: boolean;
+		bearerToken?: string;
+	};
+
 	mediaProxy?: string;
 	proxyRemoteFiles?: boolean;
 
@@ -107,6 +120,9 @@ export type Source = {
 	isManagedHosting?: boolean;
 	maxNoteLength?: number;
 	maxCaptionLength?: number;
+	maxImagePixels?: number;
+	sensitiveMediaModel?: string;
+	searchIndex?: string;
 	deepl: {
 		managed?: boolean;
 		authKey?: string;
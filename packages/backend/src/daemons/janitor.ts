@@ -3,12 +3,24 @@
 const interval = 30 * 60 * 1000;
 import { AttestationChallenges } from "@/models/index.js";
 import { LessThan } from "typeorm";
+import { nativeCheckDatabaseHealth } from "native-utils/built/index.js";
+import { initNativeDb } from "@/db/postgre.js";
+import Logger from "@/services/logger.js";
+
+const logger = new Logger("janitor");
 
 /**
  * Clean up database occasionally
  */
 export default function () {
 	async function tick() {
+		// Reconnect the native module, e.g. after a failover
+		const health = await nativeCheckDatabaseHealth().catch(() => null);
+		if (!health?.healthy) {
+			logger.warn("Reconnecting the native database connections");
+			await initNativeDb().catch((e) => logger.error(e));
+		}
+
 		await AttestationChallenges.delete({
 			createdAt: LessThan(new Date(new Date().getTime() - 5 * 60 * 1000)),
 		});
@@ -0,0 +1,23 @@
+import { nativeInitSearch } from "native-utils/built/index.js";
+import { dbLogger } from "./logger.js";
+
+import config from "@/config/index.js";
+import es from "./elasticsearch.js";
+import sonic from "./sonic.js";
+import meilisearch from "./meilisearch.js";
+
+const logger = dbLogger.createSubLogger("native-search", "gray", false);
+
+// The embedded index is only used if no external search service is configured
+const enabled =
+	config.searchIndex != null &&
+	es == null &&
+	sonic == null &&
+	meilisearch == null;
+
+if (enabled) {
+	logger.info(`Opening the search index at ${config.searchIndex}`);
+	nativeInitSearch(config.searchIndex as string);
+}
+
+export default enabled;
@@ -2,6 +2,11 @@
 import pg from "pg";
 pg.types.setTypeParser(20, Number);
 
+import { createHash } from "node:crypto";
+import { writeFileSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+
 import type { Logger } from "typeorm";
 import { DataSource } from "typeorm";
 import * as highlight from "cli-highlight";
@@ -76,7 +81,7 @@ import { NoteEdit } from "@/models/entities/note-edit.js";
 import { entities as charts } from "@/services/chart/entities.js";
 import { envOption } from "../env.js";
 import { dbLogger } from "./logger.js";
-import { redisClient } from "./redis.js";
+import { initNativeCache, redisClient } from "./redis.js";
 import { nativeInitDatabase } from "native-utils/built/index.js";
 
 const sqlLogger = dbLogger.createSubLogger("sql", "gray", false);
@@ -220,12 +225,81 @@ export const db = new DataSource({
 	migrations: ["../../migration/*.js"],
 });
 
+function postgresUri(db: {
+	host: string;
+	port: number;
+	db: string;
+	user: string;
+	pass: string;
+}) {
+	return `postgres://${db.user}:${encodeURIComponent(db.pass)}@${db.host}:${
+		db.port
+	}/${db.db}`;
+}
+
+/**
+ * Returns the `sslmode` equivalent to the `ssl` option of node-postgres.
+ */
+function sslMode(ssl: unknown): string | undefined {
+	if (ssl == null) return undefined;
+	if (ssl === false) return "disable";
+	if (typeof ssl === "object" && (ssl as any).rejectUnauthorized === false) {
+		return "require";
+	}
+	if (typeof ssl === "object" && ((ssl as any).cert || (ssl as any).key)) {
+		dbLogger.warn(
+			"Client certificates are not supported by the native database connections, which verify the server only",
+		);
+	}
+	return "verify-full";
+}
+
+/**
+ * Writes the certificates in the `ca` option of node-postgres to a file,
+ * as the native module only reads them from a file, and returns its path.
+ */
+function sslRootCert(ssl: unknown): string | undefined {
+	if (ssl == null || typeof ssl !== "object") return undefined;
+	const ca: unknown = (ssl as any).ca;
+	if (ca == null) return undefined;
+
+	const pem = (Array.isArray(ca) ? ca : [ca])
+		.map((cert) => cert.toString())
+		.join("\n");
+	const hash = createHash("sha256").update(pem).digest("hex").slice(0, 16);
+	const path = join(tmpdir(), `firefish-db-ca-${hash}.pem`);
+	writeFileSync(path, pem, { mode: 0o644 });
+	return path;
+}
+
+function optionalNumber(value: unknown): number | undefined {
+	return value == null ? undefined : Number(value);
+}
+
+/**
+ * Connects the native module to the database, replacing its connections if
+ * they exist. The pool, timeouts and SSL follow the options of node-postgres
+ * in `db.extra`, like the TypeORM connection.
+ */
+export async function initNativeDb() {
+	const extra: { [x: string]: any } = config.db.extra ?? {};
+	await nativeInitDatabase(postgresUri(config.db), {
+		maxConnections: optionalNumber(extra.max),
+		connectTimeout: optionalNumber(extra.connectionTimeoutMillis),
+		acquireTimeout: optionalNumber(extra.connectionTimeoutMillis),
+		idleTimeout: optionalNumber(extra.idleTimeoutMillis),
+		statementTimeout: optionalNumber(extra.statement_timeout ?? 1000 * 10),
+		sslMode: sslMode(extra.ssl),
+		sslRootCert: sslRootCert(extra.ssl),
+		replicas: config.dbReplications
+			? (config.dbSlaves ?? []).map(postgresUri)
+			: undefined,
+	});
+}
+
 export async function initDb(force = false) {
-	await nativeInitDatabase(
-		`postgres://${config.db.user}:${encodeURIComponent(config.db.pass)}@${
-			config.db.host
-		}:${config.db.port}/${config.db.db}`,
-	);
+	await initNativeDb();
+	await initNativeCache();
 	if (force) {
 		if (db.isInitialized) {
 			await db.destroy();
@@ -1,11 +1,10 @@
 import Redis from "ioredis";
 import config from "@/config/index.js";
+import { nativeInitCache } from "native-utils/built/index.js";
+
+const source = config.cacheServer ?? config.redis;
 
 export function createConnection() {
-	let source = config.redis;
-	if (config.cacheServer) {
-		source = config.cacheServer;
-	}
 	return new Redis({
 		port: source.port,
 		host: source.host,
@@ -22,3 +21,20 @@ export const subscriber = createConnection();
 subscriber.subscribe(config.host);
 
 export const redisClient = createConnection();
+
+/**
+ * Connects the native module to the same server as the ioredis clients,
+ * with the same key prefix
+ */
+export async function initNativeCache() {
+	const scheme = source.tls ? "rediss" : "redis";
+	const auth = source.pass
+		? `${encodeURIComponent(source.user ?? "default")}:${encodeURIComponent(
+				source.pass,
+		  )}@`
+		: "";
+	await nativeInitCache(
+		`${scheme}://${auth}${source.host}:${source.port}/${source.db || 0}`,
+		source.prefix!,
+	);
+}
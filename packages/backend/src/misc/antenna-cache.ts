@@ -1,11 +1,13 @@
 import { Antennas } from "@/models/index.js";
 import type { Antenna } from "@/models/entities/antenna.js";
 import { subscriber } from "@/db/redis.js";
+import { nativeRecordCacheRead } from "native-utils/built/index.js";
 
 let antennasFetched = false;
 let antennas: Antenna[] = [];
 
 export async function getAntennas() {
+	nativeRecordCacheRead("antennas", antennasFetched);
 	if (!antennasFetched) {
 		antennas = await Antennas.find();
 		antennasFetched = true;
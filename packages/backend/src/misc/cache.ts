@@ -1,12 +1,15 @@
 import { redisClient } from "@/db/redis.js";
 import { encode, decode } from "msgpackr";
 import { ChainableCommander } from "ioredis";
+import { nativeRecordCacheRead } from "native-utils/built/index.js";
 
 export class Cache<T> {
+	private name: string;
 	private ttl: number;
 	private prefix: string;
 
 	constructor(name: string, ttlSeconds: number) {
+		this.name = name;
 		this.ttl = ttlSeconds;
 		this.prefix = `cache:${name}`;
 	}
@@ -29,6 +32,7 @@ export class Cache<T> {
 	public async get(key: string | null, renew = false): Promise<T | undefined> {
 		const _key = this.prefixedKey(key);
 		const cached = await redisClient.getBuffer(_key);
+		nativeRecordCacheRead(this.name, cached !== null);
 		if (cached === null) return undefined;
 
 		if (renew) await redisClient.expire(_key, this.ttl);
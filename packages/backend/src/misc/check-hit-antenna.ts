@@ -6,8 +6,9 @@ import {
 	UserGroupJoinings,
 	Blockings,
 } from "@/models/index.js";
-import { getFullApAccount } from "./convert-host.js";
-import * as Acct from "@/misc/acct.js";
+import { nativeAcctsInclude } from "native-utils/built/index.js";
+// sets the local host used by nativeAcctsInclude
+import "./convert-host.js";
 import type { Packed } from "./schema.js";
 import { Cache } from "./cache.js";
 
@@ -71,15 +72,7 @@ export async function checkHitAntenna(
 
 		if (!groupUsers.includes(note.userId)) return false;
 	} else if (antenna.src === "users") {
-		const accts = antenna.users.map((x) => {
-			const { username, host } = Acct.parse(x);
-			return getFullApAccount(username, host).toLowerCase();
-		});
-		if (
-			!accts.includes(
-				getFullApAccount(noteUser.username, noteUser.host).toLowerCase(),
-			)
-		)
+		if (!nativeAcctsInclude(antenna.users, noteUser.username, noteUser.host))
 			return false;
 	} else if (antenna.src === "instances") {
 		const instances = antenna.instances
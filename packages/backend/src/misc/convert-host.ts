@@ -1,6 +1,9 @@
 import { URL } from "node:url";
 import config from "@/config/index.js";
 import { toASCII } from "punycode";
+import { nativeInitLocalHost } from "native-utils/built/index.js";
+
+nativeInitLocalHost(config.host);
 
 export function getFullApAccount(username: string, host: string | null) {
 	return host
@@ -1,5 +1,6 @@
 import { db } from "@/db/postgre.js";
 import { Meta } from "@/models/entities/meta.js";
+import { nativeRecordCacheRead } from "native-utils/built/index.js";
 
 let cache: Meta;
 
@@ -30,6 +31,7 @@ export function metaToPugArgs(meta: Meta): object {
 }
 
 export async function fetchMeta(noCache = false): Promise<Meta> {
+	if (!noCache) nativeRecordCacheRead("meta", cache != null);
 	if (!noCache && cache) return cache;
 
 	return await db.transaction(async (transactionalEntityManager) => {
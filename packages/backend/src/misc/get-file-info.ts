@@ -1,11 +1,8 @@
 import * as fs from "node:fs";
-import * as crypto from "node:crypto";
 import { join } from "node:path";
-import * as stream from "node:stream";
 import * as util from "node:util";
 import { FSWatcher } from "chokidar";
 import { fileTypeFromFile } from "file-type";
-import probeImageSize from "probe-image-size";
 import FFmpeg from "fluent-ffmpeg";
 import isSvg from "is-svg";
 import { type predictionType } from "nsfwjs";
@@ -13,8 +10,11 @@ import sharp from "sharp";
 import { encode } from "blurhash";
 import { detectSensitive } from "@/services/detect-sensitive.js";
 import { createTempDir } from "./create-temp.js";
-
-const pipeline = util.promisify(stream.pipeline);
+import config from "@/config/index.js";
+import {
+	nativeDetectSensitive,
+	nativeGetFileInfo,
+} from "native-utils/built/index.js";
 
 export type FileInfo = {
 	size: number;
@@ -37,6 +37,11 @@ const TYPE_OCTET_STREAM = {
 	ext: null,
 };
 
+/**
+ * Types of which the blurhash is not encoded by nativeGetFileInfo
+ */
+const SHARP_BLURHASH_TYPES = ["image/svg+xml", "image/avif"];
+
 const TYPE_SVG = {
 	mime: "image/svg+xml",
 	ext: "svg",
@@ -54,69 +59,12 @@ export async function getFileInfo(
 		enableSensitiveMediaDetectionForVideos?: boolean;
 	},
 ): Promise<FileInfo> {
-	const warnings = [] as string[];
-
-	const size = await getFileSize(path);
-	const md5 = await calcHash(path);
+	const info = await nativeGetFileInfo(path);
+	const { size, md5, width, height, orientation, warnings } = info;
+	const type = { mime: info.type.mime, ext: info.type.ext ?? null };
 
-	let type = await detectType(path);
-
-	// image dimensions
-	let width: number | undefined;
-	let height: number | undefined;
-	let orientation: number | undefined;
-
-	if (
-		[
-			"image/jpeg",
-			"image/gif",
-			"image/png",
-			"image/apng",
-			"image/webp",
-			"image/bmp",
-			"image/tiff",
-			"image/svg+xml",
-			"image/vnd.adobe.photoshop",
-			"image/avif",
-		].includes(type.mime)
-	) {
-		const imageSize = await detectImageSize(path).catch((e) => {
-			warnings.push(`detectImageSize failed: ${e}`);
-			return undefined;
-		});
-
-		// うまく判定できない画像は octet-stream にする
-		if (!imageSize) {
-			warnings.push("cannot detect image dimensions");
-			type = TYPE_OCTET_STREAM;
-		} else if (imageSize.wUnits === "px") {
-			width = imageSize.width;
-			height = imageSize.height;
-			orientation = imageSize.orientation;
-
-			// 制限を超えている画像は octet-stream にする
-			if (imageSize.width > 16383 || imageSize.height > 16383) {
-				warnings.push("image dimensions exceeds limits");
-				type = TYPE_OCTET_STREAM;
-			}
-		} else {
-			warnings.push(`unsupported unit type: ${imageSize.wUnits}`);
-		}
-	}
-
-	let blurhash: string | undefined;
-
-	if (
-		[
-			"image/jpeg",
-			"image/gif",
-			"image/png",
-			"image/apng",
-			"image/webp",
-			"image/svg+xml",
-			"image/avif",
-		].includes(type.mime)
-	) {
+	let blurhash = info.blurhash ?? undefined;
+	if (SHARP_BLURHASH_TYPES.includes(type.mime)) {
 		blurhash = await getBlurhash(path).catch((e) => {
 			warnings.push(`getBlurhash failed: ${e}`);
 			return undefined;
@@ -127,12 +75,24 @@ export async function getFileInfo(
 	let porn = false;
 
 	if (!opts.skipSensitiveDetection) {
-		await detectSensitivity(
-			path,
-			type.mime,
-			opts.sensitiveThreshold ?? 0.5,
-			opts.sensitiveThresholdForPorn ?? 0.75,
-			opts.enableSensitiveMediaDetectionForVideos ?? false,
+		const thresholds = {
+			sensitive: opts.sensitiveThreshold ?? 0.5,
+			porn: opts.sensitiveThresholdForPorn ?? 0.75,
+		};
+		const analyzeVideo = opts.enableSensitiveMediaDetectionForVideos ?? false;
+
+		// the model is run natively only if its ONNX export is configured
+		await (config.sensitiveMediaModel
+			? nativeDetectSensitive(path, type.mime, thresholds, analyzeVideo).then(
+					({ sensitive, porn }): [boolean, boolean] => [sensitive, porn],
+			  )
+			: detectSensitivity(
+					path,
+					type.mime,
+					thresholds.sensitive,
+					thresholds.porn,
+					analyzeVideo,
+			  )
 		).then(
 			(value) => {
 				[sensitive, porn] = value;
@@ -335,6 +295,28 @@ function exists(path: string): Promise<boolean> {
 	);
 }
 
+function getBlurhash(path: string): Promise<string> {
+	return new Promise((resolve, reject) => {
+		sharp(path)
+			.raw()
+			.ensureAlpha()
+			.resize(64, 64, { fit: "inside" })
+			.toBuffer((err, buffer, { width, height }) => {
+				if (err) return reject(err);
+
+				let hash;
+
+				try {
+					hash = encode(new Uint8ClampedArray(buffer), width, height, 7, 7);
+				} catch (e) {
+					return reject(e);
+				}
+
+				resolve(hash);
+			});
+	});
+}
+
 /**
  * Detect MIME Type and extension
  */
@@ -391,53 +373,3 @@ export async function getFileSize(path: string): Promise<number> {
 	const getStat = util.promisify(fs.stat);
 	return (await getStat(path)).size;
 }
-
-/**
- * Calculate MD5 hash
- */
-async function calcHash(path: string): Promise<string> {
-	const hash = crypto.createHash("md5").setEncoding("hex");
-	await pipeline(fs.createReadStream(path), hash);
-	return hash.read();
-}
-
-/**
- * Detect dimensions of image
- */
-async function detectImageSize(path: string): Promise<{
-	width: number;
-	height: number;
-	wUnits: string;
-	hUnits: string;
-	orientation?: number;
-}> {
-	const readable = fs.createReadStream(path);
-	const imageSize = await probeImageSize(readable);
-	readable.destroy();
-	return imageSize;
-}
-
-/**
- * Calculate average color of image
- */
-function getBlurhash(path: string): Promise<string> {
-	return new Promise((resolve, reject) => {
-		sharp(path)
-			.raw()
-			.ensureAlpha()
-			.resize(64, 64, { fit: "inside" })
-			.toBuffer((err, buffer, { width, height }) => {
-				if (err) return reject(err);
-
-				let hash;
-
-				try {
-					hash = encode(new Uint8ClampedArray(buffer), width, height, 7, 7);
-				} catch (e) {
-					return reject(e);
-				}
-
-				resolve(hash);
-			});
-	});
-}
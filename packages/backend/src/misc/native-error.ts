@@ -0,0 +1,54 @@
+import type { NativeErrorCode } from "native-utils/built/index.js";
+import { IdentifiableError } from "@/misc/identifiable-error.js";
+
+/**
+ * Error thrown by native-utils, whose `id` is the one of the same error in
+ * the API
+ */
+export class NativeError extends IdentifiableError {
+	public code: string;
+	public httpStatusCode: number;
+	public kind: "client" | "server";
+
+	constructor(code: NativeErrorCode, message: string) {
+		super(code.id, message);
+		this.code = code.code;
+		this.httpStatusCode = code.status;
+		this.kind = code.status < 500 ? "client" : "server";
+	}
+}
+
+/**
+ * Returns the fields of an error thrown by a native function, which are
+ * passed as JSON in the message since napi-rs can not set other properties
+ * on rejected promises.
+ */
+function nativeErrorFields(
+	e: Error,
+): (NativeErrorCode & { message: string }) | null {
+	const status = (e as { code?: unknown }).code;
+	if (status !== "GenericFailure" && status !== "InvalidArg") return null;
+	try {
+		const fields = JSON.parse(e.message);
+		if (
+			typeof fields?.code === "string" &&
+			typeof fields.id === "string" &&
+			typeof fields.status === "number" &&
+			typeof fields.message === "string"
+		) {
+			return fields;
+		}
+	} catch {}
+	return null;
+}
+
+/**
+ * Converts an error thrown by a native function into a NativeError, which
+ * can be passed to ApiError as is. Other errors are returned unchanged.
+ */
+export function fromNativeError(e: unknown): unknown {
+	if (!(e instanceof Error) || e instanceof NativeError) return e;
+	const fields = nativeErrorFields(e);
+	if (!fields) return e;
+	return new NativeError(fields, fields.message);
+}
@@ -125,21 +125,21 @@ export class DriveFile {
 	})
 	public webpublicType: string | null;
 
-	@Index({ unique: true })
+	@Index()
 	@Column("varchar", {
 		length: 256,
 		nullable: true,
 	})
 	public accessKey: string | null;
 
-	@Index({ unique: true })
+	@Index()
 	@Column("varchar", {
 		length: 256,
 		nullable: true,
 	})
 	public thumbnailAccessKey: string | null;
 
-	@Index({ unique: true })
+	@Index()
 	@Column("varchar", {
 		length: 256,
 		nullable: true,
@@ -4,11 +4,14 @@ import {
 	NativeAntennaSchema,
 	nativePackAntennaById,
 } from "native-utils/built/index.js";
+import { fromNativeError } from "@/misc/native-error.js";
 
 export const AntennaRepository = db.getRepository(Antenna).extend({
 	async pack(src: Antenna["id"] | Antenna): Promise<NativeAntennaSchema> {
 		const id = typeof src === "object" ? src.id : src;
 
-		return await nativePackAntennaById(id);
+		return await nativePackAntennaById(id).catch((e) => {
+			throw fromNativeError(e);
+		});
 	},
 });
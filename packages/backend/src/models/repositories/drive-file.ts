@@ -10,6 +10,7 @@ import { Meta } from "@/models/entities/meta.js";
 import { fetchMeta } from "@/misc/fetch-meta.js";
 import { Users, DriveFolders } from "../index.js";
 import { deepClone } from "@/misc/clone.js";
+import { nativeGetDriveUsage } from "native-utils/built/index.js";
 
 type PackOptions = {
 	detail?: boolean;
@@ -92,13 +93,7 @@ export const DriveFileRepository = db.getRepository(DriveFile).extend({
 	): Promise<number> {
 		const id = typeof user === "object" ? user.id : user;
 
-		const { sum } = await this.createQueryBuilder("file")
-			.where("file.userId = :id", { id: id })
-			.andWhere("file.isLink = FALSE")
-			.select("SUM(file.size)", "sum")
-			.getRawOne();
-
-		return parseInt(sum, 10) || 0;
+		return await nativeGetDriveUsage(id);
 	},
 
 	async calcDriveUsageOfHost(host: string): Promise<number> {
@@ -28,6 +28,7 @@ import {
 	backgroundQueue,
 } from "./queues.js";
 import type { ThinUser } from "./types.js";
+import type { NativeEvictionPolicy } from "native-utils/built/index.js";
 
 function renderError(e: Error): any {
 	return {
@@ -468,10 +469,14 @@ export function createDeleteObjectStorageFileJob(key: string) {
 	);
 }
 
-export function createCleanRemoteFilesJob() {
+export function createCleanRemoteFilesJob(
+	policy: NativeEvictionPolicy = { budget: 0 },
+) {
 	return objectStorageQueue.add(
 		"cleanRemoteFiles",
-		{},
+		{
+			policy,
+		},
 		{
 			removeOnComplete: true,
 			removeOnFail: true,
@@ -586,6 +591,16 @@ export default function () {
 		},
 	);
 
+	systemQueue.add(
+		"reconcileDriveUsages",
+		{},
+		{
+			repeat: { cron: "30 0 * * *" },
+			removeOnComplete: true,
+			removeOnFail: true,
+		},
+	);
+
 	processSystemQueue(systemQueue);
 }
 
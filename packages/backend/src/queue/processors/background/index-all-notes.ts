@@ -7,6 +7,8 @@ import { MoreThan } from "typeorm";
 import { index } from "@/services/note/create.js";
 import { Note } from "@/models/entities/note.js";
 import meilisearch from "../../../db/meilisearch.js";
+import nativeSearch from "../../../db/native-search.js";
+import { nativeReindexAllNotes } from "native-utils/built/index.js";
 
 const logger = queueLogger.createSubLogger("index-all-notes");
 
@@ -16,6 +18,19 @@ export default async function indexAllNotes(
 ): Promise<void> {
 	logger.info("Indexing all notes...");
 
+	if (nativeSearch) {
+		try {
+			const indexedCount = await nativeReindexAllNotes();
+			await job.progress(100);
+			logger.info(`Indexed ${indexedCount} notes.`);
+			done();
+		} catch (e: any) {
+			logger.error(`Failed to reindex notes ${e}`);
+			done(e);
+		}
+		return;
+	}
+
 	let cursor: string | null = (job.data.cursor as string) ?? null;
 	let indexedCount: number = (job.data.indexedCount as number) ?? 0;
 	let total: number = (job.data.total as number) ?? 0;
@@ -1,4 +1,5 @@
 import { URL } from "node:url";
+import { nativeRecordDelivery } from "native-utils/built/index.js";
 import request from "@/remote/activitypub/request.js";
 import { registerOrFetchInstanceDoc } from "@/services/register-or-fetch-instance-doc.js";
 import Logger from "@/services/logger.js";
@@ -46,6 +47,7 @@ export default async (job: Bull.Job<DeliverJobData>) => {
 			instanceChart.requestSent(i.host, true);
 			apRequestChart.deliverSucc();
 			federationChart.deliverd(i.host, true);
+			nativeRecordDelivery(i.host, true);
 		});
 
 		return "Success";
@@ -61,6 +63,7 @@ export default async (job: Bull.Job<DeliverJobData>) => {
 			instanceChart.requestSent(i.host, false);
 			apRequestChart.deliverFail();
 			federationChart.deliverd(i.host, false);
+			nativeRecordDelivery(i.host, false);
 		});
 
 		if (res instanceof StatusError) {
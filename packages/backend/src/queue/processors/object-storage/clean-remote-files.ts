@@ -1,53 +1,44 @@
 import type Bull from "bull";
 
 import { queueLogger } from "../../logger.js";
-import { deleteFileSync } from "@/services/drive/delete-file.js";
-import { DriveFiles } from "@/models/index.js";
-import { MoreThan, Not, IsNull } from "typeorm";
+import type { CleanRemoteFilesJobData } from "@/queue/types.js";
+import { nativeEvictRemoteFiles } from "native-utils/built/index.js";
+import {
+	driveChart,
+	perUserDriveChart,
+	instanceChart,
+} from "@/services/chart/index.js";
 
 const logger = queueLogger.createSubLogger("clean-remote-files");
 
 export default async function cleanRemoteFiles(
-	job: Bull.Job<Record<string, unknown>>,
+	job: Bull.Job<CleanRemoteFilesJobData>,
 	done: any,
 ): Promise<void> {
-	logger.info("Deleting cached remote files...");
-
-	let deletedCount = 0;
-	let cursor: any = null;
-
-	while (true) {
-		const files = await DriveFiles.find({
-			where: {
-				userHost: Not(IsNull()),
-				isLink: false,
-				...(cursor ? { id: MoreThan(cursor) } : {}),
-			},
-			take: 8,
-			order: {
-				id: 1,
-			},
-		});
-
-		if (files.length === 0) {
-			job.progress(100);
-			break;
+	logger.info("Evicting cached remote files...");
+
+	// jobs queued before the policy was introduced evict everything
+	const report = await nativeEvictRemoteFiles(
+		job.data.policy ?? { budget: 0 },
+		false,
+	);
+	job.progress(100);
+
+	for (const evicted of report.evicted) {
+		const file = {
+			userId: evicted.userId ?? null,
+			userHost: evicted.userHost ?? null,
+			size: evicted.size,
+		};
+		driveChart.update(file, false);
+		perUserDriveChart.update(file, false);
+		if (file.userHost !== null) {
+			instanceChart.updateDrive(file, false);
 		}
-
-		cursor = files[files.length - 1].id;
-
-		await Promise.all(files.map((file) => deleteFileSync(file, true)));
-
-		deletedCount += 8;
-
-		const total = await DriveFiles.countBy({
-			userHost: Not(IsNull()),
-			isLink: false,
-		});
-
-		job.progress(deletedCount / total);
 	}
 
-	logger.succ("All cahced remote files has been deleted.");
+	logger.succ(
+		`${report.evictedFiles} cached remote files (${report.evictedBytes} bytes) have been evicted, ${report.failedFiles} failed.`,
+	);
 	done();
 }
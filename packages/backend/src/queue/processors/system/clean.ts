@@ -1,6 +1,7 @@
 import type Bull from "bull";
 import { LessThan } from "typeorm";
 import { UserIps } from "@/models/index.js";
+import { nativePruneDriveBlobs } from "native-utils/built/index.js";
 
 import { queueLogger } from "../../logger.js";
 
@@ -16,6 +17,8 @@ export async function clean(
 		createdAt: LessThan(new Date(Date.now() - 1000 * 60 * 60 * 24 * 90)),
 	});
 
+	await nativePruneDriveBlobs();
+
 	logger.succ("Cleaned.");
 	done();
 }
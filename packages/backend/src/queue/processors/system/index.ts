@@ -6,6 +6,7 @@ import { checkExpiredMutings } from "./check-expired-mutings.js";
 import { clean } from "./clean.js";
 import { setLocalEmojiSizes } from "./local-emoji-size.js";
 import { verifyLinks } from "./verify-links.js";
+import { reconcileDriveUsages } from "./reconcile-drive-usages.js";
 
 const jobs = {
 	tickCharts,
@@ -15,6 +16,7 @@ const jobs = {
 	clean,
 	setLocalEmojiSizes,
 	verifyLinks,
+	reconcileDriveUsages,
 } as Record<
 	string,
 	| Bull.ProcessCallbackFunction<Record<string, unknown>>
@@ -0,0 +1,17 @@
+import type Bull from "bull";
+import { nativeReconcileDriveUsages } from "native-utils/built/index.js";
+import { queueLogger } from "../../logger.js";
+
+const logger = queueLogger.createSubLogger("reconcile-drive-usages");
+
+export async function reconcileDriveUsages(
+	job: Bull.Job<Record<string, unknown>>,
+	done: any,
+): Promise<void> {
+	logger.info("Reconciling drive usages...");
+
+	const fixed = await nativeReconcileDriveUsages();
+
+	logger.succ(`Drive usages reconciled, ${fixed} fixed.`);
+	done();
+}
@@ -4,6 +4,7 @@ import type { User } from "@/models/entities/user.js";
 import type { Webhook } from "@/models/entities/webhook";
 import type { IActivity } from "@/remote/activitypub/type.js";
 import type httpSignature from "@peertube/http-signature";
+import type { NativeEvictionPolicy } from "native-utils/built/index.js";
 
 export type DeliverJobData = {
 	/** Actor */
@@ -56,12 +57,17 @@ export type DbUserImportMastoPostJobData = {
 
 export type ObjectStorageJobData =
 	| ObjectStorageFileJobData
+	| CleanRemoteFilesJobData
 	| Record<string, unknown>;
 
 export type ObjectStorageFileJobData = {
 	key: string;
 };
 
+export type CleanRemoteFilesJobData = {
+	policy: NativeEvictionPolicy;
+};
+
 export type EndedPollNotificationJobData = {
 	noteId: Note["id"];
 };
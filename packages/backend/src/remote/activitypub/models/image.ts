@@ -8,6 +8,7 @@ import type { DriveFile } from "@/models/entities/drive-file.js";
 import { DriveFiles, Users } from "@/models/index.js";
 import { truncate } from "@/misc/truncate.js";
 import { DB_MAX_IMAGE_COMMENT_LENGTH } from "@/misc/hard-limits.js";
+import { nativeRecordDriveFileAccess } from "native-utils/built/index.js";
 
 const logger = apLogger;
 
@@ -46,6 +47,12 @@ export async function createImage(
 		comment: truncate(image.name, DB_MAX_IMAGE_COMMENT_LENGTH),
 	});
 
+	if (!file.isLink) {
+		nativeRecordDriveFileAccess(file.id).catch((e) =>
+			logger.warn(`failed to record the use of ${file.id}: ${e}`),
+		);
+	}
+
 	if (file.isLink) {
 		// If the URL is different, it means that the same image was previously
 		// registered with a different URL, so update the URL
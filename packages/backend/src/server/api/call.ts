@@ -4,6 +4,7 @@ import type { CacheableLocalUser } from "@/models/entities/user.js";
 import { User } from "@/models/entities/user.js";
 import type { AccessToken } from "@/models/entities/access-token.js";
 import { getIpHash } from "@/misc/get-ip-hash.js";
+import { fromNativeError, NativeError } from "@/misc/native-error.js";
 import { limiter } from "./limiter.js";
 import type { IEndpointMeta } from "./endpoints.js";
 import endpoints from "./endpoints.js";
@@ -164,8 +165,11 @@ export default async (
 	return await ep
 		.exec(data, user, token, ctx?.file, ctx?.ip, ctx?.headers)
 		.catch((e: Error) => {
+			e = fromNativeError(e) as Error;
 			if (e instanceof ApiError) {
 				throw e;
+			} else if (e instanceof NativeError && e.kind === "client") {
+				throw new ApiError(e);
 			} else {
 				apiLogger.error(`Internal error occurred in ${ep.name}: ${e.message}`, {
 					ep: ep.name,
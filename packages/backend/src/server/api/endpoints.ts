@@ -59,6 +59,7 @@ import * as ep___admin_showModerationLogs from "./endpoints/admin/show-moderatio
 import * as ep___admin_showUser from "./endpoints/admin/show-user.js";
 import * as ep___admin_showUsers from "./endpoints/admin/show-users.js";
 import * as ep___admin_silenceUser from "./endpoints/admin/silence-user.js";
+import * as ep___admin_statsSummary from "./endpoints/admin/stats-summary.js";
 import * as ep___admin_suspendUser from "./endpoints/admin/suspend-user.js";
 import * as ep___admin_unsilenceUser from "./endpoints/admin/unsilence-user.js";
 import * as ep___admin_unsuspendUser from "./endpoints/admin/unsuspend-user.js";
@@ -412,6 +413,7 @@ const eps = [
 	["admin/show-user", ep___admin_showUser],
 	["admin/show-users", ep___admin_showUsers],
 	["admin/silence-user", ep___admin_silenceUser],
+	["admin/stats-summary", ep___admin_statsSummary],
 	["admin/suspend-user", ep___admin_suspendUser],
 	["admin/unsilence-user", ep___admin_unsilenceUser],
 	["admin/unsuspend-user", ep___admin_unsuspendUser],
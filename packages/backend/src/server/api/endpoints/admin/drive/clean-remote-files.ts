@@ -1,19 +1,80 @@
 import define from "../../../define.js";
 import { createCleanRemoteFilesJob } from "@/queue/index.js";
+import { nativeEvictRemoteFiles } from "native-utils/built/index.js";
 
 export const meta = {
 	tags: ["admin"],
 
 	requireCredential: true,
 	requireModerator: true,
+
+	res: {
+		type: "object",
+		optional: false,
+		nullable: true,
+		properties: {
+			evictedFiles: {
+				type: "number",
+				optional: false,
+				nullable: false,
+			},
+			evictedBytes: {
+				type: "number",
+				optional: false,
+				nullable: false,
+			},
+			failedFiles: {
+				type: "number",
+				optional: false,
+				nullable: false,
+			},
+			keptFiles: {
+				type: "number",
+				optional: false,
+				nullable: false,
+			},
+			keptBytes: {
+				type: "number",
+				optional: false,
+				nullable: false,
+			},
+		},
+	},
 } as const;
 
 export const paramDef = {
 	type: "object",
-	properties: {},
+	properties: {
+		/** Evicts the files not used for this many days. */
+		maxIdleDays: {
+			type: "integer",
+			minimum: 0,
+			maximum: 4294967295,
+			nullable: true,
+		},
+		/** Evicts the least recently used files beyond this many MB. */
+		budgetMb: { type: "integer", minimum: 0, nullable: true },
+		/** Reports what would be evicted without evicting anything. */
+		dryRun: { type: "boolean", default: false },
+	},
 	required: [],
 } as const;
 
 export default define(meta, paramDef, async (ps, me) => {
-	createCleanRemoteFilesJob();
+	// all files are evicted unless a policy is given
+	const policy =
+		ps.maxIdleDays == null && ps.budgetMb == null
+			? { budget: 0 }
+			: {
+					maxIdleDays: ps.maxIdleDays ?? undefined,
+					budget: ps.budgetMb != null ? ps.budgetMb * 1024 * 1024 : undefined,
+			  };
+
+	if (ps.dryRun) {
+		const { evicted, ...report } = await nativeEvictRemoteFiles(policy, true);
+		return report;
+	}
+
+	createCleanRemoteFilesJob(policy);
+	return null;
 });
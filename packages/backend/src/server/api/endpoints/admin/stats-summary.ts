@@ -0,0 +1,62 @@
+import { nativeGetStatsSummary } from "native-utils/built/index.js";
+import define from "../../define.js";
+
+export const meta = {
+	requireCredential: true,
+	requireModerator: true,
+
+	tags: ["admin"],
+
+	description:
+		"Summarizes the active users and the deliveries to other instances, which are counted as they happen and saved every 20 minutes.",
+
+	res: {
+		type: "object",
+		optional: false,
+		nullable: false,
+		properties: {
+			dailyActiveUsers: { type: "number", optional: false, nullable: false },
+			weeklyActiveUsers: { type: "number", optional: false, nullable: false },
+			monthlyActiveUsers: { type: "number", optional: false, nullable: false },
+			instances: { type: "number", optional: false, nullable: false },
+			notRespondingInstances: {
+				type: "number",
+				optional: false,
+				nullable: false,
+			},
+			deliveriesSucceeded: { type: "number", optional: false, nullable: false },
+			deliveriesFailed: { type: "number", optional: false, nullable: false },
+			failingInstances: {
+				type: "array",
+				optional: false,
+				nullable: false,
+				items: {
+					type: "object",
+					optional: false,
+					nullable: false,
+					properties: {
+						host: { type: "string", optional: false, nullable: false },
+						succeeded: { type: "number", optional: false, nullable: false },
+						failed: { type: "number", optional: false, nullable: false },
+						latestStatus: { type: "number", optional: true, nullable: true },
+						isNotResponding: {
+							type: "boolean",
+							optional: false,
+							nullable: false,
+						},
+					},
+				},
+			},
+		},
+	},
+} as const;
+
+export const paramDef = {
+	type: "object",
+	properties: {},
+	required: [],
+} as const;
+
+export default define(meta, paramDef, async () => {
+	return await nativeGetStatsSummary();
+});
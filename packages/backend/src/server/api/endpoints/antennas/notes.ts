@@ -1,8 +1,8 @@
 import define from "../../define.js";
 import readNote from "@/services/note/read.js";
+import { nativeReadAntennaTimeline } from "native-utils/built/index.js";
 import { Antennas, Notes } from "@/models/index.js";
-import { redisClient } from "@/db/redis.js";
-import { genId } from "@/misc/gen-id.js";
+import type { Note } from "@/models/entities/note.js";
 import { makePaginationQuery } from "../../common/make-pagination-query.js";
 import { generateVisibilityQuery } from "../../common/generate-visibility-query.js";
 import { generateMutedUserQuery } from "../../common/generate-muted-user-query.js";
@@ -60,26 +60,6 @@ export default define(meta, paramDef, async (ps, user) => {
 		throw new ApiError(meta.errors.noSuchAntenna);
 	}
 
-	const noteIdsRes = await redisClient.xrevrange(
-		`antennaTimeline:${antenna.id}`,
-		ps.untilDate || "+",
-		"-",
-		"COUNT",
-		ps.limit + 1,
-	); // untilIdに指定したものも含まれるため+1
-
-	if (noteIdsRes.length === 0) {
-		return [];
-	}
-
-	const noteIds = noteIdsRes
-		.map((x) => x[1][1])
-		.filter((x) => x !== ps.untilId);
-
-	if (noteIds.length === 0) {
-		return [];
-	}
-
 	const query = makePaginationQuery(
 		Notes.createQueryBuilder("note"),
 		ps.sinceId,
@@ -87,7 +67,7 @@ export default define(meta, paramDef, async (ps, user) => {
 		ps.sinceDate,
 		ps.untilDate,
 	)
-		.where("note.id IN (:...noteIds)", { noteIds: noteIds })
+		.andWhere("note.id IN (:...noteIds)")
 		.innerJoinAndSelect("note.user", "user")
 		.leftJoinAndSelect("user.avatar", "avatar")
 		.leftJoinAndSelect("user.banner", "banner")
@@ -105,7 +85,29 @@ export default define(meta, paramDef, async (ps, user) => {
 	generateMutedUserQuery(query, user);
 	generateBlockedUserQuery(query, user);
 
-	const notes = await query.take(ps.limit).getMany();
+	// Reads the timeline page by page until enough notes pass the filters
+	const notes: Note[] = [];
+	let untilId = ps.untilId;
+	while (notes.length < ps.limit) {
+		const noteIds = await nativeReadAntennaTimeline(
+			antenna.id,
+			ps.limit,
+			ps.sinceId,
+			untilId,
+		);
+		if (noteIds.length === 0) break;
+
+		const found = await query.setParameter("noteIds", noteIds).getMany();
+		notes.push(...found.slice(0, ps.limit - notes.length));
+		if (noteIds.length < ps.limit) break;
+		untilId = noteIds[noteIds.length - 1];
+	}
+
+	// keeps the order of makePaginationQuery across the pages
+	const ascending =
+		ps.untilId == null &&
+		(ps.sinceId != null || (ps.sinceDate != null && ps.untilDate == null));
+	notes.sort((a, b) => (a.id < b.id === ascending ? -1 : 1));
 
 	if (notes.length > 0) {
 		readNote(user.id, notes);
@@ -5,6 +5,8 @@ import config from "@/config/index.js";
 import es from "@/db/elasticsearch.js";
 import sonic from "@/db/sonic.js";
 import meilisearch, { MeilisearchNote } from "@/db/meilisearch.js";
+import nativeSearch from "@/db/native-search.js";
+import { nativeSearchNotes } from "native-utils/built/index.js";
 import define from "../../define.js";
 import { makePaginationQuery } from "../../common/make-pagination-query.js";
 import { generateVisibilityQuery } from "../../common/generate-visibility-query.js";
@@ -63,7 +65,40 @@ export const paramDef = {
 } as const;
 
 export default define(meta, paramDef, async (ps, me) => {
-	if (es == null && sonic == null && meilisearch == null) {
+	if (nativeSearch) {
+		// Visibility, pagination and the other filters are applied by the index.
+		// The query only checks what the index does not know, e.g. muted instances.
+		const query = Notes.createQueryBuilder("note").andWhere(
+			"note.id IN (:...ids)",
+		);
+		if (me) generateMutedUserQuery(query, me);
+		if (me) generateBlockedUserQuery(query, me);
+
+		const found: Note[] = [];
+		let offset = ps.offset;
+		while (found.length < ps.limit) {
+			const ids = await nativeSearchNotes(ps.query, me?.id, {
+				limit: ps.limit,
+				offset,
+				sinceId: ps.sinceId,
+				untilId: ps.untilId,
+				host: ps.host ?? undefined,
+				localOnly: ps.host === null,
+				userId: ps.userId ?? undefined,
+				channelId: ps.userId ? undefined : ps.channelId ?? undefined,
+			});
+			if (ids.length === 0) break;
+
+			const notes = await query.setParameter("ids", ids).getMany();
+			// keeps the order of the index
+			notes.sort((a, b) => ids.indexOf(a.id) - ids.indexOf(b.id));
+			found.push(...notes.slice(0, ps.limit - found.length));
+			if (ids.length < ps.limit) break;
+			offset += ids.length;
+		}
+
+		return await Notes.packMany(found, me);
+	} else if (es == null && sonic == null && meilisearch == null) {
 		const query = makePaginationQuery(
 			Notes.createQueryBuilder("note"),
 			ps.sinceId,
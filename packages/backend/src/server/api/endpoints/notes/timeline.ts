@@ -1,4 +1,5 @@
 import { Brackets } from "typeorm";
+import { nativeReadHomeTimeline } from "native-utils/built/index.js";
 import { Notes, Followings } from "@/models/index.js";
 import { activeUsersChart } from "@/services/chart/index.js";
 import define from "../../define.js";
@@ -169,18 +170,43 @@ export default define(meta, paramDef, async (ps, user) => {
 	// requested, the pagination stops.
 	const found = [];
 	const take = Math.floor(ps.limit * 1.5);
-	let skip = 0;
 	try {
-		while (found.length < ps.limit) {
-			const notes = await query.take(take).skip(skip).getMany();
-			found.push(...(await Notes.packMany(notes, user)));
-			skip += take;
-			if (notes.length < take) break;
+		if (ps.sinceDate == null && ps.untilDate == null) {
+			// The note IDs are read from the stream of the home timeline, and the
+			// notes are filtered by the query
+			query.andWhere("note.id IN (:...noteIds)");
+			let untilId = ps.untilId;
+			while (found.length < ps.limit) {
+				const noteIds = await nativeReadHomeTimeline(
+					user.id,
+					take,
+					ps.sinceId,
+					untilId,
+				);
+				if (noteIds.length === 0) break;
+				const notes = await query.setParameter("noteIds", noteIds).getMany();
+				found.push(...(await Notes.packMany(notes, user)));
+				if (noteIds.length < take) break;
+				untilId = noteIds[noteIds.length - 1];
+			}
+		} else {
+			let skip = 0;
+			while (found.length < ps.limit) {
+				const notes = await query.take(take).skip(skip).getMany();
+				found.push(...(await Notes.packMany(notes, user)));
+				skip += take;
+				if (notes.length < take) break;
+			}
 		}
 	} catch (error) {
 		throw new ApiError(meta.errors.queryError);
 	}
 
+	// keeps the order of makePaginationQuery across the pages
+	if (ps.sinceId != null && ps.untilId == null) {
+		found.sort((a, b) => (a.id < b.id ? -1 : 1));
+	}
+
 	if (found.length > ps.limit) {
 		found.length = ps.limit;
 	}
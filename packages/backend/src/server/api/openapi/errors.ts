@@ -67,4 +67,24 @@ export const errors = {
 			},
 		},
 	},
+	"503": {
+		SERVICE_UNAVAILABLE: {
+			value: {
+				error: {
+					message: "The database or cache can not be reached.",
+					code: "SERVICE_UNAVAILABLE",
+					id: "b0036050-920f-4dba-99a2-9f27f502c023",
+				},
+			},
+		},
+		NOT_INITIALIZED: {
+			value: {
+				error: {
+					message: "The server is not ready yet.",
+					code: "NOT_INITIALIZED",
+					id: "8cdccf14-2668-4569-82b2-8337deb29dcd",
+				},
+			},
+		},
+	},
 };
@@ -205,6 +205,17 @@ export function genOpenapiSpec() {
 						},
 					},
 				},
+				"503": {
+					description: "Service unavailable",
+					content: {
+						"application/json": {
+							schema: {
+								$ref: "#/components/schemas/Error",
+							},
+							examples: basicErrors["503"],
+						},
+					},
+				},
 			},
 		};
 
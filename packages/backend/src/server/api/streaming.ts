@@ -2,6 +2,7 @@ import type * as http from "node:http";
 import { EventEmitter } from "events";
 import type { ParsedUrlQuery } from "querystring";
 import * as websocket from "websocket";
+import { nativeRecordActiveUser } from "native-utils/built/index.js";
 
 import { subscriber as redisClient } from "@/db/redis.js";
 import { Users } from "@/models/index.js";
@@ -65,12 +66,14 @@ export const initializeStreamingServer = (server: http.Server) => {
 					Users.update(user.id, {
 						lastActiveDate: new Date(),
 					});
+					nativeRecordActiveUser(user.id);
 			  }, 1000 * 60 * 5)
 			: null;
 		if (user) {
 			Users.update(user.id, {
 				lastActiveDate: new Date(),
 			});
+			nativeRecordActiveUser(user.id);
 		}
 
 		connection.once("close", () => {
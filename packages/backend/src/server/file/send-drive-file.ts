@@ -15,6 +15,7 @@ import { convertToWebp } from "@/services/drive/image-processor.js";
 import { GenerateVideoThumbnail } from "@/services/drive/generate-video-thumbnail.js";
 import { StatusError } from "@/misc/fetch.js";
 import { FILE_TYPE_BROWSERSAFE } from "@/const.js";
+import { nativeRecordDriveFileAccess } from "native-utils/built/index.js";
 
 const _filename = fileURLToPath(import.meta.url);
 const _dirname = dirname(_filename);
@@ -122,6 +123,10 @@ export default async function (ctx: Koa.Context) {
 		return;
 	}
 
+	if (file.userHost !== null) {
+		nativeRecordDriveFileAccess(file.id).catch(() => {});
+	}
+
 	if (isThumbnail || isWebpublic) {
 		const { mime, ext } = await detectType(InternalStorage.resolvePath(key));
 		const filename = rename(file.name, {
@@ -25,6 +25,7 @@ import { envOption } from "@/env.js";
 import megalodon, { MegalodonInterface } from "megalodon";
 import activityPub from "./activitypub.js";
 import nodeinfo from "./nodeinfo.js";
+import metrics from "./metrics.js";
 import wellKnown from "./well-known.js";
 import apiServer from "./api/index.js";
 import fileServer from "./file/index.js";
@@ -105,6 +106,7 @@ mastoRouter.use(async (ctx, next) => {
 // Routing
 router.use(activityPub.routes());
 router.use(nodeinfo.routes());
+router.use(metrics.routes());
 router.use(wellKnown.routes());
 
 router.get("/avatar/@:acct", async (ctx) => {
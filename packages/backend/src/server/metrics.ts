@@ -0,0 +1,34 @@
+import { timingSafeEqual } from "node:crypto";
+import Router from "@koa/router";
+import config from "@/config/index.js";
+import { nativeEncodeMetrics } from "native-utils/built/index.js";
+
+const router = new Router();
+
+router.get("/metrics", async (ctx) => {
+	if (!config.metrics?.enable) {
+		ctx.status = 404;
+		return;
+	}
+
+	const token = config.metrics.bearerToken;
+	if (token && !isAuthorized(ctx.headers.authorization, token)) {
+		ctx.status = 401;
+		return;
+	}
+
+	ctx.set("Cache-Control", "no-store");
+	ctx.type = "text/plain; version=0.0.4; charset=utf-8";
+	ctx.body = nativeEncodeMetrics();
+});
+
+function isAuthorized(header: string | undefined, token: string): boolean {
+	const expected = Buffer.from(`Bearer ${token}`);
+	const actual = Buffer.from(header ?? "");
+	// timingSafeEqual throws on buffers of different lengths
+	return (
+		actual.length === expected.length && timingSafeEqual(actual, expected)
+	);
+}
+
+export default router;
@@ -1,3 +1,4 @@
+import { nativeRecordActiveUser } from "native-utils/built/index.js";
 import type { KVs } from "../core.js";
 import Chart from "../core.js";
 import type { User } from "@/models/entities/user.js";
@@ -30,6 +31,7 @@ export default class ActiveUsersChart extends Chart<typeof schema> {
 		host: null;
 		createdAt: User["createdAt"];
 	}) {
+		nativeRecordActiveUser(user.id);
 		this.commit({
 			read: [user.id],
 			registeredWithinWeek:
@@ -52,6 +54,7 @@ export default class ActiveUsersChart extends Chart<typeof schema> {
 		host: null;
 		createdAt: User["createdAt"];
 	}): Promise<void> {
+		nativeRecordActiveUser(user.id);
 		await this.commit({
 			write: [user.id],
 		});
@@ -1,12 +1,12 @@
 import type { KVs } from "../core.js";
-import Chart from "../core.js";
+import NativeChart from "../native.js";
 import { name, schema } from "./entities/ap-request.js";
 
 /**
- * Chart about ActivityPub requests
+ * Chart about ActivityPub requests, saved by the native engine
  */
 
-export default class ApRequestChart extends Chart<typeof schema> {
+export default class ApRequestChart extends NativeChart<typeof schema> {
 	constructor() {
 		super(name, schema);
 	}
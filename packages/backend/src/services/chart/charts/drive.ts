@@ -22,7 +22,10 @@ export default class DriveChart extends Chart<typeof schema> {
 		return {};
 	}
 
-	public async update(file: DriveFile, isAdditional: boolean): Promise<void> {
+	public async update(
+		file: Pick<DriveFile, "userHost" | "size">,
+		isAdditional: boolean,
+	): Promise<void> {
 		const fileSizeKb = file.size / 1000;
 		await this.commit(
 			file.userHost === null
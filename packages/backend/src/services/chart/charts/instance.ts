@@ -124,7 +124,7 @@ export default class InstanceChart extends Chart<typeof schema> {
 	}
 
 	public async updateDrive(
-		file: DriveFile,
+		file: Pick<DriveFile, "userHost" | "size">,
 		isAdditional: boolean,
 	): Promise<void> {
 		const fileSizeKb = file.size / 1000;
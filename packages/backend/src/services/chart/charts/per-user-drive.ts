@@ -31,7 +31,10 @@ export default class PerUserDriveChart extends Chart<typeof schema> {
 		return {};
 	}
 
-	public async update(file: DriveFile, isAdditional: boolean): Promise<void> {
+	public async update(
+		file: Pick<DriveFile, "userId" | "size">,
+		isAdditional: boolean,
+	): Promise<void> {
 		const fileSizeKb = file.size / 1000;
 		await this.commit(
 			{
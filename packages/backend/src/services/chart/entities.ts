@@ -10,6 +10,7 @@ import { entity as HashtagChart } from "./charts/entities/hashtag.js";
 import { entity as PerUserFollowingChart } from "./charts/entities/per-user-following.js";
 import { entity as PerUserDriveChart } from "./charts/entities/per-user-drive.js";
 import { entity as ApRequestChart } from "./charts/entities/ap-request.js";
+import { sketchEntity } from "./native.js";
 
 import { entity as TestChart } from "./charts/entities/test.js";
 import { entity as TestGroupedChart } from "./charts/entities/test-grouped.js";
@@ -44,6 +45,7 @@ export const entities = [
 
 	...(process.env.NODE_ENV === "test"
 		? [
+				sketchEntity,
 				TestChart.hour,
 				TestChart.day,
 				TestGroupedChart.hour,
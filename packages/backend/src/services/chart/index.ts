@@ -1,3 +1,4 @@
+import { nativeSaveStats } from "native-utils/built/index.js";
 import { beforeShutdown } from "@/misc/before-shutdown.js";
 
 import FederationChart from "./charts/federation.js";
@@ -46,6 +47,9 @@ setInterval(() => {
 	for (const chart of charts) {
 		chart.save();
 	}
+	nativeSaveStats();
 }, 1000 * 60 * 20);
 
-beforeShutdown(() => Promise.all(charts.map((chart) => chart.save())));
+beforeShutdown(() =>
+	Promise.all([...charts.map((chart) => chart.save()), nativeSaveStats()]),
+);
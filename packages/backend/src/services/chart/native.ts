@@ -0,0 +1,128 @@
+/**
+ * Charts saved by the native engine, with the same interface as `Chart` of
+ * core.ts. Their schemas are declared in native-utils as well.
+ */
+
+import * as nestedProperty from "nested-property";
+import { EntitySchema } from "typeorm";
+import {
+	nativeChartClean,
+	nativeChartCommit,
+	nativeChartRange,
+	nativeChartSave,
+	nativeChartTick,
+} from "native-utils/built/index.js";
+import type { KVs } from "./core.js";
+
+type Schema = Record<
+	string,
+	{
+		uniqueIncrement?: boolean;
+		intersection?: string[] | ReadonlyArray<string>;
+		range?: "big" | "small" | "medium";
+		accumulate?: boolean;
+	}
+>;
+
+type Commit<S extends Schema> = {
+	[K in keyof S]?: S[K]["uniqueIncrement"] extends true ? string[] : number;
+};
+
+/**
+ * Sketches of the unique columns, which are created by the migration of
+ * native-utils and only declared here for the tests.
+ */
+export const sketchEntity = new EntitySchema({
+	name: "chart_sketch",
+	columns: {
+		chart: { type: "varchar", length: 128, primary: true },
+		date: { type: "integer", primary: true },
+		group: { type: "varchar", length: 128, primary: true },
+		key: { type: "varchar", length: 128, primary: true },
+		sketch: { type: "bytea" },
+	},
+});
+
+export default abstract class NativeChart<T extends Schema> {
+	public schema: T;
+
+	private name: string;
+
+	protected abstract tickMajor(group: string | null): Promise<Partial<KVs<T>>>;
+
+	protected abstract tickMinor(group: string | null): Promise<Partial<KVs<T>>>;
+
+	constructor(name: string, schema: T) {
+		this.name = name;
+		this.schema = schema;
+	}
+
+	protected commit(diff: Commit<T>, group: string | null = null): void {
+		const counts: Record<string, number> = {};
+		const uniques: Record<string, string[]> = {};
+		for (const [k, v] of Object.entries(diff)) {
+			if (Array.isArray(v)) {
+				uniques[k] = v;
+			} else if (typeof v === "number") {
+				counts[k] = v;
+			}
+		}
+		nativeChartCommit(this.name, group, counts, uniques, Date.now());
+	}
+
+	public async save(): Promise<void> {
+		await nativeChartSave(this.name);
+	}
+
+	public async tick(
+		major: boolean,
+		group: string | null = null,
+	): Promise<void> {
+		const data = major
+			? await this.tickMajor(group)
+			: await this.tickMinor(group);
+		await nativeChartTick(
+			this.name,
+			group,
+			data as Record<string, number>,
+			Date.now(),
+		);
+	}
+
+	public resync(group: string | null = null): Promise<void> {
+		return this.tick(true, group);
+	}
+
+	public async clean(): Promise<void> {
+		await nativeChartClean(this.name, Date.now());
+	}
+
+	public async getChartRaw(
+		span: "hour" | "day",
+		amount: number,
+		cursor: Date | null,
+		group: string | null = null,
+	): Promise<Record<keyof T, number[]>> {
+		return (await nativeChartRange(
+			this.name,
+			span,
+			amount,
+			cursor?.getTime() ?? Date.now(),
+			group,
+		)) as Record<keyof T, number[]>;
+	}
+
+	public async getChart(
+		span: "hour" | "day",
+		amount: number,
+		cursor: Date | null,
+		group: string | null = null,
+	): Promise<any> {
+		const result = await this.getChartRaw(span, amount, cursor, group);
+		const object = {};
+		for (const [k, v] of Object.entries(result)) {
+			nestedProperty.set(object, k, v);
+		}
+		return object;
+	}
+}
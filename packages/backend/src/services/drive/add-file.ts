@@ -2,12 +2,10 @@ import * as fs from "node:fs";
 
 import { v4 as uuid } from "uuid";
 
-import type S3 from "aws-sdk/clients/s3.js";
 import sharp from "sharp";
 import { IsNull } from "typeorm";
 import { publishMainStream, publishDriveStream } from "@/services/stream.js";
 import { fetchMeta } from "@/misc/fetch-meta.js";
-import { contentDisposition } from "@/misc/content-disposition.js";
 import { getFileInfo } from "@/misc/get-file-info.js";
 import {
 	DriveFiles,
@@ -17,7 +15,7 @@ import {
 	UserProfiles,
 } from "@/models/index.js";
 import { DriveFile } from "@/models/entities/drive-file.js";
-import type { IRemoteUser, User } from "@/models/entities/user.js";
+import type { User } from "@/models/entities/user.js";
 import {
 	driveChart,
 	perUserDriveChart,
@@ -27,12 +25,23 @@ import { genId } from "@/misc/gen-id.js";
 import { isDuplicateKeyValueError } from "@/misc/is-duplicate-key-value-error.js";
 import { FILE_TYPE_BROWSERSAFE } from "@/const.js";
 import { IdentifiableError } from "@/misc/identifiable-error.js";
-import { getS3 } from "./s3.js";
 import { InternalStorage } from "./internal-storage.js";
 import type { IImage } from "./image-processor.js";
 import { convertSharpToWebp } from "./image-processor.js";
 import { driveLogger } from "./logger.js";
 import { GenerateVideoThumbnail } from "./generate-video-thumbnail.js";
+import {
+	nativeAcquireDriveBlob,
+	nativeCheckDriveCapacity,
+	nativeGenerateImageAlts,
+	nativePutDriveObject,
+	nativePutDriveObjectFromPath,
+	nativeReleaseDriveBlob,
+	nativeSensitiveThresholds,
+	nativeShouldDetectSensitive,
+	nativeUpdateDriveUsage,
+} from "native-utils/built/index.js";
+import { fromNativeError, NativeError } from "@/misc/native-error.js";
 import { deleteFile } from "./delete-file.js";
 
 const logger = driveLogger.createSubLogger("register", "yellow");
@@ -53,6 +62,44 @@ async function save(
 	hash: string,
 	size: number,
 ): Promise<DriveFile> {
+	// share the objects of a file with the same content
+	const blob = await nativeAcquireDriveBlob(hash, size, !file.uri);
+	if (blob) {
+		logger.info(`sharing objects with the same content: ${blob.accessKey}`);
+
+		file.storedInternal = blob.storedInternal;
+		file.url = blob.url;
+		file.thumbnailUrl = blob.thumbnailUrl ?? null;
+		file.webpublicUrl = blob.webpublicUrl ?? null;
+		file.accessKey = blob.accessKey;
+		file.thumbnailAccessKey = blob.thumbnailAccessKey ?? null;
+		file.webpublicAccessKey = blob.webpublicAccessKey ?? null;
+		file.webpublicType = blob.webpublicType ?? null;
+		file.name = name;
+		file.type = type;
+		file.md5 = hash;
+		file.size = size;
+
+		return await DriveFiles.insert(file)
+			.then((x) => DriveFiles.findOneByOrFail(x.identifiers[0]))
+			.catch(async (err) => {
+				// the linked internal objects belong to the new file only
+				if (
+					(await nativeReleaseDriveBlob(blob.accessKey)) &&
+					blob.storedInternal
+				) {
+					InternalStorage.del(blob.accessKey);
+					if (blob.thumbnailAccessKey) {
+						InternalStorage.del(blob.thumbnailAccessKey);
+					}
+					if (blob.webpublicAccessKey) {
+						InternalStorage.del(blob.webpublicAccessKey);
+					}
+				}
+				throw err;
+			});
+	}
+
 	// thunbnail, webpublic を必要なら生成
 	const alts = await generateAlts(path, type, !file.uri);
 
@@ -77,54 +124,32 @@ async function save(
 			ext = "";
 		}
 
-		const baseUrl =
-			meta.objectStorageBaseUrl ||
-			`${meta.objectStorageUseSSL ? "https" : "http"}://${
-				meta.objectStorageEndpoint
-			}${meta.objectStoragePort ? `:${meta.objectStoragePort}` : ""}/${
-				meta.objectStorageBucket
-			}`;
-
 		// for original
 		const key = `${meta.objectStoragePrefix}/${uuid()}${ext}`;
-		const url = `${baseUrl}/${key}`;
 
 		// for alts
-		let webpublicKey: string | null = null;
-		let webpublicUrl: string | null = null;
-		let thumbnailKey: string | null = null;
-		let thumbnailUrl: string | null = null;
+		const webpublicKey = alts.webpublic
+			? `${meta.objectStoragePrefix}/webpublic-${uuid()}.${alts.webpublic.ext}`
+			: null;
+		const thumbnailKey = alts.thumbnail
+			? `${meta.objectStoragePrefix}/thumbnail-${uuid()}.${alts.thumbnail.ext}`
+			: null;
 		//#endregion
 
 		//#region Uploads
 		logger.info(`uploading original: ${key}`);
-		const uploads = [upload(key, fs.createReadStream(path), type, name)];
-
-		if (alts.webpublic) {
-			webpublicKey = `${meta.objectStoragePrefix}/webpublic-${uuid()}.${
-				alts.webpublic.ext
-			}`;
-			webpublicUrl = `${baseUrl}/${webpublicKey}`;
-
-			logger.info(`uploading webpublic: ${webpublicKey}`);
-			uploads.push(
-				upload(webpublicKey, alts.webpublic.data, alts.webpublic.type, name),
-			);
-		}
-
-		if (alts.thumbnail) {
-			thumbnailKey = `${meta.objectStoragePrefix}/thumbnail-${uuid()}.${
-				alts.thumbnail.ext
-			}`;
-			thumbnailUrl = `${baseUrl}/${thumbnailKey}`;
-
-			logger.info(`uploading thumbnail: ${thumbnailKey}`);
-			uploads.push(
-				upload(thumbnailKey, alts.thumbnail.data, alts.thumbnail.type),
-			);
-		}
-
-		await Promise.all(uploads);
+		if (webpublicKey) logger.info(`uploading webpublic: ${webpublicKey}`);
+		if (thumbnailKey) logger.info(`uploading thumbnail: ${thumbnailKey}`);
+
+		const [url, webpublicUrl, thumbnailUrl] = await Promise.all([
+			upload(key, path, type, name),
+			alts.webpublic && webpublicKey
+				? upload(webpublicKey, alts.webpublic.data, alts.webpublic.type, name)
+				: null,
+			alts.thumbnail && thumbnailKey
+				? upload(thumbnailKey, alts.thumbnail.data, alts.thumbnail.type)
+				: null,
+		]);
 		//#endregion
 
 		file.url = url;
@@ -217,14 +242,30 @@ export async function generateAlts(
 	}
 
 	if (
-		![
+		[
 			"image/jpeg",
 			"image/png",
 			"image/webp",
-			"image/svg+xml",
-			"image/avif",
+			"image/gif",
+			"image/apng",
 		].includes(type)
 	) {
+		try {
+			return await nativeGenerateImageAlts(path, type, generateWeb);
+		} catch (err) {
+			logger.warn(
+				"web image and thumbnail not created (an error occured)",
+				err as Error,
+			);
+			return {
+				webpublic: null,
+				thumbnail: null,
+			};
+		}
+	}
+
+	// formats not supported by the native decoder
+	if (!["image/svg+xml", "image/avif"].includes(type)) {
 		logger.debug("web image and thumbnail not created (not an required file)");
 		return {
 			webpublic: null,
@@ -250,7 +291,6 @@ export async function generateAlts(
 
 		satisfyWebpublic = !!(
 			type !== "image/svg+xml" &&
-			type !== "image/webp" &&
 			!(
 				metadata.exif ||
 				metadata.iptc ||
@@ -277,13 +317,7 @@ export async function generateAlts(
 		logger.info("creating web image");
 
 		try {
-			if (["image/jpeg"].includes(type)) {
-				webpublic = await convertSharpToWebp(img, 2048, 2048);
-			} else if (["image/webp"].includes(type)) {
-				webpublic = await convertSharpToWebp(img, 2048, 2048);
-			} else if (["image/png"].includes(type)) {
-				webpublic = await convertSharpToWebp(img, 2048, 2048, 100);
-			} else if (["image/svg+xml"].includes(type)) {
+			if (["image/svg+xml"].includes(type)) {
 				webpublic = await convertSharpToWebp(img, 2048, 2048);
 			} else {
 				logger.debug("web image not created (not an required image)");
@@ -302,15 +336,7 @@ export async function generateAlts(
 	let thumbnail: IImage | null = null;
 
 	try {
-		if (
-			[
-				"image/jpeg",
-				"image/webp",
-				"image/png",
-				"image/svg+xml",
-				"image/avif",
-			].includes(type)
-		) {
+		if (["image/svg+xml", "image/avif"].includes(type)) {
 			thumbnail = await convertSharpToWebp(img, 996, 560);
 		} else {
 			logger.debug("thumbnail not created (not an required file)");
@@ -331,73 +357,21 @@ export async function generateAlts(
  */
 async function upload(
 	key: string,
-	stream: fs.ReadStream | Buffer,
+	source: string | Buffer,
 	type: string,
 	filename?: string,
-) {
+): Promise<string> {
 	if (type === "image/apng") type = "image/png";
 	if (!FILE_TYPE_BROWSERSAFE.includes(type)) type = "application/octet-stream";
 
-	const meta = await fetchMeta();
-
-	const params = {
-		Bucket: meta.objectStorageBucket,
-		Key: key,
-		Body: stream,
-		ContentType: type,
-		CacheControl: "max-age=31536000, immutable",
-	} as S3.PutObjectRequest;
-
-	if (filename)
-		params.ContentDisposition = contentDisposition("inline", filename);
-	if (meta.objectStorageSetPublicRead) params.ACL = "public-read";
-
-	const s3 = getS3(meta);
-
-	const upload = s3.upload(params, {
-		partSize:
-			s3.endpoint.hostname === "storage.googleapis.com"
-				? 500 * 1024 * 1024
-				: 8 * 1024 * 1024,
+	const url = await (typeof source === "string"
+		? nativePutDriveObjectFromPath(key, source, type, filename)
+		: nativePutDriveObject(key, source, type, filename)
+	).catch((e) => {
+		throw fromNativeError(e);
 	});
-
-	const result = await upload.promise();
-	if (result)
-		logger.debug(
-			`Uploaded: ${result.Bucket}/${result.Key} => ${result.Location}`,
-		);
-}
-
-async function expireOldFile(user: IRemoteUser, driveCapacity: number) {
-	const q = DriveFiles.createQueryBuilder("file")
-		.where("file.userId = :userId", { userId: user.id })
-		.andWhere("file.isLink = FALSE");
-
-	if (user.avatarId) {
-		q.andWhere("file.id != :avatarId", { avatarId: user.avatarId });
-	}
-
-	if (user.bannerId) {
-		q.andWhere("file.id != :bannerId", { bannerId: user.bannerId });
-	}
-
-	//This selete is hard coded, be careful if change database schema
-	q.addSelect(
-		'SUM("file"."size") OVER (ORDER BY "file"."id" DESC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)',
-		"acc_usage",
-	);
-
-	q.orderBy("file.id", "ASC");
-
-	const fileList = await q.getRawMany();
-	const exceedFileIds = fileList
-		.filter((x: any) => x.acc_usage > driveCapacity)
-		.map((x: any) => x.file_id);
-
-	for (const fileId of exceedFileIds) {
-		const file = await DriveFiles.findOneBy({ id: fileId });
-		deleteFile(file, true);
-	}
+	logger.debug(`Uploaded: ${key} => ${url}`);
+	return url;
 }
 
 type AddFileArgs = {
@@ -448,36 +422,20 @@ export async function addFile({
 	requestIp = null,
 	requestHeaders = null,
 }: AddFileArgs): Promise<DriveFile> {
-	let skipNsfwCheck = false;
 	const instance = await fetchMeta();
-	if (user == null) skipNsfwCheck = true;
-	if (instance.sensitiveMediaDetection === "none") skipNsfwCheck = true;
-	if (
-		user &&
-		instance.sensitiveMediaDetection === "local" &&
-		Users.isRemoteUser(user)
-	)
-		skipNsfwCheck = true;
-	if (
-		user &&
-		instance.sensitiveMediaDetection === "remote" &&
-		Users.isLocalUser(user)
-	)
-		skipNsfwCheck = true;
+	const skipNsfwCheck = !nativeShouldDetectSensitive(
+		instance.sensitiveMediaDetection,
+		user ? Users.isLocalUser(user) : undefined,
+	);
+	// 感度が高いほどしきい値は低くすることになる
+	const thresholds = nativeSensitiveThresholds(
+		instance.sensitiveMediaDetectionSensitivity,
+	);
 
 	const info = await getFileInfo(path, {
 		skipSensitiveDetection: skipNsfwCheck,
-		sensitiveThreshold: // 感度が高いほどしきい値は低くすることになる
-			instance.sensitiveMediaDetectionSensitivity === "veryHigh"
-				? 0.1
-				: instance.sensitiveMediaDetectionSensitivity === "high"
-				? 0.3
-				: instance.sensitiveMediaDetectionSensitivity === "low"
-				? 0.7
-				: instance.sensitiveMediaDetectionSensitivity === "veryLow"
-				? 0.9
-				: 0.5,
-		sensitiveThresholdForPorn: 0.75,
+		sensitiveThreshold: thresholds.sensitive,
+		sensitiveThresholdForPorn: thresholds.porn,
 		enableSensitiveMediaDetectionForVideos:
 			instance.enableSensitiveMediaDetectionForVideos,
 	});
@@ -507,43 +465,24 @@ export async function addFile({
 
 	//#region Check drive usage
 	if (user && !isLink) {
-		const usage = await DriveFiles.calcDriveUsageOf(user);
-		const u = await Users.findOneBy({ id: user.id });
-
-		const instance = await fetchMeta();
-		let driveCapacity =
-			1024 *
-			1024 *
-			(Users.isLocalUser(user)
-				? instance.localDriveCapacityMb
-				: instance.remoteDriveCapacityMb);
-
-		if (Users.isLocalUser(user) && u?.driveCapacityOverrideMb != null) {
-			driveCapacity = 1024 * 1024 * u.driveCapacityOverrideMb;
-			logger.debug("drive capacity override applied");
-			logger.debug(
-				`overrideCap: ${driveCapacity}bytes, usage: ${usage}bytes, u+s: ${
-					usage + info.size
-				}bytes`,
-			);
-		}
-
-		logger.debug(`drive usage is ${usage} (max: ${driveCapacity})`);
-
-		// If usage limit exceeded
-		if (usage + info.size > driveCapacity) {
-			if (Users.isLocalUser(user)) {
+		const expiredFileIds = await nativeCheckDriveCapacity(
+			user.id,
+			info.size,
+		).catch((e) => {
+			const err = fromNativeError(e);
+			if (err instanceof NativeError && err.code === "NO_FREE_SPACE") {
 				throw new IdentifiableError(
 					"c6244ed2-a39a-4e1c-bf93-f0fbd7764fa6",
 					"No free space.",
 				);
-			} else {
-				// (アバターまたはバナーを含まず)最も古いファイルを削除する
-				expireOldFile(
-					(await Users.findOneByOrFail({ id: user.id })) as IRemoteUser,
-					driveCapacity - info.size,
-				);
 			}
+			throw err;
+		});
+
+		// (アバターまたはバナーを含まず)最も古いファイルを削除する
+		for (const fileId of expiredFileIds) {
+			const file = await DriveFiles.findOneBy({ id: fileId });
+			if (file) deleteFile(file, true);
 		}
 	}
 	//#endregion
@@ -663,6 +602,12 @@ export async function addFile({
 
 	logger.succ(`drive file has been created ${file.id}`);
 
+	if (user && !isLink) {
+		nativeUpdateDriveUsage(user.id, file.size).catch((e) =>
+			logger.warn(`failed to update drive usage: ${e}`),
+		);
+	}
+
 	if (user) {
 		DriveFiles.pack(file, { self: true }).then((packedFile) => {
 			// Publish driveFileCreated event
@@ -7,30 +7,35 @@ import {
 	instanceChart,
 } from "@/services/chart/index.js";
 import { createDeleteObjectStorageFileJob } from "@/queue/index.js";
-import { fetchMeta } from "@/misc/fetch-meta.js";
-import { getS3 } from "./s3.js";
 import { v4 as uuid } from "uuid";
+import {
+	nativeDeleteDriveObject,
+	nativeReleaseDriveBlob,
+	nativeUpdateDriveUsage,
+} from "native-utils/built/index.js";
 
 export async function deleteFile(file: DriveFile, isExpired = false) {
-	if (file.storedInternal) {
-		InternalStorage.del(file.accessKey!);
-
-		if (file.thumbnailUrl) {
-			InternalStorage.del(file.thumbnailAccessKey!);
-		}
-
-		if (file.webpublicUrl) {
-			InternalStorage.del(file.webpublicAccessKey!);
-		}
-	} else if (!file.isLink) {
-		createDeleteObjectStorageFileJob(file.accessKey!);
-
-		if (file.thumbnailUrl) {
-			createDeleteObjectStorageFileJob(file.thumbnailAccessKey!);
-		}
-
-		if (file.webpublicUrl) {
-			createDeleteObjectStorageFileJob(file.webpublicAccessKey!);
+	if (await releaseObjects(file)) {
+		if (file.storedInternal) {
+			InternalStorage.del(file.accessKey!);
+
+			if (file.thumbnailUrl) {
+				InternalStorage.del(file.thumbnailAccessKey!);
+			}
+
+			if (file.webpublicUrl) {
+				InternalStorage.del(file.webpublicAccessKey!);
+			}
+		} else {
+			createDeleteObjectStorageFileJob(file.accessKey!);
+
+			if (file.thumbnailUrl) {
+				createDeleteObjectStorageFileJob(file.thumbnailAccessKey!);
+			}
+
+			if (file.webpublicUrl) {
+				createDeleteObjectStorageFileJob(file.webpublicAccessKey!);
+			}
 		}
 	}
 
@@ -38,36 +43,51 @@ export async function deleteFile(file: DriveFile, isExpired = false) {
 }
 
 export async function deleteFileSync(file: DriveFile, isExpired = false) {
-	if (file.storedInternal) {
-		InternalStorage.del(file.accessKey!);
+	if (await releaseObjects(file)) {
+		if (file.storedInternal) {
+			InternalStorage.del(file.accessKey!);
 
-		if (file.thumbnailUrl) {
-			InternalStorage.del(file.thumbnailAccessKey!);
-		}
+			if (file.thumbnailUrl) {
+				InternalStorage.del(file.thumbnailAccessKey!);
+			}
 
-		if (file.webpublicUrl) {
-			InternalStorage.del(file.webpublicAccessKey!);
-		}
-	} else if (!file.isLink) {
-		const promises = [];
+			if (file.webpublicUrl) {
+				InternalStorage.del(file.webpublicAccessKey!);
+			}
+		} else {
+			const promises = [];
 
-		promises.push(deleteObjectStorageFile(file.accessKey!));
+			promises.push(deleteObjectStorageFile(file.accessKey!));
 
-		if (file.thumbnailUrl) {
-			promises.push(deleteObjectStorageFile(file.thumbnailAccessKey!));
-		}
+			if (file.thumbnailUrl) {
+				promises.push(deleteObjectStorageFile(file.thumbnailAccessKey!));
+			}
 
-		if (file.webpublicUrl) {
-			promises.push(deleteObjectStorageFile(file.webpublicAccessKey!));
-		}
+			if (file.webpublicUrl) {
+				promises.push(deleteObjectStorageFile(file.webpublicAccessKey!));
+			}
 
-		await Promise.all(promises);
+			await Promise.all(promises);
+		}
 	}
 
 	postProcess(file, isExpired);
 }
 
+/**
+ * Drops the reference of the file to its objects, and returns whether the
+ * objects are no longer shared with other files and have to be deleted.
+ */
+async function releaseObjects(file: DriveFile): Promise<boolean> {
+	if (file.isLink || file.accessKey == null) return false;
+	return await nativeReleaseDriveBlob(file.accessKey);
+}
+
 async function postProcess(file: DriveFile, isExpired = false) {
+	if (file.userId !== null && !file.isLink) {
+		nativeUpdateDriveUsage(file.userId, -file.size).catch(() => {});
+	}
+
 	// リモートファイル期限切れ削除後は直リンクにする
 	if (isExpired && file.userHost !== null && file.uri != null) {
 		DriveFiles.update(file.id, {
@@ -94,14 +114,5 @@ async function postProcess(file: DriveFile, isExpired = false) {
 }
 
 export async function deleteObjectStorageFile(key: string) {
-	const meta = await fetchMeta();
-
-	const s3 = getS3(meta);
-
-	await s3
-		.deleteObject({
-			Bucket: meta.objectStorageBucket!,
-			Key: key,
-		})
-		.promise();
+	await nativeDeleteDriveObject(key, false);
 }
@@ -1,4 +1,16 @@
 import sharp from "sharp";
+import { nativeConvertToWebp, nativeInitDrive } from "native-utils/built/index.js";
+import config from "@/config/index.js";
+import { InternalStorage } from "./internal-storage.js";
+
+nativeInitDrive({
+	maxImagePixels: config.maxImagePixels,
+	filesDir: InternalStorage.path,
+	url: config.url,
+	sensitiveMediaModel: config.sensitiveMediaModel,
+	proxy: config.proxy,
+	proxyBypassHosts: config.proxyBypassHosts,
+});
 
 export type IImage = {
 	data: Buffer;
@@ -16,7 +28,9 @@ export async function convertToWebp(
 	height: number,
 	quality = 85,
 ): Promise<IImage> {
-	return convertSharpToWebp(await sharp(path), width, height, quality);
+	const image = await nativeConvertToWebp(path, width, height, quality);
+	// fall back to sharp for formats not supported natively, e.g. SVG and AVIF
+	return image ?? convertSharpToWebp(await sharp(path), width, height, quality);
 }
 
 export async function convertSharpToWebp(
@@ -0,0 +1,50 @@
+import { nativeInitLogger } from "native-utils/built/index.js";
+import type { NativeLogRecord } from "native-utils/built/index.js";
+import Logger from "@/services/logger.js";
+import { envOption } from "../env.js";
+
+const logger = new Logger("native", "gray");
+const moduleLoggers = new Map<string, Logger>();
+
+function getModuleLogger(module: string): Logger {
+	const name = module.replace(/^native_utils::/, "");
+	let moduleLogger = moduleLoggers.get(name);
+	if (moduleLogger == null) {
+		moduleLogger = logger.createSubLogger(name);
+		moduleLoggers.set(name, moduleLogger);
+	}
+	return moduleLogger;
+}
+
+function log(record: NativeLogRecord): void {
+	const moduleLogger = getModuleLogger(record.module);
+	const fields = Object.entries(record.fields)
+		.map(([key, value]) => `${key}=${value}`)
+		.join(" ");
+	const message = fields ? `${record.message} (${fields})` : record.message;
+
+	switch (record.level) {
+		case "error":
+			moduleLogger.error(message, record.fields);
+			break;
+		case "warn":
+			moduleLogger.warn(message, record.fields);
+			break;
+		case "info":
+			moduleLogger.info(message, record.fields);
+			break;
+		default:
+			moduleLogger.debug(message, record.fields);
+	}
+}
+
+/**
+ * Forwards the logs of native-utils, including the queries that take longer
+ * than 300ms, to the logger.
+ */
+export function initNativeLogger(): void {
+	nativeInitLogger(log, {
+		level: envOption.verbose ? "debug" : "info",
+		slowSpanThreshold: 300,
+	});
+}
@@ -65,9 +65,15 @@ import { db } from "@/db/postgre.js";
 import { getActiveWebhooks } from "@/misc/webhook-cache.js";
 import { shouldSilenceInstance } from "@/misc/should-block-instance.js";
 import meilisearch from "../../db/meilisearch.js";
+import nativeSearch from "../../db/native-search.js";
+import { nativeFanOutNote, nativeIndexNotes } from "native-utils/built/index.js";
+import type { Antenna } from "@/models/entities/antenna.js";
+import Logger from "../logger.js";
 import { redisClient } from "@/db/redis.js";
 import { Mutex } from "redis-semaphore";
 
+const logger = new Logger("note:create");
+
 const mutedWordsCache = new Cache<
 	{ userId: UserProfile["userId"]; mutedWords: UserProfile["mutedWords"] }[]
 >("mutedWords", 60 * 5);
@@ -381,14 +387,33 @@ export default async (
 				}
 			});
 
-		// Antenna
-		for (const antenna of await getAntennas()) {
-			checkHitAntenna(antenna, note, user).then((hit) => {
-				if (hit) {
-					addNoteToAntenna(antenna, note, user);
+		// Push into the home timelines and the timelines of the hit antennas
+		const antennas = await getAntennas();
+		Promise.all(
+			antennas.map((antenna) =>
+				checkHitAntenna(antenna, note, user).then((hit) =>
+					hit ? antenna : null,
+				),
+			),
+		)
+			.then(async (hits) => {
+				const hitAntennas = hits.filter(
+					(antenna): antenna is Antenna => antenna != null,
+				);
+				try {
+					await nativeFanOutNote(
+						note.id,
+						hitAntennas.map((antenna) => antenna.id),
+					);
+				} finally {
+					for (const antenna of hitAntennas) {
+						addNoteToAntenna(antenna, note, user);
+					}
 				}
+			})
+			.catch((e) => {
+				logger.error(`Failed to fan out ${note.id}: ${e}`);
 			});
-		}
 
 		// Channel
 		if (note.channelId) {
@@ -836,6 +861,10 @@ export async function index(note: Note, reindexing: boolean): Promise<void> {
 	if (meilisearch && !reindexing) {
 		await meilisearch.ingestNote(note);
 	}
+
+	if (nativeSearch && !reindexing) {
+		await nativeIndexNotes([note.id]);
+	}
 }
 
 async function notifyToWatchersOfRenotee(
@@ -22,6 +22,8 @@ import { countSameRenotes } from "@/misc/count-same-renotes.js";
 import { registerOrFetchInstanceDoc } from "../register-or-fetch-instance-doc.js";
 import { deliverToRelays } from "../relay.js";
 import meilisearch from "@/db/meilisearch.js";
+import nativeSearch from "@/db/native-search.js";
+import { nativeDeleteNotesFromIndex } from "native-utils/built/index.js";
 
 /**
  * 投稿を削除します。
@@ -124,6 +126,10 @@ export default async function (
 	if (meilisearch) {
 		await meilisearch.deleteNotes(note.id);
 	}
+
+	if (nativeSearch) {
+		await nativeDeleteNotesFromIndex([note.id]);
+	}
 }
 
 async function findCascadingNotes(note: Note) {
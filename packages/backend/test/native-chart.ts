@@ -0,0 +1,404 @@
+process.env.NODE_ENV = "test";
+
+import * as assert from "assert";
+import * as lolex from "@sinonjs/fake-timers";
+import type { KVs } from "../src/services/chart/core.js";
+import Chart from "../src/services/chart/core.js";
+import NativeChart from "../src/services/chart/native.js";
+import ApRequestChart from "../src/services/chart/charts/ap-request.js";
+import TestChart from "../src/services/chart/charts/test.js";
+import TestGroupedChart from "../src/services/chart/charts/test-grouped.js";
+import TestUniqueChart from "../src/services/chart/charts/test-unique.js";
+import TestIntersectionChart from "../src/services/chart/charts/test-intersection.js";
+import * as apRequest from "../src/services/chart/charts/entities/ap-request.js";
+import * as test from "../src/services/chart/charts/entities/test.js";
+import * as testGrouped from "../src/services/chart/charts/entities/test-grouped.js";
+import * as testUnique from "../src/services/chart/charts/entities/test-unique.js";
+import * as testIntersection from "../src/services/chart/charts/entities/test-intersection.js";
+import { initDb } from "../src/db/postgre.js";
+
+/**
+ * The test charts of charts/*.ts, saved by the native engine
+ */
+
+class NativeTestChart extends NativeChart<typeof test.schema> {
+	public total = 0;
+
+	constructor() {
+		super(test.name, test.schema);
+	}
+
+	protected async tickMajor(): Promise<Partial<KVs<typeof test.schema>>> {
+		return {
+			"foo.total": this.total,
+		};
+	}
+
+	protected async tickMinor(): Promise<Partial<KVs<typeof test.schema>>> {
+		return {};
+	}
+
+	public async increment(): Promise<void> {
+		this.total++;
+
+		await this.commit({
+			"foo.total": 1,
+			"foo.inc": 1,
+		});
+	}
+
+	public async decrement(): Promise<void> {
+		this.total--;
+
+		await this.commit({
+			"foo.total": -1,
+			"foo.dec": 1,
+		});
+	}
+}
+
+class NativeTestGroupedChart extends NativeChart<typeof testGrouped.schema> {
+	private total = {} as Record<string, number>;
+
+	constructor() {
+		super(testGrouped.name, testGrouped.schema);
+	}
+
+	protected async tickMajor(
+		group: string,
+	): Promise<Partial<KVs<typeof testGrouped.schema>>> {
+		return {
+			"foo.total": this.total[group],
+		};
+	}
+
+	protected async tickMinor(): Promise<
+		Partial<KVs<typeof testGrouped.schema>>
+	> {
+		return {};
+	}
+
+	public async increment(group: string): Promise<void> {
+		if (this.total[group] == null) this.total[group] = 0;
+
+		this.total[group]++;
+
+		await this.commit(
+			{
+				"foo.total": 1,
+				"foo.inc": 1,
+			},
+			group,
+		);
+	}
+}
+
+class NativeTestUniqueChart extends NativeChart<typeof testUnique.schema> {
+	constructor() {
+		super(testUnique.name, testUnique.schema);
+	}
+
+	protected async tickMajor(): Promise<
+		Partial<KVs<typeof testUnique.schema>>
+	> {
+		return {};
+	}
+
+	protected async tickMinor(): Promise<
+		Partial<KVs<typeof testUnique.schema>>
+	> {
+		return {};
+	}
+
+	public async uniqueIncrement(key: string): Promise<void> {
+		await this.commit({
+			foo: [key],
+		});
+	}
+}
+
+class NativeTestIntersectionChart extends NativeChart<
+	typeof testIntersection.schema
+> {
+	constructor() {
+		super(testIntersection.name, testIntersection.schema);
+	}
+
+	protected async tickMajor(): Promise<
+		Partial<KVs<typeof testIntersection.schema>>
+	> {
+		return {};
+	}
+
+	protected async tickMinor(): Promise<
+		Partial<KVs<typeof testIntersection.schema>>
+	> {
+		return {};
+	}
+
+	public async addA(key: string): Promise<void> {
+		await this.commit({
+			a: [key],
+		});
+	}
+
+	public async addB(key: string): Promise<void> {
+		await this.commit({
+			b: [key],
+		});
+	}
+}
+
+/**
+ * `ApRequestChart` as it was saved by core.ts, which the native one replaces
+ */
+
+class CoreApRequestChart extends Chart<typeof apRequest.schema> {
+	constructor() {
+		super(apRequest.name, apRequest.schema);
+	}
+
+	protected async tickMajor(): Promise<
+		Partial<KVs<typeof apRequest.schema>>
+	> {
+		return {};
+	}
+
+	protected async tickMinor(): Promise<
+		Partial<KVs<typeof apRequest.schema>>
+	> {
+		return {};
+	}
+
+	public async deliverSucc(): Promise<void> {
+		await this.commit({
+			deliverSucceeded: 1,
+		});
+	}
+
+	public async deliverFail(): Promise<void> {
+		await this.commit({
+			deliverFailed: 1,
+		});
+	}
+
+	public async inbox(): Promise<void> {
+		await this.commit({
+			inboxReceived: 1,
+		});
+	}
+}
+
+type Charts = {
+	apRequest: CoreApRequestChart | ApRequestChart;
+	test: TestChart | NativeTestChart;
+	grouped: TestGroupedChart | NativeTestGroupedChart;
+	unique: TestUniqueChart | NativeTestUniqueChart;
+	intersection: TestIntersectionChart | NativeTestIntersectionChart;
+};
+
+type Scenario = (charts: Charts, clock: lolex.InstalledClock) => Promise<any>;
+
+/**
+ * Runs `scenario` with the charts of core.ts and of the native engine, each on
+ * an empty database, and asserts that both return the same results.
+ */
+async function assertParity(scenario: Scenario): Promise<void> {
+	const run = async (charts: Charts) => {
+		await initDb(true);
+		const clock = lolex.install({
+			now: new Date(Date.UTC(2000, 0, 1, 0, 0, 0)),
+			shouldClearNativeTimers: true,
+		});
+		try {
+			return await scenario(charts, clock);
+		} finally {
+			clock.uninstall();
+		}
+	};
+
+	const core = await run({
+		apRequest: new CoreApRequestChart(),
+		test: new TestChart(),
+		grouped: new TestGroupedChart(),
+		unique: new TestUniqueChart(),
+		intersection: new TestIntersectionChart(),
+	});
+	const native = await run({
+		apRequest: new ApRequestChart(),
+		test: new NativeTestChart(),
+		grouped: new NativeTestGroupedChart(),
+		unique: new NativeTestUniqueChart(),
+		intersection: new NativeTestIntersectionChart(),
+	});
+	assert.deepStrictEqual(native, core);
+}
+
+describe("Native chart", () => {
+	it("Can updates", () =>
+		assertParity(async ({ test }) => {
+			await test.increment();
+			await test.decrement();
+			await test.increment();
+			await test.save();
+
+			return [
+				await test.getChart("hour", 3, null),
+				await test.getChart("day", 3, null),
+			];
+		}));
+
+	it("Empty chart", () =>
+		assertParity(async ({ test }) => [
+			await test.getChart("hour", 3, null),
+			await test.getChart("day", 3, null),
+		]));
+
+	it("複数回saveされてもデータの更新は一度だけ", () =>
+		assertParity(async ({ test }) => {
+			await test.increment();
+			await test.save();
+			await test.save();
+			await test.save();
+
+			return [
+				await test.getChart("hour", 3, null),
+				await test.getChart("day", 3, null),
+			];
+		}));
+
+	it("Can updates at different times", () =>
+		assertParity(async ({ test }, clock) => {
+			await test.increment();
+			await test.save();
+
+			clock.tick("01:00:00");
+
+			await test.increment();
+			await test.save();
+
+			clock.tick("1:00:00:00");
+
+			await test.decrement();
+			await test.save();
+
+			return [
+				await test.getChart("hour", 30, null),
+				await test.getChart("day", 3, null),
+			];
+		}));
+
+	it("Can padding from past range", () =>
+		assertParity(async ({ test }, clock) => {
+			await test.increment();
+			await test.save();
+
+			clock.tick("05:00:00");
+
+			return [
+				await test.getChart("hour", 3, null),
+				await test.getChart("day", 3, null),
+			];
+		}));
+
+	it("Can specify offset", () =>
+		assertParity(async ({ test }, clock) => {
+			await test.increment();
+			await test.save();
+
+			clock.tick("01:00:00");
+
+			await test.increment();
+			await test.save();
+
+			const cursor = new Date(Date.UTC(2000, 0, 1, 0, 0, 0));
+			return [
+				await test.getChart("hour", 3, cursor),
+				await test.getChart("day", 3, cursor),
+			];
+		}));
+
+	it("Can updates grouped charts", () =>
+		assertParity(async ({ grouped }) => {
+			await grouped.increment("alice");
+			await grouped.increment("alice");
+			await grouped.save();
+
+			return [
+				await grouped.getChart("hour", 3, null, "alice"),
+				await grouped.getChart("day", 3, null, "alice"),
+				await grouped.getChart("hour", 3, null, "bob"),
+				await grouped.getChart("day", 3, null, "bob"),
+			];
+		}));
+
+	it("Can updates unique increments", () =>
+		assertParity(async ({ unique }, clock) => {
+			await unique.uniqueIncrement("alice");
+			await unique.uniqueIncrement("alice");
+			await unique.uniqueIncrement("bob");
+			await unique.save();
+
+			clock.tick("01:00:00");
+
+			await unique.uniqueIncrement("alice");
+			await unique.uniqueIncrement("carol");
+			await unique.save();
+
+			return [
+				await unique.getChart("hour", 3, null),
+				await unique.getChart("day", 3, null),
+			];
+		}));
+
+	it("Can updates intersections", () =>
+		assertParity(async ({ intersection }) => {
+			await intersection.addA("alice");
+			await intersection.addA("bob");
+			await intersection.addB("carol");
+			await intersection.addB("alice");
+			await intersection.save();
+
+			return [
+				await intersection.getChart("hour", 3, null),
+				await intersection.getChart("day", 3, null),
+			];
+		}));
+
+	it("Can resync", () =>
+		assertParity(async ({ test }, clock) => {
+			await test.increment();
+			await test.save();
+
+			clock.tick("01:00:00");
+
+			test.total = 100;
+
+			await test.resync();
+
+			return [
+				await test.getChart("hour", 3, null),
+				await test.getChart("day", 3, null),
+			];
+		}));
+
+	it("Can updates the ActivityPub requests", () =>
+		assertParity(async ({ apRequest }, clock) => {
+			await apRequest.deliverSucc();
+			await apRequest.deliverSucc();
+			await apRequest.deliverFail();
+			await apRequest.inbox();
+			await apRequest.save();
+
+			clock.tick("01:00:00");
+
+			await apRequest.inbox();
+			await apRequest.tick(false);
+			await apRequest.save();
+
+			return [
+				await apRequest.getChart("hour", 3, null),
+				await apRequest.getChart("day", 3, null),
+			];
+		}));
+});